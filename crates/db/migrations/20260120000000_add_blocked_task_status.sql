@@ -0,0 +1,62 @@
+-- Add 'blocked' to the allowed task statuses.
+-- SQLite cannot alter a CHECK constraint in place, so the tasks table is rebuilt.
+
+-- sqlx workaround: end auto-transaction to allow PRAGMA to take effect
+COMMIT;
+
+PRAGMA foreign_keys = OFF;
+
+BEGIN TRANSACTION;
+
+CREATE TABLE tasks_new (
+    id                  BLOB PRIMARY KEY,
+    project_id          BLOB NOT NULL,
+    title               TEXT NOT NULL,
+    description         TEXT,
+    status              TEXT NOT NULL DEFAULT 'todo'
+                           CHECK (status IN ('todo','inprogress','done','cancelled','inreview','blocked')),
+    created_at          TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    updated_at          TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    parent_workspace_id BLOB REFERENCES workspaces(id),
+    shared_task_id      BLOB,
+    position            INTEGER,
+    dag_position_x      REAL,
+    dag_position_y      REAL,
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+INSERT INTO tasks_new (
+    id, project_id, title, description, status, created_at, updated_at,
+    parent_workspace_id, shared_task_id, position, dag_position_x, dag_position_y
+)
+SELECT
+    id, project_id, title, description, status, created_at, updated_at,
+    parent_workspace_id, shared_task_id, position, dag_position_x, dag_position_y
+FROM tasks;
+
+DROP TABLE tasks;
+
+ALTER TABLE tasks_new RENAME TO tasks;
+
+-- Rebuild indexes dropped along with the original table
+CREATE INDEX idx_tasks_project_created_at
+    ON tasks (project_id, created_at DESC);
+
+CREATE UNIQUE INDEX idx_tasks_shared_task_unique
+    ON tasks(shared_task_id)
+    WHERE shared_task_id IS NOT NULL;
+
+CREATE INDEX idx_tasks_parent_workspace_id ON tasks(parent_workspace_id);
+
+CREATE INDEX idx_tasks_project_position ON tasks(project_id, position);
+
+-- Verify foreign key constraints before committing the transaction
+PRAGMA foreign_key_check;
+
+COMMIT;
+
+PRAGMA foreign_keys = ON;
+
+-- sqlx workaround due to lack of `-- no-transaction` in sqlx-sqlite.
+-- Starts a new empty transaction for sqlx to close successfully.
+BEGIN TRANSACTION;
@@ -0,0 +1,7 @@
+-- Add kind to task_dependencies to describe when a dependency blocks its dependent
+-- 'strict': the prerequisite must be done
+-- 'skip_if_cancelled': a cancelled prerequisite also counts as satisfied
+-- 'optional': never blocks, only drawn as an edge
+
+ALTER TABLE task_dependencies ADD COLUMN kind TEXT NOT NULL DEFAULT 'strict'
+    CHECK (kind IN ('strict', 'skip_if_cancelled', 'optional'));
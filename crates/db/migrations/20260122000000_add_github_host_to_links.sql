@@ -0,0 +1,2 @@
+-- GitHub Enterprise 用のホスト名を追加（NULL の場合は github.com）
+ALTER TABLE github_project_links ADD COLUMN github_host TEXT;
@@ -0,0 +1,2 @@
+-- GitHub Projectから外されたIssueのマッピングを示すフラグを追加
+ALTER TABLE github_issue_mappings ADD COLUMN stale INTEGER NOT NULL DEFAULT 0;
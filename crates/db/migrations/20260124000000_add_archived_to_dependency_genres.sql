@@ -0,0 +1,3 @@
+-- Add archived flag to dependency_genres
+-- Archived genres keep categorizing existing dependencies but are hidden from pickers
+ALTER TABLE dependency_genres ADD COLUMN archived INTEGER NOT NULL DEFAULT 0;
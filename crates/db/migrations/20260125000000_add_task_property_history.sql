@@ -0,0 +1,13 @@
+-- Audit trail of task property values, e.g. to follow GitHub status churn
+-- One row per upsert that actually changed an existing value
+CREATE TABLE task_property_history (
+    id              BLOB PRIMARY KEY,
+    task_id         BLOB NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    property_name   TEXT NOT NULL,
+    old_value       TEXT NOT NULL,
+    new_value       TEXT NOT NULL,
+    source          TEXT NOT NULL DEFAULT 'vibe' CHECK(source IN ('vibe', 'github')),
+    changed_at      TEXT NOT NULL DEFAULT (datetime('now', 'subsec'))
+);
+CREATE INDEX idx_task_property_history_task_name
+    ON task_property_history(task_id, property_name, changed_at);
@@ -0,0 +1,3 @@
+-- Kind of work a task represents; existing tasks are treated as features
+ALTER TABLE tasks ADD COLUMN task_type TEXT NOT NULL DEFAULT 'feature'
+    CHECK (task_type IN ('feature','bug','chore'));
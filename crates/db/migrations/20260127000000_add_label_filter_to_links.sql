@@ -0,0 +1,2 @@
+-- Only import issues carrying this label (matched case-insensitively); NULL imports every issue
+ALTER TABLE github_project_links ADD COLUMN label_filter TEXT;
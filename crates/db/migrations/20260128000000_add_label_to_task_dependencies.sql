@@ -0,0 +1,4 @@
+-- Add a short free-text label to dependency edges (e.g. "schema", "API contract")
+-- Independent of genre_id; NULL means the edge is unlabelled
+
+ALTER TABLE task_dependencies ADD COLUMN label TEXT;
@@ -0,0 +1,52 @@
+-- Per-project counter bumped whenever a task or dependency changes, so cached execution plans
+-- can tell they are stale no matter which code path wrote the change
+CREATE TABLE task_graph_versions (
+    project_id  BLOB PRIMARY KEY,
+    version     INTEGER NOT NULL DEFAULT 0
+);
+
+CREATE TRIGGER bump_task_graph_version_on_task_insert
+AFTER INSERT ON tasks
+BEGIN
+    INSERT INTO task_graph_versions (project_id, version) VALUES (NEW.project_id, 1)
+    ON CONFLICT(project_id) DO UPDATE SET version = version + 1;
+END;
+
+CREATE TRIGGER bump_task_graph_version_on_task_update
+AFTER UPDATE ON tasks
+BEGIN
+    INSERT INTO task_graph_versions (project_id, version) VALUES (NEW.project_id, 1)
+    ON CONFLICT(project_id) DO UPDATE SET version = version + 1;
+END;
+
+CREATE TRIGGER bump_task_graph_version_on_task_delete
+AFTER DELETE ON tasks
+BEGIN
+    INSERT INTO task_graph_versions (project_id, version) VALUES (OLD.project_id, 1)
+    ON CONFLICT(project_id) DO UPDATE SET version = version + 1;
+END;
+
+-- Dependencies removed along with their task are already covered by the task trigger
+CREATE TRIGGER bump_task_graph_version_on_dependency_insert
+AFTER INSERT ON task_dependencies
+BEGIN
+    INSERT INTO task_graph_versions (project_id, version)
+    SELECT project_id, 1 FROM tasks WHERE id = NEW.task_id
+    ON CONFLICT(project_id) DO UPDATE SET version = version + 1;
+END;
+
+CREATE TRIGGER bump_task_graph_version_on_dependency_update
+AFTER UPDATE ON task_dependencies
+BEGIN
+    INSERT INTO task_graph_versions (project_id, version)
+    SELECT project_id, 1 FROM tasks WHERE id = NEW.task_id
+    ON CONFLICT(project_id) DO UPDATE SET version = version + 1;
+END;
+
+CREATE TRIGGER bump_task_graph_version_on_dependency_delete
+AFTER DELETE ON task_dependencies
+BEGIN
+    INSERT INTO task_graph_versions (project_id, version)
+    SELECT project_id, 1 FROM tasks WHERE id = OLD.task_id
+    ON CONFLICT(project_id) DO UPDATE SET version = version + 1;
+END;
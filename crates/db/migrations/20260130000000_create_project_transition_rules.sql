@@ -0,0 +1,10 @@
+-- Custom status transitions per project; a project without rows uses the built-in matrix
+CREATE TABLE project_transition_rules (
+    project_id  BLOB NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    from_status TEXT NOT NULL
+                   CHECK (from_status IN ('todo','inprogress','done','cancelled','inreview','blocked')),
+    to_status   TEXT NOT NULL
+                   CHECK (to_status IN ('todo','inprogress','done','cancelled','inreview','blocked')),
+    position    INTEGER NOT NULL,
+    PRIMARY KEY (project_id, from_status, to_status)
+);
@@ -1,9 +1,13 @@
+use std::collections::HashMap;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::{Executor, FromRow, Sqlite, SqlitePool};
 use ts_rs::TS;
 use uuid::Uuid;
 
+use super::task_dependency::TaskDependency;
+
 /// Represents a genre/category for task dependencies
 /// Genres are project-specific and can be created dynamically
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize, TS)]
@@ -13,10 +17,21 @@ pub struct DependencyGenre {
     pub name: String,
     pub color: String,
     pub position: i32,
+    /// Hidden from pickers but still used to render the dependencies it categorizes
+    pub archived: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+/// A genre together with the number of dependencies that use it
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct DependencyGenreWithUsage {
+    #[serde(flatten)]
+    #[ts(flatten)]
+    pub genre: DependencyGenre,
+    pub usage_count: usize,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 pub struct CreateDependencyGenre {
     pub project_id: Uuid,
@@ -37,6 +52,14 @@ pub struct ReorderGenresRequest {
     pub genre_ids: Vec<Uuid>,
 }
 
+/// Genres seeded into a project that has none yet, as (name, color) pairs
+pub const DEFAULT_GENRES: [(&str, &str); 4] = [
+    ("技術的依存", "#3B82F6"),
+    ("データ依存", "#10B981"),
+    ("デザイン依存", "#F59E0B"),
+    ("外部依存", "#EF4444"),
+];
+
 impl DependencyGenre {
     /// Find a genre by its ID
     pub async fn find_by_id(pool: &SqlitePool, id: Uuid) -> Result<Option<Self>, sqlx::Error> {
@@ -48,6 +71,7 @@ impl DependencyGenre {
                 name,
                 color,
                 position as "position!: i32",
+                archived as "archived!: bool",
                 created_at as "created_at!: DateTime<Utc>",
                 updated_at as "updated_at!: DateTime<Utc>"
             FROM dependency_genres
@@ -68,6 +92,7 @@ impl DependencyGenre {
                 name,
                 color,
                 position as "position!: i32",
+                archived as "archived!: bool",
                 created_at as "created_at!: DateTime<Utc>",
                 updated_at as "updated_at!: DateTime<Utc>"
             FROM dependency_genres
@@ -78,11 +103,16 @@ impl DependencyGenre {
         .await
     }
 
-    /// Find all genres for a project, ordered by position
-    pub async fn find_by_project_id(
-        pool: &SqlitePool,
+    /// Find the genres of a project, ordered by position.
+    /// Archived genres are only included when `include_archived` is set.
+    pub async fn find_by_project_id<'e, E>(
+        executor: E,
         project_id: Uuid,
-    ) -> Result<Vec<Self>, sqlx::Error> {
+        include_archived: bool,
+    ) -> Result<Vec<Self>, sqlx::Error>
+    where
+        E: Executor<'e, Database = Sqlite>,
+    {
         sqlx::query_as!(
             DependencyGenre,
             r#"SELECT
@@ -91,14 +121,16 @@ impl DependencyGenre {
                 name,
                 color,
                 position as "position!: i32",
+                archived as "archived!: bool",
                 created_at as "created_at!: DateTime<Utc>",
                 updated_at as "updated_at!: DateTime<Utc>"
             FROM dependency_genres
-            WHERE project_id = $1
+            WHERE project_id = $1 AND ($2 OR archived = FALSE)
             ORDER BY position ASC, created_at ASC"#,
-            project_id
+            project_id,
+            include_archived
         )
-        .fetch_all(pool)
+        .fetch_all(executor)
         .await
     }
 
@@ -116,6 +148,7 @@ impl DependencyGenre {
                 name,
                 color,
                 position as "position!: i32",
+                archived as "archived!: bool",
                 created_at as "created_at!: DateTime<Utc>",
                 updated_at as "updated_at!: DateTime<Utc>"
             FROM dependency_genres
@@ -127,6 +160,31 @@ impl DependencyGenre {
         .await
     }
 
+    /// Count the dependencies referencing each genre of a project.
+    /// Genres that are not used by any dependency are included with a count of zero.
+    pub async fn usage_counts(
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<HashMap<Uuid, usize>, sqlx::Error> {
+        let rows = sqlx::query!(
+            r#"SELECT
+                dg.id as "genre_id!: Uuid",
+                COUNT(td.id) as "usage_count!: i64"
+            FROM dependency_genres dg
+            LEFT JOIN task_dependencies td ON td.genre_id = dg.id
+            WHERE dg.project_id = $1
+            GROUP BY dg.id"#,
+            project_id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| (row.genre_id, row.usage_count as usize))
+            .collect())
+    }
+
     /// Get the next position for a new genre in a project
     async fn get_next_position(pool: &SqlitePool, project_id: Uuid) -> Result<i32, sqlx::Error> {
         let result = sqlx::query_scalar!(
@@ -142,12 +200,24 @@ impl DependencyGenre {
 
     /// Create a new genre
     pub async fn create(pool: &SqlitePool, data: &CreateDependencyGenre) -> Result<Self, sqlx::Error> {
-        let id = Uuid::new_v4();
-        let color = data.color.clone().unwrap_or_else(|| "#808080".to_string());
         let position = match data.position {
             Some(p) => p,
             None => Self::get_next_position(pool, data.project_id).await?,
         };
+        Self::create_at_position(pool, data, position).await
+    }
+
+    /// Create a new genre at an already resolved position, ignoring `data.position`
+    pub async fn create_at_position<'e, E>(
+        executor: E,
+        data: &CreateDependencyGenre,
+        position: i32,
+    ) -> Result<Self, sqlx::Error>
+    where
+        E: Executor<'e, Database = Sqlite>,
+    {
+        let id = Uuid::new_v4();
+        let color = data.color.clone().unwrap_or_else(|| "#808080".to_string());
 
         sqlx::query_as!(
             DependencyGenre,
@@ -159,6 +229,7 @@ impl DependencyGenre {
                    name,
                    color,
                    position as "position!: i32",
+                   archived as "archived!: bool",
                    created_at as "created_at!: DateTime<Utc>",
                    updated_at as "updated_at!: DateTime<Utc>""#,
             id,
@@ -167,10 +238,32 @@ impl DependencyGenre {
             color,
             position
         )
-        .fetch_one(pool)
+        .fetch_one(executor)
         .await
     }
 
+    /// Insert the default genres for a project.
+    /// Does nothing if the project already has any genre, so it is safe to call repeatedly.
+    pub async fn seed_defaults(pool: &SqlitePool, project_id: Uuid) -> Result<Vec<Self>, sqlx::Error> {
+        let existing = Self::find_by_project_id(pool, project_id, true).await?;
+        if !existing.is_empty() {
+            return Ok(existing);
+        }
+
+        let mut genres = Vec::with_capacity(DEFAULT_GENRES.len());
+        for (position, (name, color)) in DEFAULT_GENRES.iter().enumerate() {
+            let data = CreateDependencyGenre {
+                project_id,
+                name: name.to_string(),
+                color: Some(color.to_string()),
+                position: Some(position as i32),
+            };
+            genres.push(Self::create(pool, &data).await?);
+        }
+
+        Ok(genres)
+    }
+
     /// Update a genre
     pub async fn update(
         pool: &SqlitePool,
@@ -196,6 +289,7 @@ impl DependencyGenre {
                    name,
                    color,
                    position as "position!: i32",
+                   archived as "archived!: bool",
                    created_at as "created_at!: DateTime<Utc>",
                    updated_at as "updated_at!: DateTime<Utc>""#,
             id,
@@ -207,6 +301,33 @@ impl DependencyGenre {
         .await
     }
 
+    /// Archive or restore a genre
+    pub async fn set_archived(
+        pool: &SqlitePool,
+        id: Uuid,
+        archived: bool,
+    ) -> Result<Self, sqlx::Error> {
+        sqlx::query_as!(
+            DependencyGenre,
+            r#"UPDATE dependency_genres
+               SET archived = $2, updated_at = datetime('now', 'subsec')
+               WHERE id = $1
+               RETURNING
+                   id as "id!: Uuid",
+                   project_id as "project_id!: Uuid",
+                   name,
+                   color,
+                   position as "position!: i32",
+                   archived as "archived!: bool",
+                   created_at as "created_at!: DateTime<Utc>",
+                   updated_at as "updated_at!: DateTime<Utc>""#,
+            id,
+            archived
+        )
+        .fetch_one(pool)
+        .await
+    }
+
     /// Delete a genre by its ID
     pub async fn delete<'e, E>(executor: E, id: Uuid) -> Result<u64, sqlx::Error>
     where
@@ -218,6 +339,32 @@ impl DependencyGenre {
         Ok(result.rows_affected())
     }
 
+    /// Merge `source_id` into `target_id`: dependencies using the source genre are moved to the
+    /// target and the source genre is deleted, all in one transaction. Returns the target genre.
+    pub async fn merge(
+        pool: &SqlitePool,
+        source_id: Uuid,
+        target_id: Uuid,
+    ) -> Result<Self, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+        TaskDependency::reassign_genre(&mut *tx, source_id, Some(target_id)).await?;
+        Self::delete(&mut *tx, source_id).await?;
+        tx.commit().await?;
+
+        Self::find_by_id(pool, target_id)
+            .await?
+            .ok_or(sqlx::Error::RowNotFound)
+    }
+
+    /// Delete a genre after untagging the dependencies that use it, in one transaction
+    pub async fn delete_clearing_references(pool: &SqlitePool, id: Uuid) -> Result<u64, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+        TaskDependency::reassign_genre(&mut *tx, id, None).await?;
+        let rows_affected = Self::delete(&mut *tx, id).await?;
+        tx.commit().await?;
+        Ok(rows_affected)
+    }
+
     /// Reorder genres by updating their positions based on the provided order
     pub async fn reorder(pool: &SqlitePool, genre_ids: &[Uuid]) -> Result<Vec<Self>, sqlx::Error> {
         // Update positions for each genre based on its index in the array
@@ -238,7 +385,7 @@ impl DependencyGenre {
         if let Some(first_id) = genre_ids.first()
             && let Some(first_genre) = Self::find_by_id(pool, *first_id).await?
         {
-            return Self::find_by_project_id(pool, first_genre.project_id).await;
+            return Self::find_by_project_id(pool, first_genre.project_id, false).await;
         }
 
         Ok(vec![])
@@ -248,6 +395,56 @@ impl DependencyGenre {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::{
+        project::{CreateProject, Project},
+        task::{CreateTask, Task},
+        task_dependency::CreateTaskDependency,
+    };
+
+    async fn create_test_project(pool: &SqlitePool) -> Uuid {
+        let project_id = Uuid::new_v4();
+        let project = CreateProject {
+            name: "Genre Test".to_string(),
+            repositories: vec![],
+        };
+        Project::create(pool, &project, project_id).await.unwrap();
+        project_id
+    }
+
+    async fn create_test_genre(pool: &SqlitePool, project_id: Uuid, name: &str) -> DependencyGenre {
+        let data = CreateDependencyGenre {
+            project_id,
+            name: name.to_string(),
+            color: None,
+            position: None,
+        };
+        DependencyGenre::create(pool, &data).await.unwrap()
+    }
+
+    /// Create two fresh tasks and a dependency between them tagged with `genre_id`
+    async fn create_test_dependency(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        genre_id: Option<Uuid>,
+    ) -> TaskDependency {
+        let mut task_ids = Vec::new();
+        for title in ["Upstream", "Downstream"] {
+            let task_id = Uuid::new_v4();
+            let data = CreateTask::from_title_description(project_id, title.to_string(), None);
+            Task::create(pool, &data, task_id).await.unwrap();
+            task_ids.push(task_id);
+        }
+
+        let data = CreateTaskDependency {
+            task_id: task_ids[1],
+            depends_on_task_id: task_ids[0],
+            created_by: None,
+            genre_id,
+            kind: None,
+            label: None,
+        };
+        TaskDependency::create(pool, &data).await.unwrap()
+    }
 
     #[test]
     fn test_create_dependency_genre_defaults() {
@@ -260,4 +457,118 @@ mod tests {
         assert!(data.color.is_none());
         assert!(data.position.is_none());
     }
+
+    #[sqlx::test]
+    async fn test_seed_defaults_is_idempotent(pool: SqlitePool) {
+        let project_id = create_test_project(&pool).await;
+
+        let first = DependencyGenre::seed_defaults(&pool, project_id).await.unwrap();
+        assert_eq!(first.len(), DEFAULT_GENRES.len());
+
+        let second = DependencyGenre::seed_defaults(&pool, project_id).await.unwrap();
+        assert_eq!(second.len(), DEFAULT_GENRES.len());
+
+        let stored = DependencyGenre::find_by_project_id(&pool, project_id, false)
+            .await
+            .unwrap();
+        assert_eq!(stored.len(), DEFAULT_GENRES.len());
+        let positions: Vec<i32> = stored.iter().map(|g| g.position).collect();
+        assert_eq!(positions, vec![0, 1, 2, 3]);
+    }
+
+    #[sqlx::test]
+    async fn test_usage_counts(pool: SqlitePool) {
+        let project_id = create_test_project(&pool).await;
+        let used = create_test_genre(&pool, project_id, "技術的依存").await;
+        let unused = create_test_genre(&pool, project_id, "データ依存").await;
+
+        create_test_dependency(&pool, project_id, Some(used.id)).await;
+        create_test_dependency(&pool, project_id, Some(used.id)).await;
+        create_test_dependency(&pool, project_id, None).await;
+
+        let counts = DependencyGenre::usage_counts(&pool, project_id).await.unwrap();
+        assert_eq!(counts.get(&used.id), Some(&2));
+        assert_eq!(counts.get(&unused.id), Some(&0));
+    }
+
+    #[sqlx::test]
+    async fn test_merge_reassigns_dependencies(pool: SqlitePool) {
+        let project_id = create_test_project(&pool).await;
+        let source = create_test_genre(&pool, project_id, "DB").await;
+        let target = create_test_genre(&pool, project_id, "database").await;
+
+        let moved = create_test_dependency(&pool, project_id, Some(source.id)).await;
+        create_test_dependency(&pool, project_id, Some(target.id)).await;
+
+        let merged = DependencyGenre::merge(&pool, source.id, target.id).await.unwrap();
+        assert_eq!(merged.id, target.id);
+
+        let moved = TaskDependency::find_by_id(&pool, moved.id).await.unwrap().unwrap();
+        assert_eq!(moved.genre_id, Some(target.id));
+        assert!(DependencyGenre::find_by_id(&pool, source.id).await.unwrap().is_none());
+
+        let counts = DependencyGenre::usage_counts(&pool, project_id).await.unwrap();
+        assert_eq!(counts.get(&target.id), Some(&2));
+    }
+
+    #[sqlx::test]
+    async fn test_delete_clearing_references(pool: SqlitePool) {
+        let project_id = create_test_project(&pool).await;
+        let genre = create_test_genre(&pool, project_id, "技術的依存").await;
+        let dependency = create_test_dependency(&pool, project_id, Some(genre.id)).await;
+
+        let deleted = DependencyGenre::delete_clearing_references(&pool, genre.id)
+            .await
+            .unwrap();
+        assert_eq!(deleted, 1);
+        assert!(DependencyGenre::find_by_id(&pool, genre.id).await.unwrap().is_none());
+
+        let dependency = TaskDependency::find_by_id(&pool, dependency.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(dependency.genre_id.is_none());
+    }
+
+    #[sqlx::test]
+    async fn test_archived_genres_hidden_from_default_listing(pool: SqlitePool) {
+        let project_id = create_test_project(&pool).await;
+        let active = create_test_genre(&pool, project_id, "技術的依存").await;
+        let old = create_test_genre(&pool, project_id, "旧ジャンル").await;
+        let dependency = create_test_dependency(&pool, project_id, Some(old.id)).await;
+
+        let archived = DependencyGenre::set_archived(&pool, old.id, true)
+            .await
+            .unwrap();
+        assert!(archived.archived);
+
+        let listed = DependencyGenre::find_by_project_id(&pool, project_id, false)
+            .await
+            .unwrap();
+        let ids: Vec<Uuid> = listed.iter().map(|g| g.id).collect();
+        assert_eq!(ids, vec![active.id]);
+
+        let all = DependencyGenre::find_by_project_id(&pool, project_id, true)
+            .await
+            .unwrap();
+        assert_eq!(all.len(), 2);
+
+        // Still resolvable by id, with its color, for the dependencies it categorizes
+        let resolved = DependencyGenre::find_by_id(&pool, old.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(resolved.archived);
+        assert_eq!(resolved.color, old.color);
+        let dependency = TaskDependency::find_by_id(&pool, dependency.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(dependency.genre_id, Some(old.id));
+
+        let restored = DependencyGenre::set_archived(&pool, old.id, false)
+            .await
+            .unwrap();
+        assert!(!restored.archived);
+    }
 }
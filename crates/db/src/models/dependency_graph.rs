@@ -0,0 +1,639 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use ts_rs::TS;
+use uuid::Uuid;
+
+use super::{
+    dependency_genre::{CreateDependencyGenre, DependencyGenre},
+    task::{CreateTask, DagPositionUpdate, Task},
+    task_dependency::{CreateTaskDependency, DependencyCreator, DependencyKind, TaskDependency},
+};
+
+/// Portable snapshot of a project's dependency graph
+/// Tasks and genres are referenced by title and name so the document can be applied to
+/// another project with matching tasks.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct DependencyGraphExport {
+    pub genres: Vec<ExportedGenre>,
+    pub dependencies: Vec<ExportedDependency>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct ExportedGenre {
+    pub name: String,
+    pub color: String,
+    pub position: i32,
+}
+
+/// A dependency edge: the task titled `task` depends on the task titled `depends_on`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+pub struct ExportedDependency {
+    pub task: String,
+    pub depends_on: String,
+    pub genre: Option<String>,
+    #[serde(default)]
+    pub kind: DependencyKind,
+    #[serde(default)]
+    pub label: Option<String>,
+}
+
+/// Outcome of importing a dependency graph
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+pub struct DependencyImportReport {
+    pub created_genres: usize,
+    pub created_dependencies: usize,
+    /// Edges that already existed in the project
+    pub skipped_duplicates: usize,
+    /// Edges left out because they would close a cycle
+    pub skipped_cycles: Vec<ExportedDependency>,
+    /// Titles with no task in the project; edges referencing them are skipped
+    pub unmatched_titles: Vec<String>,
+    /// Titles shared by several tasks in the project; edges referencing them are skipped
+    pub ambiguous_titles: Vec<String>,
+}
+
+/// Outcome of cloning a project's task graph into another project
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+pub struct GraphCloneReport {
+    /// Source task id to the id of the task created for it in the target project
+    #[ts(type = "{ [key in string]?: string }")]
+    pub task_ids: HashMap<Uuid, Uuid>,
+    pub created_genres: usize,
+    pub created_dependencies: usize,
+    /// Edges left out because they would close a cycle in the target project
+    pub skipped_cycles: usize,
+}
+
+/// How a title resolved against the project's tasks
+enum TitleMatch {
+    Unique(Uuid),
+    Ambiguous,
+}
+
+impl DependencyGraphExport {
+    /// Snapshot the genres and dependency edges of a project
+    pub async fn from_project(pool: &SqlitePool, project_id: Uuid) -> Result<Self, sqlx::Error> {
+        let tasks = Task::find_by_project_id(pool, project_id).await?;
+        let genres = DependencyGenre::find_by_project_id(pool, project_id, true).await?;
+        let dependencies = TaskDependency::find_by_project_id(pool, project_id).await?;
+
+        let titles: HashMap<Uuid, &str> = tasks.iter().map(|t| (t.id, t.title.as_str())).collect();
+        let genre_names: HashMap<Uuid, &str> =
+            genres.iter().map(|g| (g.id, g.name.as_str())).collect();
+
+        let dependencies = dependencies
+            .iter()
+            .filter_map(|dep| {
+                Some(ExportedDependency {
+                    task: titles.get(&dep.task_id)?.to_string(),
+                    depends_on: titles.get(&dep.depends_on_task_id)?.to_string(),
+                    genre: dep
+                        .genre_id
+                        .and_then(|id| genre_names.get(&id))
+                        .map(|name| name.to_string()),
+                    kind: dep.kind,
+                    label: dep.label.clone(),
+                })
+            })
+            .collect();
+
+        let genres = genres
+            .into_iter()
+            .map(|g| ExportedGenre {
+                name: g.name,
+                color: g.color,
+                position: g.position,
+            })
+            .collect();
+
+        Ok(Self {
+            genres,
+            dependencies,
+        })
+    }
+
+    /// Apply this snapshot to a project in one transaction
+    ///
+    /// Titles are matched against the project's tasks and missing genres are created by name.
+    /// Duplicate edges, edges that would create a cycle and edges with unmatched or ambiguous
+    /// titles are skipped and listed in the report instead of failing the import.
+    pub async fn import(
+        &self,
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<DependencyImportReport, sqlx::Error> {
+        let tasks = Task::find_by_project_id(pool, project_id).await?;
+        let existing_genres = DependencyGenre::find_by_project_id(pool, project_id, true).await?;
+
+        let mut titles: HashMap<&str, TitleMatch> = HashMap::new();
+        for task in &tasks {
+            titles
+                .entry(task.title.as_str())
+                .and_modify(|m| *m = TitleMatch::Ambiguous)
+                .or_insert(TitleMatch::Unique(task.id));
+        }
+
+        let mut genre_ids: HashMap<String, Uuid> = existing_genres
+            .iter()
+            .map(|g| (g.name.clone(), g.id))
+            .collect();
+        let mut next_position = existing_genres
+            .iter()
+            .map(|g| g.position + 1)
+            .max()
+            .unwrap_or(0);
+
+        let mut report = DependencyImportReport::default();
+        let mut tx = pool.begin().await?;
+
+        let mut genres: Vec<&ExportedGenre> = self.genres.iter().collect();
+        genres.sort_by_key(|g| g.position);
+        for genre in genres {
+            if genre_ids.contains_key(&genre.name) {
+                continue;
+            }
+            let data = CreateDependencyGenre {
+                project_id,
+                name: genre.name.clone(),
+                color: Some(genre.color.clone()),
+                position: None,
+            };
+            let created =
+                DependencyGenre::create_at_position(&mut *tx, &data, next_position).await?;
+            next_position += 1;
+            genre_ids.insert(created.name, created.id);
+            report.created_genres += 1;
+        }
+
+        for edge in &self.dependencies {
+            let task_id = resolve_title(&titles, &edge.task, &mut report);
+            let depends_on_task_id = resolve_title(&titles, &edge.depends_on, &mut report);
+            let (Some(task_id), Some(depends_on_task_id)) = (task_id, depends_on_task_id) else {
+                continue;
+            };
+
+            if task_id == depends_on_task_id
+                || TaskDependency::would_create_cycle(&mut *tx, task_id, depends_on_task_id).await?
+            {
+                report.skipped_cycles.push(edge.clone());
+                continue;
+            }
+            if TaskDependency::exists(&mut *tx, task_id, depends_on_task_id).await? {
+                report.skipped_duplicates += 1;
+                continue;
+            }
+
+            let data = CreateTaskDependency {
+                task_id,
+                depends_on_task_id,
+                created_by: Some(DependencyCreator::User),
+                genre_id: edge
+                    .genre
+                    .as_ref()
+                    .and_then(|name| genre_ids.get(name).copied()),
+                kind: Some(edge.kind),
+                label: edge.label.clone(),
+            };
+            TaskDependency::create(&mut *tx, &data).await?;
+            report.created_dependencies += 1;
+        }
+
+        tx.commit().await?;
+        Ok(report)
+    }
+}
+
+/// Recreate a project's tasks and dependency graph in another project in one transaction
+///
+/// Every source task gets a fresh Todo task with the same title and description, genres are
+/// reused or created by name, and each edge is rebuilt against the new task ids. Edges that
+/// would close a cycle with tasks already in the target are skipped and counted.
+pub async fn clone_graph(
+    pool: &SqlitePool,
+    source_project_id: Uuid,
+    target_project_id: Uuid,
+) -> Result<GraphCloneReport, sqlx::Error> {
+    // Read the source inside the transaction so the clone is a consistent snapshot
+    let mut tx = pool.begin().await?;
+    let mut tasks = Task::find_by_project_id(&mut *tx, source_project_id).await?;
+    let source_genres =
+        DependencyGenre::find_by_project_id(&mut *tx, source_project_id, true).await?;
+    let dependencies = TaskDependency::find_by_project_id(&mut *tx, source_project_id).await?;
+    let target_genres =
+        DependencyGenre::find_by_project_id(&mut *tx, target_project_id, true).await?;
+
+    let mut genre_ids: HashMap<String, Uuid> = target_genres
+        .iter()
+        .map(|g| (g.name.clone(), g.id))
+        .collect();
+    let mut next_position = target_genres
+        .iter()
+        .map(|g| g.position + 1)
+        .max()
+        .unwrap_or(0);
+
+    let mut report = GraphCloneReport::default();
+
+    // Genre ids in the source project mapped to their counterpart in the target
+    let mut cloned_genres: HashMap<Uuid, Uuid> = HashMap::new();
+    let mut source_genres: Vec<&DependencyGenre> = source_genres.iter().collect();
+    source_genres.sort_by_key(|g| g.position);
+    for genre in source_genres {
+        let genre_id = match genre_ids.get(&genre.name) {
+            Some(id) => *id,
+            None => {
+                let data = CreateDependencyGenre {
+                    project_id: target_project_id,
+                    name: genre.name.clone(),
+                    color: Some(genre.color.clone()),
+                    position: None,
+                };
+                let created =
+                    DependencyGenre::create_at_position(&mut *tx, &data, next_position).await?;
+                next_position += 1;
+                report.created_genres += 1;
+                genre_ids.insert(created.name, created.id);
+                created.id
+            }
+        };
+        cloned_genres.insert(genre.id, genre_id);
+    }
+
+    // Keep the source creation order so the clone lists the same way
+    tasks.sort_by_key(|task| (task.created_at, task.id));
+    for task in &tasks {
+        let data = CreateTask::from_title_description(
+            target_project_id,
+            task.title.clone(),
+            task.description.clone(),
+        );
+        let new_id = Uuid::new_v4();
+        Task::create(&mut *tx, &data, new_id).await?;
+        report.task_ids.insert(task.id, new_id);
+    }
+
+    for dep in &dependencies {
+        let (Some(&task_id), Some(&depends_on_task_id)) = (
+            report.task_ids.get(&dep.task_id),
+            report.task_ids.get(&dep.depends_on_task_id),
+        ) else {
+            continue;
+        };
+        if TaskDependency::would_create_cycle(&mut *tx, task_id, depends_on_task_id).await? {
+            report.skipped_cycles += 1;
+            continue;
+        }
+
+        let data = CreateTaskDependency {
+            task_id,
+            depends_on_task_id,
+            created_by: Some(DependencyCreator::User),
+            genre_id: dep.genre_id.and_then(|id| cloned_genres.get(&id).copied()),
+            kind: Some(dep.kind),
+            label: dep.label.clone(),
+        };
+        TaskDependency::create(&mut *tx, &data).await?;
+        report.created_dependencies += 1;
+    }
+
+    tx.commit().await?;
+    Ok(report)
+}
+
+// DAG layout spacing, matching the node size used by the frontend
+const NODE_WIDTH: f64 = 220.0;
+const NODE_HEIGHT: f64 = 80.0;
+const HORIZONTAL_SPACING: f64 = 120.0;
+const VERTICAL_SPACING: f64 = 40.0;
+
+/// Lay out the project's tasks in columns, one column per level of `levels`
+///
+/// Levels come from the orchestrator's leveling of the dependency graph, so each task goes one
+/// column to the right of its deepest dependency; tasks not in any level keep their position.
+/// Tasks within a column are stacked in creation order, so the layout only depends on the graph
+/// and repeated calls leave positions untouched. Returns the number of tasks whose position
+/// changed.
+pub async fn apply_layout(
+    pool: &SqlitePool,
+    project_id: Uuid,
+    levels: &[Vec<Uuid>],
+) -> Result<u64, sqlx::Error> {
+    if levels.is_empty() {
+        return Ok(0);
+    }
+    let tasks = Task::find_by_project_id(pool, project_id).await?;
+
+    let levels: HashMap<Uuid, usize> = levels
+        .iter()
+        .enumerate()
+        .flat_map(|(level, task_ids)| task_ids.iter().map(move |id| (*id, level)))
+        .collect();
+
+    let mut columns: HashMap<usize, Vec<&Task>> = HashMap::new();
+    for task in &tasks {
+        if let Some(level) = levels.get(&task.id) {
+            columns.entry(*level).or_default().push(task);
+        }
+    }
+
+    let mut updates = Vec::new();
+    for (level, column) in &mut columns {
+        column.sort_by_key(|task| (task.created_at, task.id));
+        let x = (*level as f64) * (NODE_WIDTH + HORIZONTAL_SPACING);
+        for (index, task) in column.iter().enumerate() {
+            let y = (index as f64) * (NODE_HEIGHT + VERTICAL_SPACING);
+            if task.dag_position_x != Some(x) || task.dag_position_y != Some(y) {
+                updates.push(DagPositionUpdate {
+                    task_id: task.id,
+                    x,
+                    y,
+                });
+            }
+        }
+    }
+
+    if updates.is_empty() {
+        return Ok(0);
+    }
+    let updated = Task::update_dag_positions_bulk(pool, project_id, &updates).await?;
+
+    tracing::info!(
+        "Recalculated DAG layout for project {}: {} tasks in {} levels, {} repositioned",
+        project_id,
+        levels.len(),
+        columns.len(),
+        updated
+    );
+
+    Ok(updated)
+}
+
+/// Look up a task by title, recording unmatched and ambiguous titles once each
+fn resolve_title(
+    titles: &HashMap<&str, TitleMatch>,
+    title: &str,
+    report: &mut DependencyImportReport,
+) -> Option<Uuid> {
+    match titles.get(title) {
+        Some(TitleMatch::Unique(id)) => Some(*id),
+        Some(TitleMatch::Ambiguous) => {
+            if !report.ambiguous_titles.iter().any(|t| t == title) {
+                report.ambiguous_titles.push(title.to_string());
+            }
+            None
+        }
+        None => {
+            if !report.unmatched_titles.iter().any(|t| t == title) {
+                report.unmatched_titles.push(title.to_string());
+            }
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        project::{CreateProject, Project},
+        task::TaskStatus,
+    };
+
+    async fn create_test_project(pool: &SqlitePool) -> Uuid {
+        let project_id = Uuid::new_v4();
+        let project = CreateProject {
+            name: "Graph Test".to_string(),
+            repositories: vec![],
+        };
+        Project::create(pool, &project, project_id).await.unwrap();
+        project_id
+    }
+
+    async fn create_test_tasks(pool: &SqlitePool, project_id: Uuid, titles: &[&str]) -> Vec<Uuid> {
+        let mut task_ids = Vec::new();
+        for title in titles {
+            let task_id = Uuid::new_v4();
+            let data = CreateTask::from_title_description(project_id, title.to_string(), None);
+            Task::create(pool, &data, task_id).await.unwrap();
+            task_ids.push(task_id);
+        }
+        task_ids
+    }
+
+    async fn create_edge(
+        pool: &SqlitePool,
+        task_id: Uuid,
+        depends_on_task_id: Uuid,
+        genre_id: Option<Uuid>,
+        kind: DependencyKind,
+    ) {
+        let data = CreateTaskDependency {
+            task_id,
+            depends_on_task_id,
+            created_by: None,
+            genre_id,
+            kind: Some(kind),
+            label: None,
+        };
+        TaskDependency::create(pool, &data).await.unwrap();
+    }
+
+    #[sqlx::test]
+    async fn test_export_import_round_trip(pool: SqlitePool) {
+        let source = create_test_project(&pool).await;
+        let ids = create_test_tasks(&pool, source, &["設計", "実装", "テスト"]).await;
+        let genre = DependencyGenre::create(
+            &pool,
+            &CreateDependencyGenre {
+                project_id: source,
+                name: "技術的依存".to_string(),
+                color: Some("#3B82F6".to_string()),
+                position: None,
+            },
+        )
+        .await
+        .unwrap();
+        create_edge(
+            &pool,
+            ids[1],
+            ids[0],
+            Some(genre.id),
+            DependencyKind::Strict,
+        )
+        .await;
+        create_edge(&pool, ids[2], ids[1], None, DependencyKind::Optional).await;
+
+        let export = DependencyGraphExport::from_project(&pool, source)
+            .await
+            .unwrap();
+        assert_eq!(export.genres.len(), 1);
+        assert_eq!(export.dependencies.len(), 2);
+
+        // Round-trip through JSON into a project with the same task titles
+        let json = serde_json::to_string(&export).unwrap();
+        let document: DependencyGraphExport = serde_json::from_str(&json).unwrap();
+        let target = create_test_project(&pool).await;
+        create_test_tasks(&pool, target, &["設計", "実装", "テスト"]).await;
+
+        let report = document.import(&pool, target).await.unwrap();
+        assert_eq!(report.created_genres, 1);
+        assert_eq!(report.created_dependencies, 2);
+        assert!(report.unmatched_titles.is_empty());
+
+        let reexported = DependencyGraphExport::from_project(&pool, target)
+            .await
+            .unwrap();
+        let mut expected = export.dependencies.clone();
+        let mut actual = reexported.dependencies.clone();
+        expected.sort_by(|a, b| a.task.cmp(&b.task));
+        actual.sort_by(|a, b| a.task.cmp(&b.task));
+        assert_eq!(actual, expected);
+        assert_eq!(reexported.genres[0].name, "技術的依存");
+        assert_eq!(reexported.genres[0].color, "#3B82F6");
+
+        // Importing again only finds duplicates
+        let again = document.import(&pool, target).await.unwrap();
+        assert_eq!(again.created_genres, 0);
+        assert_eq!(again.created_dependencies, 0);
+        assert_eq!(again.skipped_duplicates, 2);
+    }
+
+    #[sqlx::test]
+    async fn test_import_reports_unmatched_titles_and_cycles(pool: SqlitePool) {
+        let project_id = create_test_project(&pool).await;
+        let ids = create_test_tasks(&pool, project_id, &["A", "B"]).await;
+        create_edge(&pool, ids[1], ids[0], None, DependencyKind::Strict).await;
+
+        let edge = |task: &str, depends_on: &str| ExportedDependency {
+            task: task.to_string(),
+            depends_on: depends_on.to_string(),
+            genre: None,
+            kind: DependencyKind::Strict,
+            label: None,
+        };
+        let document = DependencyGraphExport {
+            genres: vec![],
+            dependencies: vec![edge("A", "B"), edge("C", "A"), edge("B", "C")],
+        };
+
+        let report = document.import(&pool, project_id).await.unwrap();
+
+        assert_eq!(report.created_dependencies, 0);
+        assert_eq!(report.skipped_cycles, vec![edge("A", "B")]);
+        assert_eq!(report.unmatched_titles, vec!["C".to_string()]);
+        let stored = TaskDependency::find_by_project_id(&pool, project_id)
+            .await
+            .unwrap();
+        assert_eq!(stored.len(), 1);
+    }
+
+    #[sqlx::test]
+    async fn test_clone_graph_round_trip(pool: SqlitePool) {
+        let source = create_test_project(&pool).await;
+        let ids = create_test_tasks(&pool, source, &["設計", "実装", "テスト"]).await;
+        let genre = DependencyGenre::create(
+            &pool,
+            &CreateDependencyGenre {
+                project_id: source,
+                name: "技術的依存".to_string(),
+                color: Some("#3B82F6".to_string()),
+                position: None,
+            },
+        )
+        .await
+        .unwrap();
+        create_edge(
+            &pool,
+            ids[1],
+            ids[0],
+            Some(genre.id),
+            DependencyKind::Strict,
+        )
+        .await;
+        create_edge(&pool, ids[2], ids[1], None, DependencyKind::Optional).await;
+        Task::update_status(&pool, ids[0], TaskStatus::Done)
+            .await
+            .unwrap();
+
+        let target = create_test_project(&pool).await;
+        let report = clone_graph(&pool, source, target).await.unwrap();
+        assert_eq!(report.task_ids.len(), 3);
+        assert_eq!(report.created_genres, 1);
+        assert_eq!(report.created_dependencies, 2);
+        assert_eq!(report.skipped_cycles, 0);
+
+        let cloned = Task::find_by_project_id(&pool, target).await.unwrap();
+        assert_eq!(cloned.len(), 3);
+        assert!(cloned.iter().all(|t| t.status == TaskStatus::Todo));
+        let new_first = report.task_ids[&ids[0]];
+        assert!(
+            cloned
+                .iter()
+                .any(|t| t.id == new_first && t.title == "設計")
+        );
+
+        let edges = TaskDependency::find_by_project_id(&pool, target)
+            .await
+            .unwrap();
+        let target_genre = DependencyGenre::find_by_project_id(&pool, target, true)
+            .await
+            .unwrap()
+            .remove(0);
+        let strict = edges
+            .iter()
+            .find(|e| e.task_id == report.task_ids[&ids[1]])
+            .unwrap();
+        assert_eq!(strict.depends_on_task_id, new_first);
+        assert_eq!(strict.genre_id, Some(target_genre.id));
+        assert_eq!(strict.kind, DependencyKind::Strict);
+
+        // The exported graphs of both projects match edge for edge
+        let mut expected = DependencyGraphExport::from_project(&pool, source)
+            .await
+            .unwrap()
+            .dependencies;
+        let mut actual = DependencyGraphExport::from_project(&pool, target)
+            .await
+            .unwrap()
+            .dependencies;
+        expected.sort_by(|a, b| a.task.cmp(&b.task));
+        actual.sort_by(|a, b| a.task.cmp(&b.task));
+        assert_eq!(actual, expected);
+    }
+
+    #[sqlx::test]
+    async fn test_apply_layout_restores_scrambled_positions(pool: SqlitePool) {
+        let project_id = create_test_project(&pool).await;
+        let ids = create_test_tasks(&pool, project_id, &["A", "B", "C", "D"]).await;
+        // Levels of A <- B <- D, A <- C
+        let levels = vec![vec![ids[0]], vec![ids[1], ids[2]], vec![ids[3]]];
+
+        apply_layout(&pool, project_id, &levels).await.unwrap();
+        let positions = |tasks: Vec<Task>| -> HashMap<Uuid, (Option<f64>, Option<f64>)> {
+            tasks
+                .into_iter()
+                .map(|t| (t.id, (t.dag_position_x, t.dag_position_y)))
+                .collect()
+        };
+        let laid_out = positions(Task::find_by_project_id(&pool, project_id).await.unwrap());
+        assert_eq!(laid_out[&ids[0]].0, Some(0.0));
+        assert_eq!(laid_out[&ids[1]].0, laid_out[&ids[2]].0);
+        assert!(laid_out[&ids[3]].0 > laid_out[&ids[1]].0);
+
+        for (index, task_id) in ids.iter().enumerate() {
+            Task::update_dag_position(&pool, *task_id, Some(-500.0), Some(index as f64 * 7.0))
+                .await
+                .unwrap();
+        }
+
+        let repositioned = apply_layout(&pool, project_id, &levels).await.unwrap();
+        assert_eq!(repositioned, 4);
+        let restored = positions(Task::find_by_project_id(&pool, project_id).await.unwrap());
+        assert_eq!(restored, laid_out);
+
+        // A second run finds nothing to move
+        assert_eq!(apply_layout(&pool, project_id, &levels).await.unwrap(), 0);
+    }
+}
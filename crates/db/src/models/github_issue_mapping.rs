@@ -1,3 +1,5 @@
+use std::collections::{HashMap, HashSet};
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::{Executor, FromRow, Sqlite, SqlitePool, Type};
@@ -5,6 +7,8 @@ use strum_macros::{Display, EnumString};
 use ts_rs::TS;
 use uuid::Uuid;
 
+use super::task_property::{PropertySource, TaskProperty};
+
 #[derive(Debug, Clone, Type, Serialize, Deserialize, PartialEq, TS, EnumString, Display, Default)]
 #[sqlx(type_name = "sync_direction", rename_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
@@ -28,6 +32,8 @@ pub struct GitHubIssueMapping {
     pub last_synced_at: Option<DateTime<Utc>>,
     pub github_updated_at: Option<DateTime<Utc>>,
     pub vibe_updated_at: Option<DateTime<Utc>>,
+    /// The issue was no longer in the GitHub Project at the last sync
+    pub stale: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -57,6 +63,7 @@ impl GitHubIssueMapping {
                 last_synced_at as "last_synced_at: DateTime<Utc>",
                 github_updated_at as "github_updated_at: DateTime<Utc>",
                 vibe_updated_at as "vibe_updated_at: DateTime<Utc>",
+                stale as "stale!: bool",
                 created_at as "created_at!: DateTime<Utc>",
                 updated_at as "updated_at!: DateTime<Utc>"
             FROM github_issue_mappings
@@ -84,6 +91,7 @@ impl GitHubIssueMapping {
                 last_synced_at as "last_synced_at: DateTime<Utc>",
                 github_updated_at as "github_updated_at: DateTime<Utc>",
                 vibe_updated_at as "vibe_updated_at: DateTime<Utc>",
+                stale as "stale!: bool",
                 created_at as "created_at!: DateTime<Utc>",
                 updated_at as "updated_at!: DateTime<Utc>"
             FROM github_issue_mappings
@@ -112,6 +120,7 @@ impl GitHubIssueMapping {
                 last_synced_at as "last_synced_at: DateTime<Utc>",
                 github_updated_at as "github_updated_at: DateTime<Utc>",
                 vibe_updated_at as "vibe_updated_at: DateTime<Utc>",
+                stale as "stale!: bool",
                 created_at as "created_at!: DateTime<Utc>",
                 updated_at as "updated_at!: DateTime<Utc>"
             FROM github_issue_mappings
@@ -140,6 +149,7 @@ impl GitHubIssueMapping {
                 last_synced_at as "last_synced_at: DateTime<Utc>",
                 github_updated_at as "github_updated_at: DateTime<Utc>",
                 vibe_updated_at as "vibe_updated_at: DateTime<Utc>",
+                stale as "stale!: bool",
                 created_at as "created_at!: DateTime<Utc>",
                 updated_at as "updated_at!: DateTime<Utc>"
             FROM github_issue_mappings
@@ -151,6 +161,31 @@ impl GitHubIssueMapping {
         .await
     }
 
+    /// Count issue mappings per GitHub link of a project, keyed by link ID.
+    /// Links without mappings are absent from the map.
+    pub async fn count_by_project(
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<HashMap<Uuid, usize>, sqlx::Error> {
+        let rows = sqlx::query!(
+            r#"SELECT
+                m.github_project_link_id as "github_project_link_id!: Uuid",
+                COUNT(*) as "issue_count!: i64"
+            FROM github_issue_mappings m
+            INNER JOIN github_project_links l ON m.github_project_link_id = l.id
+            WHERE l.project_id = $1
+            GROUP BY m.github_project_link_id"#,
+            project_id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| (row.github_project_link_id, row.issue_count as usize))
+            .collect())
+    }
+
     pub async fn create(
         pool: &SqlitePool,
         data: &CreateGitHubIssueMapping,
@@ -172,6 +207,51 @@ impl GitHubIssueMapping {
                 last_synced_at as "last_synced_at: DateTime<Utc>",
                 github_updated_at as "github_updated_at: DateTime<Utc>",
                 vibe_updated_at as "vibe_updated_at: DateTime<Utc>",
+                stale as "stale!: bool",
+                created_at as "created_at!: DateTime<Utc>",
+                updated_at as "updated_at!: DateTime<Utc>""#,
+            id,
+            data.task_id,
+            data.github_project_link_id,
+            data.github_issue_number,
+            data.github_issue_id,
+            data.github_issue_url,
+            sync_direction
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    /// Insert a mapping, or refresh the issue details of the one already holding
+    /// `(github_project_link_id, github_issue_number)`.
+    /// The existing row keeps its task, so callers should compare `task_id` with what they passed.
+    pub async fn upsert(
+        pool: &SqlitePool,
+        data: &CreateGitHubIssueMapping,
+    ) -> Result<Self, sqlx::Error> {
+        let id = Uuid::new_v4();
+        let sync_direction = data.sync_direction.clone().unwrap_or_default();
+        sqlx::query_as!(
+            GitHubIssueMapping,
+            r#"INSERT INTO github_issue_mappings (id, task_id, github_project_link_id, github_issue_number, github_issue_id, github_issue_url, sync_direction)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT(github_project_link_id, github_issue_number) DO UPDATE SET
+                github_issue_id = excluded.github_issue_id,
+                github_issue_url = excluded.github_issue_url,
+                sync_direction = excluded.sync_direction,
+                updated_at = CURRENT_TIMESTAMP
+            RETURNING
+                id as "id!: Uuid",
+                task_id as "task_id!: Uuid",
+                github_project_link_id as "github_project_link_id!: Uuid",
+                github_issue_number as "github_issue_number!: i64",
+                github_issue_id,
+                github_issue_url,
+                sync_direction as "sync_direction!: SyncDirection",
+                last_synced_at as "last_synced_at: DateTime<Utc>",
+                github_updated_at as "github_updated_at: DateTime<Utc>",
+                vibe_updated_at as "vibe_updated_at: DateTime<Utc>",
+                stale as "stale!: bool",
                 created_at as "created_at!: DateTime<Utc>",
                 updated_at as "updated_at!: DateTime<Utc>""#,
             id,
@@ -208,6 +288,82 @@ impl GitHubIssueMapping {
         Ok(())
     }
 
+    /// Change which way changes flow between the issue and its task
+    pub async fn update_sync_direction(
+        pool: &SqlitePool,
+        id: Uuid,
+        sync_direction: SyncDirection,
+    ) -> Result<Self, sqlx::Error> {
+        sqlx::query_as!(
+            GitHubIssueMapping,
+            r#"UPDATE github_issue_mappings
+            SET sync_direction = $2,
+                updated_at = CURRENT_TIMESTAMP
+            WHERE id = $1
+            RETURNING
+                id as "id!: Uuid",
+                task_id as "task_id!: Uuid",
+                github_project_link_id as "github_project_link_id!: Uuid",
+                github_issue_number as "github_issue_number!: i64",
+                github_issue_id,
+                github_issue_url,
+                sync_direction as "sync_direction!: SyncDirection",
+                last_synced_at as "last_synced_at: DateTime<Utc>",
+                github_updated_at as "github_updated_at: DateTime<Utc>",
+                vibe_updated_at as "vibe_updated_at: DateTime<Utc>",
+                stale as "stale!: bool",
+                created_at as "created_at!: DateTime<Utc>",
+                updated_at as "updated_at!: DateTime<Utc>""#,
+            id,
+            sync_direction
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    /// Flag the link's mappings whose issue number is not in `seen` as stale, and clear the flag
+    /// on stale mappings whose issue is back in `seen`.
+    /// Returns every mapping missing from `seen`, including ones already flagged by an earlier sync.
+    pub async fn update_stale_flags(
+        pool: &SqlitePool,
+        github_project_link_id: Uuid,
+        seen: &HashSet<i64>,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        let (returned, mut missing): (Vec<Self>, Vec<Self>) =
+            Self::find_by_link_id(pool, github_project_link_id)
+                .await?
+                .into_iter()
+                .partition(|mapping| seen.contains(&mapping.github_issue_number));
+
+        let mut tx = pool.begin().await?;
+        for mapping in missing.iter_mut().filter(|mapping| !mapping.stale) {
+            sqlx::query!(
+                r#"UPDATE github_issue_mappings
+                SET stale = TRUE,
+                    updated_at = CURRENT_TIMESTAMP
+                WHERE id = $1"#,
+                mapping.id
+            )
+            .execute(&mut *tx)
+            .await?;
+            mapping.stale = true;
+        }
+        for mapping in returned.iter().filter(|mapping| mapping.stale) {
+            sqlx::query!(
+                r#"UPDATE github_issue_mappings
+                SET stale = FALSE,
+                    updated_at = CURRENT_TIMESTAMP
+                WHERE id = $1"#,
+                mapping.id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+
+        Ok(missing)
+    }
+
     pub async fn delete<'e, E>(executor: E, id: Uuid) -> Result<u64, sqlx::Error>
     where
         E: Executor<'e, Database = Sqlite>,
@@ -217,4 +373,278 @@ impl GitHubIssueMapping {
             .await?;
         Ok(result.rows_affected())
     }
+
+    /// Stop syncing a single task without touching the task itself.
+    /// With `clear_properties` the GitHub-sourced task properties go too; returns how many were removed.
+    pub async fn unlink(
+        &self,
+        pool: &SqlitePool,
+        clear_properties: bool,
+    ) -> Result<u64, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+        Self::delete(&mut *tx, self.id).await?;
+        let cleared = if clear_properties {
+            TaskProperty::delete_by_task_and_source(&mut *tx, self.task_id, PropertySource::Github)
+                .await?
+        } else {
+            0
+        };
+        tx.commit().await?;
+        Ok(cleared)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        github_project_link::{CreateGitHubProjectLink, GitHubProjectLink},
+        project::{CreateProject, Project},
+        task::{CreateTask, Task},
+        task_property::CreateTaskProperty,
+    };
+
+    async fn create_test_link(pool: &SqlitePool, project_id: Uuid, github_project_id: &str) -> Uuid {
+        let data = CreateGitHubProjectLink {
+            project_id,
+            github_project_id: github_project_id.to_string(),
+            github_owner: "owner".to_string(),
+            github_repo: Some("repo".to_string()),
+            github_project_number: Some(1),
+            github_host: None,
+            label_filter: None,
+        };
+        GitHubProjectLink::create(pool, &data).await.unwrap().id
+    }
+
+    async fn create_test_mapping(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        link_id: Uuid,
+        number: i64,
+    ) -> GitHubIssueMapping {
+        let task_id = Uuid::new_v4();
+        let task = CreateTask::from_title_description(project_id, format!("Issue #{number}"), None);
+        Task::create(pool, &task, task_id).await.unwrap();
+
+        let data = CreateGitHubIssueMapping {
+            task_id,
+            github_project_link_id: link_id,
+            github_issue_number: number,
+            github_issue_id: format!("I_{number}"),
+            github_issue_url: format!("https://github.com/owner/repo/issues/{number}"),
+            sync_direction: None,
+        };
+        GitHubIssueMapping::create(pool, &data).await.unwrap()
+    }
+
+    async fn create_test_project(pool: &SqlitePool) -> Uuid {
+        let project_id = Uuid::new_v4();
+        let project = CreateProject {
+            name: "Mapping Test".to_string(),
+            repositories: vec![],
+        };
+        Project::create(pool, &project, project_id).await.unwrap();
+        project_id
+    }
+
+    #[sqlx::test]
+    async fn test_count_by_project(pool: SqlitePool) {
+        let project_id = create_test_project(&pool).await;
+
+        let busy_link = create_test_link(&pool, project_id, "PVT_busy").await;
+        let quiet_link = create_test_link(&pool, project_id, "PVT_quiet").await;
+        let empty_link = create_test_link(&pool, project_id, "PVT_empty").await;
+        for number in 1..=3 {
+            create_test_mapping(&pool, project_id, busy_link, number).await;
+        }
+        create_test_mapping(&pool, project_id, quiet_link, 1).await;
+
+        let counts = GitHubIssueMapping::count_by_project(&pool, project_id)
+            .await
+            .unwrap();
+        assert_eq!(counts.get(&busy_link), Some(&3));
+        assert_eq!(counts.get(&quiet_link), Some(&1));
+        assert_eq!(counts.get(&empty_link), None);
+    }
+
+    #[sqlx::test]
+    async fn test_update_stale_flags(pool: SqlitePool) {
+        let project_id = create_test_project(&pool).await;
+        let link_id = create_test_link(&pool, project_id, "PVT_stale").await;
+        let kept = create_test_mapping(&pool, project_id, link_id, 1).await;
+        let removed = create_test_mapping(&pool, project_id, link_id, 2).await;
+
+        let seen = HashSet::from([1]);
+        let missing = GitHubIssueMapping::update_stale_flags(&pool, link_id, &seen)
+            .await
+            .unwrap();
+        assert_eq!(missing.len(), 1);
+        assert_eq!(missing[0].id, removed.id);
+        assert!(missing[0].stale);
+
+        let stored = GitHubIssueMapping::find_by_id(&pool, removed.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(stored.stale);
+        let stored = GitHubIssueMapping::find_by_id(&pool, kept.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!stored.stale);
+
+        // Pushing a local change to GitHub doesn't bring the issue back into the project
+        GitHubIssueMapping::update_sync_timestamps(&pool, removed.id, None, Some(Utc::now()))
+            .await
+            .unwrap();
+        let stored = GitHubIssueMapping::find_by_id(&pool, removed.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(stored.stale);
+
+        // The issue being seen again clears the flag
+        let seen = HashSet::from([1, 2]);
+        let missing = GitHubIssueMapping::update_stale_flags(&pool, link_id, &seen)
+            .await
+            .unwrap();
+        assert!(missing.is_empty());
+        let stored = GitHubIssueMapping::find_by_id(&pool, removed.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!stored.stale);
+    }
+
+    async fn set_property(pool: &SqlitePool, task_id: Uuid, name: &str, source: PropertySource) {
+        let data = CreateTaskProperty {
+            task_id,
+            property_name: name.to_string(),
+            property_value: "1".to_string(),
+            source: Some(source),
+        };
+        TaskProperty::upsert(pool, &data).await.unwrap();
+    }
+
+    #[sqlx::test]
+    async fn test_unlink_keeps_task_properties(pool: SqlitePool) {
+        let project_id = create_test_project(&pool).await;
+        let link_id = create_test_link(&pool, project_id, "PVT_unlink").await;
+        let mapping = create_test_mapping(&pool, project_id, link_id, 1).await;
+        set_property(
+            &pool,
+            mapping.task_id,
+            "github_issue_number",
+            PropertySource::Github,
+        )
+        .await;
+
+        let cleared = mapping.unlink(&pool, false).await.unwrap();
+
+        assert_eq!(cleared, 0);
+        assert!(
+            GitHubIssueMapping::find_by_id(&pool, mapping.id)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            Task::find_by_id(&pool, mapping.task_id)
+                .await
+                .unwrap()
+                .is_some()
+        );
+        let properties = TaskProperty::find_by_task_id(&pool, mapping.task_id)
+            .await
+            .unwrap();
+        assert_eq!(properties.len(), 1);
+    }
+
+    #[sqlx::test]
+    async fn test_unlink_clears_github_properties(pool: SqlitePool) {
+        let project_id = create_test_project(&pool).await;
+        let link_id = create_test_link(&pool, project_id, "PVT_unlink").await;
+        let mapping = create_test_mapping(&pool, project_id, link_id, 1).await;
+        set_property(
+            &pool,
+            mapping.task_id,
+            "github_issue_number",
+            PropertySource::Github,
+        )
+        .await;
+        set_property(&pool, mapping.task_id, "labels", PropertySource::Github).await;
+        set_property(&pool, mapping.task_id, "estimate", PropertySource::Vibe).await;
+
+        let cleared = mapping.unlink(&pool, true).await.unwrap();
+
+        assert_eq!(cleared, 2);
+        assert!(
+            GitHubIssueMapping::find_by_id(&pool, mapping.id)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            Task::find_by_id(&pool, mapping.task_id)
+                .await
+                .unwrap()
+                .is_some()
+        );
+        let properties = TaskProperty::find_by_task_id(&pool, mapping.task_id)
+            .await
+            .unwrap();
+        assert_eq!(properties.len(), 1);
+        assert_eq!(properties[0].property_name, "estimate");
+    }
+
+    #[sqlx::test]
+    async fn test_upsert_same_issue_keeps_one_row(pool: SqlitePool) {
+        let project_id = create_test_project(&pool).await;
+        let link_id = create_test_link(&pool, project_id, "PVT_upsert").await;
+        let first = create_test_mapping(&pool, project_id, link_id, 7).await;
+
+        let data = CreateGitHubIssueMapping {
+            task_id: first.task_id,
+            github_project_link_id: link_id,
+            github_issue_number: 7,
+            github_issue_id: "I_moved".to_string(),
+            github_issue_url: "https://github.com/owner/other/issues/7".to_string(),
+            sync_direction: Some(SyncDirection::GithubToVibe),
+        };
+        let second = GitHubIssueMapping::upsert(&pool, &data).await.unwrap();
+
+        assert_eq!(second.id, first.id);
+        assert_eq!(second.github_issue_id, "I_moved");
+        assert_eq!(second.github_issue_url, data.github_issue_url);
+        assert_eq!(second.sync_direction, SyncDirection::GithubToVibe);
+        let mappings = GitHubIssueMapping::find_by_link_id(&pool, link_id)
+            .await
+            .unwrap();
+        assert_eq!(mappings.len(), 1);
+    }
+
+    #[sqlx::test]
+    async fn test_update_sync_direction(pool: SqlitePool) {
+        let project_id = create_test_project(&pool).await;
+        let link_id = create_test_link(&pool, project_id, "PVT_direction").await;
+        let mapping = create_test_mapping(&pool, project_id, link_id, 3).await;
+        assert_eq!(mapping.sync_direction, SyncDirection::Bidirectional);
+
+        let updated = GitHubIssueMapping::update_sync_direction(
+            &pool,
+            mapping.id,
+            SyncDirection::VibeToGithub,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(updated.id, mapping.id);
+        assert_eq!(updated.sync_direction, SyncDirection::VibeToGithub);
+        let stored = GitHubIssueMapping::find_by_id(&pool, mapping.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.sync_direction, SyncDirection::VibeToGithub);
+    }
 }
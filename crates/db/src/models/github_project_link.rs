@@ -12,6 +12,10 @@ pub struct GitHubProjectLink {
     pub github_owner: String,
     pub github_repo: Option<String>,
     pub github_project_number: Option<i64>,
+    /// GitHub Enterprise hostname; `None` targets github.com
+    pub github_host: Option<String>,
+    /// Only issues carrying this label (case-insensitive) are synced; `None` syncs every issue
+    pub label_filter: Option<String>,
     pub sync_enabled: bool,
     pub last_sync_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
@@ -25,6 +29,8 @@ pub struct CreateGitHubProjectLink {
     pub github_owner: String,
     pub github_repo: Option<String>,
     pub github_project_number: Option<i64>,
+    pub github_host: Option<String>,
+    pub label_filter: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
@@ -43,6 +49,8 @@ impl GitHubProjectLink {
                 github_owner,
                 github_repo,
                 github_project_number as "github_project_number: i64",
+                github_host,
+                label_filter,
                 sync_enabled as "sync_enabled!: bool",
                 last_sync_at as "last_sync_at: DateTime<Utc>",
                 created_at as "created_at!: DateTime<Utc>",
@@ -68,6 +76,8 @@ impl GitHubProjectLink {
                 github_owner,
                 github_repo,
                 github_project_number as "github_project_number: i64",
+                github_host,
+                label_filter,
                 sync_enabled as "sync_enabled!: bool",
                 last_sync_at as "last_sync_at: DateTime<Utc>",
                 created_at as "created_at!: DateTime<Utc>",
@@ -94,6 +104,8 @@ impl GitHubProjectLink {
                 github_owner,
                 github_repo,
                 github_project_number as "github_project_number: i64",
+                github_host,
+                label_filter,
                 sync_enabled as "sync_enabled!: bool",
                 last_sync_at as "last_sync_at: DateTime<Utc>",
                 created_at as "created_at!: DateTime<Utc>",
@@ -114,8 +126,8 @@ impl GitHubProjectLink {
         let id = Uuid::new_v4();
         sqlx::query_as!(
             GitHubProjectLink,
-            r#"INSERT INTO github_project_links (id, project_id, github_project_id, github_owner, github_repo, github_project_number)
-            VALUES ($1, $2, $3, $4, $5, $6)
+            r#"INSERT INTO github_project_links (id, project_id, github_project_id, github_owner, github_repo, github_project_number, github_host, label_filter)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
             RETURNING
                 id as "id!: Uuid",
                 project_id as "project_id!: Uuid",
@@ -123,6 +135,8 @@ impl GitHubProjectLink {
                 github_owner,
                 github_repo,
                 github_project_number as "github_project_number: i64",
+                github_host,
+                label_filter,
                 sync_enabled as "sync_enabled!: bool",
                 last_sync_at as "last_sync_at: DateTime<Utc>",
                 created_at as "created_at!: DateTime<Utc>",
@@ -132,7 +146,9 @@ impl GitHubProjectLink {
             data.github_project_id,
             data.github_owner,
             data.github_repo,
-            data.github_project_number
+            data.github_project_number,
+            data.github_host,
+            data.label_filter
         )
         .fetch_one(pool)
         .await
@@ -188,6 +204,8 @@ impl GitHubProjectLink {
                 github_owner,
                 github_repo,
                 github_project_number as "github_project_number: i64",
+                github_host,
+                label_filter,
                 sync_enabled as "sync_enabled!: bool",
                 last_sync_at as "last_sync_at: DateTime<Utc>",
                 created_at as "created_at!: DateTime<Utc>",
@@ -1,5 +1,6 @@
 pub mod coding_agent_turn;
 pub mod dependency_genre;
+pub mod dependency_graph;
 pub mod execution_process;
 pub mod execution_process_logs;
 pub mod execution_process_repo_state;
@@ -9,12 +10,14 @@ pub mod image;
 pub mod merge;
 pub mod project;
 pub mod project_repo;
+pub mod project_transition_rule;
 pub mod repo;
 pub mod scratch;
 pub mod session;
 pub mod tag;
 pub mod task;
 pub mod task_dependency;
+pub mod task_graph_version;
 pub mod task_property;
 pub mod workspace;
 pub mod workspace_repo;
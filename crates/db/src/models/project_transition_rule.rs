@@ -0,0 +1,118 @@
+use sqlx::{FromRow, SqlitePool};
+use uuid::Uuid;
+
+use super::task::TaskStatus;
+
+/// A status change a project allows in place of the built-in transition matrix
+#[derive(Debug, Clone, PartialEq, FromRow)]
+pub struct ProjectTransitionRule {
+    pub from_status: TaskStatus,
+    pub to_status: TaskStatus,
+}
+
+impl ProjectTransitionRule {
+    /// The project's custom transitions in the order they were saved; empty when it has none
+    pub async fn find_by_project_id(
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            ProjectTransitionRule,
+            r#"SELECT
+                from_status as "from_status!: TaskStatus",
+                to_status as "to_status!: TaskStatus"
+               FROM project_transition_rules
+               WHERE project_id = $1
+               ORDER BY position ASC"#,
+            project_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Replace all of the project's custom transitions in one transaction
+    pub async fn replace_for_project(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        rules: &[ProjectTransitionRule],
+    ) -> Result<(), sqlx::Error> {
+        let mut tx = pool.begin().await?;
+
+        sqlx::query!(
+            "DELETE FROM project_transition_rules WHERE project_id = $1",
+            project_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        for (position, rule) in rules.iter().enumerate() {
+            let position = position as i64;
+            sqlx::query!(
+                "INSERT INTO project_transition_rules (project_id, from_status, to_status, position) VALUES ($1, $2, $3, $4)",
+                project_id,
+                rule.from_status,
+                rule.to_status,
+                position
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::project::{CreateProject, Project};
+
+    #[sqlx::test]
+    async fn test_replace_and_find_transition_rules(pool: SqlitePool) {
+        let project_id = Uuid::new_v4();
+        let project = CreateProject {
+            name: "Transition Rules Test".to_string(),
+            repositories: vec![],
+        };
+        Project::create(&pool, &project, project_id).await.unwrap();
+        assert!(
+            ProjectTransitionRule::find_by_project_id(&pool, project_id)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        let rules = vec![
+            ProjectTransitionRule {
+                from_status: TaskStatus::Todo,
+                to_status: TaskStatus::InProgress,
+            },
+            ProjectTransitionRule {
+                from_status: TaskStatus::InProgress,
+                to_status: TaskStatus::Done,
+            },
+        ];
+        ProjectTransitionRule::replace_for_project(&pool, project_id, &rules)
+            .await
+            .unwrap();
+        assert_eq!(
+            ProjectTransitionRule::find_by_project_id(&pool, project_id)
+                .await
+                .unwrap(),
+            rules
+        );
+
+        // Saving again replaces the whole set
+        let shorter = vec![rules[1].clone()];
+        ProjectTransitionRule::replace_for_project(&pool, project_id, &shorter)
+            .await
+            .unwrap();
+        assert_eq!(
+            ProjectTransitionRule::find_by_project_id(&pool, project_id)
+                .await
+                .unwrap(),
+            shorter
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use sqlx::{Executor, FromRow, Sqlite, SqlitePool, Type};
 use strum_macros::{Display, EnumString};
@@ -8,7 +9,17 @@ use uuid::Uuid;
 use super::{project::Project, workspace::Workspace};
 
 #[derive(
-    Debug, Clone, Type, Serialize, Deserialize, PartialEq, TS, EnumString, Display, Default,
+    Debug,
+    Clone,
+    Type,
+    Serialize,
+    Deserialize,
+    PartialEq,
+    TS,
+    JsonSchema,
+    EnumString,
+    Display,
+    Default,
 )]
 #[sqlx(type_name = "task_status", rename_all = "lowercase")]
 #[serde(rename_all = "lowercase")]
@@ -18,10 +29,35 @@ pub enum TaskStatus {
     Todo,
     InProgress,
     InReview,
+    Blocked,
     Done,
     Cancelled,
 }
 
+/// Kind of work a task represents, used to label it in the UI
+#[derive(
+    Debug,
+    Clone,
+    Type,
+    Serialize,
+    Deserialize,
+    PartialEq,
+    TS,
+    JsonSchema,
+    EnumString,
+    Display,
+    Default,
+)]
+#[sqlx(type_name = "task_type", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum TaskType {
+    #[default]
+    Feature,
+    Bug,
+    Chore,
+}
+
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize, TS)]
 pub struct Task {
     pub id: Uuid,
@@ -29,6 +65,7 @@ pub struct Task {
     pub title: String,
     pub description: Option<String>,
     pub status: TaskStatus,
+    pub task_type: TaskType,
     pub parent_workspace_id: Option<Uuid>, // Foreign key to parent Workspace
     pub shared_task_id: Option<Uuid>,
     pub position: Option<i32>, // Position for ordering tasks in a list
@@ -138,6 +175,15 @@ pub struct UpdateTask {
     pub clear_dag_position: bool,
 }
 
+/// New DAG coordinates for one task in a bulk position update
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+pub struct DagPositionUpdate {
+    pub task_id: Uuid,
+    pub x: f64,
+    pub y: f64,
+}
+
 impl Task {
     pub fn to_prompt(&self) -> String {
         if let Some(description) = self.description.as_ref().filter(|d| !d.trim().is_empty()) {
@@ -162,6 +208,7 @@ impl Task {
   t.title,
   t.description,
   t.status                        AS "status!: TaskStatus",
+  t.task_type                     AS "task_type!: TaskType",
   t.parent_workspace_id           AS "parent_workspace_id: Uuid",
   t.shared_task_id                AS "shared_task_id: Uuid",
   t.position                      AS "position: i32",
@@ -218,6 +265,7 @@ ORDER BY t.created_at DESC"#,
                     title: rec.title,
                     description: rec.description,
                     status: rec.status,
+                    task_type: rec.task_type,
                     parent_workspace_id: rec.parent_workspace_id,
                     shared_task_id: rec.shared_task_id,
                     position: rec.position,
@@ -238,7 +286,7 @@ ORDER BY t.created_at DESC"#,
     pub async fn find_by_id(pool: &SqlitePool, id: Uuid) -> Result<Option<Self>, sqlx::Error> {
         sqlx::query_as!(
             Task,
-            r#"SELECT id as "id!: Uuid", project_id as "project_id!: Uuid", title, description, status as "status!: TaskStatus", parent_workspace_id as "parent_workspace_id: Uuid", shared_task_id as "shared_task_id: Uuid", position as "position: i32", dag_position_x as "dag_position_x: f64", dag_position_y as "dag_position_y: f64", created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>"
+            r#"SELECT id as "id!: Uuid", project_id as "project_id!: Uuid", title, description, status as "status!: TaskStatus", task_type as "task_type!: TaskType", parent_workspace_id as "parent_workspace_id: Uuid", shared_task_id as "shared_task_id: Uuid", position as "position: i32", dag_position_x as "dag_position_x: f64", dag_position_y as "dag_position_y: f64", created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>"
                FROM tasks
                WHERE id = $1"#,
             id
@@ -250,7 +298,7 @@ ORDER BY t.created_at DESC"#,
     pub async fn find_by_rowid(pool: &SqlitePool, rowid: i64) -> Result<Option<Self>, sqlx::Error> {
         sqlx::query_as!(
             Task,
-            r#"SELECT id as "id!: Uuid", project_id as "project_id!: Uuid", title, description, status as "status!: TaskStatus", parent_workspace_id as "parent_workspace_id: Uuid", shared_task_id as "shared_task_id: Uuid", position as "position: i32", dag_position_x as "dag_position_x: f64", dag_position_y as "dag_position_y: f64", created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>"
+            r#"SELECT id as "id!: Uuid", project_id as "project_id!: Uuid", title, description, status as "status!: TaskStatus", task_type as "task_type!: TaskType", parent_workspace_id as "parent_workspace_id: Uuid", shared_task_id as "shared_task_id: Uuid", position as "position: i32", dag_position_x as "dag_position_x: f64", dag_position_y as "dag_position_y: f64", created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>"
                FROM tasks
                WHERE rowid = $1"#,
             rowid
@@ -259,15 +307,42 @@ ORDER BY t.created_at DESC"#,
         .await
     }
 
-    pub async fn find_by_project_id(pool: &SqlitePool, project_id: Uuid) -> Result<Vec<Self>, sqlx::Error> {
+    pub async fn find_by_project_id<'e, E>(
+        executor: E,
+        project_id: Uuid,
+    ) -> Result<Vec<Self>, sqlx::Error>
+    where
+        E: Executor<'e, Database = Sqlite>,
+    {
         sqlx::query_as!(
             Task,
-            r#"SELECT id as "id!: Uuid", project_id as "project_id!: Uuid", title, description, status as "status!: TaskStatus", parent_workspace_id as "parent_workspace_id: Uuid", shared_task_id as "shared_task_id: Uuid", position as "position: i32", dag_position_x as "dag_position_x: f64", dag_position_y as "dag_position_y: f64", created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>"
+            r#"SELECT id as "id!: Uuid", project_id as "project_id!: Uuid", title, description, status as "status!: TaskStatus", task_type as "task_type!: TaskType", parent_workspace_id as "parent_workspace_id: Uuid", shared_task_id as "shared_task_id: Uuid", position as "position: i32", dag_position_x as "dag_position_x: f64", dag_position_y as "dag_position_y: f64", created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>"
                FROM tasks
                WHERE project_id = $1
                ORDER BY created_at DESC"#,
             project_id
         )
+        .fetch_all(executor)
+        .await
+    }
+
+    /// Tasks on neither side of any dependency; the DAG layout skips these
+    pub async fn find_orphans_by_project_id(
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            Task,
+            r#"SELECT t.id as "id!: Uuid", t.project_id as "project_id!: Uuid", t.title, t.description, t.status as "status!: TaskStatus", t.task_type as "task_type!: TaskType", t.parent_workspace_id as "parent_workspace_id: Uuid", t.shared_task_id as "shared_task_id: Uuid", t.position as "position: i32", t.dag_position_x as "dag_position_x: f64", t.dag_position_y as "dag_position_y: f64", t.created_at as "created_at!: DateTime<Utc>", t.updated_at as "updated_at!: DateTime<Utc>"
+               FROM tasks t
+               WHERE t.project_id = $1
+                 AND NOT EXISTS (
+                     SELECT 1 FROM task_dependencies td
+                     WHERE td.task_id = t.id OR td.depends_on_task_id = t.id
+                 )
+               ORDER BY t.created_at DESC"#,
+            project_id
+        )
         .fetch_all(pool)
         .await
     }
@@ -281,7 +356,7 @@ ORDER BY t.created_at DESC"#,
     {
         sqlx::query_as!(
             Task,
-            r#"SELECT id as "id!: Uuid", project_id as "project_id!: Uuid", title, description, status as "status!: TaskStatus", parent_workspace_id as "parent_workspace_id: Uuid", shared_task_id as "shared_task_id: Uuid", position as "position: i32", dag_position_x as "dag_position_x: f64", dag_position_y as "dag_position_y: f64", created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>"
+            r#"SELECT id as "id!: Uuid", project_id as "project_id!: Uuid", title, description, status as "status!: TaskStatus", task_type as "task_type!: TaskType", parent_workspace_id as "parent_workspace_id: Uuid", shared_task_id as "shared_task_id: Uuid", position as "position: i32", dag_position_x as "dag_position_x: f64", dag_position_y as "dag_position_y: f64", created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>"
                FROM tasks
                WHERE shared_task_id = $1
                LIMIT 1"#,
@@ -294,7 +369,7 @@ ORDER BY t.created_at DESC"#,
     pub async fn find_all_shared(pool: &SqlitePool) -> Result<Vec<Self>, sqlx::Error> {
         sqlx::query_as!(
             Task,
-            r#"SELECT id as "id!: Uuid", project_id as "project_id!: Uuid", title, description, status as "status!: TaskStatus", parent_workspace_id as "parent_workspace_id: Uuid", shared_task_id as "shared_task_id: Uuid", position as "position: i32", dag_position_x as "dag_position_x: f64", dag_position_y as "dag_position_y: f64", created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>"
+            r#"SELECT id as "id!: Uuid", project_id as "project_id!: Uuid", title, description, status as "status!: TaskStatus", task_type as "task_type!: TaskType", parent_workspace_id as "parent_workspace_id: Uuid", shared_task_id as "shared_task_id: Uuid", position as "position: i32", dag_position_x as "dag_position_x: f64", dag_position_y as "dag_position_y: f64", created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>"
                FROM tasks
                WHERE shared_task_id IS NOT NULL"#
         )
@@ -302,17 +377,20 @@ ORDER BY t.created_at DESC"#,
         .await
     }
 
-    pub async fn create(
-        pool: &SqlitePool,
+    pub async fn create<'e, E>(
+        executor: E,
         data: &CreateTask,
         task_id: Uuid,
-    ) -> Result<Self, sqlx::Error> {
+    ) -> Result<Self, sqlx::Error>
+    where
+        E: Executor<'e, Database = Sqlite>,
+    {
         let status = data.status.clone().unwrap_or_default();
         sqlx::query_as!(
             Task,
             r#"INSERT INTO tasks (id, project_id, title, description, status, parent_workspace_id, shared_task_id)
                VALUES ($1, $2, $3, $4, $5, $6, $7)
-               RETURNING id as "id!: Uuid", project_id as "project_id!: Uuid", title, description, status as "status!: TaskStatus", parent_workspace_id as "parent_workspace_id: Uuid", shared_task_id as "shared_task_id: Uuid", position as "position: i32", dag_position_x as "dag_position_x: f64", dag_position_y as "dag_position_y: f64", created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>""#,
+               RETURNING id as "id!: Uuid", project_id as "project_id!: Uuid", title, description, status as "status!: TaskStatus", task_type as "task_type!: TaskType", parent_workspace_id as "parent_workspace_id: Uuid", shared_task_id as "shared_task_id: Uuid", position as "position: i32", dag_position_x as "dag_position_x: f64", dag_position_y as "dag_position_y: f64", created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>""#,
             task_id,
             data.project_id,
             data.title,
@@ -321,7 +399,7 @@ ORDER BY t.created_at DESC"#,
             data.parent_workspace_id,
             data.shared_task_id
         )
-        .fetch_one(pool)
+        .fetch_one(executor)
         .await
     }
 
@@ -339,7 +417,7 @@ ORDER BY t.created_at DESC"#,
             r#"UPDATE tasks
                SET title = $3, description = $4, status = $5, parent_workspace_id = $6
                WHERE id = $1 AND project_id = $2
-               RETURNING id as "id!: Uuid", project_id as "project_id!: Uuid", title, description, status as "status!: TaskStatus", parent_workspace_id as "parent_workspace_id: Uuid", shared_task_id as "shared_task_id: Uuid", position as "position: i32", dag_position_x as "dag_position_x: f64", dag_position_y as "dag_position_y: f64", created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>""#,
+               RETURNING id as "id!: Uuid", project_id as "project_id!: Uuid", title, description, status as "status!: TaskStatus", task_type as "task_type!: TaskType", parent_workspace_id as "parent_workspace_id: Uuid", shared_task_id as "shared_task_id: Uuid", position as "position: i32", dag_position_x as "dag_position_x: f64", dag_position_y as "dag_position_y: f64", created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>""#,
             id,
             project_id,
             title,
@@ -377,7 +455,7 @@ ORDER BY t.created_at DESC"#,
             r#"UPDATE tasks
                SET position = $2, updated_at = CURRENT_TIMESTAMP
                WHERE id = $1
-               RETURNING id as "id!: Uuid", project_id as "project_id!: Uuid", title, description, status as "status!: TaskStatus", parent_workspace_id as "parent_workspace_id: Uuid", shared_task_id as "shared_task_id: Uuid", position as "position: i32", dag_position_x as "dag_position_x: f64", dag_position_y as "dag_position_y: f64", created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>""#,
+               RETURNING id as "id!: Uuid", project_id as "project_id!: Uuid", title, description, status as "status!: TaskStatus", task_type as "task_type!: TaskType", parent_workspace_id as "parent_workspace_id: Uuid", shared_task_id as "shared_task_id: Uuid", position as "position: i32", dag_position_x as "dag_position_x: f64", dag_position_y as "dag_position_y: f64", created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>""#,
             id,
             position
         )
@@ -501,7 +579,7 @@ ORDER BY t.created_at DESC"#,
         // Find only child tasks that have this workspace as their parent
         sqlx::query_as!(
             Task,
-            r#"SELECT id as "id!: Uuid", project_id as "project_id!: Uuid", title, description, status as "status!: TaskStatus", parent_workspace_id as "parent_workspace_id: Uuid", shared_task_id as "shared_task_id: Uuid", position as "position: i32", dag_position_x as "dag_position_x: f64", dag_position_y as "dag_position_y: f64", created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>"
+            r#"SELECT id as "id!: Uuid", project_id as "project_id!: Uuid", title, description, status as "status!: TaskStatus", task_type as "task_type!: TaskType", parent_workspace_id as "parent_workspace_id: Uuid", shared_task_id as "shared_task_id: Uuid", position as "position: i32", dag_position_x as "dag_position_x: f64", dag_position_y as "dag_position_y: f64", created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>"
                FROM tasks
                WHERE parent_workspace_id = $1
                ORDER BY created_at DESC"#,
@@ -562,4 +640,198 @@ ORDER BY t.created_at DESC"#,
         .await?;
         Ok(())
     }
+
+    /// Update the DAG positions of several tasks in one transaction
+    /// Fails with `RowNotFound` and changes nothing if any task is not in the project.
+    pub async fn update_dag_positions_bulk(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        updates: &[DagPositionUpdate],
+    ) -> Result<u64, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+
+        for update in updates {
+            let result = sqlx::query!(
+                "UPDATE tasks SET dag_position_x = $3, dag_position_y = $4, updated_at = CURRENT_TIMESTAMP WHERE id = $1 AND project_id = $2",
+                update.task_id,
+                project_id,
+                update.x,
+                update.y
+            )
+            .execute(&mut *tx)
+            .await?;
+            if result.rows_affected() == 0 {
+                return Err(sqlx::Error::RowNotFound);
+            }
+        }
+
+        tx.commit().await?;
+        Ok(updates.len() as u64)
+    }
+
+    /// Set the status of several tasks in one transaction
+    /// Fails with `RowNotFound` and changes nothing if any task is not in the project.
+    pub async fn update_status_bulk(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        task_ids: &[Uuid],
+        status: TaskStatus,
+    ) -> Result<u64, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+
+        for task_id in task_ids {
+            let result = sqlx::query!(
+                "UPDATE tasks SET status = $3, updated_at = CURRENT_TIMESTAMP WHERE id = $1 AND project_id = $2",
+                task_id,
+                project_id,
+                status
+            )
+            .execute(&mut *tx)
+            .await?;
+            if result.rows_affected() == 0 {
+                return Err(sqlx::Error::RowNotFound);
+            }
+        }
+
+        tx.commit().await?;
+        Ok(task_ids.len() as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        project::CreateProject,
+        task_dependency::{CreateTaskDependency, TaskDependency},
+    };
+
+    async fn create_test_project(pool: &SqlitePool) -> Uuid {
+        let project_id = Uuid::new_v4();
+        let project = CreateProject {
+            name: "Position Test".to_string(),
+            repositories: vec![],
+        };
+        Project::create(pool, &project, project_id).await.unwrap();
+        project_id
+    }
+
+    async fn create_test_task(pool: &SqlitePool, project_id: Uuid, title: &str) -> Uuid {
+        let task_id = Uuid::new_v4();
+        let data = CreateTask::from_title_description(project_id, title.to_string(), None);
+        Task::create(pool, &data, task_id).await.unwrap();
+        task_id
+    }
+
+    #[sqlx::test]
+    async fn test_update_dag_positions_bulk(pool: SqlitePool) {
+        let project_id = create_test_project(&pool).await;
+        let a = create_test_task(&pool, project_id, "A").await;
+        let b = create_test_task(&pool, project_id, "B").await;
+
+        let updates = vec![
+            DagPositionUpdate {
+                task_id: a,
+                x: 10.0,
+                y: 20.0,
+            },
+            DagPositionUpdate {
+                task_id: b,
+                x: 30.0,
+                y: 40.0,
+            },
+        ];
+        let updated = Task::update_dag_positions_bulk(&pool, project_id, &updates)
+            .await
+            .unwrap();
+        assert_eq!(updated, 2);
+
+        let a_task = Task::find_by_id(&pool, a).await.unwrap().unwrap();
+        assert_eq!(a_task.dag_position_x, Some(10.0));
+        assert_eq!(a_task.dag_position_y, Some(20.0));
+        let b_task = Task::find_by_id(&pool, b).await.unwrap().unwrap();
+        assert_eq!(b_task.dag_position_x, Some(30.0));
+        assert_eq!(b_task.dag_position_y, Some(40.0));
+    }
+
+    #[sqlx::test]
+    async fn test_update_dag_positions_bulk_is_atomic(pool: SqlitePool) {
+        let project_id = create_test_project(&pool).await;
+        let other_project_id = create_test_project(&pool).await;
+        let a = create_test_task(&pool, project_id, "A").await;
+        let foreign = create_test_task(&pool, other_project_id, "Foreign").await;
+
+        let updates = vec![
+            DagPositionUpdate {
+                task_id: a,
+                x: 10.0,
+                y: 20.0,
+            },
+            DagPositionUpdate {
+                task_id: foreign,
+                x: 30.0,
+                y: 40.0,
+            },
+        ];
+        let result = Task::update_dag_positions_bulk(&pool, project_id, &updates).await;
+        assert!(matches!(result, Err(sqlx::Error::RowNotFound)));
+
+        // The first update was rolled back with the rest of the batch
+        let a_task = Task::find_by_id(&pool, a).await.unwrap().unwrap();
+        assert_eq!(a_task.dag_position_x, None);
+        let foreign_task = Task::find_by_id(&pool, foreign).await.unwrap().unwrap();
+        assert_eq!(foreign_task.dag_position_x, None);
+    }
+
+    #[sqlx::test]
+    async fn test_update_status_bulk_is_atomic(pool: SqlitePool) {
+        let project_id = create_test_project(&pool).await;
+        let other_project_id = create_test_project(&pool).await;
+        let a = create_test_task(&pool, project_id, "A").await;
+        let b = create_test_task(&pool, project_id, "B").await;
+        let foreign = create_test_task(&pool, other_project_id, "Foreign").await;
+
+        let updated = Task::update_status_bulk(&pool, project_id, &[a, b], TaskStatus::Cancelled)
+            .await
+            .unwrap();
+        assert_eq!(updated, 2);
+        let b_task = Task::find_by_id(&pool, b).await.unwrap().unwrap();
+        assert_eq!(b_task.status, TaskStatus::Cancelled);
+
+        let result =
+            Task::update_status_bulk(&pool, project_id, &[a, foreign], TaskStatus::Done).await;
+        assert!(matches!(result, Err(sqlx::Error::RowNotFound)));
+        let a_task = Task::find_by_id(&pool, a).await.unwrap().unwrap();
+        assert_eq!(a_task.status, TaskStatus::Cancelled);
+    }
+
+    #[sqlx::test]
+    async fn test_find_orphans_by_project_id(pool: SqlitePool) {
+        let project_id = create_test_project(&pool).await;
+        let upstream = create_test_task(&pool, project_id, "Upstream").await;
+        let downstream = create_test_task(&pool, project_id, "Downstream").await;
+        let orphan = create_test_task(&pool, project_id, "Orphan").await;
+        let other_project_id = create_test_project(&pool).await;
+        create_test_task(&pool, other_project_id, "Other project").await;
+
+        TaskDependency::create(
+            &pool,
+            &CreateTaskDependency {
+                task_id: downstream,
+                depends_on_task_id: upstream,
+                created_by: None,
+                genre_id: None,
+                kind: None,
+                label: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        let orphans = Task::find_orphans_by_project_id(&pool, project_id)
+            .await
+            .unwrap();
+        let ids: Vec<Uuid> = orphans.iter().map(|t| t.id).collect();
+        assert_eq!(ids, vec![orphan]);
+    }
 }
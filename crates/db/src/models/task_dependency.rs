@@ -1,10 +1,15 @@
+use std::collections::{HashMap, VecDeque};
+
 use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use sqlx::{Executor, FromRow, Sqlite, SqlitePool, Type};
 use strum_macros::{Display, EnumString};
 use ts_rs::TS;
 use uuid::Uuid;
 
+use super::task::TaskStatus;
+
 /// Who created the dependency relationship
 #[derive(Debug, Clone, Type, Serialize, Deserialize, PartialEq, TS, EnumString, Display, Default)]
 #[sqlx(type_name = "dependency_creator", rename_all = "lowercase")]
@@ -16,6 +21,48 @@ pub enum DependencyCreator {
     Ai,
 }
 
+/// When a dependency holds back its dependent task
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Type,
+    Serialize,
+    Deserialize,
+    PartialEq,
+    Eq,
+    TS,
+    JsonSchema,
+    EnumString,
+    Display,
+    Default,
+)]
+#[sqlx(type_name = "dependency_kind", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DependencyKind {
+    /// The prerequisite must be done
+    #[default]
+    Strict,
+    /// A cancelled prerequisite counts as satisfied
+    SkipIfCancelled,
+    /// Never blocks; the edge is only informational
+    Optional,
+}
+
+impl DependencyKind {
+    /// Whether a prerequisite in `status` no longer holds back the dependent task
+    pub fn is_satisfied_by(&self, status: &TaskStatus) -> bool {
+        match self {
+            DependencyKind::Strict => *status == TaskStatus::Done,
+            DependencyKind::SkipIfCancelled => {
+                matches!(status, TaskStatus::Done | TaskStatus::Cancelled)
+            }
+            DependencyKind::Optional => true,
+        }
+    }
+}
+
 /// Represents a dependency relationship between tasks
 /// A dependency means task_id cannot be started until depends_on_task_id is completed
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize, TS)]
@@ -26,6 +73,9 @@ pub struct TaskDependency {
     pub genre_id: Option<Uuid>,   // Optional genre/category for this dependency
     pub created_at: DateTime<Utc>,
     pub created_by: DependencyCreator,
+    pub kind: DependencyKind,
+    /// Short free-text annotation drawn on the edge, independent of the genre
+    pub label: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
@@ -34,11 +84,29 @@ pub struct CreateTaskDependency {
     pub depends_on_task_id: Uuid,
     pub created_by: Option<DependencyCreator>,
     pub genre_id: Option<Uuid>,
+    pub kind: Option<DependencyKind>,
+    pub label: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 pub struct UpdateTaskDependency {
     pub genre_id: Option<Option<Uuid>>, // Option<Option<>> to allow unsetting
+    pub label: Option<Option<String>>,
+}
+
+/// Filters for listing a project's dependencies; unset fields match every edge
+#[derive(Debug, Clone, Default)]
+pub struct DependencyFilter {
+    pub created_by: Option<DependencyCreator>,
+    pub genre_id: Option<Uuid>,
+    /// Only edges created at or after this time
+    pub since: Option<DateTime<Utc>>,
+}
+
+impl DependencyFilter {
+    pub fn is_empty(&self) -> bool {
+        self.created_by.is_none() && self.genre_id.is_none() && self.since.is_none()
+    }
 }
 
 impl TaskDependency {
@@ -52,7 +120,9 @@ impl TaskDependency {
                 depends_on_task_id as "depends_on_task_id!: Uuid",
                 genre_id as "genre_id: Uuid",
                 created_at as "created_at!: DateTime<Utc>",
-                created_by as "created_by!: DependencyCreator"
+                created_by as "created_by!: DependencyCreator",
+                kind as "kind!: DependencyKind",
+                label
             FROM task_dependencies
             WHERE id = $1"#,
             id
@@ -71,7 +141,9 @@ impl TaskDependency {
                 depends_on_task_id as "depends_on_task_id!: Uuid",
                 genre_id as "genre_id: Uuid",
                 created_at as "created_at!: DateTime<Utc>",
-                created_by as "created_by!: DependencyCreator"
+                created_by as "created_by!: DependencyCreator",
+                kind as "kind!: DependencyKind",
+                label
             FROM task_dependencies
             WHERE rowid = $1"#,
             rowid
@@ -93,7 +165,9 @@ impl TaskDependency {
                 depends_on_task_id as "depends_on_task_id!: Uuid",
                 genre_id as "genre_id: Uuid",
                 created_at as "created_at!: DateTime<Utc>",
-                created_by as "created_by!: DependencyCreator"
+                created_by as "created_by!: DependencyCreator",
+                kind as "kind!: DependencyKind",
+                label
             FROM task_dependencies
             WHERE task_id = $1
             ORDER BY created_at ASC"#,
@@ -104,9 +178,39 @@ impl TaskDependency {
     }
 
     /// Find all dependencies for tasks in a given project
-    pub async fn find_by_project_id(
+    pub async fn find_by_project_id<'e, E>(
+        executor: E,
+        project_id: Uuid,
+    ) -> Result<Vec<Self>, sqlx::Error>
+    where
+        E: Executor<'e, Database = Sqlite>,
+    {
+        sqlx::query_as!(
+            TaskDependency,
+            r#"SELECT
+                td.id as "id!: Uuid",
+                td.task_id as "task_id!: Uuid",
+                td.depends_on_task_id as "depends_on_task_id!: Uuid",
+                td.genre_id as "genre_id: Uuid",
+                td.created_at as "created_at!: DateTime<Utc>",
+                td.created_by as "created_by!: DependencyCreator",
+                td.kind as "kind!: DependencyKind",
+                td.label
+            FROM task_dependencies td
+            INNER JOIN tasks t ON td.task_id = t.id
+            WHERE t.project_id = $1
+            ORDER BY td.created_at ASC"#,
+            project_id
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    /// Find the dependencies of a project created by a user or inferred by AI
+    pub async fn find_by_project_and_creator(
         pool: &SqlitePool,
         project_id: Uuid,
+        created_by: DependencyCreator,
     ) -> Result<Vec<Self>, sqlx::Error> {
         sqlx::query_as!(
             TaskDependency,
@@ -116,12 +220,49 @@ impl TaskDependency {
                 td.depends_on_task_id as "depends_on_task_id!: Uuid",
                 td.genre_id as "genre_id: Uuid",
                 td.created_at as "created_at!: DateTime<Utc>",
-                td.created_by as "created_by!: DependencyCreator"
+                td.created_by as "created_by!: DependencyCreator",
+                td.kind as "kind!: DependencyKind",
+                td.label
+            FROM task_dependencies td
+            INNER JOIN tasks t ON td.task_id = t.id
+            WHERE t.project_id = $1 AND td.created_by = $2
+            ORDER BY td.created_at ASC"#,
+            project_id,
+            created_by
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Find the dependencies of a project matching every set field of `filter`
+    pub async fn find_by_project_filtered(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        filter: &DependencyFilter,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        // julianday() compares the stored "YYYY-MM-DD HH:MM:SS" text with an RFC 3339 bound
+        sqlx::query_as!(
+            TaskDependency,
+            r#"SELECT
+                td.id as "id!: Uuid",
+                td.task_id as "task_id!: Uuid",
+                td.depends_on_task_id as "depends_on_task_id!: Uuid",
+                td.genre_id as "genre_id: Uuid",
+                td.created_at as "created_at!: DateTime<Utc>",
+                td.created_by as "created_by!: DependencyCreator",
+                td.kind as "kind!: DependencyKind",
+                td.label
             FROM task_dependencies td
             INNER JOIN tasks t ON td.task_id = t.id
             WHERE t.project_id = $1
+              AND ($2 IS NULL OR td.created_by = $2)
+              AND ($3 IS NULL OR td.genre_id = $3)
+              AND ($4 IS NULL OR julianday(td.created_at) >= julianday($4))
             ORDER BY td.created_at ASC"#,
-            project_id
+            project_id,
+            filter.created_by,
+            filter.genre_id,
+            filter.since
         )
         .fetch_all(pool)
         .await
@@ -140,7 +281,9 @@ impl TaskDependency {
                 depends_on_task_id as "depends_on_task_id!: Uuid",
                 genre_id as "genre_id: Uuid",
                 created_at as "created_at!: DateTime<Utc>",
-                created_by as "created_by!: DependencyCreator"
+                created_by as "created_by!: DependencyCreator",
+                kind as "kind!: DependencyKind",
+                label
             FROM task_dependencies
             WHERE depends_on_task_id = $1
             ORDER BY created_at ASC"#,
@@ -150,12 +293,37 @@ impl TaskDependency {
         .await
     }
 
+    /// Find every dependency a task takes part in, either as the dependent or as the prerequisite
+    pub async fn find_touching(pool: &SqlitePool, task_id: Uuid) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            TaskDependency,
+            r#"SELECT
+                id as "id!: Uuid",
+                task_id as "task_id!: Uuid",
+                depends_on_task_id as "depends_on_task_id!: Uuid",
+                genre_id as "genre_id: Uuid",
+                created_at as "created_at!: DateTime<Utc>",
+                created_by as "created_by!: DependencyCreator",
+                kind as "kind!: DependencyKind",
+                label
+            FROM task_dependencies
+            WHERE task_id = $1 OR depends_on_task_id = $1
+            ORDER BY created_at ASC"#,
+            task_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
     /// Check if a dependency exists between two tasks
-    pub async fn exists(
-        pool: &SqlitePool,
+    pub async fn exists<'e, E>(
+        executor: E,
         task_id: Uuid,
         depends_on_task_id: Uuid,
-    ) -> Result<bool, sqlx::Error> {
+    ) -> Result<bool, sqlx::Error>
+    where
+        E: Executor<'e, Database = Sqlite>,
+    {
         let result = sqlx::query_scalar!(
             r#"SELECT EXISTS(
                 SELECT 1 FROM task_dependencies
@@ -164,35 +332,43 @@ impl TaskDependency {
             task_id,
             depends_on_task_id
         )
-        .fetch_one(pool)
+        .fetch_one(executor)
         .await?;
         Ok(result)
     }
 
     /// Create a new dependency relationship
     /// Returns an error if the dependency would create a cycle
-    pub async fn create(pool: &SqlitePool, data: &CreateTaskDependency) -> Result<Self, sqlx::Error> {
+    pub async fn create<'e, E>(executor: E, data: &CreateTaskDependency) -> Result<Self, sqlx::Error>
+    where
+        E: Executor<'e, Database = Sqlite>,
+    {
         let id = Uuid::new_v4();
         let created_by = data.created_by.clone().unwrap_or_default();
+        let kind = data.kind.unwrap_or_default();
 
         sqlx::query_as!(
             TaskDependency,
-            r#"INSERT INTO task_dependencies (id, task_id, depends_on_task_id, genre_id, created_by)
-               VALUES ($1, $2, $3, $4, $5)
+            r#"INSERT INTO task_dependencies (id, task_id, depends_on_task_id, genre_id, created_by, kind, label)
+               VALUES ($1, $2, $3, $4, $5, $6, $7)
                RETURNING
                    id as "id!: Uuid",
                    task_id as "task_id!: Uuid",
                    depends_on_task_id as "depends_on_task_id!: Uuid",
                    genre_id as "genre_id: Uuid",
                    created_at as "created_at!: DateTime<Utc>",
-                   created_by as "created_by!: DependencyCreator""#,
+                   created_by as "created_by!: DependencyCreator",
+                   kind as "kind!: DependencyKind",
+                   label"#,
             id,
             data.task_id,
             data.depends_on_task_id,
             data.genre_id,
-            created_by
+            created_by,
+            kind,
+            data.label
         )
-        .fetch_one(pool)
+        .fetch_one(executor)
         .await
     }
 
@@ -212,11 +388,15 @@ impl TaskDependency {
             Some(g) => g.as_ref(),
             None => existing.genre_id.as_ref(),
         };
+        let label = match &data.label {
+            Some(label) => label.as_deref(),
+            None => existing.label.as_deref(),
+        };
 
         sqlx::query_as!(
             TaskDependency,
             r#"UPDATE task_dependencies
-               SET genre_id = $2
+               SET genre_id = $2, label = $3
                WHERE id = $1
                RETURNING
                    id as "id!: Uuid",
@@ -224,14 +404,63 @@ impl TaskDependency {
                    depends_on_task_id as "depends_on_task_id!: Uuid",
                    genre_id as "genre_id: Uuid",
                    created_at as "created_at!: DateTime<Utc>",
-                   created_by as "created_by!: DependencyCreator""#,
+                   created_by as "created_by!: DependencyCreator",
+                   kind as "kind!: DependencyKind",
+                   label"#,
             id,
-            genre_id
+            genre_id,
+            label
         )
         .fetch_one(pool)
         .await
     }
 
+    /// Change how strictly a dependency blocks, keeping its creation metadata
+    pub async fn update_kind(
+        pool: &SqlitePool,
+        id: Uuid,
+        kind: DependencyKind,
+    ) -> Result<Self, sqlx::Error> {
+        sqlx::query_as!(
+            TaskDependency,
+            r#"UPDATE task_dependencies
+               SET kind = $2
+               WHERE id = $1
+               RETURNING
+                   id as "id!: Uuid",
+                   task_id as "task_id!: Uuid",
+                   depends_on_task_id as "depends_on_task_id!: Uuid",
+                   genre_id as "genre_id: Uuid",
+                   created_at as "created_at!: DateTime<Utc>",
+                   created_by as "created_by!: DependencyCreator",
+                   kind as "kind!: DependencyKind",
+                   label"#,
+            id,
+            kind
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    /// Move every dependency tagged with `from_genre_id` to `to_genre_id` (or untag it when None)
+    pub async fn reassign_genre<'e, E>(
+        executor: E,
+        from_genre_id: Uuid,
+        to_genre_id: Option<Uuid>,
+    ) -> Result<u64, sqlx::Error>
+    where
+        E: Executor<'e, Database = Sqlite>,
+    {
+        let result = sqlx::query!(
+            "UPDATE task_dependencies SET genre_id = $2 WHERE genre_id = $1",
+            from_genre_id,
+            to_genre_id
+        )
+        .execute(executor)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
     /// Delete a dependency by its ID
     pub async fn delete<'e, E>(executor: E, id: Uuid) -> Result<u64, sqlx::Error>
     where
@@ -254,6 +483,26 @@ impl TaskDependency {
         Ok(result.rows_affected())
     }
 
+    /// Delete every dependency of a project with the given creator, e.g. rejected AI suggestions
+    pub async fn delete_by_project_and_creator(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        created_by: DependencyCreator,
+    ) -> Result<u64, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+        let result = sqlx::query!(
+            r#"DELETE FROM task_dependencies
+               WHERE created_by = $2
+                 AND task_id IN (SELECT id FROM tasks WHERE project_id = $1)"#,
+            project_id,
+            created_by
+        )
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(result.rows_affected())
+    }
+
     /// Delete a specific dependency between two tasks
     pub async fn delete_dependency<'e, E>(
         executor: E,
@@ -275,11 +524,14 @@ impl TaskDependency {
 
     /// Check if adding a dependency would create a cycle
     /// Uses recursive CTE to detect if depends_on_task_id can reach task_id through existing dependencies
-    pub async fn would_create_cycle(
-        pool: &SqlitePool,
+    pub async fn would_create_cycle<'e, E>(
+        executor: E,
         task_id: Uuid,
         depends_on_task_id: Uuid,
-    ) -> Result<bool, sqlx::Error> {
+    ) -> Result<bool, sqlx::Error>
+    where
+        E: Executor<'e, Database = Sqlite>,
+    {
         // If task_id depends on depends_on_task_id, we need to check if
         // depends_on_task_id can reach task_id through existing dependencies
         let result = sqlx::query_scalar!(
@@ -302,15 +554,82 @@ impl TaskDependency {
             task_id,
             depends_on_task_id
         )
-        .fetch_one(pool)
+        .fetch_one(executor)
         .await?;
         Ok(result)
     }
+
+    /// Find the chain that adding `task_id -> depends_on_task_id` would close into a cycle
+    /// Returns the shortest existing path from depends_on_task_id back to task_id, both included,
+    /// or None when the new dependency is safe.
+    pub async fn find_cycle_path<'e, E>(
+        executor: E,
+        task_id: Uuid,
+        depends_on_task_id: Uuid,
+    ) -> Result<Option<Vec<Uuid>>, sqlx::Error>
+    where
+        E: Executor<'e, Database = Sqlite>,
+    {
+        // Every edge reachable from depends_on_task_id, each listed once; UNION keeps the walk
+        // linear in the number of edges however many paths lead through them
+        let edges = sqlx::query!(
+            r#"WITH RECURSIVE reachable(node, parent) AS (
+                SELECT $2, NULL
+
+                UNION
+
+                SELECT td.depends_on_task_id, td.task_id
+                FROM task_dependencies td
+                INNER JOIN reachable r ON td.task_id = r.node
+            )
+            SELECT node as "node!: Uuid", parent as "parent: Uuid" FROM reachable"#,
+            task_id,
+            depends_on_task_id
+        )
+        .fetch_all(executor)
+        .await?;
+
+        let mut successors: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+        for edge in &edges {
+            if let Some(parent) = edge.parent {
+                successors.entry(parent).or_default().push(edge.node);
+            }
+        }
+
+        // Breadth-first search with one visited map, so the first hit is a shortest path
+        let mut came_from: HashMap<Uuid, Option<Uuid>> =
+            HashMap::from([(depends_on_task_id, None)]);
+        let mut queue = VecDeque::from([depends_on_task_id]);
+        while let Some(node) = queue.pop_front() {
+            if node == task_id {
+                let mut path = vec![node];
+                let mut current = node;
+                while let Some(&Some(previous)) = came_from.get(&current) {
+                    path.push(previous);
+                    current = previous;
+                }
+                path.reverse();
+                return Ok(Some(path));
+            }
+            for &next in successors.get(&node).into_iter().flatten() {
+                if !came_from.contains_key(&next) {
+                    came_from.insert(next, Some(node));
+                    queue.push_back(next);
+                }
+            }
+        }
+        Ok(None)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::{
+        dependency_genre::{CreateDependencyGenre, DependencyGenre},
+        project::{CreateProject, Project},
+        task::{CreateTask, Task},
+    };
 
     #[test]
     fn test_dependency_creator_display() {
@@ -324,4 +643,378 @@ mod tests {
         assert_eq!(DependencyCreator::from_str("user").unwrap(), DependencyCreator::User);
         assert_eq!(DependencyCreator::from_str("ai").unwrap(), DependencyCreator::Ai);
     }
+
+    #[test]
+    fn test_dependency_kind_display() {
+        assert_eq!(DependencyKind::Strict.to_string(), "strict");
+        assert_eq!(DependencyKind::SkipIfCancelled.to_string(), "skip_if_cancelled");
+        assert_eq!(DependencyKind::Optional.to_string(), "optional");
+    }
+
+    #[test]
+    fn test_dependency_kind_is_satisfied_by() {
+        assert!(DependencyKind::Strict.is_satisfied_by(&TaskStatus::Done));
+        assert!(!DependencyKind::Strict.is_satisfied_by(&TaskStatus::Cancelled));
+        assert!(DependencyKind::SkipIfCancelled.is_satisfied_by(&TaskStatus::Cancelled));
+        assert!(!DependencyKind::SkipIfCancelled.is_satisfied_by(&TaskStatus::InProgress));
+        assert!(DependencyKind::Optional.is_satisfied_by(&TaskStatus::Todo));
+    }
+
+    async fn create_test_project(pool: &SqlitePool) -> Uuid {
+        let project_id = Uuid::new_v4();
+        let project = CreateProject {
+            name: "Dependency Test".to_string(),
+            repositories: vec![],
+        };
+        Project::create(pool, &project, project_id).await.unwrap();
+        project_id
+    }
+
+    async fn create_test_tasks(pool: &SqlitePool, count: usize) -> Vec<Uuid> {
+        let project_id = create_test_project(pool).await;
+        create_project_tasks(pool, project_id, count).await
+    }
+
+    async fn create_project_tasks(pool: &SqlitePool, project_id: Uuid, count: usize) -> Vec<Uuid> {
+        let mut task_ids = Vec::new();
+        for index in 0..count {
+            let task_id = Uuid::new_v4();
+            let data =
+                CreateTask::from_title_description(project_id, format!("Task {}", index), None);
+            Task::create(pool, &data, task_id).await.unwrap();
+            task_ids.push(task_id);
+        }
+        task_ids
+    }
+
+    async fn create_test_dependency(
+        pool: &SqlitePool,
+        task_id: Uuid,
+        depends_on_task_id: Uuid,
+    ) -> TaskDependency {
+        create_test_dependency_by(pool, task_id, depends_on_task_id, DependencyCreator::User).await
+    }
+
+    async fn create_test_dependency_by(
+        pool: &SqlitePool,
+        task_id: Uuid,
+        depends_on_task_id: Uuid,
+        created_by: DependencyCreator,
+    ) -> TaskDependency {
+        let data = CreateTaskDependency {
+            task_id,
+            depends_on_task_id,
+            created_by: Some(created_by),
+            genre_id: None,
+            kind: None,
+            label: None,
+        };
+        TaskDependency::create(pool, &data).await.unwrap()
+    }
+
+    #[sqlx::test]
+    async fn test_delete_dependency_by_pair(pool: SqlitePool) {
+        let ids = create_test_tasks(&pool, 3).await;
+        create_test_dependency(&pool, ids[1], ids[0]).await;
+        let kept = create_test_dependency(&pool, ids[2], ids[0]).await;
+
+        let deleted = TaskDependency::delete_dependency(&pool, ids[1], ids[0]).await.unwrap();
+        assert_eq!(deleted, 1);
+        assert!(!TaskDependency::exists(&pool, ids[1], ids[0]).await.unwrap());
+        assert!(TaskDependency::find_by_id(&pool, kept.id).await.unwrap().is_some());
+
+        // The pair is directional, and a missing pair deletes nothing
+        let deleted = TaskDependency::delete_dependency(&pool, ids[0], ids[2]).await.unwrap();
+        assert_eq!(deleted, 0);
+    }
+
+    #[sqlx::test]
+    async fn test_find_and_delete_by_creator(pool: SqlitePool) {
+        let project_id = create_test_project(&pool).await;
+        let ids = create_project_tasks(&pool, project_id, 4).await;
+        let user = create_test_dependency(&pool, ids[1], ids[0]).await;
+        create_test_dependency_by(&pool, ids[2], ids[0], DependencyCreator::Ai).await;
+        create_test_dependency_by(&pool, ids[3], ids[2], DependencyCreator::Ai).await;
+
+        // AI edges in another project are left alone
+        let other = create_test_tasks(&pool, 2).await;
+        create_test_dependency_by(&pool, other[1], other[0], DependencyCreator::Ai).await;
+
+        let ai = TaskDependency::find_by_project_and_creator(&pool, project_id, DependencyCreator::Ai)
+            .await
+            .unwrap();
+        assert_eq!(ai.len(), 2);
+        assert!(ai.iter().all(|d| d.created_by == DependencyCreator::Ai));
+        let users =
+            TaskDependency::find_by_project_and_creator(&pool, project_id, DependencyCreator::User)
+                .await
+                .unwrap();
+        assert_eq!(users.len(), 1);
+
+        let deleted =
+            TaskDependency::delete_by_project_and_creator(&pool, project_id, DependencyCreator::Ai)
+                .await
+                .unwrap();
+        assert_eq!(deleted, 2);
+
+        let remaining = TaskDependency::find_by_project_id(&pool, project_id).await.unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].id, user.id);
+        assert!(TaskDependency::exists(&pool, other[1], other[0]).await.unwrap());
+    }
+
+    #[sqlx::test]
+    async fn test_find_by_project_filtered(pool: SqlitePool) {
+        let project_id = create_test_project(&pool).await;
+        let ids = create_project_tasks(&pool, project_id, 4).await;
+        let genre = DependencyGenre::create(
+            &pool,
+            &CreateDependencyGenre {
+                project_id,
+                name: "技術的依存".to_string(),
+                color: None,
+                position: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        let old = create_test_dependency(&pool, ids[1], ids[0]).await;
+        let ai = create_test_dependency_by(&pool, ids[2], ids[0], DependencyCreator::Ai).await;
+        let genred = TaskDependency::create(
+            &pool,
+            &CreateTaskDependency {
+                task_id: ids[3],
+                depends_on_task_id: ids[1],
+                created_by: None,
+                genre_id: Some(genre.id),
+                kind: None,
+                label: None,
+            },
+        )
+        .await
+        .unwrap();
+        sqlx::query(
+            "UPDATE task_dependencies SET created_at = '2020-01-01 00:00:00.000' WHERE id = $1",
+        )
+        .bind(old.id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let find = |filter: DependencyFilter| {
+            let pool = pool.clone();
+            async move {
+                TaskDependency::find_by_project_filtered(&pool, project_id, &filter)
+                    .await
+                    .unwrap()
+                    .into_iter()
+                    .map(|d| d.id)
+                    .collect::<Vec<_>>()
+            }
+        };
+
+        // No filter matches the unfiltered listing
+        let all = TaskDependency::find_by_project_id(&pool, project_id)
+            .await
+            .unwrap();
+        assert_eq!(
+            find(DependencyFilter::default()).await,
+            all.iter().map(|d| d.id).collect::<Vec<_>>()
+        );
+
+        let by_creator = DependencyFilter {
+            created_by: Some(DependencyCreator::Ai),
+            ..Default::default()
+        };
+        assert_eq!(find(by_creator).await, vec![ai.id]);
+
+        let by_genre = DependencyFilter {
+            genre_id: Some(genre.id),
+            ..Default::default()
+        };
+        assert_eq!(find(by_genre).await, vec![genred.id]);
+
+        let since = DependencyFilter {
+            since: Some("2021-01-01T00:00:00Z".parse().unwrap()),
+            ..Default::default()
+        };
+        let recent = find(since).await;
+        assert_eq!(recent.len(), 2);
+        assert!(!recent.contains(&old.id));
+
+        // Filters combine
+        let combined = DependencyFilter {
+            created_by: Some(DependencyCreator::User),
+            since: Some("2021-01-01T00:00:00Z".parse().unwrap()),
+            ..Default::default()
+        };
+        assert_eq!(find(combined).await, vec![genred.id]);
+    }
+
+    #[sqlx::test]
+    async fn test_find_cycle_path(pool: SqlitePool) {
+        let ids = create_test_tasks(&pool, 4).await;
+        let (a, b, c, d) = (ids[0], ids[1], ids[2], ids[3]);
+        // A -> B -> C, plus a side branch A -> D
+        create_test_dependency(&pool, a, b).await;
+        create_test_dependency(&pool, b, c).await;
+        create_test_dependency(&pool, a, d).await;
+
+        let path = TaskDependency::find_cycle_path(&pool, c, a).await.unwrap();
+        assert_eq!(path, Some(vec![a, b, c]));
+        assert!(TaskDependency::would_create_cycle(&pool, c, a).await.unwrap());
+
+        // Adding an edge along the existing direction is fine
+        assert_eq!(
+            TaskDependency::find_cycle_path(&pool, d, c).await.unwrap(),
+            None
+        );
+    }
+
+    #[sqlx::test]
+    async fn test_find_cycle_path_through_wide_diamonds(pool: SqlitePool) {
+        // Twenty stacked diamonds: 2^20 distinct paths from the top to the bottom
+        let layers = 20;
+        let ids = create_test_tasks(&pool, layers * 3 + 1).await;
+        for layer in 0..layers {
+            let (top, left, right, bottom) = (
+                ids[layer * 3],
+                ids[layer * 3 + 1],
+                ids[layer * 3 + 2],
+                ids[layer * 3 + 3],
+            );
+            create_test_dependency(&pool, top, left).await;
+            create_test_dependency(&pool, top, right).await;
+            create_test_dependency(&pool, left, bottom).await;
+            create_test_dependency(&pool, right, bottom).await;
+        }
+        let (top, bottom) = (ids[0], ids[layers * 3]);
+
+        // No cycle: the walk must finish without listing every path
+        let unrelated = create_test_tasks(&pool, 1).await[0];
+        assert_eq!(
+            TaskDependency::find_cycle_path(&pool, unrelated, top)
+                .await
+                .unwrap(),
+            None
+        );
+
+        // A cycle comes back as one shortest path, two steps per diamond
+        let path = TaskDependency::find_cycle_path(&pool, bottom, top)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(path.len(), layers * 2 + 1);
+        assert_eq!(path.first(), Some(&top));
+        assert_eq!(path.last(), Some(&bottom));
+    }
+
+    #[sqlx::test]
+    async fn test_create_dependency_with_label(pool: SqlitePool) {
+        let project_id = create_test_project(&pool).await;
+        let ids = create_project_tasks(&pool, project_id, 3).await;
+        let data = CreateTaskDependency {
+            task_id: ids[1],
+            depends_on_task_id: ids[0],
+            created_by: None,
+            genre_id: None,
+            kind: None,
+            label: Some("schema".to_string()),
+        };
+        let labelled = TaskDependency::create(&pool, &data).await.unwrap();
+        let unlabelled = create_test_dependency(&pool, ids[2], ids[0]).await;
+
+        assert_eq!(labelled.label.as_deref(), Some("schema"));
+        assert_eq!(unlabelled.label, None);
+
+        let listed = TaskDependency::find_by_project_id(&pool, project_id)
+            .await
+            .unwrap();
+        let labels: Vec<Option<&str>> = listed.iter().map(|d| d.label.as_deref()).collect();
+        assert_eq!(labels, vec![Some("schema"), None]);
+    }
+
+    #[sqlx::test]
+    async fn test_update_dependency_label(pool: SqlitePool) {
+        let project_id = create_test_project(&pool).await;
+        let ids = create_project_tasks(&pool, project_id, 2).await;
+        let genre = DependencyGenre::create(
+            &pool,
+            &CreateDependencyGenre {
+                project_id,
+                name: "技術的依存".to_string(),
+                color: None,
+                position: None,
+            },
+        )
+        .await
+        .unwrap();
+        let dependency = create_test_dependency(&pool, ids[1], ids[0]).await;
+
+        let set_genre = UpdateTaskDependency {
+            genre_id: Some(Some(genre.id)),
+            label: None,
+        };
+        TaskDependency::update(&pool, dependency.id, &set_genre)
+            .await
+            .unwrap();
+
+        // The label changes independently of the genre
+        let set_label = UpdateTaskDependency {
+            genre_id: None,
+            label: Some(Some("API contract".to_string())),
+        };
+        let updated = TaskDependency::update(&pool, dependency.id, &set_label)
+            .await
+            .unwrap();
+        assert_eq!(updated.label.as_deref(), Some("API contract"));
+        assert_eq!(updated.genre_id, Some(genre.id));
+
+        let clear_label = UpdateTaskDependency {
+            genre_id: None,
+            label: Some(None),
+        };
+        let cleared = TaskDependency::update(&pool, dependency.id, &clear_label)
+            .await
+            .unwrap();
+        assert_eq!(cleared.label, None);
+        assert_eq!(cleared.genre_id, Some(genre.id));
+    }
+
+    #[sqlx::test]
+    async fn test_update_dependency_kind(pool: SqlitePool) {
+        let project_id = create_test_project(&pool).await;
+        let ids = create_project_tasks(&pool, project_id, 2).await;
+        let dependency = create_test_dependency(&pool, ids[1], ids[0]).await;
+        assert_eq!(dependency.kind, DependencyKind::Strict);
+
+        let updated = TaskDependency::update_kind(&pool, dependency.id, DependencyKind::Optional)
+            .await
+            .unwrap();
+        assert_eq!(updated.kind, DependencyKind::Optional);
+        assert_eq!(updated.created_at, dependency.created_at);
+        assert_eq!(updated.created_by, dependency.created_by);
+    }
+
+    #[sqlx::test]
+    async fn test_find_touching(pool: SqlitePool) {
+        let ids = create_test_tasks(&pool, 4).await;
+        // 1 depends on 0, 2 depends on 1, 3 depends on 2
+        let incoming = create_test_dependency(&pool, ids[1], ids[0]).await;
+        let outgoing = create_test_dependency(&pool, ids[2], ids[1]).await;
+        create_test_dependency(&pool, ids[3], ids[2]).await;
+
+        let touching = TaskDependency::find_touching(&pool, ids[1]).await.unwrap();
+        let mut touching: Vec<Uuid> = touching.into_iter().map(|d| d.id).collect();
+        touching.sort();
+        let mut expected = vec![incoming.id, outgoing.id];
+        expected.sort();
+        assert_eq!(touching, expected);
+
+        // A task with no edges touches nothing
+        let lone = create_test_tasks(&pool, 1).await;
+        let untouched = TaskDependency::find_touching(&pool, lone[0]).await.unwrap();
+        assert!(untouched.is_empty());
+    }
 }
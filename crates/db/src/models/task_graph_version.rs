@@ -0,0 +1,95 @@
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+/// Per-project counter that database triggers bump on every task or dependency write
+///
+/// Lets a cached view of a project's graph detect changes made through any code path.
+pub struct TaskGraphVersion;
+
+impl TaskGraphVersion {
+    /// Current version of the project's task graph; 0 if it never changed
+    pub async fn find_by_project_id(
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<i64, sqlx::Error> {
+        let version = sqlx::query_scalar!(
+            r#"SELECT version as "version!: i64" FROM task_graph_versions WHERE project_id = $1"#,
+            project_id
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(version.unwrap_or(0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        project::{CreateProject, Project},
+        task::{CreateTask, Task, TaskStatus},
+        task_dependency::{CreateTaskDependency, TaskDependency},
+    };
+
+    #[sqlx::test]
+    async fn test_version_bumps_on_task_and_dependency_writes(pool: SqlitePool) {
+        let project_id = Uuid::new_v4();
+        let project = CreateProject {
+            name: "Graph Version Test".to_string(),
+            repositories: vec![],
+        };
+        Project::create(&pool, &project, project_id).await.unwrap();
+        assert_eq!(
+            TaskGraphVersion::find_by_project_id(&pool, project_id)
+                .await
+                .unwrap(),
+            0
+        );
+
+        let mut task_ids = Vec::new();
+        for title in ["a", "b"] {
+            let task_id = Uuid::new_v4();
+            let data = CreateTask::from_title_description(project_id, title.to_string(), None);
+            Task::create(&pool, &data, task_id).await.unwrap();
+            task_ids.push(task_id);
+        }
+        let after_create = TaskGraphVersion::find_by_project_id(&pool, project_id)
+            .await
+            .unwrap();
+        assert!(after_create > 0);
+
+        Task::update_status(&pool, task_ids[0], TaskStatus::Done)
+            .await
+            .unwrap();
+        let after_status = TaskGraphVersion::find_by_project_id(&pool, project_id)
+            .await
+            .unwrap();
+        assert!(after_status > after_create);
+
+        TaskDependency::create(
+            &pool,
+            &CreateTaskDependency {
+                task_id: task_ids[1],
+                depends_on_task_id: task_ids[0],
+                genre_id: None,
+                created_by: None,
+                kind: None,
+                label: None,
+            },
+        )
+        .await
+        .unwrap();
+        let after_dependency = TaskGraphVersion::find_by_project_id(&pool, project_id)
+            .await
+            .unwrap();
+        assert!(after_dependency > after_status);
+
+        // Other projects are unaffected
+        assert_eq!(
+            TaskGraphVersion::find_by_project_id(&pool, Uuid::new_v4())
+                .await
+                .unwrap(),
+            0
+        );
+    }
+}
@@ -1,10 +1,13 @@
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
 use sqlx::{Executor, FromRow, Sqlite, SqlitePool, Type};
 use strum_macros::{Display, EnumString};
 use ts_rs::TS;
 use uuid::Uuid;
 
+/// Task property written by the GitHub sync with the issue's assignee logins as a JSON array
+pub const ASSIGNEES_PROPERTY: &str = "github_assignees";
+
 #[derive(Debug, Clone, Type, Serialize, Deserialize, PartialEq, TS, EnumString, Display, Default)]
 #[sqlx(type_name = "property_source", rename_all = "lowercase")]
 #[serde(rename_all = "lowercase")]
@@ -26,6 +29,18 @@ pub struct TaskProperty {
     pub updated_at: DateTime<Utc>,
 }
 
+/// A recorded change of a task property's value
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize, TS)]
+pub struct TaskPropertyChange {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub property_name: String,
+    pub old_value: String,
+    pub new_value: String,
+    pub source: PropertySource,
+    pub changed_at: DateTime<Utc>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 pub struct CreateTaskProperty {
     pub task_id: Uuid,
@@ -101,13 +116,78 @@ impl TaskProperty {
         .await
     }
 
+    /// Decode the stored JSON value, or None (with a warning) when it doesn't match `T`
+    pub fn parse_value<T: DeserializeOwned>(&self) -> Option<T> {
+        match serde_json::from_str(&self.property_value) {
+            Ok(value) => Some(value),
+            Err(e) => {
+                tracing::warn!(
+                    "Ignoring malformed task property {} on task {}: {}",
+                    self.property_name,
+                    self.task_id,
+                    e
+                );
+                None
+            }
+        }
+    }
+
+    /// Look up one property of a task and decode its JSON value
+    /// Missing and malformed values both come back as None.
+    pub async fn find_json<T: DeserializeOwned>(
+        pool: &SqlitePool,
+        task_id: Uuid,
+        property_name: &str,
+    ) -> Result<Option<T>, sqlx::Error> {
+        Ok(Self::find_by_task_and_name(pool, task_id, property_name)
+            .await?
+            .and_then(|property| property.parse_value()))
+    }
+
+    /// One property across every task of a project, e.g. for building a task-to-value map
+    pub async fn find_by_project_and_name(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        property_name: &str,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            TaskProperty,
+            r#"SELECT
+                tp.id as "id!: Uuid",
+                tp.task_id as "task_id!: Uuid",
+                tp.property_name,
+                tp.property_value,
+                tp.source as "source!: PropertySource",
+                tp.created_at as "created_at!: DateTime<Utc>",
+                tp.updated_at as "updated_at!: DateTime<Utc>"
+            FROM task_properties tp
+            JOIN tasks t ON t.id = tp.task_id
+            WHERE t.project_id = $1 AND tp.property_name = $2"#,
+            project_id,
+            property_name
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Insert or overwrite a property, recording the old value in its history if it changed
     pub async fn upsert(
         pool: &SqlitePool,
         data: &CreateTaskProperty,
     ) -> Result<Self, sqlx::Error> {
         let id = Uuid::new_v4();
         let source = data.source.clone().unwrap_or_default();
-        sqlx::query_as!(
+        let mut tx = pool.begin().await?;
+
+        let previous_value = sqlx::query_scalar!(
+            "SELECT property_value FROM task_properties WHERE task_id = $1 AND property_name = $2",
+            data.task_id,
+            data.property_name
+        )
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let property = sqlx::query_as!(
             TaskProperty,
             r#"INSERT INTO task_properties (id, task_id, property_name, property_value, source)
             VALUES ($1, $2, $3, $4, $5)
@@ -129,7 +209,52 @@ impl TaskProperty {
             data.property_value,
             source
         )
-        .fetch_one(pool)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        if let Some(old_value) = previous_value.filter(|old| *old != property.property_value) {
+            let change_id = Uuid::new_v4();
+            sqlx::query!(
+                r#"INSERT INTO task_property_history (id, task_id, property_name, old_value, new_value, source)
+                VALUES ($1, $2, $3, $4, $5, $6)"#,
+                change_id,
+                property.task_id,
+                property.property_name,
+                old_value,
+                property.property_value,
+                property.source
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(property)
+    }
+
+    /// Past value changes of one property, oldest first
+    pub async fn history(
+        pool: &SqlitePool,
+        task_id: Uuid,
+        property_name: &str,
+    ) -> Result<Vec<TaskPropertyChange>, sqlx::Error> {
+        sqlx::query_as!(
+            TaskPropertyChange,
+            r#"SELECT
+                id as "id!: Uuid",
+                task_id as "task_id!: Uuid",
+                property_name,
+                old_value,
+                new_value,
+                source as "source!: PropertySource",
+                changed_at as "changed_at!: DateTime<Utc>"
+            FROM task_property_history
+            WHERE task_id = $1 AND property_name = $2
+            ORDER BY changed_at ASC, rowid ASC"#,
+            task_id,
+            property_name
+        )
+        .fetch_all(pool)
         .await
     }
 
@@ -143,6 +268,24 @@ impl TaskProperty {
         Ok(result.rows_affected())
     }
 
+    pub async fn delete_by_task_and_source<'e, E>(
+        executor: E,
+        task_id: Uuid,
+        source: PropertySource,
+    ) -> Result<u64, sqlx::Error>
+    where
+        E: Executor<'e, Database = Sqlite>,
+    {
+        let result = sqlx::query!(
+            "DELETE FROM task_properties WHERE task_id = $1 AND source = $2",
+            task_id,
+            source
+        )
+        .execute(executor)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
     pub async fn delete_by_task_id<'e, E>(executor: E, task_id: Uuid) -> Result<u64, sqlx::Error>
     where
         E: Executor<'e, Database = Sqlite>,
@@ -153,3 +296,79 @@ impl TaskProperty {
         Ok(result.rows_affected())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        project::{CreateProject, Project},
+        task::{CreateTask, Task},
+    };
+
+    async fn create_test_task(pool: &SqlitePool) -> Uuid {
+        let project_id = Uuid::new_v4();
+        let project = CreateProject {
+            name: "Property Test".to_string(),
+            repositories: vec![],
+        };
+        Project::create(pool, &project, project_id).await.unwrap();
+
+        let task_id = Uuid::new_v4();
+        let task = CreateTask::from_title_description(project_id, "Task".to_string(), None);
+        Task::create(pool, &task, task_id).await.unwrap();
+        task_id
+    }
+
+    async fn set_property(pool: &SqlitePool, task_id: Uuid, name: &str, value: &str) {
+        let data = CreateTaskProperty {
+            task_id,
+            property_name: name.to_string(),
+            property_value: value.to_string(),
+            source: None,
+        };
+        TaskProperty::upsert(pool, &data).await.unwrap();
+    }
+
+    #[sqlx::test]
+    async fn test_find_json(pool: SqlitePool) {
+        let task_id = create_test_task(&pool).await;
+        set_property(&pool, task_id, "estimate_minutes", "90").await;
+        set_property(&pool, task_id, "labels", "[\"bug\"").await;
+
+        let estimate: Option<u32> = TaskProperty::find_json(&pool, task_id, "estimate_minutes")
+            .await
+            .unwrap();
+        assert_eq!(estimate, Some(90));
+
+        // Malformed and missing values fall back to None
+        let labels: Option<Vec<String>> = TaskProperty::find_json(&pool, task_id, "labels")
+            .await
+            .unwrap();
+        assert_eq!(labels, None);
+        let milestone: Option<String> = TaskProperty::find_json(&pool, task_id, "milestone")
+            .await
+            .unwrap();
+        assert_eq!(milestone, None);
+    }
+
+    #[sqlx::test]
+    async fn test_upsert_records_value_changes(pool: SqlitePool) {
+        let task_id = create_test_task(&pool).await;
+        set_property(&pool, task_id, "github_status", "Todo").await;
+        set_property(&pool, task_id, "github_status", "In Progress").await;
+
+        let history = TaskProperty::history(&pool, task_id, "github_status")
+            .await
+            .unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].old_value, "Todo");
+        assert_eq!(history[0].new_value, "In Progress");
+
+        // Re-writing the same value is not a change
+        set_property(&pool, task_id, "github_status", "In Progress").await;
+        let history = TaskProperty::history(&pool, task_id, "github_status")
+            .await
+            .unwrap();
+        assert_eq!(history.len(), 1);
+    }
+}
@@ -1,15 +1,48 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::future::Future;
 use std::sync::Arc;
+use chrono::{DateTime, Utc};
 use tokio::sync::{broadcast, RwLock};
+use tracing::{debug, info, instrument, warn};
 use uuid::Uuid;
 
+use db::models::project_transition_rule::ProjectTransitionRule;
 use db::models::task::{Task, TaskStatus};
 use db::models::task_dependency::TaskDependency;
+use db::models::task_graph_version::TaskGraphVersion;
+use db::models::task_property::{TaskProperty, ASSIGNEES_PROPERTY};
 use sqlx::SqlitePool;
 
-use crate::models::{ExecutionPlan, OrchestratorEvent, OrchestratorState};
-use crate::scheduler::{build_execution_plan, get_ready_tasks, get_tasks_unblocked_by_completion};
-use crate::state_machine::validate_transition;
+use crate::messages::OrchestratorMessage;
+use crate::models::{
+    AtRiskTask, BulkTransitionResult, ExecutionPlan, ExplanationLanguage, GanttChart, NextAction,
+    OrchestratorEvent, OrchestratorMetrics, OrchestratorState, PlanDiff, TaskReadiness,
+    TransitionValidation, VersionedPlan,
+};
+use crate::scheduler::{
+    assign_owners, at_risk_tasks, build_execution_plan, build_execution_plan_capped,
+    build_execution_plan_filtered, build_gantt, compute_metrics, diff_readiness,
+    forecast_unblocked, get_ready_tasks, get_tasks_unblocked_by_completion, next_action,
+    occupied_execution_slots, DEFAULT_STALE_AFTER_SECS,
+};
+use crate::state_machine::{
+    validate_transition_with_override, StatusTransition, TransitionRuleSet,
+};
+
+/// Task property holding the expected duration of a task in minutes
+const ESTIMATE_PROPERTY: &str = "estimate_minutes";
+
+/// Number of past events kept for `recent_events`
+const RECENT_EVENTS_CAPACITY: usize = 100;
+
+/// Default number of events buffered per subscriber before it starts lagging
+pub const DEFAULT_EVENT_CHANNEL_CAPACITY: usize = 100;
+
+/// Number of past plan versions kept for `plan_diff_since`
+const PLAN_HISTORY_CAPACITY: usize = 20;
+
+/// Default longest dependency chain above which `DeepChainWarning` is emitted
+pub const DEFAULT_DEEP_CHAIN_THRESHOLD: usize = 10;
 
 /// Error types for orchestrator operations
 #[derive(Debug, thiserror::Error)]
@@ -23,6 +56,9 @@ pub enum OrchestratorError {
     #[error("Task not found: {0}")]
     TaskNotFound(Uuid),
 
+    #[error("Invalid transition rules: {}", .0.render(ExplanationLanguage::En))]
+    InvalidTransitionRules(OrchestratorMessage),
+
     #[error("Orchestrator is not running")]
     NotRunning,
 
@@ -37,39 +73,580 @@ pub struct ProjectOrchestrator {
     event_sender: broadcast::Sender<OrchestratorEvent>,
     /// Maximum number of tasks that can run in parallel
     max_parallel_tasks: usize,
+    /// Status transitions this project allows, or None until read from the database
+    transition_rules: RwLock<Option<TransitionRuleSet>>,
+    /// Whether newly ready tasks are requested to start automatically
+    auto_start: RwLock<bool>,
+    /// Last built execution plan, or None once invalidated by a task or dependency change
+    plan_cache: RwLock<Option<ExecutionPlan>>,
+    /// Task graph version last read from the database, to catch writes made without notifying
+    graph_version: RwLock<i64>,
+    /// Plan sent with the last `PlanUpdated`, used to detect readiness changes
+    last_emitted_plan: RwLock<Option<ExecutionPlan>>,
+    /// Tasks already reported completed in this run, so retried notifications are ignored
+    completed_tasks: RwLock<HashSet<Uuid>>,
+    /// Tasks reported failed and not started or completed since, for `retry_failed`
+    failed_tasks: RwLock<HashSet<Uuid>>,
+    /// When each currently ready task was first seen as ready by a plan rebuild
+    ready_since: RwLock<HashMap<Uuid, DateTime<Utc>>>,
+    /// The last `RECENT_EVENTS_CAPACITY` events, oldest first, for subscribers that join late
+    recent_events: RwLock<VecDeque<OrchestratorEvent>>,
+    /// Tasks the user excluded from scheduling; they stay in the plan flagged as skipped
+    skipped_task_ids: RwLock<HashSet<Uuid>>,
+    /// Identifies this instance's plan versions, which restart from 1 after a reset or eviction
+    plan_epoch: Uuid,
+    /// The last `PLAN_HISTORY_CAPACITY` distinct plans, oldest first, for serving diffs
+    plan_history: RwLock<VecDeque<VersionedPlan>>,
+    /// Longest dependency chain tolerated before warning that the graph is too sequential
+    deep_chain_threshold: RwLock<usize>,
+    /// How long a task may run before it is reported timed out; `None` disables the check
+    task_timeout_secs: RwLock<Option<u32>>,
+    /// When each running task was reported started, for timeout checks
+    started_at: RwLock<HashMap<Uuid, DateTime<Utc>>>,
+    /// Running tasks already reported with `TaskTimedOut`, so the event is emitted once per run
+    timed_out_tasks: RwLock<HashSet<Uuid>>,
 }
 
 impl ProjectOrchestrator {
     pub fn new(project_id: Uuid, max_parallel_tasks: usize) -> Self {
-        let (event_sender, _) = broadcast::channel(100);
+        Self::with_event_capacity(
+            project_id,
+            max_parallel_tasks,
+            DEFAULT_EVENT_CHANNEL_CAPACITY,
+        )
+    }
+
+    /// Create an orchestrator whose event channel buffers `event_capacity` events per subscriber
+    ///
+    /// Subscribers that fall further behind receive `RecvError::Lagged` and miss the oldest events.
+    pub fn with_event_capacity(
+        project_id: Uuid,
+        max_parallel_tasks: usize,
+        event_capacity: usize,
+    ) -> Self {
+        let (event_sender, _) = broadcast::channel(event_capacity.max(1));
         Self {
             project_id,
             state: RwLock::new(OrchestratorState::Idle),
             event_sender,
             max_parallel_tasks,
+            transition_rules: RwLock::new(None),
+            auto_start: RwLock::new(false),
+            plan_cache: RwLock::new(None),
+            graph_version: RwLock::new(0),
+            last_emitted_plan: RwLock::new(None),
+            completed_tasks: RwLock::new(HashSet::new()),
+            failed_tasks: RwLock::new(HashSet::new()),
+            ready_since: RwLock::new(HashMap::new()),
+            recent_events: RwLock::new(VecDeque::with_capacity(RECENT_EVENTS_CAPACITY)),
+            skipped_task_ids: RwLock::new(HashSet::new()),
+            plan_epoch: Uuid::new_v4(),
+            plan_history: RwLock::new(VecDeque::with_capacity(PLAN_HISTORY_CAPACITY)),
+            deep_chain_threshold: RwLock::new(DEFAULT_DEEP_CHAIN_THRESHOLD),
+            task_timeout_secs: RwLock::new(None),
+            started_at: RwLock::new(HashMap::new()),
+            timed_out_tasks: RwLock::new(HashSet::new()),
         }
     }
 
+    /// Maximum number of tasks this orchestrator runs at once
+    pub fn max_parallel_tasks(&self) -> usize {
+        self.max_parallel_tasks
+    }
+
     /// Subscribe to orchestrator events
     pub fn subscribe(&self) -> broadcast::Receiver<OrchestratorEvent> {
         self.event_sender.subscribe()
     }
 
+    /// The last `limit` emitted events, oldest first
+    ///
+    /// Only covers events since this orchestrator was created, up to `RECENT_EVENTS_CAPACITY`.
+    pub async fn recent_events(&self, limit: usize) -> Vec<OrchestratorEvent> {
+        let recent = self.recent_events.read().await;
+        let skip = recent.len().saturating_sub(limit);
+        recent.iter().skip(skip).cloned().collect()
+    }
+
+    /// Events that bring a subscriber that missed some events back in sync
+    ///
+    /// Sent to lagging subscribers in place of the events dropped from their buffer.
+    #[instrument(level = "debug", skip_all, fields(project_id = %self.project_id))]
+    pub async fn snapshot_events(
+        &self,
+        pool: &SqlitePool,
+    ) -> Result<Vec<OrchestratorEvent>, OrchestratorError> {
+        let plan = self.build_plan(pool).await?;
+        Ok(vec![
+            OrchestratorEvent::StateChanged {
+                state: self.get_state().await,
+            },
+            OrchestratorEvent::PlanUpdated { plan },
+        ])
+    }
+
     /// Get current orchestrator state
     pub async fn get_state(&self) -> OrchestratorState {
         *self.state.read().await
     }
 
+    /// Check whether auto-start is enabled
+    pub async fn is_auto_start(&self) -> bool {
+        *self.auto_start.read().await
+    }
+
+    /// Enable or disable automatically requesting newly ready tasks to start
+    #[instrument(skip(self), fields(project_id = %self.project_id))]
+    pub async fn set_auto_start(&self, enabled: bool) {
+        *self.auto_start.write().await = enabled;
+    }
+
+    /// Get the transition rules used to validate status changes
+    ///
+    /// The project's saved rules are read on first use; a project without saved rules gets the
+    /// built-in matrix.
+    pub async fn get_transition_rules(
+        &self,
+        pool: &SqlitePool,
+    ) -> Result<TransitionRuleSet, OrchestratorError> {
+        if let Some(rules) = self.transition_rules.read().await.as_ref() {
+            return Ok(rules.clone());
+        }
+
+        let mut cached = self.transition_rules.write().await;
+        if let Some(rules) = cached.as_ref() {
+            return Ok(rules.clone());
+        }
+        let saved = ProjectTransitionRule::find_by_project_id(pool, self.project_id).await?;
+        let rules = if saved.is_empty() {
+            TransitionRuleSet::default()
+        } else {
+            TransitionRuleSet {
+                allowed: saved
+                    .into_iter()
+                    .map(|rule| StatusTransition {
+                        from: rule.from_status,
+                        to: rule.to_status,
+                    })
+                    .collect(),
+            }
+        };
+        *cached = Some(rules.clone());
+        Ok(rules)
+    }
+
+    /// Validate, save and apply the transition rules used to validate status changes
+    #[instrument(skip_all, fields(project_id = %self.project_id))]
+    pub async fn set_transition_rules(
+        &self,
+        pool: &SqlitePool,
+        rules: TransitionRuleSet,
+    ) -> Result<(), OrchestratorError> {
+        rules
+            .validate()
+            .map_err(OrchestratorError::InvalidTransitionRules)?;
+
+        let saved: Vec<ProjectTransitionRule> = rules
+            .allowed
+            .iter()
+            .map(|transition| ProjectTransitionRule {
+                from_status: transition.from.clone(),
+                to_status: transition.to.clone(),
+            })
+            .collect();
+        let mut cached = self.transition_rules.write().await;
+        ProjectTransitionRule::replace_for_project(pool, self.project_id, &saved).await?;
+        *cached = Some(rules);
+        Ok(())
+    }
+
+    /// Get the chain depth above which `DeepChainWarning` is emitted
+    pub async fn deep_chain_threshold(&self) -> usize {
+        *self.deep_chain_threshold.read().await
+    }
+
+    /// Set the chain depth above which `DeepChainWarning` is emitted
+    #[instrument(skip(self), fields(project_id = %self.project_id))]
+    pub async fn set_deep_chain_threshold(&self, threshold: usize) {
+        *self.deep_chain_threshold.write().await = threshold;
+    }
+
+    /// Get how long a task may run before it is reported timed out, if the check is enabled
+    pub async fn task_timeout_secs(&self) -> Option<u32> {
+        *self.task_timeout_secs.read().await
+    }
+
+    /// Set how long a task may run before it is reported timed out; `None` disables the check
+    #[instrument(skip(self), fields(project_id = %self.project_id))]
+    pub async fn set_task_timeout_secs(&self, timeout_secs: Option<u32>) {
+        *self.task_timeout_secs.write().await = timeout_secs;
+    }
+
+    /// Running tasks that have exceeded the task timeout, longest running first
+    ///
+    /// Emits `TaskTimedOut` the first time each task is found over the timeout. Only tasks
+    /// reported through `on_task_started` are tracked, until they finish or a plan rebuild
+    /// shows them finished, and their status is left unchanged.
+    #[instrument(level = "debug", skip_all, fields(project_id = %self.project_id))]
+    pub async fn check_timeouts(&self) -> Vec<Uuid> {
+        self.check_timeouts_at(Utc::now()).await
+    }
+
+    async fn check_timeouts_at(&self, now: DateTime<Utc>) -> Vec<Uuid> {
+        let Some(timeout_secs) = self.task_timeout_secs().await else {
+            return vec![];
+        };
+
+        let mut overdue: Vec<(Uuid, u32)> = self
+            .started_at
+            .read()
+            .await
+            .iter()
+            .filter_map(|(task_id, started)| {
+                let elapsed = u32::try_from((now - *started).num_seconds()).ok()?;
+                (elapsed > timeout_secs).then_some((*task_id, elapsed))
+            })
+            .collect();
+        overdue.sort_by(|a, b| b.1.cmp(&a.1));
+
+        for &(task_id, elapsed_secs) in &overdue {
+            if self.timed_out_tasks.write().await.insert(task_id) {
+                warn!(task_id = %task_id, elapsed_secs, "task exceeded the timeout");
+                self.emit_event(OrchestratorEvent::TaskTimedOut {
+                    task_id,
+                    elapsed_secs,
+                })
+                .await;
+            }
+        }
+
+        overdue.into_iter().map(|(task_id, _)| task_id).collect()
+    }
+
+    /// Start tracking a task's run time for timeout checks
+    async fn track_started(&self, task_id: Uuid, at: DateTime<Utc>) {
+        self.started_at.write().await.insert(task_id, at);
+        self.timed_out_tasks.write().await.remove(&task_id);
+    }
+
+    /// Stop tracking a task's run time once it is no longer running
+    async fn untrack_started(&self, task_id: Uuid) {
+        self.started_at.write().await.remove(&task_id);
+        self.timed_out_tasks.write().await.remove(&task_id);
+    }
+
+    /// Stop tracking tasks the plan shows as done, cancelled, in review or deleted
+    ///
+    /// Catches status changes made without `on_task_completed` and friends. Todo tasks stay
+    /// tracked, since the started notification may arrive before the status is written.
+    async fn untrack_finished(&self, plan: &ExecutionPlan) {
+        let statuses: HashMap<Uuid, &TaskStatus> = plan
+            .levels
+            .iter()
+            .flat_map(|level| level.tasks.iter())
+            .map(|task| (task.task_id, &task.status))
+            .collect();
+        let finished: Vec<Uuid> = self
+            .started_at
+            .read()
+            .await
+            .keys()
+            .filter(|task_id| {
+                !matches!(
+                    statuses.get(*task_id),
+                    Some(TaskStatus::Todo | TaskStatus::InProgress)
+                )
+            })
+            .copied()
+            .collect();
+        for task_id in finished {
+            self.untrack_started(task_id).await;
+        }
+    }
+
+    /// Exclude a task from scheduling without changing its status
+    ///
+    /// Returns false if the task was already skipped.
+    #[instrument(skip_all, fields(project_id = %self.project_id, task_id = %task_id))]
+    pub async fn skip_task(&self, task_id: Uuid) -> bool {
+        let inserted = self.skipped_task_ids.write().await.insert(task_id);
+        if inserted {
+            self.invalidate_plan().await;
+        }
+        inserted
+    }
+
+    /// Let a skipped task be scheduled again
+    ///
+    /// Returns false if the task wasn't skipped.
+    #[instrument(skip_all, fields(project_id = %self.project_id, task_id = %task_id))]
+    pub async fn unskip_task(&self, task_id: Uuid) -> bool {
+        let removed = self.skipped_task_ids.write().await.remove(&task_id);
+        if removed {
+            self.invalidate_plan().await;
+        }
+        removed
+    }
+
     /// Build execution plan for this project
+    ///
+    /// Returns the cached plan unless it was invalidated, or the project's tasks or dependencies
+    /// changed in the database, since the last build.
+    #[instrument(level = "debug", skip_all, fields(project_id = %self.project_id))]
     pub async fn build_plan(&self, pool: &SqlitePool) -> Result<ExecutionPlan, OrchestratorError> {
+        self.refresh_graph_version(pool).await?;
+        let mut plan = self.build_plan_with(|| self.load_project(pool)).await?;
+        assign_owners(&mut plan, &self.load_owners(pool).await?);
+        Ok(plan)
+    }
+
+    /// Snapshot counts, graph shape and state for monitoring
+    #[instrument(level = "debug", skip_all, fields(project_id = %self.project_id))]
+    pub async fn metrics(
+        &self,
+        pool: &SqlitePool,
+    ) -> Result<OrchestratorMetrics, OrchestratorError> {
+        let plan = self.build_plan(pool).await?;
+        Ok(compute_metrics(&plan, self.get_state().await))
+    }
+
+    /// Project when each task of the plan would start and finish
+    ///
+    /// Durations come from each task's `estimate_minutes` property; slot contention follows
+    /// this orchestrator's parallel limit.
+    #[instrument(level = "debug", skip_all, fields(project_id = %self.project_id))]
+    pub async fn gantt(&self, pool: &SqlitePool) -> Result<GanttChart, OrchestratorError> {
+        let plan = self.build_plan(pool).await?;
+        let estimates = self.load_estimates(pool).await?;
+        Ok(build_gantt(&plan, &estimates, self.max_parallel_tasks))
+    }
+
+    /// Build a plan restricted to one dependency genre's edges
+    ///
+    /// Always reloads from the database; the cache only holds the unfiltered plan.
+    #[instrument(level = "debug", skip(self, pool), fields(project_id = %self.project_id))]
+    pub async fn build_plan_for_genre(
+        &self,
+        pool: &SqlitePool,
+        genre_id: Uuid,
+    ) -> Result<ExecutionPlan, OrchestratorError> {
+        let (tasks, dependencies) = self.load_project(pool).await?;
+        let mut plan = build_execution_plan_filtered(&tasks, &dependencies, genre_id);
+        self.mark_skipped(&mut plan).await;
+        assign_owners(&mut plan, &self.load_owners(pool).await?);
+        Ok(plan)
+    }
+
+    /// Build a plan whose levels hold at most this orchestrator's parallel limit of tasks
+    ///
+    /// With `genre_id`, only that genre's edges are considered. Like
+    /// [`Self::build_plan_for_genre`], this bypasses the plan cache.
+    #[instrument(level = "debug", skip(self, pool), fields(project_id = %self.project_id))]
+    pub async fn build_capped_plan(
+        &self,
+        pool: &SqlitePool,
+        genre_id: Option<Uuid>,
+    ) -> Result<ExecutionPlan, OrchestratorError> {
+        let (tasks, mut dependencies) = self.load_project(pool).await?;
+        if let Some(genre_id) = genre_id {
+            dependencies.retain(|dep| dep.genre_id == Some(genre_id));
+        }
+        let mut plan = build_execution_plan_capped(&tasks, &dependencies, self.max_parallel_tasks);
+        self.mark_skipped(&mut plan).await;
+        assign_owners(&mut plan, &self.load_owners(pool).await?);
+        Ok(plan)
+    }
+
+    async fn load_project(
+        &self,
+        pool: &SqlitePool,
+    ) -> Result<(Vec<Task>, Vec<TaskDependency>), OrchestratorError> {
         let tasks = Task::find_by_project_id(pool, self.project_id).await?;
-        let dependencies =
-            TaskDependency::find_by_project_id(pool, self.project_id).await?;
+        let dependencies = TaskDependency::find_by_project_id(pool, self.project_id).await?;
+        Ok((tasks, dependencies))
+    }
+
+    /// Map each task to its first GitHub assignee
+    ///
+    /// Loaded on every build rather than cached, since syncs update assignees without
+    /// invalidating the plan.
+    async fn load_owners(
+        &self,
+        pool: &SqlitePool,
+    ) -> Result<HashMap<Uuid, String>, OrchestratorError> {
+        let properties =
+            TaskProperty::find_by_project_and_name(pool, self.project_id, ASSIGNEES_PROPERTY)
+                .await?;
+        Ok(properties
+            .into_iter()
+            .filter_map(|property| {
+                let logins: Vec<String> = property.parse_value()?;
+                Some((property.task_id, logins.into_iter().next()?))
+            })
+            .collect())
+    }
+
+    /// Map each task to its estimated duration, skipping values that aren't a whole number
+    async fn load_estimates(
+        &self,
+        pool: &SqlitePool,
+    ) -> Result<HashMap<Uuid, u32>, OrchestratorError> {
+        let properties =
+            TaskProperty::find_by_project_and_name(pool, self.project_id, ESTIMATE_PROPERTY)
+                .await?;
+        Ok(properties
+            .into_iter()
+            .filter_map(|property| Some((property.task_id, property.parse_value()?)))
+            .collect())
+    }
+
+    /// Mark the cached plan as stale so the next build reloads tasks and dependencies
+    #[instrument(level = "debug", skip_all, fields(project_id = %self.project_id))]
+    pub async fn invalidate_plan(&self) {
+        *self.plan_cache.write().await = None;
+    }
+
+    /// Invalidate the cached plan if the project's tasks or dependencies changed in the database
+    ///
+    /// Covers writes that never reach `invalidate_plan`, such as an executor finishing a task or
+    /// a GitHub sync updating statuses.
+    async fn refresh_graph_version(&self, pool: &SqlitePool) -> Result<(), OrchestratorError> {
+        let version = TaskGraphVersion::find_by_project_id(pool, self.project_id).await?;
+        let mut seen = self.graph_version.write().await;
+        if *seen != version {
+            *seen = version;
+            self.invalidate_plan().await;
+        }
+        Ok(())
+    }
+
+    async fn build_plan_with<F, Fut>(&self, load: F) -> Result<ExecutionPlan, OrchestratorError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<(Vec<Task>, Vec<TaskDependency>), OrchestratorError>>,
+    {
+        // Hold the write lock while rebuilding so an invalidation can't be lost mid-build
+        let mut cache = self.plan_cache.write().await;
+        if let Some(plan) = cache.as_ref() {
+            return Ok(plan.clone());
+        }
 
-        Ok(build_execution_plan(&tasks, &dependencies))
+        let (tasks, dependencies) = load().await?;
+        let mut plan = build_execution_plan(&tasks, &dependencies);
+        self.stamp_ready_since(&mut plan).await;
+        self.mark_skipped(&mut plan).await;
+        self.record_plan(&plan).await;
+        *cache = Some(plan.clone());
+        drop(cache);
+
+        self.untrack_finished(&plan).await;
+        self.check_timeouts().await;
+        Ok(plan)
+    }
+
+    /// Store the plan under a new version if it differs from the latest recorded one
+    async fn record_plan(&self, plan: &ExecutionPlan) {
+        let mut history = self.plan_history.write().await;
+        let version = match history.back() {
+            Some(latest) if plan.diff(&latest.plan).is_empty() => return,
+            Some(latest) => latest.version + 1,
+            None => 1,
+        };
+        if history.len() == PLAN_HISTORY_CAPACITY {
+            history.pop_front();
+        }
+        history.push_back(VersionedPlan {
+            epoch: self.plan_epoch,
+            version,
+            plan: plan.clone(),
+        });
+    }
+
+    /// Current plan together with its version
+    #[instrument(level = "debug", skip_all, fields(project_id = %self.project_id))]
+    pub async fn versioned_plan(
+        &self,
+        pool: &SqlitePool,
+    ) -> Result<VersionedPlan, OrchestratorError> {
+        let plan = self.build_plan(pool).await?;
+        let version = self.plan_version().await;
+        Ok(VersionedPlan {
+            epoch: self.plan_epoch,
+            version,
+            plan,
+        })
+    }
+
+    /// Epoch the versions from `plan_version` belong to
+    pub fn plan_epoch(&self) -> Uuid {
+        self.plan_epoch
+    }
+
+    /// Version of the latest recorded plan, or 0 before the first build
+    pub async fn plan_version(&self) -> u32 {
+        self.plan_history
+            .read()
+            .await
+            .back()
+            .map_or(0, |latest| latest.version)
+    }
+
+    /// Changes from the plan recorded as `version` in `epoch` to `current`
+    ///
+    /// None when the epoch belongs to another orchestrator instance, or the version is unknown
+    /// or no longer kept, in which case clients should reload the full plan.
+    pub async fn plan_diff_since(
+        &self,
+        epoch: Uuid,
+        version: u32,
+        current: &ExecutionPlan,
+    ) -> Option<PlanDiff> {
+        if epoch != self.plan_epoch {
+            return None;
+        }
+        let history = self.plan_history.read().await;
+        history
+            .iter()
+            .find(|recorded| recorded.version == version)
+            .map(|recorded| current.diff(&recorded.plan))
+    }
+
+    /// Fill in `ready_since` for the plan's ready tasks, keeping the time each was first seen
+    /// ready and forgetting tasks that are no longer ready
+    async fn stamp_ready_since(&self, plan: &mut ExecutionPlan) {
+        let now = Utc::now();
+        let mut ready_since = self.ready_since.write().await;
+        let mut still_ready = HashMap::new();
+
+        for task in plan
+            .levels
+            .iter_mut()
+            .flat_map(|level| level.tasks.iter_mut())
+        {
+            if matches!(task.readiness, TaskReadiness::Ready) {
+                let since = ready_since.get(&task.task_id).copied().unwrap_or(now);
+                still_ready.insert(task.task_id, since);
+                task.ready_since = Some(since);
+            }
+        }
+
+        *ready_since = still_ready;
+    }
+
+    /// Flag the plan's tasks that the user excluded from scheduling
+    async fn mark_skipped(&self, plan: &mut ExecutionPlan) {
+        let skipped = self.skipped_task_ids.read().await;
+        for task in plan
+            .levels
+            .iter_mut()
+            .flat_map(|level| level.tasks.iter_mut())
+        {
+            task.skipped = skipped.contains(&task.task_id);
+        }
     }
 
     /// Start the orchestrator
+    #[instrument(skip_all, fields(project_id = %self.project_id))]
     pub async fn start(&self, pool: &SqlitePool) -> Result<(), OrchestratorError> {
         let mut state = self.state.write().await;
         if *state == OrchestratorState::Running {
@@ -79,17 +656,26 @@ impl ProjectOrchestrator {
         *state = OrchestratorState::Running;
         self.emit_event(OrchestratorEvent::StateChanged {
             state: OrchestratorState::Running,
-        });
+        })
+        .await;
 
         // Build and emit initial plan
         drop(state); // Release lock before async operation
+        self.invalidate_plan().await;
         let plan = self.build_plan(pool).await?;
-        self.emit_event(OrchestratorEvent::PlanUpdated { plan });
+        info!(
+            ready_tasks = plan.ready_tasks,
+            total_tasks = plan.total_tasks,
+            "orchestrator started"
+        );
+        self.emit_plan_updated(&plan).await;
+        self.request_auto_start(&plan).await;
 
         Ok(())
     }
 
     /// Pause the orchestrator (in-progress tasks will complete, but no new tasks start)
+    #[instrument(skip_all, fields(project_id = %self.project_id))]
     pub async fn pause(&self) -> Result<(), OrchestratorError> {
         let mut state = self.state.write().await;
         if *state != OrchestratorState::Running {
@@ -97,14 +683,17 @@ impl ProjectOrchestrator {
         }
 
         *state = OrchestratorState::Paused;
+        info!("orchestrator paused");
         self.emit_event(OrchestratorEvent::StateChanged {
             state: OrchestratorState::Paused,
-        });
+        })
+        .await;
 
         Ok(())
     }
 
     /// Resume the orchestrator from paused state
+    #[instrument(skip_all, fields(project_id = %self.project_id))]
     pub async fn resume(&self, pool: &SqlitePool) -> Result<(), OrchestratorError> {
         let mut state = self.state.write().await;
         if *state != OrchestratorState::Paused {
@@ -114,19 +703,25 @@ impl ProjectOrchestrator {
         *state = OrchestratorState::Running;
         self.emit_event(OrchestratorEvent::StateChanged {
             state: OrchestratorState::Running,
-        });
+        })
+        .await;
 
         // Rebuild and emit plan
         drop(state);
+        self.invalidate_plan().await;
         let plan = self.build_plan(pool).await?;
-        self.emit_event(OrchestratorEvent::PlanUpdated { plan });
+        info!(ready_tasks = plan.ready_tasks, "orchestrator resumed");
+        self.emit_plan_updated(&plan).await;
 
         Ok(())
     }
 
     /// Stop the orchestrator
+    #[instrument(skip_all, fields(project_id = %self.project_id))]
     pub async fn stop(&self) -> Result<(), OrchestratorError> {
         let mut state = self.state.write().await;
+        // The next run reports completions afresh
+        self.completed_tasks.write().await.clear();
         if *state == OrchestratorState::Idle {
             return Ok(()); // Already stopped
         }
@@ -134,113 +729,237 @@ impl ProjectOrchestrator {
         *state = OrchestratorState::Stopping;
         self.emit_event(OrchestratorEvent::StateChanged {
             state: OrchestratorState::Stopping,
-        });
+        })
+        .await;
 
         // After all in-progress tasks complete, transition to Idle
         // This would be handled by the task completion handler
         *state = OrchestratorState::Idle;
         self.emit_event(OrchestratorEvent::StateChanged {
             state: OrchestratorState::Idle,
-        });
+        })
+        .await;
+        info!("orchestrator stopped");
 
         Ok(())
     }
 
     /// Get tasks that are ready to execute
+    #[instrument(level = "debug", skip_all, fields(project_id = %self.project_id))]
     pub async fn get_ready_to_execute(
         &self,
         pool: &SqlitePool,
     ) -> Result<Vec<Uuid>, OrchestratorError> {
+        self.refresh_graph_version(pool).await?;
+        self.get_ready_to_execute_with(|| self.load_project(pool))
+            .await
+    }
+
+    async fn get_ready_to_execute_with<F, Fut>(
+        &self,
+        load: F,
+    ) -> Result<Vec<Uuid>, OrchestratorError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<(Vec<Task>, Vec<TaskDependency>), OrchestratorError>>,
+    {
         let state = self.state.read().await;
         if *state != OrchestratorState::Running {
             return Ok(vec![]);
         }
         drop(state);
 
+        self.preview_ready_with(load).await
+    }
+
+    /// Get the tasks that would be started if the orchestrator were running
+    ///
+    /// Same selection as `get_ready_to_execute` (including the `max_parallel` limit), but
+    /// regardless of state, so a paused or idle project can show what would run next.
+    #[instrument(level = "debug", skip_all, fields(project_id = %self.project_id))]
+    pub async fn preview_ready(&self, pool: &SqlitePool) -> Result<Vec<Uuid>, OrchestratorError> {
+        self.refresh_graph_version(pool).await?;
+        self.preview_ready_with(|| self.load_project(pool)).await
+    }
+
+    /// Tasks that would become ready if all of `completing` were done, without touching the DB
+    #[instrument(level = "debug", skip_all, fields(project_id = %self.project_id))]
+    pub async fn forecast_unblocked(
+        &self,
+        pool: &SqlitePool,
+        completing: &[Uuid],
+    ) -> Result<Vec<Uuid>, OrchestratorError> {
+        let plan = self.build_plan(pool).await?;
+        Ok(forecast_unblocked(&plan, completing))
+    }
+
+    /// The single next step for the project: a task to start, or why none can start
+    #[instrument(level = "debug", skip_all, fields(project_id = %self.project_id))]
+    pub async fn next_action(&self, pool: &SqlitePool) -> Result<NextAction, OrchestratorError> {
         let plan = self.build_plan(pool).await?;
-        let ready = get_ready_tasks(&plan);
+        let state = self.get_state().await;
+        Ok(next_action(&plan, state))
+    }
 
-        // Limit by max_parallel_tasks
-        let in_progress_count = plan.in_progress_tasks;
-        let available_slots = self.max_parallel_tasks.saturating_sub(in_progress_count);
+    /// Tasks stuck behind other stuck tasks or in progress longer than `stale_after_secs`
+    ///
+    /// The threshold falls back to the task timeout, then `DEFAULT_STALE_AFTER_SECS`. Run time is
+    /// measured from `on_task_started`, so tasks started before the orchestrator was are skipped.
+    #[instrument(level = "debug", skip(self, pool), fields(project_id = %self.project_id))]
+    pub async fn at_risk_tasks(
+        &self,
+        pool: &SqlitePool,
+        stale_after_secs: Option<u32>,
+    ) -> Result<Vec<AtRiskTask>, OrchestratorError> {
+        let plan = self.build_plan(pool).await?;
+        let stale_after_secs = match stale_after_secs {
+            Some(secs) => secs,
+            None => self
+                .task_timeout_secs()
+                .await
+                .unwrap_or(DEFAULT_STALE_AFTER_SECS),
+        };
+        let started_at = self.started_at.read().await;
+        Ok(at_risk_tasks(
+            &plan,
+            &started_at,
+            stale_after_secs,
+            Utc::now(),
+        ))
+    }
 
-        Ok(ready
-            .into_iter()
-            .take(available_slots)
-            .map(|t| t.task_id)
-            .collect())
+    async fn preview_ready_with<F, Fut>(&self, load: F) -> Result<Vec<Uuid>, OrchestratorError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<(Vec<Task>, Vec<TaskDependency>), OrchestratorError>>,
+    {
+        let plan = self.build_plan_with(load).await?;
+        Ok(select_tasks_to_start(&plan, self.max_parallel_tasks))
     }
 
     /// Notify that a task has started
+    #[instrument(skip_all, fields(project_id = %self.project_id, task_id = %task_id))]
     pub async fn on_task_started(
         &self,
         task_id: Uuid,
         pool: &SqlitePool,
     ) -> Result<(), OrchestratorError> {
-        self.emit_event(OrchestratorEvent::TaskStarted { task_id });
+        self.emit_event(OrchestratorEvent::TaskStarted { task_id })
+            .await;
+        self.completed_tasks.write().await.remove(&task_id);
+        self.failed_tasks.write().await.remove(&task_id);
+        self.track_started(task_id, Utc::now()).await;
+        self.invalidate_plan().await;
 
         // Rebuild plan
         let plan = self.build_plan(pool).await?;
-        self.emit_event(OrchestratorEvent::PlanUpdated { plan });
+        self.emit_plan_updated(&plan).await;
 
         Ok(())
     }
 
     /// Notify that a task has completed
+    ///
+    /// Repeated notifications for a task already reported completed are ignored and return no
+    /// newly ready tasks, until the task is started, fails or goes back to review.
+    #[instrument(skip_all, fields(project_id = %self.project_id, task_id = %task_id))]
     pub async fn on_task_completed(
         &self,
         task_id: Uuid,
         pool: &SqlitePool,
     ) -> Result<Vec<Uuid>, OrchestratorError> {
-        self.emit_event(OrchestratorEvent::TaskCompleted { task_id });
+        let owners = self.load_owners(pool).await?;
+        self.on_task_completed_with(task_id, &owners, || self.load_project(pool))
+            .await
+    }
+
+    async fn on_task_completed_with<F, Fut>(
+        &self,
+        task_id: Uuid,
+        owners: &HashMap<Uuid, String>,
+        load: F,
+    ) -> Result<Vec<Uuid>, OrchestratorError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<(Vec<Task>, Vec<TaskDependency>), OrchestratorError>>,
+    {
+        if !self.completed_tasks.write().await.insert(task_id) {
+            debug!("ignoring repeated completion");
+            return Ok(vec![]);
+        }
+        self.failed_tasks.write().await.remove(&task_id);
+        self.untrack_started(task_id).await;
+
+        self.emit_event(OrchestratorEvent::TaskCompleted { task_id })
+            .await;
+        self.invalidate_plan().await;
 
         // Rebuild plan and find newly ready tasks
-        let plan = self.build_plan(pool).await?;
+        let mut plan = self.build_plan_with(load).await?;
+        assign_owners(&mut plan, owners);
         let newly_ready = get_tasks_unblocked_by_completion(&plan, task_id);
+        info!(newly_ready = newly_ready.len(), "task completed");
 
-        self.emit_event(OrchestratorEvent::PlanUpdated { plan });
+        self.emit_plan_updated(&plan).await;
+        self.request_auto_start(&plan).await;
 
         Ok(newly_ready)
     }
 
     /// Notify that a task has failed
+    #[instrument(skip_all, fields(project_id = %self.project_id, task_id = %task_id))]
     pub async fn on_task_failed(
         &self,
         task_id: Uuid,
         error: String,
         pool: &SqlitePool,
     ) -> Result<(), OrchestratorError> {
-        self.emit_event(OrchestratorEvent::TaskFailed { task_id, error });
+        warn!(error = %error, "task failed");
+        self.emit_event(OrchestratorEvent::TaskFailed { task_id, error })
+            .await;
+        self.completed_tasks.write().await.remove(&task_id);
+        self.failed_tasks.write().await.insert(task_id);
+        self.untrack_started(task_id).await;
+        self.invalidate_plan().await;
 
         // Rebuild plan
         let plan = self.build_plan(pool).await?;
-        self.emit_event(OrchestratorEvent::PlanUpdated { plan });
+        self.emit_plan_updated(&plan).await;
 
         Ok(())
     }
 
     /// Notify that a task is awaiting review
+    #[instrument(skip_all, fields(project_id = %self.project_id, task_id = %task_id))]
     pub async fn on_task_review(
         &self,
         task_id: Uuid,
         pool: &SqlitePool,
     ) -> Result<(), OrchestratorError> {
-        self.emit_event(OrchestratorEvent::TaskAwaitingReview { task_id });
+        self.emit_event(OrchestratorEvent::TaskAwaitingReview { task_id })
+            .await;
+        self.completed_tasks.write().await.remove(&task_id);
+        self.untrack_started(task_id).await;
+        self.invalidate_plan().await;
 
         // Rebuild plan
         let plan = self.build_plan(pool).await?;
-        self.emit_event(OrchestratorEvent::PlanUpdated { plan });
+        self.emit_plan_updated(&plan).await;
 
         Ok(())
     }
 
     /// Validate a task status transition
+    ///
+    /// With `allow_override`, transitions the rule set rejects are reported as forced instead.
+    #[instrument(level = "debug", skip(self, pool), fields(project_id = %self.project_id))]
     pub async fn validate_task_transition(
         &self,
         task_id: Uuid,
         new_status: &TaskStatus,
+        allow_override: bool,
         pool: &SqlitePool,
-    ) -> Result<crate::models::TransitionValidation, OrchestratorError> {
+    ) -> Result<TransitionValidation, OrchestratorError> {
         let tasks = Task::find_by_project_id(pool, self.project_id).await?;
         let task = tasks
             .iter()
@@ -248,27 +967,332 @@ impl ProjectOrchestrator {
             .ok_or(OrchestratorError::TaskNotFound(task_id))?;
         let dependencies =
             TaskDependency::find_by_project_id(pool, self.project_id).await?;
+        let rules = self.get_transition_rules(pool).await?;
+
+        Ok(validate_transition_with_override(
+            task,
+            new_status,
+            &tasks,
+            &dependencies,
+            &rules,
+            allow_override,
+        ))
+    }
+
+    /// Validate several task status transitions against a single snapshot of the project
+    #[instrument(level = "debug", skip_all, fields(project_id = %self.project_id))]
+    pub async fn validate_task_transitions(
+        &self,
+        requests: &[TransitionRequest],
+        pool: &SqlitePool,
+    ) -> Result<Vec<(Uuid, TransitionValidation)>, OrchestratorError> {
+        let tasks = Task::find_by_project_id(pool, self.project_id).await?;
+        let dependencies =
+            TaskDependency::find_by_project_id(pool, self.project_id).await?;
+        let rules = self.get_transition_rules(pool).await?;
+
+        validate_transitions(requests, &tasks, &dependencies, &rules)
+    }
+
+    /// Move several tasks to `new_status` at once, e.g. cancelling everything downstream
+    ///
+    /// Every transition is validated against one snapshot of the project. Valid ones, plus
+    /// those needing confirmation when `confirm` is set, are written in a single transaction and
+    /// the plan is rebuilt once afterwards.
+    #[instrument(
+        skip(self, task_ids, pool),
+        fields(project_id = %self.project_id, tasks = task_ids.len())
+    )]
+    pub async fn bulk_transition(
+        &self,
+        task_ids: &[Uuid],
+        new_status: TaskStatus,
+        confirm: bool,
+        pool: &SqlitePool,
+    ) -> Result<Vec<BulkTransitionResult>, OrchestratorError> {
+        let (tasks, dependencies) = self.load_project(pool).await?;
+        let rules = self.get_transition_rules(pool).await?;
+        let results = plan_bulk_transition(
+            task_ids,
+            &new_status,
+            confirm,
+            &tasks,
+            &dependencies,
+            &rules,
+        );
+
+        let applied: Vec<Uuid> = results
+            .iter()
+            .filter(|result| result.applied)
+            .map(|result| result.task_id)
+            .collect();
+        if applied.is_empty() {
+            return Ok(results);
+        }
+
+        Task::update_status_bulk(pool, self.project_id, &applied, new_status).await?;
+        info!(applied = applied.len(), "bulk transition applied");
+        self.invalidate_plan().await;
+
+        let plan = self.build_plan(pool).await?;
+        self.emit_plan_updated(&plan).await;
+        self.request_auto_start(&plan).await;
+
+        Ok(results)
+    }
+
+    /// Tasks reported failed that haven't been started or completed since
+    pub async fn failed_task_ids(&self) -> Vec<Uuid> {
+        let mut ids: Vec<Uuid> = self.failed_tasks.read().await.iter().copied().collect();
+        ids.sort();
+        ids
+    }
+
+    /// Move every failed task back to `Todo` so it can be scheduled again
+    ///
+    /// Only tasks whose transition to `Todo` is valid are restarted; the rest stay failed.
+    /// Restarted tasks with unfinished prerequisites come back blocked rather than ready.
+    /// Returns the restarted task IDs.
+    #[instrument(skip_all, fields(project_id = %self.project_id))]
+    pub async fn retry_failed(&self, pool: &SqlitePool) -> Result<Vec<Uuid>, OrchestratorError> {
+        let failed = self.failed_task_ids().await;
+        if failed.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let (tasks, dependencies) = self.load_project(pool).await?;
+        let rules = self.get_transition_rules(pool).await?;
+        let restarted = plan_retry(&failed, &tasks, &dependencies, &rules);
+
+        let to_reset: Vec<Uuid> = tasks
+            .iter()
+            .filter(|task| restarted.contains(&task.id) && task.status != TaskStatus::Todo)
+            .map(|task| task.id)
+            .collect();
+        if !to_reset.is_empty() {
+            Task::update_status_bulk(pool, self.project_id, &to_reset, TaskStatus::Todo).await?;
+        }
+
+        // Deleted tasks can't be retried, so they are forgotten along with the restarted ones
+        let known: HashSet<Uuid> = tasks.iter().map(|task| task.id).collect();
+        self.failed_tasks
+            .write()
+            .await
+            .retain(|id| known.contains(id) && !restarted.contains(id));
+        if restarted.is_empty() {
+            return Ok(restarted);
+        }
+        info!(
+            failed = failed.len(),
+            restarted = restarted.len(),
+            "restarted failed tasks"
+        );
+
+        self.invalidate_plan().await;
+        let plan = self.build_plan(pool).await?;
+        self.emit_plan_updated(&plan).await;
+        self.request_auto_start(&plan).await;
+
+        Ok(restarted)
+    }
+
+    /// Ask the deployment layer to start whatever fits in the free parallel slots
+    ///
+    /// Only emits when auto-start is enabled and the orchestrator is running. Starting the
+    /// agents is left to subscribers so the orchestrator stays free of executor dependencies.
+    async fn request_auto_start(&self, plan: &ExecutionPlan) {
+        if !self.is_auto_start().await || self.get_state().await != OrchestratorState::Running {
+            return;
+        }
+
+        let task_ids = select_tasks_to_start(plan, self.max_parallel_tasks);
+        if !task_ids.is_empty() {
+            debug!(task_ids = ?task_ids, "requesting auto-start");
+            self.emit_event(OrchestratorEvent::AutoStartRequested { task_ids })
+                .await;
+        }
+    }
+
+    /// Emit `ReadinessChanged` for every task whose readiness moved since the last emitted
+    /// plan and `DeepChainWarning` for an overly long chain, followed by the full `PlanUpdated`
+    async fn emit_plan_updated(&self, plan: &ExecutionPlan) {
+        let mut last_emitted = self.last_emitted_plan.write().await;
+        if let Some(previous) = last_emitted.as_ref() {
+            for event in diff_readiness(previous, plan) {
+                self.emit_event(event).await;
+            }
+        }
 
-        Ok(validate_transition(task, new_status, &tasks, &dependencies))
+        // Warn once per depth rather than on every update of an already deep plan
+        let depth_changed = last_emitted
+            .as_ref()
+            .is_none_or(|previous| previous.max_depth != plan.max_depth);
+        if depth_changed && plan.max_depth > self.deep_chain_threshold().await {
+            self.emit_event(OrchestratorEvent::DeepChainWarning {
+                depth: plan.max_depth,
+            })
+            .await;
+        }
+
+        self.emit_event(OrchestratorEvent::PlanUpdated { plan: plan.clone() })
+            .await;
+        *last_emitted = Some(plan.clone());
     }
 
-    fn emit_event(&self, event: OrchestratorEvent) {
+    async fn emit_event(&self, event: OrchestratorEvent) {
+        {
+            let mut recent = self.recent_events.write().await;
+            if recent.len() == RECENT_EVENTS_CAPACITY {
+                recent.pop_front();
+            }
+            recent.push_back(event.clone());
+        }
         // Ignore send errors (no receivers)
         let _ = self.event_sender.send(event);
     }
 }
 
+/// A single status change to validate in a batch
+#[derive(Debug, Clone)]
+pub struct TransitionRequest {
+    pub task_id: Uuid,
+    pub new_status: TaskStatus,
+    pub allow_override: bool,
+}
+
+/// Validate each requested transition against shared task and dependency slices
+fn validate_transitions(
+    requests: &[TransitionRequest],
+    tasks: &[Task],
+    dependencies: &[TaskDependency],
+    rules: &TransitionRuleSet,
+) -> Result<Vec<(Uuid, TransitionValidation)>, OrchestratorError> {
+    let task_map: HashMap<Uuid, &Task> = tasks.iter().map(|t| (t.id, t)).collect();
+
+    requests
+        .iter()
+        .map(|request| {
+            let task = task_map
+                .get(&request.task_id)
+                .ok_or(OrchestratorError::TaskNotFound(request.task_id))?;
+            let validation = validate_transition_with_override(
+                task,
+                &request.new_status,
+                tasks,
+                dependencies,
+                rules,
+                request.allow_override,
+            );
+            Ok((request.task_id, validation))
+        })
+        .collect()
+}
+
+/// Validate moving each of `task_ids` to `new_status` and decide which to apply
+///
+/// Unknown and repeated task ids are reported as invalid rather than failing the batch.
+fn plan_bulk_transition(
+    task_ids: &[Uuid],
+    new_status: &TaskStatus,
+    confirm: bool,
+    tasks: &[Task],
+    dependencies: &[TaskDependency],
+    rules: &TransitionRuleSet,
+) -> Vec<BulkTransitionResult> {
+    let task_map: HashMap<Uuid, &Task> = tasks.iter().map(|t| (t.id, t)).collect();
+    let mut seen = HashSet::new();
+
+    task_ids
+        .iter()
+        .map(|&task_id| {
+            let validation = match task_map.get(&task_id) {
+                _ if !seen.insert(task_id) => {
+                    TransitionValidation::invalid(OrchestratorMessage::DuplicateTask)
+                }
+                Some(task) => validate_transition_with_override(
+                    task,
+                    new_status,
+                    tasks,
+                    dependencies,
+                    rules,
+                    false,
+                ),
+                None => {
+                    TransitionValidation::invalid(OrchestratorMessage::TaskNotFound { task_id })
+                }
+            };
+            let applied = match &validation {
+                TransitionValidation::Valid { .. } => true,
+                TransitionValidation::RequiresConfirmation { .. } => confirm,
+                TransitionValidation::Invalid { .. } => false,
+            };
+            BulkTransitionResult {
+                task_id,
+                validation,
+                applied,
+            }
+        })
+        .collect()
+}
+
+/// Failed tasks that may move back to `Todo`, in project order
+/// Tasks already in `Todo` count as restartable without a transition.
+fn plan_retry(
+    failed: &[Uuid],
+    tasks: &[Task],
+    dependencies: &[TaskDependency],
+    rules: &TransitionRuleSet,
+) -> Vec<Uuid> {
+    tasks
+        .iter()
+        .filter(|task| failed.contains(&task.id))
+        .filter(|task| {
+            task.status == TaskStatus::Todo
+                || matches!(
+                    validate_transition_with_override(
+                        task,
+                        &TaskStatus::Todo,
+                        tasks,
+                        dependencies,
+                        rules,
+                        false,
+                    ),
+                    TransitionValidation::Valid { .. }
+                )
+        })
+        .map(|task| task.id)
+        .collect()
+}
+
+/// Pick the highest-priority ready tasks from the plan, limited by the free parallel slots
+/// Skipped tasks are passed over.
+fn select_tasks_to_start(plan: &ExecutionPlan, max_parallel_tasks: usize) -> Vec<Uuid> {
+    let ready = get_ready_tasks(plan);
+
+    // Limit by max_parallel_tasks; tasks in review don't hold a slot
+    let available_slots = max_parallel_tasks.saturating_sub(occupied_execution_slots(plan));
+
+    ready
+        .into_iter()
+        .filter(|t| !t.skipped)
+        .take(available_slots)
+        .map(|t| t.task_id)
+        .collect()
+}
+
 /// Global orchestrator manager
 pub struct OrchestratorManager {
     orchestrators: RwLock<HashMap<Uuid, Arc<ProjectOrchestrator>>>,
     default_max_parallel: usize,
+    event_capacity: usize,
 }
 
 impl OrchestratorManager {
-    pub fn new(default_max_parallel: usize) -> Self {
+    pub fn new(default_max_parallel: usize, event_capacity: usize) -> Self {
         Self {
             orchestrators: RwLock::new(HashMap::new()),
             default_max_parallel,
+            event_capacity,
         }
     }
 
@@ -286,14 +1310,47 @@ impl OrchestratorManager {
             return Arc::clone(orch);
         }
 
-        let orch = Arc::new(ProjectOrchestrator::new(
+        let orch = Arc::new(ProjectOrchestrator::with_event_capacity(
             project_id,
             self.default_max_parallel,
+            self.event_capacity,
         ));
         orchestrators.insert(project_id, Arc::clone(&orch));
         orch
     }
 
+    /// Replace a project's orchestrator with a fresh, idle one
+    ///
+    /// Subscribers of the old instance receive a final `StateChanged { Idle }` so they know to
+    /// resubscribe; the new instance starts with no auto-start and no cached plan, and reads the
+    /// project's saved transition rules again.
+    pub async fn reset(&self, project_id: Uuid) -> Arc<ProjectOrchestrator> {
+        let mut orchestrators = self.orchestrators.write().await;
+
+        if let Some(old) = orchestrators.remove(&project_id) {
+            old.emit_event(OrchestratorEvent::StateChanged {
+                state: OrchestratorState::Idle,
+            })
+            .await;
+        }
+
+        let orch = Arc::new(ProjectOrchestrator::with_event_capacity(
+            project_id,
+            self.default_max_parallel,
+            self.event_capacity,
+        ));
+        orchestrators.insert(project_id, Arc::clone(&orch));
+        orch
+    }
+
+    /// Invalidate the cached plan of a project's orchestrator, if one exists
+    pub async fn invalidate_plan(&self, project_id: Uuid) {
+        let orch = self.orchestrators.read().await.get(&project_id).cloned();
+        if let Some(orch) = orch {
+            orch.invalidate_plan().await;
+        }
+    }
+
     /// Remove an orchestrator for a project
     pub async fn remove(&self, project_id: Uuid) {
         let mut orchestrators = self.orchestrators.write().await;
@@ -304,6 +1361,277 @@ impl OrchestratorManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::TaskReadiness;
+    use db::models::task::TaskType;
+
+    fn create_test_task(id: Uuid, status: TaskStatus) -> Task {
+        Task {
+            id,
+            project_id: Uuid::new_v4(),
+            title: format!("Task {}", id),
+            description: None,
+            status,
+            task_type: TaskType::Feature,
+            parent_workspace_id: None,
+            shared_task_id: None,
+            position: None,
+            dag_position_x: None,
+            dag_position_y: None,
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_blocked_task_is_not_scheduled() {
+        let blocked = create_test_task(Uuid::new_v4(), TaskStatus::Blocked);
+        let ready = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+
+        let plan = build_execution_plan(&[blocked.clone(), ready.clone()], &[]);
+        let to_start = select_tasks_to_start(&plan, 1);
+
+        // The on-hold task neither gets scheduled nor occupies a parallel slot
+        assert_eq!(to_start, vec![ready.id]);
+        assert_eq!(plan.on_hold_tasks, 1);
+        assert_eq!(plan.in_progress_tasks, 0);
+    }
+
+    #[test]
+    fn test_validate_transitions_batch() {
+        let done = create_test_task(Uuid::new_v4(), TaskStatus::Done);
+        let todo = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let dependent = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let tasks = vec![done.clone(), todo.clone(), dependent.clone()];
+        let dependencies = vec![TaskDependency {
+            id: Uuid::new_v4(),
+            task_id: dependent.id,
+            depends_on_task_id: todo.id,
+            genre_id: None,
+            created_by: db::models::task_dependency::DependencyCreator::User,
+            created_at: chrono::Utc::now(),
+            kind: db::models::task_dependency::DependencyKind::Strict,
+            label: None,
+        }];
+
+        let requests = vec![
+            TransitionRequest {
+                task_id: todo.id,
+                new_status: TaskStatus::InProgress,
+                allow_override: false,
+            },
+            TransitionRequest {
+                task_id: todo.id,
+                new_status: TaskStatus::Done,
+                allow_override: false,
+            },
+            TransitionRequest {
+                task_id: dependent.id,
+                new_status: TaskStatus::InProgress,
+                allow_override: false,
+            },
+        ];
+
+        let results = validate_transitions(
+            &requests,
+            &tasks,
+            &dependencies,
+            &TransitionRuleSet::default(),
+        )
+        .unwrap();
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].0, todo.id);
+        assert!(matches!(results[0].1, TransitionValidation::Valid { forced: false }));
+        assert!(matches!(results[1].1, TransitionValidation::Invalid { .. }));
+        assert_eq!(results[2].0, dependent.id);
+        assert!(matches!(
+            results[2].1,
+            TransitionValidation::RequiresConfirmation { .. }
+        ));
+    }
+
+    #[test]
+    fn test_validate_transitions_unknown_task() {
+        let missing = Uuid::new_v4();
+        let requests = vec![TransitionRequest {
+            task_id: missing,
+            new_status: TaskStatus::InProgress,
+            allow_override: false,
+        }];
+
+        let result = validate_transitions(&requests, &[], &[], &TransitionRuleSet::default());
+
+        assert!(matches!(result, Err(OrchestratorError::TaskNotFound(id)) if id == missing));
+    }
+
+    #[test]
+    fn test_plan_retry_restarts_failed_tasks() {
+        let prerequisite = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let independent = create_test_task(Uuid::new_v4(), TaskStatus::InProgress);
+        let dependent = create_test_task(Uuid::new_v4(), TaskStatus::InProgress);
+        let in_review = create_test_task(Uuid::new_v4(), TaskStatus::InReview);
+        let tasks = vec![
+            prerequisite.clone(),
+            independent.clone(),
+            dependent.clone(),
+            in_review.clone(),
+        ];
+        let dependencies = vec![TaskDependency {
+            id: Uuid::new_v4(),
+            task_id: dependent.id,
+            depends_on_task_id: prerequisite.id,
+            genre_id: None,
+            created_by: db::models::task_dependency::DependencyCreator::User,
+            created_at: chrono::Utc::now(),
+            kind: db::models::task_dependency::DependencyKind::Strict,
+            label: None,
+        }];
+        let failed = vec![dependent.id, independent.id, in_review.id, Uuid::new_v4()];
+
+        let restarted = plan_retry(
+            &failed,
+            &tasks,
+            &dependencies,
+            &TransitionRuleSet::default(),
+        );
+        // InReview can't go back to Todo, and unknown tasks are ignored
+        assert_eq!(restarted, vec![independent.id, dependent.id]);
+
+        let reset: Vec<Task> = tasks
+            .into_iter()
+            .map(|mut task| {
+                if restarted.contains(&task.id) {
+                    task.status = TaskStatus::Todo;
+                }
+                task
+            })
+            .collect();
+        let plan = build_execution_plan(&reset, &dependencies);
+        let readiness = |id: Uuid| {
+            plan.levels
+                .iter()
+                .flat_map(|level| level.tasks.iter())
+                .find(|task| task.task_id == id)
+                .map(|task| task.readiness.clone())
+                .unwrap()
+        };
+        assert!(matches!(readiness(independent.id), TaskReadiness::Ready));
+        assert!(matches!(
+            readiness(dependent.id),
+            TaskReadiness::Blocked { .. }
+        ));
+    }
+
+    #[test]
+    fn test_plan_bulk_transition_mixes_valid_and_invalid() {
+        let todo = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let cancelled = create_test_task(Uuid::new_v4(), TaskStatus::Cancelled);
+        let dependent = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let tasks = vec![todo.clone(), cancelled.clone(), dependent.clone()];
+        let dependencies = vec![TaskDependency {
+            id: Uuid::new_v4(),
+            task_id: dependent.id,
+            depends_on_task_id: todo.id,
+            genre_id: None,
+            created_by: db::models::task_dependency::DependencyCreator::User,
+            created_at: chrono::Utc::now(),
+            kind: db::models::task_dependency::DependencyKind::Strict,
+            label: None,
+        }];
+        let missing = Uuid::new_v4();
+        let task_ids = vec![todo.id, cancelled.id, dependent.id, missing, todo.id];
+        let rules = TransitionRuleSet::default();
+
+        let plan = |confirm| {
+            plan_bulk_transition(
+                &task_ids,
+                &TaskStatus::InProgress,
+                confirm,
+                &tasks,
+                &dependencies,
+                &rules,
+            )
+        };
+
+        let results = plan(false);
+        assert_eq!(results.len(), 5);
+        let applied: Vec<Uuid> = results
+            .iter()
+            .filter(|r| r.applied)
+            .map(|r| r.task_id)
+            .collect();
+        assert_eq!(applied, vec![todo.id]);
+        assert!(matches!(
+            results[1].validation,
+            TransitionValidation::Invalid { .. }
+        ));
+        assert!(matches!(
+            results[2].validation,
+            TransitionValidation::RequiresConfirmation { .. }
+        ));
+        assert!(matches!(
+            results[3].validation,
+            TransitionValidation::Invalid { .. }
+        ));
+        // A repeated id is only applied once
+        assert!(matches!(
+            results[4].validation,
+            TransitionValidation::Invalid { .. }
+        ));
+
+        // Confirming also applies the transition that needed it
+        let applied: Vec<Uuid> = plan(true)
+            .iter()
+            .filter(|r| r.applied)
+            .map(|r| r.task_id)
+            .collect();
+        assert_eq!(applied, vec![todo.id, dependent.id]);
+    }
+
+    #[tokio::test]
+    async fn test_auto_start_requests_unblocked_task() {
+        let completed = create_test_task(Uuid::new_v4(), TaskStatus::Done);
+        let unblocked = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let dependencies = vec![TaskDependency {
+            id: Uuid::new_v4(),
+            task_id: unblocked.id,
+            depends_on_task_id: completed.id,
+            genre_id: None,
+            created_by: db::models::task_dependency::DependencyCreator::User,
+            created_at: chrono::Utc::now(),
+            kind: db::models::task_dependency::DependencyKind::Strict,
+            label: None,
+        }];
+        let plan = build_execution_plan(&[completed, unblocked.clone()], &dependencies);
+
+        let orch = ProjectOrchestrator::new(Uuid::new_v4(), 3);
+        *orch.state.write().await = OrchestratorState::Running;
+        orch.set_auto_start(true).await;
+        let mut receiver = orch.subscribe();
+
+        orch.request_auto_start(&plan).await;
+
+        match receiver.try_recv() {
+            Ok(OrchestratorEvent::AutoStartRequested { task_ids }) => {
+                assert_eq!(task_ids, vec![unblocked.id]);
+            }
+            other => panic!("expected auto-start request, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_auto_start_disabled_emits_nothing() {
+        let ready = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let plan = build_execution_plan(&[ready], &[]);
+
+        let orch = ProjectOrchestrator::new(Uuid::new_v4(), 3);
+        *orch.state.write().await = OrchestratorState::Running;
+        let mut receiver = orch.subscribe();
+
+        orch.request_auto_start(&plan).await;
+
+        assert!(receiver.try_recv().is_err());
+    }
 
     #[tokio::test]
     async fn test_orchestrator_state_transitions() {
@@ -322,7 +1650,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_orchestrator_manager() {
-        let manager = OrchestratorManager::new(3);
+        let manager = OrchestratorManager::new(3, DEFAULT_EVENT_CHANNEL_CAPACITY);
         let project_id = Uuid::new_v4();
 
         let orch1 = manager.get_or_create(project_id).await;
@@ -331,4 +1659,723 @@ mod tests {
         // Should return same instance
         assert!(Arc::ptr_eq(&orch1, &orch2));
     }
+
+    #[tokio::test]
+    async fn test_build_plan_uses_cache_until_invalidated() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let orch = ProjectOrchestrator::new(Uuid::new_v4(), 3);
+        let loads = AtomicUsize::new(0);
+        let load = || async {
+            loads.fetch_add(1, Ordering::SeqCst);
+            Ok((vec![create_test_task(Uuid::new_v4(), TaskStatus::Todo)], vec![]))
+        };
+
+        let first = orch.build_plan_with(load).await.unwrap();
+        let second = orch.build_plan_with(load).await.unwrap();
+        assert_eq!(loads.load(Ordering::SeqCst), 1);
+        assert_eq!(first.total_tasks, second.total_tasks);
+
+        orch.invalidate_plan().await;
+        orch.build_plan_with(load).await.unwrap();
+        assert_eq!(loads.load(Ordering::SeqCst), 2);
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    async fn test_build_plan_sees_status_changes_made_without_notification(pool: SqlitePool) {
+        use db::models::project::{CreateProject, Project};
+        use db::models::task::CreateTask;
+
+        let project_id = Uuid::new_v4();
+        let project = CreateProject {
+            name: "Plan Cache Test".to_string(),
+            repositories: vec![],
+        };
+        Project::create(&pool, &project, project_id).await.unwrap();
+        let task_id = Uuid::new_v4();
+        let data = CreateTask::from_title_description(project_id, "Task".to_string(), None);
+        Task::create(&pool, &data, task_id).await.unwrap();
+
+        let orch = ProjectOrchestrator::new(project_id, 3);
+        let plan = orch.build_plan(&pool).await.unwrap();
+        assert_eq!(plan.ready_tasks, 1);
+
+        // Written straight through the model, as executors and the GitHub sync do
+        Task::update_status(&pool, task_id, TaskStatus::InProgress)
+            .await
+            .unwrap();
+
+        let plan = orch.build_plan(&pool).await.unwrap();
+        assert_eq!(plan.ready_tasks, 0);
+        assert_eq!(plan.in_progress_tasks, 1);
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    async fn test_transition_rules_survive_a_fresh_orchestrator(pool: SqlitePool) {
+        use db::models::project::{CreateProject, Project};
+
+        let project_id = Uuid::new_v4();
+        let project = CreateProject {
+            name: "Transition Rules Test".to_string(),
+            repositories: vec![],
+        };
+        Project::create(&pool, &project, project_id).await.unwrap();
+
+        let orch = ProjectOrchestrator::new(project_id, 3);
+        let mut strict = orch.get_transition_rules(&pool).await.unwrap();
+        strict.allowed.retain(|t| t.from != TaskStatus::Done);
+        orch.set_transition_rules(&pool, strict.clone())
+            .await
+            .unwrap();
+
+        // A rule set that can never finish a task is rejected and leaves the saved one in place
+        let result = orch
+            .set_transition_rules(&pool, TransitionRuleSet { allowed: vec![] })
+            .await;
+        assert!(matches!(
+            result,
+            Err(OrchestratorError::InvalidTransitionRules(
+                OrchestratorMessage::NoPathToDone
+            ))
+        ));
+
+        // As after a restart or a reset
+        let fresh = ProjectOrchestrator::new(project_id, 3);
+        let loaded = fresh.get_transition_rules(&pool).await.unwrap();
+        assert_eq!(loaded.allowed, strict.allowed);
+        assert!(!loaded.allows(&TaskStatus::Done, &TaskStatus::Todo));
+
+        // Other projects keep the built-in matrix
+        let other = ProjectOrchestrator::new(Uuid::new_v4(), 3);
+        assert_eq!(
+            other.get_transition_rules(&pool).await.unwrap().allowed,
+            TransitionRuleSet::default().allowed
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ready_since_tracks_ready_state() {
+        let prerequisite = create_test_task(Uuid::new_v4(), TaskStatus::InProgress);
+        let task = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let dependencies = vec![TaskDependency {
+            id: Uuid::new_v4(),
+            task_id: task.id,
+            depends_on_task_id: prerequisite.id,
+            genre_id: None,
+            created_by: db::models::task_dependency::DependencyCreator::User,
+            created_at: chrono::Utc::now(),
+            kind: db::models::task_dependency::DependencyKind::Strict,
+            label: None,
+        }];
+        let orch = ProjectOrchestrator::new(Uuid::new_v4(), 3);
+        let ready_since = |plan: &ExecutionPlan| {
+            plan.levels
+                .iter()
+                .flat_map(|level| level.tasks.iter())
+                .find(|t| t.task_id == task.id)
+                .and_then(|t| t.ready_since)
+        };
+        let rebuild = |prerequisite_status: TaskStatus, task_status: TaskStatus| {
+            let tasks = vec![
+                Task {
+                    status: prerequisite_status,
+                    ..prerequisite.clone()
+                },
+                Task {
+                    status: task_status,
+                    ..task.clone()
+                },
+            ];
+            let dependencies = dependencies.clone();
+            let orch = &orch;
+            async move {
+                orch.invalidate_plan().await;
+                orch.build_plan_with(move || async move { Ok((tasks, dependencies)) })
+                    .await
+                    .unwrap()
+            }
+        };
+
+        // Blocked by the running prerequisite
+        let plan = rebuild(TaskStatus::InProgress, TaskStatus::Todo).await;
+        assert_eq!(ready_since(&plan), None);
+
+        // Becomes ready once the prerequisite is done, and keeps its first timestamp
+        let plan = rebuild(TaskStatus::Done, TaskStatus::Todo).await;
+        let since = ready_since(&plan).expect("ready task has ready_since");
+        let plan = rebuild(TaskStatus::Done, TaskStatus::Todo).await;
+        assert_eq!(ready_since(&plan), Some(since));
+
+        // Cleared when the task starts
+        let plan = rebuild(TaskStatus::Done, TaskStatus::InProgress).await;
+        assert_eq!(ready_since(&plan), None);
+        assert!(orch.ready_since.read().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_preview_ready_ignores_state() {
+        let ready = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let also_ready = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let running = create_test_task(Uuid::new_v4(), TaskStatus::InProgress);
+        let tasks = vec![ready, also_ready, running];
+        let load = || async { Ok((tasks.clone(), vec![])) };
+
+        // Two slots, one already taken by the running task
+        let orch = ProjectOrchestrator::new(Uuid::new_v4(), 2);
+        assert_eq!(orch.get_state().await, OrchestratorState::Idle);
+
+        let ready = orch.get_ready_to_execute_with(load).await.unwrap();
+        assert!(ready.is_empty());
+        assert_eq!(orch.preview_ready_with(load).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_skipped_task_stays_in_plan_but_is_not_scheduled() {
+        let skipped = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let other = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let (skipped_id, other_id) = (skipped.id, other.id);
+        let tasks = vec![skipped, other];
+        let load = || async { Ok((tasks.clone(), vec![])) };
+
+        let orch = ProjectOrchestrator::new(Uuid::new_v4(), 2);
+        *orch.state.write().await = OrchestratorState::Running;
+        assert_eq!(orch.preview_ready_with(load).await.unwrap().len(), 2);
+
+        assert!(orch.skip_task(skipped_id).await);
+        assert!(!orch.skip_task(skipped_id).await);
+
+        assert_eq!(
+            orch.get_ready_to_execute_with(load).await.unwrap(),
+            vec![other_id]
+        );
+        assert_eq!(orch.preview_ready_with(load).await.unwrap(), vec![other_id]);
+        let plan = orch.build_plan_with(load).await.unwrap();
+        let task = plan
+            .levels
+            .iter()
+            .flat_map(|level| level.tasks.iter())
+            .find(|t| t.task_id == skipped_id)
+            .unwrap();
+        assert!(task.skipped);
+        assert!(matches!(task.readiness, TaskReadiness::Ready));
+
+        assert!(orch.unskip_task(skipped_id).await);
+        assert_eq!(orch.preview_ready_with(load).await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_plan_versions_and_diff() {
+        let first = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let second = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let dependency = TaskDependency {
+            id: Uuid::new_v4(),
+            task_id: second.id,
+            depends_on_task_id: first.id,
+            genre_id: None,
+            created_by: db::models::task_dependency::DependencyCreator::User,
+            created_at: chrono::Utc::now(),
+            kind: db::models::task_dependency::DependencyKind::Strict,
+            label: None,
+        };
+        let orch = ProjectOrchestrator::new(Uuid::new_v4(), 2);
+        let epoch = orch.plan_epoch();
+        assert_eq!(orch.plan_version().await, 0);
+
+        let rebuild = |tasks: Vec<Task>| {
+            let dependencies = vec![dependency.clone()];
+            let orch = &orch;
+            async move {
+                orch.invalidate_plan().await;
+                orch.build_plan_with(|| async { Ok((tasks, dependencies)) })
+                    .await
+                    .unwrap()
+            }
+        };
+
+        rebuild(vec![first.clone(), second.clone()]).await;
+        assert_eq!(orch.plan_version().await, 1);
+
+        // An identical rebuild keeps the version
+        rebuild(vec![first.clone(), second.clone()]).await;
+        assert_eq!(orch.plan_version().await, 1);
+
+        let mut done = first.clone();
+        done.status = TaskStatus::Done;
+        let added = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let current = rebuild(vec![done, second.clone(), added.clone()]).await;
+        assert_eq!(orch.plan_version().await, 2);
+
+        let diff = orch.plan_diff_since(epoch, 1, &current).await.unwrap();
+        assert_eq!(diff.added_tasks, vec![added.id]);
+        assert!(diff.removed_tasks.is_empty());
+        assert!(diff.level_changes.is_empty());
+        assert_eq!(diff.readiness_changes.len(), 2);
+        let unblocked = diff
+            .readiness_changes
+            .iter()
+            .find(|change| change.task_id == second.id)
+            .unwrap();
+        assert!(matches!(unblocked.from, TaskReadiness::Blocked { .. }));
+        assert!(matches!(unblocked.to, TaskReadiness::Ready));
+
+        assert!(orch
+            .plan_diff_since(epoch, 2, &current)
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(orch.plan_diff_since(epoch, 7, &current).await.is_none());
+
+        // A fresh instance, as after a reset, numbers its plans from 1 again under a new epoch
+        let fresh = ProjectOrchestrator::new(Uuid::new_v4(), 2);
+        fresh.invalidate_plan().await;
+        let fresh_plan = fresh
+            .build_plan_with(|| async { Ok((vec![first.clone()], vec![])) })
+            .await
+            .unwrap();
+        assert_eq!(fresh.plan_version().await, 1);
+        assert_ne!(fresh.plan_epoch(), epoch);
+        assert!(fresh.plan_diff_since(epoch, 1, &fresh_plan).await.is_none());
+        assert!(fresh
+            .plan_diff_since(fresh.plan_epoch(), 1, &fresh_plan)
+            .await
+            .is_some());
+    }
+
+    #[tokio::test]
+    async fn test_deep_chain_warning() {
+        let tasks: Vec<Task> = (0..4)
+            .map(|_| create_test_task(Uuid::new_v4(), TaskStatus::Todo))
+            .collect();
+        let dependencies: Vec<TaskDependency> = tasks
+            .windows(2)
+            .map(|pair| TaskDependency {
+                id: Uuid::new_v4(),
+                task_id: pair[1].id,
+                depends_on_task_id: pair[0].id,
+                genre_id: None,
+                created_by: db::models::task_dependency::DependencyCreator::User,
+                created_at: chrono::Utc::now(),
+                kind: db::models::task_dependency::DependencyKind::Strict,
+                label: None,
+            })
+            .collect();
+        let plan = build_execution_plan(&tasks, &dependencies);
+        assert_eq!(plan.max_depth, 3);
+
+        let orch = ProjectOrchestrator::new(Uuid::new_v4(), 3);
+        let mut receiver = orch.subscribe();
+        let warnings = |receiver: &mut broadcast::Receiver<OrchestratorEvent>| {
+            std::iter::from_fn(|| receiver.try_recv().ok())
+                .filter(|event| matches!(event, OrchestratorEvent::DeepChainWarning { .. }))
+                .count()
+        };
+
+        // Within the default threshold
+        orch.emit_plan_updated(&plan).await;
+        assert_eq!(warnings(&mut receiver), 0);
+
+        // Over the threshold: warned once, not again for the same depth
+        orch.set_deep_chain_threshold(2).await;
+        *orch.last_emitted_plan.write().await = None;
+        orch.emit_plan_updated(&plan).await;
+        orch.emit_plan_updated(&plan).await;
+        assert_eq!(warnings(&mut receiver), 1);
+    }
+
+    #[tokio::test]
+    async fn test_in_review_tasks_do_not_hold_slots() {
+        let running = create_test_task(Uuid::new_v4(), TaskStatus::InProgress);
+        let reviewing = create_test_task(Uuid::new_v4(), TaskStatus::InReview);
+        let ready = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let ready_id = ready.id;
+        let tasks = vec![running, reviewing, ready];
+        let load = || async { Ok((tasks.clone(), vec![])) };
+
+        let orch = ProjectOrchestrator::new(Uuid::new_v4(), 2);
+        *orch.state.write().await = OrchestratorState::Running;
+
+        let started = orch.get_ready_to_execute_with(load).await.unwrap();
+        assert_eq!(started, vec![ready_id]);
+    }
+
+    #[tokio::test]
+    async fn test_orchestrator_manager_reset() {
+        let manager = OrchestratorManager::new(3, DEFAULT_EVENT_CHANNEL_CAPACITY);
+        let project_id = Uuid::new_v4();
+
+        let old = manager.get_or_create(project_id).await;
+        *old.state.write().await = OrchestratorState::Running;
+        old.set_auto_start(true).await;
+        let mut old_receiver = old.subscribe();
+
+        let fresh = manager.reset(project_id).await;
+
+        assert!(matches!(
+            old_receiver.try_recv(),
+            Ok(OrchestratorEvent::StateChanged {
+                state: OrchestratorState::Idle
+            })
+        ));
+        assert!(!Arc::ptr_eq(&old, &fresh));
+        assert!(Arc::ptr_eq(
+            &fresh,
+            &manager.get_or_create(project_id).await
+        ));
+        assert_eq!(fresh.get_state().await, OrchestratorState::Idle);
+        assert!(!fresh.is_auto_start().await);
+
+        let mut receiver = fresh.subscribe();
+        fresh
+            .emit_event(OrchestratorEvent::TaskStarted {
+                task_id: project_id,
+            })
+            .await;
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(OrchestratorEvent::TaskStarted { task_id }) if task_id == project_id
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_completing_blocker_emits_readiness_changed() {
+        let blocker = create_test_task(Uuid::new_v4(), TaskStatus::InProgress);
+        let dependent = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let dependencies = vec![TaskDependency {
+            id: Uuid::new_v4(),
+            task_id: dependent.id,
+            depends_on_task_id: blocker.id,
+            genre_id: None,
+            created_by: db::models::task_dependency::DependencyCreator::User,
+            created_at: chrono::Utc::now(),
+            kind: db::models::task_dependency::DependencyKind::Strict,
+            label: None,
+        }];
+        let before = build_execution_plan(&[blocker.clone(), dependent.clone()], &dependencies);
+        let completed = Task {
+            status: TaskStatus::Done,
+            ..blocker.clone()
+        };
+        let after = build_execution_plan(&[completed, dependent.clone()], &dependencies);
+
+        let orch = ProjectOrchestrator::new(Uuid::new_v4(), 3);
+        let mut receiver = orch.subscribe();
+
+        // The first plan has nothing to compare against
+        orch.emit_plan_updated(&before).await;
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(OrchestratorEvent::PlanUpdated { .. })
+        ));
+
+        orch.emit_plan_updated(&after).await;
+        let mut changes = Vec::new();
+        loop {
+            match receiver.try_recv() {
+                Ok(OrchestratorEvent::ReadinessChanged { task_id, from, to }) => {
+                    changes.push((task_id, from, to))
+                }
+                Ok(OrchestratorEvent::PlanUpdated { .. }) => break,
+                other => panic!("unexpected event {:?}", other),
+            }
+        }
+
+        assert!(changes
+            .iter()
+            .any(|(task_id, from, to)| *task_id == dependent.id
+                && matches!(from, TaskReadiness::Blocked { .. })
+                && matches!(to, TaskReadiness::Ready)));
+    }
+
+    #[test]
+    fn test_higher_priority_tasks_take_scarce_slots() {
+        let positioned = |position| Task {
+            position: Some(position),
+            ..create_test_task(Uuid::new_v4(), TaskStatus::Todo)
+        };
+        let unpositioned = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let last = positioned(2);
+        let first = positioned(0);
+        let second = positioned(1);
+        let running = create_test_task(Uuid::new_v4(), TaskStatus::InProgress);
+
+        let plan = build_execution_plan(
+            &[unpositioned, last, first.clone(), second.clone(), running],
+            &[],
+        );
+
+        // One of three slots is taken, so only the two lowest positions start
+        assert_eq!(select_tasks_to_start(&plan, 3), vec![first.id, second.id]);
+    }
+
+    #[test]
+    fn test_ready_tasks_without_position_start_oldest_first() {
+        let older = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let newer = Task {
+            created_at: older.created_at + chrono::Duration::seconds(1),
+            ..create_test_task(Uuid::new_v4(), TaskStatus::Todo)
+        };
+
+        let plan = build_execution_plan(&[newer, older.clone()], &[]);
+
+        assert_eq!(select_tasks_to_start(&plan, 1), vec![older.id]);
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_completion_emits_once() {
+        let completed = create_test_task(Uuid::new_v4(), TaskStatus::Done);
+        let dependent = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let dependencies = vec![TaskDependency {
+            id: Uuid::new_v4(),
+            task_id: dependent.id,
+            depends_on_task_id: completed.id,
+            genre_id: None,
+            created_by: db::models::task_dependency::DependencyCreator::User,
+            created_at: chrono::Utc::now(),
+            kind: db::models::task_dependency::DependencyKind::Strict,
+            label: None,
+        }];
+        let load = || async {
+            Ok((
+                vec![completed.clone(), dependent.clone()],
+                dependencies.clone(),
+            ))
+        };
+
+        let orch = ProjectOrchestrator::new(Uuid::new_v4(), 3);
+        let mut receiver = orch.subscribe();
+
+        orch.on_task_completed_with(completed.id, &HashMap::new(), load)
+            .await
+            .unwrap();
+        let retried = orch
+            .on_task_completed_with(completed.id, &HashMap::new(), load)
+            .await
+            .unwrap();
+        assert!(retried.is_empty());
+
+        let mut completions = 0;
+        while let Ok(event) = receiver.try_recv() {
+            if matches!(event, OrchestratorEvent::TaskCompleted { task_id } if task_id == completed.id)
+            {
+                completions += 1;
+            }
+        }
+        assert_eq!(completions, 1);
+
+        // Stopping starts a new run, so the task can be reported again
+        orch.stop().await.unwrap();
+        orch.on_task_completed_with(completed.id, &HashMap::new(), load)
+            .await
+            .unwrap();
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(OrchestratorEvent::TaskCompleted { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_completion_plan_update_carries_owners() {
+        let completed = create_test_task(Uuid::new_v4(), TaskStatus::Done);
+        let dependent = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let tasks = vec![completed.clone(), dependent.clone()];
+        let load = || async { Ok((tasks.clone(), vec![])) };
+        let owners = HashMap::from([(dependent.id, "alice".to_string())]);
+
+        let orch = ProjectOrchestrator::new(Uuid::new_v4(), 3);
+        let mut receiver = orch.subscribe();
+        orch.on_task_completed_with(completed.id, &owners, load)
+            .await
+            .unwrap();
+
+        let plan = std::iter::from_fn(|| receiver.try_recv().ok())
+            .find_map(|event| match event {
+                OrchestratorEvent::PlanUpdated { plan } => Some(plan),
+                _ => None,
+            })
+            .expect("plan update");
+        let owner = plan
+            .levels
+            .iter()
+            .flat_map(|level| level.tasks.iter())
+            .find(|task| task.task_id == dependent.id)
+            .and_then(|task| task.assigned_to.clone());
+        assert_eq!(owner.as_deref(), Some("alice"));
+    }
+
+    #[tokio::test]
+    async fn test_recent_events_keeps_latest_in_order() {
+        let orch = ProjectOrchestrator::new(Uuid::new_v4(), 1);
+        let task_ids: Vec<Uuid> = (0..3).map(|_| Uuid::new_v4()).collect();
+        for task_id in &task_ids {
+            orch.emit_event(OrchestratorEvent::TaskStarted { task_id: *task_id })
+                .await;
+        }
+
+        let started = |events: Vec<OrchestratorEvent>| -> Vec<Uuid> {
+            events
+                .into_iter()
+                .map(|event| match event {
+                    OrchestratorEvent::TaskStarted { task_id } => task_id,
+                    other => panic!("unexpected event {:?}", other),
+                })
+                .collect()
+        };
+        assert_eq!(started(orch.recent_events(10).await), task_ids);
+        assert_eq!(started(orch.recent_events(2).await), task_ids[1..].to_vec());
+
+        // Only the newest RECENT_EVENTS_CAPACITY events are kept
+        for _ in 0..RECENT_EVENTS_CAPACITY {
+            orch.emit_event(OrchestratorEvent::TaskCompleted {
+                task_id: Uuid::new_v4(),
+            })
+            .await;
+        }
+        let recent = orch.recent_events(usize::MAX).await;
+        assert_eq!(recent.len(), RECENT_EVENTS_CAPACITY);
+        assert!(recent
+            .iter()
+            .all(|event| matches!(event, OrchestratorEvent::TaskCompleted { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_small_event_capacity_lags_slow_subscriber() {
+        let orch = ProjectOrchestrator::with_event_capacity(Uuid::new_v4(), 1, 2);
+        let mut receiver = orch.subscribe();
+        let task_ids: Vec<Uuid> = (0..5).map(|_| Uuid::new_v4()).collect();
+        for task_id in &task_ids {
+            orch.emit_event(OrchestratorEvent::TaskStarted { task_id: *task_id })
+                .await;
+        }
+
+        // Only the newest two events are still buffered
+        assert!(matches!(
+            receiver.recv().await,
+            Err(broadcast::error::RecvError::Lagged(3))
+        ));
+        for task_id in &task_ids[3..] {
+            assert!(matches!(
+                receiver.recv().await,
+                Ok(OrchestratorEvent::TaskStarted { task_id: id }) if id == *task_id
+            ));
+        }
+
+        // The receiver keeps working after catching up
+        orch.emit_event(OrchestratorEvent::TaskCompleted {
+            task_id: task_ids[0],
+        })
+        .await;
+        assert!(matches!(
+            receiver.recv().await,
+            Ok(OrchestratorEvent::TaskCompleted { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_check_timeouts_reports_overdue_tasks_once() {
+        let orch = ProjectOrchestrator::new(Uuid::new_v4(), 3);
+        let mut receiver = orch.subscribe();
+        let started = Utc::now();
+        let slow = Uuid::new_v4();
+        let quick = Uuid::new_v4();
+        orch.track_started(slow, started).await;
+        orch.track_started(quick, started + chrono::Duration::seconds(50))
+            .await;
+        let now = started + chrono::Duration::seconds(61);
+
+        // Disabled until a timeout is set
+        assert!(orch.check_timeouts_at(now).await.is_empty());
+
+        orch.set_task_timeout_secs(Some(60)).await;
+        assert_eq!(orch.check_timeouts_at(now).await, vec![slow]);
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(OrchestratorEvent::TaskTimedOut { task_id, elapsed_secs: 61 }) if task_id == slow
+        ));
+
+        // Still reported, but the event is not repeated
+        assert_eq!(orch.check_timeouts_at(now).await, vec![slow]);
+        assert!(receiver.try_recv().is_err());
+
+        // A finished task is no longer tracked
+        orch.untrack_started(slow).await;
+        assert!(orch.check_timeouts_at(now).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_check_timeouts_skips_tasks_finished_without_notification() {
+        let orch = ProjectOrchestrator::new(Uuid::new_v4(), 3);
+        orch.set_task_timeout_secs(Some(60)).await;
+        let done = create_test_task(Uuid::new_v4(), TaskStatus::InProgress);
+        let reviewed = create_test_task(Uuid::new_v4(), TaskStatus::InProgress);
+        let running = create_test_task(Uuid::new_v4(), TaskStatus::InProgress);
+        let started = Utc::now() - chrono::Duration::seconds(30);
+        for task in [&done, &reviewed, &running] {
+            orch.track_started(task.id, started).await;
+        }
+
+        // Two finish before the deadline, with only the database updated
+        let mut finished = vec![done.clone(), reviewed.clone(), running.clone()];
+        finished[0].status = TaskStatus::Done;
+        finished[1].status = TaskStatus::InReview;
+        orch.build_plan_with(|| async { Ok((finished, vec![])) })
+            .await
+            .unwrap();
+
+        let deadline = started + chrono::Duration::seconds(61);
+        assert_eq!(orch.check_timeouts_at(deadline).await, vec![running.id]);
+    }
+
+    /// Records the name and fields of every span opened while it is the default subscriber
+    #[derive(Clone, Default)]
+    struct SpanRecorder(Arc<std::sync::Mutex<Vec<(String, HashMap<String, String>)>>>);
+
+    struct FieldVisitor(HashMap<String, String>);
+
+    impl tracing::field::Visit for FieldVisitor {
+        fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+            self.0
+                .insert(field.name().to_string(), format!("{:?}", value));
+        }
+    }
+
+    impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for SpanRecorder {
+        fn on_new_span(
+            &self,
+            attrs: &tracing::span::Attributes<'_>,
+            _id: &tracing::span::Id,
+            _ctx: tracing_subscriber::layer::Context<'_, S>,
+        ) {
+            let mut visitor = FieldVisitor(HashMap::new());
+            attrs.record(&mut visitor);
+            self.0
+                .lock()
+                .unwrap()
+                .push((attrs.metadata().name().to_string(), visitor.0));
+        }
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    async fn test_start_emits_span_with_project_id(pool: SqlitePool) {
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let recorder = SpanRecorder::default();
+        let _guard =
+            tracing::subscriber::set_default(tracing_subscriber::registry().with(recorder.clone()));
+
+        let project_id = Uuid::new_v4();
+        let orch = ProjectOrchestrator::new(project_id, 3);
+        orch.start(&pool).await.unwrap();
+
+        let expected = project_id.to_string();
+        let spans = recorder.0.lock().unwrap();
+        let (_, fields) = spans
+            .iter()
+            .find(|(name, _)| name == "start")
+            .expect("start span");
+        assert_eq!(fields.get("project_id"), Some(&expected));
+        // Work done inside start is tagged with the project too
+        assert!(spans.iter().any(|(name, fields)| {
+            name == "build_plan" && fields.get("project_id") == Some(&expected)
+        }));
+    }
 }
@@ -8,19 +8,35 @@
 //! - Real-time execution plan updates
 
 pub mod engine;
+pub mod messages;
 pub mod models;
 pub mod scheduler;
 pub mod state_machine;
 
-pub use engine::{OrchestratorError, OrchestratorManager, ProjectOrchestrator};
+pub use engine::{
+    OrchestratorError, OrchestratorManager, ProjectOrchestrator, TransitionRequest,
+    DEFAULT_DEEP_CHAIN_THRESHOLD, DEFAULT_EVENT_CHANNEL_CAPACITY,
+};
+pub use messages::OrchestratorMessage;
 pub use models::{
-    ExecutableTask, ExecutionLevel, ExecutionPlan, OrchestratorEvent, OrchestratorState,
-    TaskReadiness, TransitionValidation,
+    AtRiskTask, BlockedByCancelled, BlockedTask, BulkTransitionResult, DependencyExplanation,
+    ExecutableTask, ExecutionLevel, ExecutionPlan, ExplanationLanguage, GanttChart, GanttEntry,
+    GraphValidationReport, LevelChange, NextAction, OrchestratorEvent, OrchestratorMetrics,
+    OrchestratorState, PlanDiff, PlanMode, ReadinessExplanation, ReadinessFilter,
+    ReadinessTransition, RiskReason, StartReadiness, TaskDepth, TaskReadiness, TopologicalOrder,
+    TransitionValidation, UnblockingTask, VersionedPlan, WaitReason,
 };
 pub use scheduler::{
-    build_execution_plan, get_in_progress_tasks, get_ready_tasks, get_tasks_blocked_by,
-    get_tasks_unblocked_by_completion,
+    assign_owners, at_risk_tasks, build_execution_plan, build_execution_plan_capped,
+    build_execution_plan_filtered, build_gantt, compact_levels, compute_metrics, diff_readiness,
+    explain_readiness, filter_plan_by_assignee, forecast_unblocked, get_blocked_downstream,
+    get_critical_path, get_in_progress_tasks, get_ready_tasks, get_tasks_blocked_by,
+    get_tasks_by_readiness, get_tasks_unblocked_by_completion, level_assignment, next_action,
+    occupied_execution_slots, occupies_execution_slot, schedule_with_cap, topological_order,
+    validate_graph, DEFAULT_STALE_AFTER_SECS, DEFAULT_TASK_MINUTES,
 };
 pub use state_machine::{
-    can_start_task, get_dependency_tasks, get_dependent_tasks, validate_transition,
+    can_start_task, cancellation_impact, get_all_ancestors, get_all_descendants,
+    get_dependency_tasks, get_dependent_tasks, is_transitively_blocked_by, start_readiness,
+    validate_transition, validate_transition_with_override, StatusTransition, TransitionRuleSet,
 };
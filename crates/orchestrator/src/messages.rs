@@ -0,0 +1,236 @@
+//! Catalog of user-facing orchestrator error messages.
+//!
+//! Errors are carried as an `OrchestratorMessage` code with its arguments and rendered into
+//! Japanese or English only when a response is built, so the state machine and the dependency
+//! routes report the same failure with the same wording.
+
+use db::models::task::TaskStatus;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use uuid::Uuid;
+
+use crate::models::ExplanationLanguage;
+use crate::state_machine::status_to_string;
+
+/// A user-facing error, identified by a stable code plus the arguments needed to render it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS, JsonSchema)]
+#[serde(tag = "code", content = "args", rename_all = "snake_case")]
+pub enum OrchestratorMessage {
+    /// The transition rules don't allow this status change
+    InvalidTransition {
+        from: TaskStatus,
+        to: TaskStatus,
+    },
+    /// Starting the task would skip unfinished dependencies
+    IncompleteDependencies {
+        count: usize,
+    },
+    /// A task appears more than once in a bulk request
+    DuplicateTask,
+    TaskNotFound {
+        task_id: Uuid,
+    },
+    DependsOnTaskNotFound {
+        task_id: Uuid,
+    },
+    DependencyNotFound {
+        dependency_id: Uuid,
+    },
+    /// No dependency links the given task pair
+    DependencyPairNotFound,
+    DependencyDeleteFailed,
+    SelfDependency,
+    TaskNotInProject,
+    /// Like `TaskNotInProject`, naming the task when a request lists several
+    TaskIdNotInProject {
+        task_id: Uuid,
+    },
+    DependsOnTaskNotInProject,
+    DependencyExists,
+    /// Adding the dependency would close a cycle through these task titles
+    CycleDetected {
+        path: Vec<String>,
+    },
+    /// A bulk dependency delete named neither a task pair nor a creator
+    MissingDeleteFilter,
+    /// A graph clone targeted its own source project
+    CloneIntoSelf,
+    TargetProjectNotFound,
+    /// A transition rule set lists the same status change twice
+    DuplicateTransitionRule {
+        from: TaskStatus,
+        to: TaskStatus,
+    },
+    /// A transition rule set offers no way to take a task from todo to done
+    NoPathToDone,
+    /// A request named a status that doesn't exist
+    InvalidStatus {
+        status: String,
+    },
+}
+
+impl OrchestratorMessage {
+    /// Render the message in the given language
+    pub fn render(&self, language: ExplanationLanguage) -> String {
+        use ExplanationLanguage::{En, Ja};
+
+        match self {
+            Self::InvalidTransition { from, to } => match language {
+                Ja => format!(
+                    "{} から {} へは変更できません",
+                    status_to_string(from),
+                    status_to_string(to)
+                ),
+                En => format!(
+                    "Cannot transition from {} to {}",
+                    status_to_string(from),
+                    status_to_string(to)
+                ),
+            },
+            Self::IncompleteDependencies { count } => match language {
+                Ja => format!(
+                    "未完了の依存タスクが{}件あります。このまま開始すると問題が起きる可能性があります",
+                    count
+                ),
+                En => format!(
+                    "Task has {} incomplete dependencies. Starting this task may cause issues.",
+                    count
+                ),
+            },
+            Self::DuplicateTask => match language {
+                Ja => "タスクが複数回指定されています".to_string(),
+                En => "Task is listed more than once".to_string(),
+            },
+            Self::TaskNotFound { task_id } => match language {
+                Ja => format!("タスクが見つかりません: {}", task_id),
+                En => format!("Task not found: {}", task_id),
+            },
+            Self::DependsOnTaskNotFound { task_id } => match language {
+                Ja => format!("依存先タスクが見つかりません: {}", task_id),
+                En => format!("Depends-on task not found: {}", task_id),
+            },
+            Self::DependencyNotFound { dependency_id } => match language {
+                Ja => format!("依存関係が見つかりません: {}", dependency_id),
+                En => format!("Dependency not found: {}", dependency_id),
+            },
+            Self::DependencyPairNotFound => match language {
+                Ja => "依存関係が見つかりません".to_string(),
+                En => "Dependency not found".to_string(),
+            },
+            Self::DependencyDeleteFailed => match language {
+                Ja => "依存関係の削除に失敗しました".to_string(),
+                En => "Failed to delete the dependency".to_string(),
+            },
+            Self::SelfDependency => match language {
+                Ja => "タスクは自分自身に依存することはできません".to_string(),
+                En => "A task cannot depend on itself".to_string(),
+            },
+            Self::TaskNotInProject => match language {
+                Ja => "タスクはこのプロジェクトに属していません".to_string(),
+                En => "The task does not belong to this project".to_string(),
+            },
+            Self::TaskIdNotInProject { task_id } => match language {
+                Ja => format!("タスクはこのプロジェクトに属していません: {}", task_id),
+                En => format!("The task does not belong to this project: {}", task_id),
+            },
+            Self::DependsOnTaskNotInProject => match language {
+                Ja => "依存先タスクはこのプロジェクトに属していません".to_string(),
+                En => "The depends-on task does not belong to this project".to_string(),
+            },
+            Self::DependencyExists => match language {
+                Ja => "この依存関係は既に存在します".to_string(),
+                En => "This dependency already exists".to_string(),
+            },
+            Self::CycleDetected { path } => match language {
+                Ja => format!(
+                    "この依存関係を追加すると循環依存が発生します: {}",
+                    path.join(" → ")
+                ),
+                En => format!(
+                    "Adding this dependency would create a cycle: {}",
+                    path.join(" → ")
+                ),
+            },
+            Self::MissingDeleteFilter => match language {
+                Ja => "taskId と dependsOnTaskId の組、または createdBy のどちらかを指定してください"
+                    .to_string(),
+                En => "Specify either taskId and dependsOnTaskId, or createdBy".to_string(),
+            },
+            Self::CloneIntoSelf => match language {
+                Ja => "プロジェクトのグラフを自分自身に複製することはできません".to_string(),
+                En => "Cannot clone a project's graph into itself".to_string(),
+            },
+            Self::TargetProjectNotFound => match language {
+                Ja => "複製先のプロジェクトが見つかりません".to_string(),
+                En => "Target project not found".to_string(),
+            },
+            Self::DuplicateTransitionRule { from, to } => match language {
+                Ja => format!(
+                    "{} から {} への遷移が重複しています",
+                    status_to_string(from),
+                    status_to_string(to)
+                ),
+                En => format!(
+                    "The transition from {} to {} is listed more than once",
+                    status_to_string(from),
+                    status_to_string(to)
+                ),
+            },
+            Self::NoPathToDone => match language {
+                Ja => "todo から done へ到達できる遷移がありません".to_string(),
+                En => "The rules allow no path from todo to done".to_string(),
+            },
+            Self::InvalidStatus { status } => match language {
+                Ja => format!("不正なステータスです: {}", status),
+                En => format!("Invalid status: {}", status),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_in_both_languages() {
+        let message = OrchestratorMessage::InvalidTransition {
+            from: TaskStatus::Done,
+            to: TaskStatus::Todo,
+        };
+        assert_eq!(
+            message.render(ExplanationLanguage::En),
+            "Cannot transition from done to todo"
+        );
+        assert_eq!(
+            message.render(ExplanationLanguage::Ja),
+            "done から todo へは変更できません"
+        );
+
+        let cycle = OrchestratorMessage::CycleDetected {
+            path: vec!["A".to_string(), "B".to_string(), "A".to_string()],
+        };
+        assert_eq!(
+            cycle.render(ExplanationLanguage::En),
+            "Adding this dependency would create a cycle: A → B → A"
+        );
+        assert_eq!(
+            cycle.render(ExplanationLanguage::Ja),
+            "この依存関係を追加すると循環依存が発生します: A → B → A"
+        );
+    }
+
+    #[test]
+    fn test_message_serializes_code_and_args() {
+        let message = OrchestratorMessage::IncompleteDependencies { count: 2 };
+        assert_eq!(
+            serde_json::to_value(&message).unwrap(),
+            serde_json::json!({ "code": "incomplete_dependencies", "args": { "count": 2 } })
+        );
+        assert_eq!(
+            serde_json::to_value(OrchestratorMessage::SelfDependency).unwrap(),
+            serde_json::json!({ "code": "self_dependency" })
+        );
+    }
+}
@@ -1,10 +1,19 @@
-use db::models::task::TaskStatus;
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use db::models::{
+    task::{TaskStatus, TaskType},
+    task_dependency::DependencyKind,
+};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 use uuid::Uuid;
 
+use crate::messages::OrchestratorMessage;
+
 /// Represents the readiness state of a task for execution
-#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum TaskReadiness {
     /// Task is ready to be executed (all dependencies satisfied)
@@ -15,6 +24,8 @@ pub enum TaskReadiness {
     },
     /// Task is already in progress
     InProgress,
+    /// Task was marked blocked by an external issue and cannot be scheduled
+    OnHold,
     /// Task is already completed
     Completed,
     /// Task is cancelled
@@ -22,19 +33,29 @@ pub enum TaskReadiness {
 }
 
 /// A task with its execution metadata
-#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, JsonSchema)]
 pub struct ExecutableTask {
     pub task_id: Uuid,
     pub status: TaskStatus,
+    /// Kind of work the task represents, e.g. feature or bug
+    pub task_type: TaskType,
     pub readiness: TaskReadiness,
     /// Tasks that must complete before this task can start
     pub dependencies: Vec<Uuid>,
     /// Tasks that depend on this task
     pub dependents: Vec<Uuid>,
+    /// Scheduling rank within the plan (lower starts first): by position, then creation time
+    pub priority: usize,
+    /// When the orchestrator first saw the task as ready; `None` unless it is ready now
+    pub ready_since: Option<DateTime<Utc>>,
+    /// GitHub login of the task's first assignee, from its `github_assignees` property
+    pub assigned_to: Option<String>,
+    /// Excluded from scheduling by the user; the task keeps its status and readiness
+    pub skipped: bool,
 }
 
 /// Execution plan containing tasks in topological order
-#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, JsonSchema)]
 pub struct ExecutionPlan {
     /// All tasks grouped by execution level (tasks in same level can run in parallel)
     pub levels: Vec<ExecutionLevel>,
@@ -50,29 +71,338 @@ pub struct ExecutionPlan {
     pub ready_tasks: usize,
     /// Number of tasks blocked by dependencies
     pub blocked_tasks: usize,
+    /// Number of tasks put on hold with the `Blocked` status
+    pub on_hold_tasks: usize,
+    /// Overall completion (0-100) over non-cancelled tasks; 0 when there is nothing to do
+    pub progress_percent: f32,
+    /// Completion (0-100) of each level, in the same order as `levels`
+    pub level_progress: Vec<f32>,
+    /// Length of the longest dependency chain, i.e. the number of levels minus one
+    pub max_depth: usize,
+}
+
+impl ExecutionPlan {
+    /// Flatten the levels into a single order where every task comes after its prerequisites.
+    /// Tasks within a level are ordered by scheduling priority.
+    pub fn topological_order(&self) -> Vec<Uuid> {
+        self.levels
+            .iter()
+            .flat_map(|level| {
+                let mut tasks: Vec<&ExecutableTask> = level.tasks.iter().collect();
+                tasks.sort_by_key(|task| task.priority);
+                tasks.into_iter().map(|task| task.task_id)
+            })
+            .collect()
+    }
+
+    /// Report what changed from `previous` to this plan
+    ///
+    /// Readiness only counts as changed when it moves to a different state; a task that stays
+    /// blocked by a different set of tasks is not reported. Changes are listed in plan order.
+    pub fn diff(&self, previous: &ExecutionPlan) -> PlanDiff {
+        let locate = |plan: &ExecutionPlan| -> HashMap<Uuid, (usize, TaskReadiness)> {
+            plan.levels
+                .iter()
+                .flat_map(|level| {
+                    level
+                        .tasks
+                        .iter()
+                        .map(|task| (task.task_id, (level.level, task.readiness.clone())))
+                })
+                .collect()
+        };
+        let before = locate(previous);
+        let after = locate(self);
+
+        let mut diff = PlanDiff::default();
+        for level in &self.levels {
+            for task in &level.tasks {
+                let Some((previous_level, previous_readiness)) = before.get(&task.task_id) else {
+                    diff.added_tasks.push(task.task_id);
+                    continue;
+                };
+                if std::mem::discriminant(previous_readiness)
+                    != std::mem::discriminant(&task.readiness)
+                {
+                    diff.readiness_changes.push(ReadinessTransition {
+                        task_id: task.task_id,
+                        from: previous_readiness.clone(),
+                        to: task.readiness.clone(),
+                    });
+                }
+                if *previous_level != level.level {
+                    diff.level_changes.push(LevelChange {
+                        task_id: task.task_id,
+                        from: *previous_level,
+                        to: level.level,
+                    });
+                }
+            }
+        }
+        diff.removed_tasks = previous
+            .levels
+            .iter()
+            .flat_map(|level| level.tasks.iter())
+            .map(|task| task.task_id)
+            .filter(|task_id| !after.contains_key(task_id))
+            .collect();
+        diff
+    }
+}
+
+/// What changed between two execution plans, see [`ExecutionPlan::diff`]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS, JsonSchema)]
+pub struct PlanDiff {
+    /// Tasks only in the newer plan
+    pub added_tasks: Vec<Uuid>,
+    /// Tasks only in the older plan
+    pub removed_tasks: Vec<Uuid>,
+    pub readiness_changes: Vec<ReadinessTransition>,
+    pub level_changes: Vec<LevelChange>,
+}
+
+impl PlanDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added_tasks.is_empty()
+            && self.removed_tasks.is_empty()
+            && self.readiness_changes.is_empty()
+            && self.level_changes.is_empty()
+    }
+}
+
+/// A task whose readiness moved to a different state between two plans
+#[derive(Debug, Clone, Serialize, Deserialize, TS, JsonSchema)]
+pub struct ReadinessTransition {
+    pub task_id: Uuid,
+    pub from: TaskReadiness,
+    pub to: TaskReadiness,
+}
+
+/// A task that moved to a different execution level between two plans
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS, JsonSchema)]
+pub struct LevelChange {
+    pub task_id: Uuid,
+    pub from: usize,
+    pub to: usize,
+}
+
+/// An execution plan tagged with the version it was recorded under
+///
+/// The version increases whenever a rebuild produces a plan that differs from the previous
+/// one; it is only meaningful together with the epoch of the orchestrator instance that issued it.
+#[derive(Debug, Clone, Serialize, Deserialize, TS, JsonSchema)]
+pub struct VersionedPlan {
+    /// Changes whenever the project's orchestrator is reset or recreated
+    pub epoch: Uuid,
+    pub version: u32,
+    pub plan: ExecutionPlan,
+}
+
+/// A project's tasks as one flat dependency order
+#[derive(Debug, Clone, Serialize, Deserialize, TS, JsonSchema)]
+pub struct TopologicalOrder {
+    /// Tasks ordered so that every task comes after all of its prerequisites
+    pub order: Vec<Uuid>,
+    /// Tasks left out of `order` because they are in a dependency cycle or depend on one
+    pub cyclic: Vec<Uuid>,
+}
+
+/// One bar of a projected schedule, in minutes from the start of the plan
+#[derive(Debug, Clone, Serialize, Deserialize, TS, JsonSchema)]
+pub struct GanttEntry {
+    pub task_id: Uuid,
+    pub start_minutes: u32,
+    pub end_minutes: u32,
+    /// Whether the duration came from the task's estimate rather than the default
+    pub estimated: bool,
+    /// Prerequisites drawn as arrows into this bar
+    pub dependencies: Vec<Uuid>,
+}
+
+/// Projected schedule of a plan, see [`crate::scheduler::build_gantt`]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, JsonSchema)]
+pub struct GanttChart {
+    /// Bars in dependency order
+    pub entries: Vec<GanttEntry>,
+    /// When the last task is projected to finish
+    pub total_minutes: u32,
 }
 
 /// A level in the execution plan (tasks at same depth can run in parallel)
-#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, JsonSchema)]
 pub struct ExecutionLevel {
     pub level: usize,
     pub tasks: Vec<ExecutableTask>,
 }
 
+/// A task reached while walking the dependency graph, with its distance from the start
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS, JsonSchema)]
+pub struct TaskDepth {
+    pub task_id: Uuid,
+    pub depth: usize,
+}
+
+/// A blocked task found downstream of another task
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS, JsonSchema)]
+pub struct BlockedTask {
+    pub task_id: Uuid,
+    /// Distance from the task the walk started at
+    pub depth: usize,
+    pub blocking_task_ids: Vec<Uuid>,
+}
+
+/// Integrity problems in a project's dependency graph
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, TS, JsonSchema)]
+pub struct GraphValidationReport {
+    /// Each cycle as the tasks along it; every task depends on the next, the last on the first
+    pub cycles: Vec<Vec<Uuid>>,
+    /// Dependencies whose task depends on itself
+    pub self_dependencies: Vec<Uuid>,
+    /// Dependencies on a task outside the project
+    pub cross_project_dependencies: Vec<Uuid>,
+    /// Tasks held back only by cancelled prerequisites, so they can never become ready
+    pub blocked_by_cancelled: Vec<BlockedByCancelled>,
+}
+
+impl GraphValidationReport {
+    /// Whether no problem was found
+    pub fn is_valid(&self) -> bool {
+        self.cycles.is_empty()
+            && self.self_dependencies.is_empty()
+            && self.cross_project_dependencies.is_empty()
+            && self.blocked_by_cancelled.is_empty()
+    }
+}
+
+/// A task whose remaining blockers are all cancelled
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS, JsonSchema)]
+pub struct BlockedByCancelled {
+    pub task_id: Uuid,
+    pub cancelled_task_ids: Vec<Uuid>,
+}
+
+/// One readiness column of a plan, see [`crate::scheduler::get_tasks_by_readiness`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReadinessFilter {
+    Ready,
+    Blocked,
+    InProgress,
+    Completed,
+}
+
+/// How tasks are grouped into the levels of an execution plan
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, TS, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum PlanMode {
+    /// Every task at the earliest level its dependencies allow
+    #[default]
+    Levels,
+    /// Levels are time slots of at most `max_parallel` tasks
+    Capped,
+}
+
+/// Language of the human-readable readiness summary
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, TS, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ExplanationLanguage {
+    #[default]
+    Ja,
+    En,
+}
+
+/// One dependency of a task and whether it still holds the task back
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS, JsonSchema)]
+pub struct DependencyExplanation {
+    pub task_id: Uuid,
+    pub title: String,
+    pub status: TaskStatus,
+    pub kind: DependencyKind,
+    /// Whether the dependency's kind is satisfied by its task's status
+    pub satisfied: bool,
+}
+
+/// Why a task is ready, blocked or otherwise not schedulable
+#[derive(Debug, Clone, Serialize, Deserialize, TS, JsonSchema)]
+pub struct ReadinessExplanation {
+    pub task_id: Uuid,
+    pub status: TaskStatus,
+    pub readiness: TaskReadiness,
+    pub dependencies: Vec<DependencyExplanation>,
+    pub summary: String,
+}
+
 /// Result of validating a status transition
-#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, JsonSchema)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum TransitionValidation {
-    /// Transition is valid
-    Valid,
+    /// Transition is valid (`forced` is set when an invalid transition was overridden)
+    Valid { forced: bool },
     /// Transition is invalid
-    Invalid { reason: String },
+    Invalid {
+        code: OrchestratorMessage,
+        reason: String,
+    },
     /// Transition requires confirmation (e.g., dependencies not met)
-    RequiresConfirmation { reason: String, blocking_tasks: Vec<Uuid> },
+    RequiresConfirmation {
+        code: OrchestratorMessage,
+        reason: String,
+        blocking_tasks: Vec<Uuid>,
+    },
+}
+
+impl TransitionValidation {
+    /// Invalid result with its reason rendered in the default language
+    pub fn invalid(code: OrchestratorMessage) -> Self {
+        let reason = code.render(ExplanationLanguage::default());
+        Self::Invalid { code, reason }
+    }
+
+    /// Confirmation request with its reason rendered in the default language
+    pub fn requires_confirmation(code: OrchestratorMessage, blocking_tasks: Vec<Uuid>) -> Self {
+        let reason = code.render(ExplanationLanguage::default());
+        Self::RequiresConfirmation {
+            code,
+            reason,
+            blocking_tasks,
+        }
+    }
+
+    /// Re-render the reason in the requested language
+    pub fn localized(mut self, language: ExplanationLanguage) -> Self {
+        match &mut self {
+            Self::Valid { .. } => {}
+            Self::Invalid { code, reason } | Self::RequiresConfirmation { code, reason, .. } => {
+                *reason = code.render(language);
+            }
+        }
+        self
+    }
+}
+
+/// Outcome for one task of a bulk status change
+#[derive(Debug, Clone, Serialize, Deserialize, TS, JsonSchema)]
+pub struct BulkTransitionResult {
+    pub task_id: Uuid,
+    pub validation: TransitionValidation,
+    /// Whether the task's status was changed
+    pub applied: bool,
+}
+
+/// Whether a task could be started right now, and why not
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum StartReadiness {
+    /// The task is in `Todo` and no dependency holds it back
+    Ready,
+    /// Only `Todo` tasks can be started
+    WrongStatus { current: TaskStatus },
+    /// Dependencies that are not yet satisfied
+    Blocked { blocking: Vec<Uuid> },
 }
 
 /// Orchestration state for a project
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, TS, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, TS, JsonSchema, PartialEq, Eq, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum OrchestratorState {
     /// Orchestrator is idle, not running tasks
@@ -86,8 +416,78 @@ pub enum OrchestratorState {
     Stopping,
 }
 
+/// What to do next in a project, see [`crate::scheduler::next_action`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum NextAction {
+    /// Start this task: the highest-priority ready task
+    Start { task_id: Uuid },
+    /// No task is ready to start; `unblocking_task` is the task whose completion would make
+    /// the most blocked tasks ready, if any
+    Wait {
+        reason: WaitReason,
+        unblocking_task: Option<UnblockingTask>,
+    },
+}
+
+/// Why no task can be started right now
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WaitReason {
+    /// Every remaining task is waiting on dependencies or work already under way
+    AllBlocked,
+    /// Every task is completed or cancelled
+    AllDone,
+    /// The orchestrator is idle, so nothing under way will move the plan forward
+    OrchestratorIdle,
+}
+
+/// A task that holds back others, with the tasks that would become ready once it completes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS, JsonSchema)]
+pub struct UnblockingTask {
+    pub task_id: Uuid,
+    pub unblocks: Vec<Uuid>,
+}
+
+/// Why a task is flagged as at risk
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RiskReason {
+    /// Blocked by tasks that are themselves blocked or on hold, so finishing work won't free it
+    TransitivelyStuck { stuck_behind: Vec<Uuid> },
+    /// Running longer than the staleness threshold since it was reported started
+    StaleInProgress { elapsed_secs: u32 },
+}
+
+/// A task that needs attention, with the reason it was flagged
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS, JsonSchema)]
+pub struct AtRiskTask {
+    pub task_id: Uuid,
+    pub reason: RiskReason,
+}
+
+/// Flat snapshot of a project's orchestration, suitable for scraping
+///
+/// Every field besides `state` is a plain gauge so it maps one-to-one onto exporter metrics.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS, JsonSchema)]
+pub struct OrchestratorMetrics {
+    pub state: OrchestratorState,
+    pub total_tasks: usize,
+    pub ready_tasks: usize,
+    pub blocked_tasks: usize,
+    pub in_progress_tasks: usize,
+    pub in_review_tasks: usize,
+    pub completed_tasks: usize,
+    /// Number of execution levels
+    pub level_count: usize,
+    /// Most tasks in a single level, i.e. the peak parallelism the graph allows
+    pub max_level_width: usize,
+    /// Tasks on the longest chain of unfinished dependencies
+    pub critical_path_length: usize,
+}
+
 /// Event emitted by the orchestrator
-#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, JsonSchema)]
 #[serde(tag = "type", content = "data", rename_all = "snake_case")]
 pub enum OrchestratorEvent {
     /// A task has started execution
@@ -102,4 +502,16 @@ pub enum OrchestratorEvent {
     StateChanged { state: OrchestratorState },
     /// Execution plan updated
     PlanUpdated { plan: ExecutionPlan },
+    /// Tasks the orchestrator wants started (only emitted when auto-start is enabled)
+    AutoStartRequested { task_ids: Vec<Uuid> },
+    /// A task's readiness moved to a different state since the previous plan
+    ReadinessChanged {
+        task_id: Uuid,
+        from: TaskReadiness,
+        to: TaskReadiness,
+    },
+    /// The plan's longest dependency chain went over the configured warning threshold
+    DeepChainWarning { depth: usize },
+    /// A task has been running longer than the configured task timeout
+    TaskTimedOut { task_id: Uuid, elapsed_secs: u32 },
 }
@@ -1,10 +1,17 @@
-use std::collections::{HashMap, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use db::models::task::{Task, TaskStatus};
 use db::models::task_dependency::TaskDependency;
 
-use crate::models::{ExecutableTask, ExecutionLevel, ExecutionPlan, TaskReadiness};
+use crate::models::{
+    AtRiskTask, BlockedByCancelled, BlockedTask, DependencyExplanation, ExecutableTask,
+    ExecutionLevel, ExecutionPlan, ExplanationLanguage, GanttChart, GanttEntry,
+    GraphValidationReport, NextAction, OrchestratorEvent, OrchestratorMetrics, OrchestratorState,
+    ReadinessExplanation, ReadinessFilter, RiskReason, TaskReadiness, TopologicalOrder,
+    UnblockingTask, WaitReason,
+};
 
 /// Builds an execution plan from tasks and their dependencies using topological sort
 pub fn build_execution_plan(
@@ -17,6 +24,7 @@ pub fn build_execution_plan(
     // Build adjacency lists
     let mut deps_for_task: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
     let mut dependents_of_task: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    let mut dependency_edges: HashMap<Uuid, Vec<&TaskDependency>> = HashMap::new();
 
     for dep in dependencies {
         deps_for_task
@@ -27,10 +35,19 @@ pub fn build_execution_plan(
             .entry(dep.depends_on_task_id)
             .or_default()
             .push(dep.task_id);
+        dependency_edges.entry(dep.task_id).or_default().push(dep);
     }
 
     // Perform topological sort using Kahn's algorithm to assign levels
-    let levels = topological_sort_levels(&task_map, &deps_for_task);
+    let levels = level_assignment(
+        tasks.iter().map(|t| t.id),
+        dependencies
+            .iter()
+            .map(|dep| (dep.task_id, dep.depends_on_task_id)),
+    );
+    let max_depth = levels.len().saturating_sub(1);
+
+    let priorities = priority_ranks(tasks);
 
     // Build executable tasks with readiness info
     let mut all_executable_tasks: Vec<ExecutableTask> = Vec::new();
@@ -39,14 +56,23 @@ pub fn build_execution_plan(
         let task_deps = deps_for_task.get(&task.id).cloned().unwrap_or_default();
         let task_dependents = dependents_of_task.get(&task.id).cloned().unwrap_or_default();
 
-        let readiness = calculate_readiness(task, &task_deps, &task_map);
+        let task_edges = dependency_edges
+            .get(&task.id)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let readiness = calculate_readiness(task, task_edges, &task_map);
 
         all_executable_tasks.push(ExecutableTask {
             task_id: task.id,
             status: task.status.clone(),
+            task_type: task.task_type.clone(),
             readiness,
             dependencies: task_deps,
             dependents: task_dependents,
+            priority: priorities[&task.id],
+            ready_since: None,
+            assigned_to: None,
+            skipped: false,
         });
     }
 
@@ -69,12 +95,22 @@ pub fn build_execution_plan(
         .filter(|l| !l.tasks.is_empty())
         .collect();
 
+    summarize_plan(execution_levels, tasks.len(), max_depth)
+}
+
+/// Count readiness and progress over `execution_levels` into a plan covering `total_tasks` tasks
+fn summarize_plan(
+    execution_levels: Vec<ExecutionLevel>,
+    total_tasks: usize,
+    max_depth: usize,
+) -> ExecutionPlan {
     // Calculate statistics
     let mut completed = 0;
     let mut in_progress = 0;
     let mut in_review = 0;
     let mut ready = 0;
     let mut blocked = 0;
+    let mut on_hold = 0;
 
     for level in &execution_levels {
         for task in &level.tasks {
@@ -83,6 +119,7 @@ pub fn build_execution_plan(
                 TaskReadiness::InProgress => in_progress += 1,
                 TaskReadiness::Ready => ready += 1,
                 TaskReadiness::Blocked { .. } => blocked += 1,
+                TaskReadiness::OnHold => on_hold += 1,
                 TaskReadiness::Cancelled => {}
             }
             // Check for in_review status specifically
@@ -92,75 +129,428 @@ pub fn build_execution_plan(
         }
     }
 
+    let level_progress = execution_levels
+        .iter()
+        .map(|level| completion_percent(level.tasks.iter()))
+        .collect();
+    let progress_percent =
+        completion_percent(execution_levels.iter().flat_map(|level| level.tasks.iter()));
+
     ExecutionPlan {
         levels: execution_levels,
-        total_tasks: tasks.len(),
+        total_tasks,
         completed_tasks: completed,
         in_progress_tasks: in_progress,
         in_review_tasks: in_review,
         ready_tasks: ready,
         blocked_tasks: blocked,
+        on_hold_tasks: on_hold,
+        progress_percent,
+        level_progress,
+        max_depth,
     }
 }
 
-/// Perform topological sort and return tasks grouped by level
-/// Level 0 = tasks with no dependencies, Level 1 = tasks depending only on level 0, etc.
-fn topological_sort_levels(
-    task_map: &HashMap<Uuid, &Task>,
-    deps_for_task: &HashMap<Uuid, Vec<Uuid>>,
-) -> Vec<Vec<Uuid>> {
-    let mut in_degree: HashMap<Uuid, usize> = HashMap::new();
-    let mut levels: Vec<Vec<Uuid>> = Vec::new();
+/// Builds an execution plan that only considers dependencies tagged with `genre_id`
+///
+/// Edges of other genres, or without a genre, are ignored for both leveling and blocking, so a
+/// task with no edge in the genre lands on level 0.
+pub fn build_execution_plan_filtered(
+    tasks: &[Task],
+    dependencies: &[TaskDependency],
+    genre_id: Uuid,
+) -> ExecutionPlan {
+    let relevant: Vec<TaskDependency> = dependencies
+        .iter()
+        .filter(|dep| dep.genre_id == Some(genre_id))
+        .cloned()
+        .collect();
+    build_execution_plan(tasks, &relevant)
+}
 
-    // Initialize in-degrees
-    for &task_id in task_map.keys() {
-        let deps = deps_for_task.get(&task_id).map(|d| d.len()).unwrap_or(0);
-        in_degree.insert(task_id, deps);
+/// Set each task's `assigned_to` from `owners`, clearing it for tasks without an entry
+pub fn assign_owners(plan: &mut ExecutionPlan, owners: &HashMap<Uuid, String>) {
+    for task in plan
+        .levels
+        .iter_mut()
+        .flat_map(|level| level.tasks.iter_mut())
+    {
+        task.assigned_to = owners.get(&task.task_id).cloned();
     }
+}
 
-    // Build reverse adjacency (dependents for each task)
-    let mut dependents: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
-    for (task_id, deps) in deps_for_task {
-        for dep_id in deps {
-            dependents.entry(*dep_id).or_default().push(*task_id);
+/// Narrow a plan to the tasks assigned to `assignee`
+///
+/// Levels keep their original numbers so the view lines up with the full plan; levels left
+/// empty are dropped and the counts only cover the remaining tasks. `max_depth` stays that of
+/// the full graph.
+pub fn filter_plan_by_assignee(plan: &ExecutionPlan, assignee: &str) -> ExecutionPlan {
+    let levels: Vec<ExecutionLevel> = plan
+        .levels
+        .iter()
+        .map(|level| ExecutionLevel {
+            level: level.level,
+            tasks: level
+                .tasks
+                .iter()
+                .filter(|task| task.assigned_to.as_deref() == Some(assignee))
+                .cloned()
+                .collect(),
+        })
+        .filter(|level| !level.tasks.is_empty())
+        .collect();
+    let total_tasks = levels.iter().map(|level| level.tasks.len()).sum();
+    summarize_plan(levels, total_tasks, plan.max_depth)
+}
+
+/// Merge adjacent levels of `plan` while the merged level holds at most `max_parallel` tasks
+///
+/// A level is only folded into the one before it when none of its tasks depends on a task
+/// already in the merged level, directly or through tasks of `graph` that `plan` leaves out,
+/// so a task never shares a level with its own prerequisite. `graph` is the plan `plan` was
+/// narrowed from, or `plan` itself. This is a display convenience for sparse plans, such as one
+/// narrowed by assignee; a full plan already puts every task right after its deepest
+/// prerequisite and is returned unchanged. A merged level keeps the number of its first level.
+/// Unlike [`build_execution_plan_capped`], it does not reschedule anything.
+pub fn compact_levels(
+    plan: &ExecutionPlan,
+    graph: &ExecutionPlan,
+    max_parallel: usize,
+) -> ExecutionPlan {
+    let prerequisites: HashMap<Uuid, &[Uuid]> = graph
+        .levels
+        .iter()
+        .chain(&plan.levels)
+        .flat_map(|level| &level.tasks)
+        .map(|task| (task.task_id, task.dependencies.as_slice()))
+        .collect();
+    let depends_on_merged = |task: &ExecutableTask, merged_ids: &HashSet<Uuid>| {
+        let mut pending: Vec<Uuid> = task.dependencies.clone();
+        let mut seen: HashSet<Uuid> = HashSet::new();
+        while let Some(id) = pending.pop() {
+            if merged_ids.contains(&id) {
+                return true;
+            }
+            if seen.insert(id) {
+                pending.extend(prerequisites.get(&id).copied().unwrap_or_default());
+            }
+        }
+        false
+    };
+
+    let mut levels: Vec<ExecutionLevel> = Vec::new();
+    let mut merged_ids: HashSet<Uuid> = HashSet::new();
+    for level in &plan.levels {
+        let fits = levels.last().is_some_and(|last| {
+            last.tasks.len() + level.tasks.len() <= max_parallel
+                && level
+                    .tasks
+                    .iter()
+                    .all(|task| !depends_on_merged(task, &merged_ids))
+        });
+        if !fits {
+            merged_ids.clear();
+            levels.push(ExecutionLevel {
+                level: level.level,
+                tasks: Vec::new(),
+            });
+        }
+        merged_ids.extend(level.tasks.iter().map(|task| task.task_id));
+        if let Some(last) = levels.last_mut() {
+            last.tasks.extend(level.tasks.iter().cloned());
         }
     }
+    summarize_plan(levels, plan.total_tasks, plan.max_depth)
+}
 
-    // Kahn's algorithm with level tracking
-    let mut current_level: VecDeque<Uuid> = in_degree
+/// Duration assumed for tasks without an estimate
+pub const DEFAULT_TASK_MINUTES: u32 = 60;
+
+/// Builds an execution plan whose levels are the time slots of [`schedule_with_cap`]
+///
+/// Unlike [`build_execution_plan`], no level holds more than `max_parallel` tasks, so the number
+/// of levels is an achievable makespan rather than the length of the longest chain.
+/// `max_depth` still reports the dependency depth.
+pub fn build_execution_plan_capped(
+    tasks: &[Task],
+    dependencies: &[TaskDependency],
+    max_parallel: usize,
+) -> ExecutionPlan {
+    let plan = build_execution_plan(tasks, dependencies);
+    let mut executable: HashMap<Uuid, ExecutableTask> = plan
+        .levels
+        .into_iter()
+        .flat_map(|level| level.tasks)
+        .map(|task| (task.task_id, task))
+        .collect();
+
+    let execution_levels = schedule_with_cap(tasks, dependencies, max_parallel)
+        .into_iter()
+        .enumerate()
+        .map(|(level, task_ids)| ExecutionLevel {
+            level,
+            tasks: task_ids
+                .iter()
+                .filter_map(|id| executable.remove(id))
+                .collect(),
+        })
+        .collect();
+
+    summarize_plan(execution_levels, tasks.len(), plan.max_depth)
+}
+
+/// Assign tasks to time slots holding at most `max_parallel` tasks each
+///
+/// List scheduling: each slot takes the tasks whose prerequisites all sit in earlier slots,
+/// preferring those heading the longest remaining chain and then by priority. Tasks in a cycle
+/// are left out, as in the plan's levels.
+pub fn schedule_with_cap(
+    tasks: &[Task],
+    dependencies: &[TaskDependency],
+    max_parallel: usize,
+) -> Vec<Vec<Uuid>> {
+    let mut deps_for_task: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    let mut dependents_of_task: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    for dep in dependencies {
+        deps_for_task
+            .entry(dep.task_id)
+            .or_default()
+            .push(dep.depends_on_task_id);
+        dependents_of_task
+            .entry(dep.depends_on_task_id)
+            .or_default()
+            .push(dep.task_id);
+    }
+
+    // Length of the longest chain starting at each task, counted in tasks
+    let mut chain_length: HashMap<Uuid, usize> = HashMap::new();
+    let levels = level_assignment(
+        tasks.iter().map(|t| t.id),
+        dependencies
+            .iter()
+            .map(|dep| (dep.task_id, dep.depends_on_task_id)),
+    );
+    for level in levels.iter().rev() {
+        for task_id in level {
+            let longest_dependent = dependents_of_task
+                .get(task_id)
+                .into_iter()
+                .flatten()
+                .filter_map(|id| chain_length.get(id))
+                .max()
+                .copied()
+                .unwrap_or(0);
+            chain_length.insert(*task_id, longest_dependent + 1);
+        }
+    }
+
+    let priorities = priority_ranks(tasks);
+    let mut remaining: HashMap<Uuid, usize> = chain_length
+        .keys()
+        .map(|id| (*id, deps_for_task.get(id).map_or(0, Vec::len)))
+        .collect();
+    let mut ready: Vec<Uuid> = remaining
         .iter()
-        .filter(|(_, &deg)| deg == 0)
+        .filter(|(_, &count)| count == 0)
         .map(|(&id, _)| id)
         .collect();
+    let mut slots = Vec::new();
+
+    while !ready.is_empty() {
+        ready.sort_by_key(|id| (std::cmp::Reverse(chain_length[id]), priorities[id]));
+        let slot: Vec<Uuid> = ready
+            .drain(..ready.len().min(max_parallel.max(1)))
+            .collect();
+
+        // Unlocked tasks can only join a later slot
+        let mut unlocked = Vec::new();
+        for task_id in &slot {
+            for dependent_id in dependents_of_task.get(task_id).into_iter().flatten() {
+                if let Some(count) = remaining.get_mut(dependent_id) {
+                    *count = count.saturating_sub(1);
+                    if *count == 0 {
+                        unlocked.push(*dependent_id);
+                    }
+                }
+            }
+        }
+        ready.extend(unlocked);
+        slots.push(slot);
+    }
+
+    slots
+}
+
+/// Project a start and end for every task in the plan, in minutes from now
+///
+/// Tasks are placed in dependency order, each after its latest-finishing prerequisite and on one
+/// of `max_parallel` slots, preferring the slot that frees up closest before the task could
+/// start. Completed and cancelled tasks take no time or slot. Tasks in a cycle are not in the
+/// plan's levels and are left out.
+pub fn build_gantt(
+    plan: &ExecutionPlan,
+    estimates: &HashMap<Uuid, u32>,
+    max_parallel: usize,
+) -> GanttChart {
+    let tasks: HashMap<Uuid, &ExecutableTask> = plan
+        .levels
+        .iter()
+        .flat_map(|level| level.tasks.iter())
+        .map(|task| (task.task_id, task))
+        .collect();
+    let mut slots = vec![0u32; max_parallel.max(1)];
+    let mut ends: HashMap<Uuid, u32> = HashMap::new();
+    let mut entries = Vec::with_capacity(tasks.len());
+
+    for task_id in plan.topological_order() {
+        let task = tasks[&task_id];
+        let estimate = estimates.get(&task_id).copied();
+        let (start, end) = match task.readiness {
+            TaskReadiness::Completed | TaskReadiness::Cancelled => (0, 0),
+            _ => {
+                let ready_at = task
+                    .dependencies
+                    .iter()
+                    .filter_map(|dep| ends.get(dep))
+                    .copied()
+                    .max()
+                    .unwrap_or(0);
+                let slot = slots
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, &free_at)| free_at <= ready_at)
+                    .max_by_key(|(_, &free_at)| free_at)
+                    .or_else(|| slots.iter().enumerate().min_by_key(|(_, &free_at)| free_at))
+                    .map(|(index, _)| index)
+                    .unwrap_or_default();
+                let start = ready_at.max(slots[slot]);
+                let end = start.saturating_add(estimate.unwrap_or(DEFAULT_TASK_MINUTES));
+                slots[slot] = end;
+                (start, end)
+            }
+        };
+        ends.insert(task_id, end);
+        entries.push(GanttEntry {
+            task_id,
+            start_minutes: start,
+            end_minutes: end,
+            estimated: estimate.is_some(),
+            dependencies: task.dependencies.clone(),
+        });
+    }
+
+    let total_minutes = entries
+        .iter()
+        .map(|entry| entry.end_minutes)
+        .max()
+        .unwrap_or(0);
+    GanttChart {
+        entries,
+        total_minutes,
+    }
+}
+
+/// Order tasks so each comes after its prerequisites, see [`ExecutionPlan::topological_order`]
+/// Tasks that can't be ordered because of a cycle are returned separately, by priority.
+pub fn topological_order(tasks: &[Task], dependencies: &[TaskDependency]) -> TopologicalOrder {
+    let order = build_execution_plan(tasks, dependencies).topological_order();
+
+    let ordered: HashSet<Uuid> = order.iter().copied().collect();
+    let priorities = priority_ranks(tasks);
+    let mut cyclic: Vec<Uuid> = tasks
+        .iter()
+        .map(|task| task.id)
+        .filter(|id| !ordered.contains(id))
+        .collect();
+    cyclic.sort_by_key(|id| priorities[id]);
+
+    TopologicalOrder { order, cyclic }
+}
+
+/// Rank tasks for scheduling: positioned tasks first in ascending position, then the rest,
+/// with older tasks winning ties
+fn priority_ranks(tasks: &[Task]) -> HashMap<Uuid, usize> {
+    let mut ordered: Vec<&Task> = tasks.iter().collect();
+    ordered.sort_by_key(|task| (task.position.is_none(), task.position, task.created_at));
+
+    ordered
+        .into_iter()
+        .enumerate()
+        .map(|(rank, task)| (task.id, rank))
+        .collect()
+}
+
+/// Percentage of completed tasks among the non-cancelled ones
+/// Returns 0 when every task is cancelled or there are no tasks at all
+fn completion_percent<'a>(tasks: impl Iterator<Item = &'a ExecutableTask>) -> f32 {
+    let (active, completed) =
+        tasks.fold((0usize, 0usize), |(active, completed), task| match task.readiness {
+            TaskReadiness::Cancelled => (active, completed),
+            TaskReadiness::Completed => (active + 1, completed + 1),
+            _ => (active + 1, completed),
+        });
+
+    if active == 0 {
+        return 0.0;
+    }
+    completed as f32 / active as f32 * 100.0
+}
+
+/// Group `nodes` into levels by Kahn's algorithm over `edges`, given as
+/// `(task_id, depends_on_task_id)` pairs
+///
+/// Level 0 = nodes with no dependencies, Level 1 = nodes depending only on level 0, etc., so
+/// each node sits one level past its deepest prerequisite. Nodes in a cycle, or depending on a
+/// node that isn't listed, are left out. Both the execution plan and the DAG layout level the
+/// graph through here so they always agree.
+pub fn level_assignment(
+    nodes: impl IntoIterator<Item = Uuid>,
+    edges: impl IntoIterator<Item = (Uuid, Uuid)>,
+) -> Vec<Vec<Uuid>> {
+    let nodes: Vec<Uuid> = nodes.into_iter().collect();
+    let mut in_degree: HashMap<Uuid, usize> = nodes.iter().map(|&id| (id, 0)).collect();
+
+    // Count each node's prerequisites and build reverse adjacency (dependents for each node)
+    let mut dependents: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    for (task_id, depends_on_task_id) in edges {
+        if let Some(deg) = in_degree.get_mut(&task_id) {
+            *deg += 1;
+            dependents
+                .entry(depends_on_task_id)
+                .or_default()
+                .push(task_id);
+        }
+    }
+
+    // Kahn's algorithm with level tracking
+    let mut levels: Vec<Vec<Uuid>> = Vec::new();
+    let mut current_level: Vec<Uuid> = nodes.into_iter().filter(|id| in_degree[id] == 0).collect();
 
     while !current_level.is_empty() {
-        let level_tasks: Vec<Uuid> = current_level.drain(..).collect();
-        let mut next_level = VecDeque::new();
-
-        for task_id in &level_tasks {
-            if let Some(deps) = dependents.get(task_id) {
-                for &dependent_id in deps {
-                    if let Some(deg) = in_degree.get_mut(&dependent_id) {
-                        *deg = deg.saturating_sub(1);
-                        if *deg == 0 {
-                            next_level.push_back(dependent_id);
-                        }
+        let mut next_level = Vec::new();
+
+        for task_id in &current_level {
+            for &dependent_id in dependents.get(task_id).into_iter().flatten() {
+                if let Some(deg) = in_degree.get_mut(&dependent_id) {
+                    *deg = deg.saturating_sub(1);
+                    if *deg == 0 {
+                        next_level.push(dependent_id);
                     }
                 }
             }
         }
 
-        levels.push(level_tasks);
-        current_level = next_level;
+        levels.push(std::mem::replace(&mut current_level, next_level));
     }
 
     levels
 }
 
 /// Calculate the readiness state of a task based on its dependencies
+/// Each dependency's kind decides whether its prerequisite still blocks the task.
 fn calculate_readiness(
     task: &Task,
-    dependencies: &[Uuid],
+    dependencies: &[&TaskDependency],
     task_map: &HashMap<Uuid, &Task>,
 ) -> TaskReadiness {
     // Check task's own status first
@@ -168,16 +558,17 @@ fn calculate_readiness(
         TaskStatus::Done => return TaskReadiness::Completed,
         TaskStatus::Cancelled => return TaskReadiness::Cancelled,
         TaskStatus::InProgress | TaskStatus::InReview => return TaskReadiness::InProgress,
+        TaskStatus::Blocked => return TaskReadiness::OnHold,
         TaskStatus::Todo => {}
     }
 
-    // Check if all dependencies are completed
+    // Check if all dependencies are satisfied
     let mut blocking_tasks = Vec::new();
 
-    for &dep_id in dependencies {
-        if let Some(dep_task) = task_map.get(&dep_id) {
-            if dep_task.status != TaskStatus::Done {
-                blocking_tasks.push(dep_id);
+    for dep in dependencies {
+        if let Some(dep_task) = task_map.get(&dep.depends_on_task_id) {
+            if !dep.kind.is_satisfied_by(&dep_task.status) {
+                blocking_tasks.push(dep.depends_on_task_id);
             }
         }
     }
@@ -191,13 +582,16 @@ fn calculate_readiness(
     }
 }
 
-/// Get all tasks that are ready to execute
+/// Get all tasks that are ready to execute, highest priority first
 pub fn get_ready_tasks(plan: &ExecutionPlan) -> Vec<&ExecutableTask> {
-    plan.levels
+    let mut ready: Vec<&ExecutableTask> = plan
+        .levels
         .iter()
         .flat_map(|level| level.tasks.iter())
         .filter(|task| matches!(task.readiness, TaskReadiness::Ready))
-        .collect()
+        .collect();
+    ready.sort_by_key(|task| task.priority);
+    ready
 }
 
 /// Get all tasks that are currently in progress
@@ -209,6 +603,46 @@ pub fn get_in_progress_tasks(plan: &ExecutionPlan) -> Vec<&ExecutableTask> {
         .collect()
 }
 
+/// Get the tasks in one readiness column of the plan
+/// Ready tasks come highest priority first; the other columns keep plan order.
+pub fn get_tasks_by_readiness(
+    plan: &ExecutionPlan,
+    filter: ReadinessFilter,
+) -> Vec<&ExecutableTask> {
+    match filter {
+        ReadinessFilter::Ready => get_ready_tasks(plan),
+        ReadinessFilter::InProgress => get_in_progress_tasks(plan),
+        ReadinessFilter::Blocked => plan
+            .levels
+            .iter()
+            .flat_map(|level| level.tasks.iter())
+            .filter(|task| matches!(task.readiness, TaskReadiness::Blocked { .. }))
+            .collect(),
+        ReadinessFilter::Completed => plan
+            .levels
+            .iter()
+            .flat_map(|level| level.tasks.iter())
+            .filter(|task| matches!(task.readiness, TaskReadiness::Completed))
+            .collect(),
+    }
+}
+
+/// Whether a task in this status holds one of the orchestrator's parallel execution slots
+///
+/// Only running tasks do; tasks in review wait on a human, not an agent.
+pub fn occupies_execution_slot(status: &TaskStatus) -> bool {
+    matches!(status, TaskStatus::InProgress)
+}
+
+/// Number of parallel execution slots currently taken in the plan
+pub fn occupied_execution_slots(plan: &ExecutionPlan) -> usize {
+    plan.levels
+        .iter()
+        .flat_map(|level| level.tasks.iter())
+        .filter(|task| occupies_execution_slot(&task.status))
+        .count()
+}
+
 /// Get tasks blocked by a specific task
 pub fn get_tasks_blocked_by(plan: &ExecutionPlan, task_id: Uuid) -> Vec<&ExecutableTask> {
     plan.levels
@@ -242,10 +676,464 @@ pub fn get_tasks_unblocked_by_completion(plan: &ExecutionPlan, completed_task_id
     newly_ready
 }
 
+/// Find tasks that would become ready if all of the given tasks completed
+///
+/// Generalizes `get_tasks_unblocked_by_completion` to a set: a blocked task is returned once
+/// every task blocking it is being completed. The completing tasks themselves are never returned.
+pub fn forecast_unblocked(plan: &ExecutionPlan, completing: &[Uuid]) -> Vec<Uuid> {
+    let completing: HashSet<Uuid> = completing.iter().copied().collect();
+
+    plan.levels
+        .iter()
+        .flat_map(|level| level.tasks.iter())
+        .filter(|task| !completing.contains(&task.task_id))
+        .filter_map(|task| match &task.readiness {
+            TaskReadiness::Blocked { blocking_task_ids }
+                if blocking_task_ids.iter().all(|id| completing.contains(id)) =>
+            {
+                Some(task.task_id)
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// How long a task may stay in progress before it is reported stale, when no task timeout is set
+pub const DEFAULT_STALE_AFTER_SECS: u32 = 24 * 60 * 60;
+
+/// Tasks that are stuck behind other stuck tasks or have been in progress too long, in plan order
+///
+/// A blocked task is stuck when one of its blockers is itself blocked or on hold. A task counts
+/// as stale once it has been running more than `stale_after_secs`, measured from `started_at`;
+/// tasks without a recorded start are never stale.
+pub fn at_risk_tasks(
+    plan: &ExecutionPlan,
+    started_at: &HashMap<Uuid, DateTime<Utc>>,
+    stale_after_secs: u32,
+    now: DateTime<Utc>,
+) -> Vec<AtRiskTask> {
+    let readiness: HashMap<Uuid, &TaskReadiness> = plan
+        .levels
+        .iter()
+        .flat_map(|level| level.tasks.iter())
+        .map(|task| (task.task_id, &task.readiness))
+        .collect();
+
+    plan.levels
+        .iter()
+        .flat_map(|level| level.tasks.iter())
+        .filter_map(|task| {
+            let reason = match &task.readiness {
+                TaskReadiness::Blocked { blocking_task_ids } => {
+                    let stuck_behind: Vec<Uuid> = blocking_task_ids
+                        .iter()
+                        .copied()
+                        .filter(|id| {
+                            matches!(
+                                readiness.get(id),
+                                Some(TaskReadiness::Blocked { .. } | TaskReadiness::OnHold)
+                            )
+                        })
+                        .collect();
+                    if stuck_behind.is_empty() {
+                        return None;
+                    }
+                    RiskReason::TransitivelyStuck { stuck_behind }
+                }
+                TaskReadiness::InProgress if task.status == TaskStatus::InProgress => {
+                    let started = started_at.get(&task.task_id)?;
+                    let elapsed_secs = u32::try_from((now - *started).num_seconds()).ok()?;
+                    if elapsed_secs <= stale_after_secs {
+                        return None;
+                    }
+                    RiskReason::StaleInProgress { elapsed_secs }
+                }
+                _ => return None,
+            };
+            Some(AtRiskTask {
+                task_id: task.task_id,
+                reason,
+            })
+        })
+        .collect()
+}
+
+/// Decide the single next step for a project
+///
+/// Returns the highest-priority ready task that isn't skipped. Otherwise reports why nothing can
+/// start, along with the task that would make the most blocked dependents ready once it
+/// completes; ties go to the task in the earliest level, then by priority.
+pub fn next_action(plan: &ExecutionPlan, state: OrchestratorState) -> NextAction {
+    if let Some(task) = get_ready_tasks(plan).into_iter().find(|t| !t.skipped) {
+        return NextAction::Start {
+            task_id: task.task_id,
+        };
+    }
+
+    let all_done = plan
+        .levels
+        .iter()
+        .flat_map(|level| level.tasks.iter())
+        .all(|task| {
+            matches!(
+                task.readiness,
+                TaskReadiness::Completed | TaskReadiness::Cancelled
+            )
+        });
+    let reason = if all_done {
+        WaitReason::AllDone
+    } else if state == OrchestratorState::Idle {
+        WaitReason::OrchestratorIdle
+    } else {
+        WaitReason::AllBlocked
+    };
+
+    let tasks: HashMap<Uuid, &ExecutableTask> = plan
+        .levels
+        .iter()
+        .flat_map(|level| level.tasks.iter())
+        .map(|task| (task.task_id, task))
+        .collect();
+
+    let mut unblocking_task: Option<UnblockingTask> = None;
+    for level in &plan.levels {
+        let mut candidates: Vec<&ExecutableTask> = level.tasks.iter().collect();
+        candidates.sort_by_key(|task| task.priority);
+        for candidate in candidates {
+            // A dependent becomes ready when this task is its only remaining blocker
+            let unblocks: Vec<Uuid> = candidate
+                .dependents
+                .iter()
+                .filter(|id| {
+                    matches!(
+                        tasks.get(*id).map(|t| &t.readiness),
+                        Some(TaskReadiness::Blocked { blocking_task_ids })
+                            if blocking_task_ids == &[candidate.task_id]
+                    )
+                })
+                .copied()
+                .collect();
+            let best = unblocking_task.as_ref().map_or(0, |t| t.unblocks.len());
+            if unblocks.len() > best {
+                unblocking_task = Some(UnblockingTask {
+                    task_id: candidate.task_id,
+                    unblocks,
+                });
+            }
+        }
+    }
+
+    NextAction::Wait {
+        reason,
+        unblocking_task,
+    }
+}
+
+/// Walk every task downstream of `root` and return the ones currently blocked, nearest first
+pub fn get_blocked_downstream(plan: &ExecutionPlan, root: Uuid) -> Vec<BlockedTask> {
+    let tasks: HashMap<Uuid, &ExecutableTask> = plan
+        .levels
+        .iter()
+        .flat_map(|level| level.tasks.iter())
+        .map(|task| (task.task_id, task))
+        .collect();
+
+    let mut visited: HashSet<Uuid> = HashSet::from([root]);
+    let mut queue: VecDeque<(Uuid, usize)> = VecDeque::from([(root, 0)]);
+    let mut blocked = Vec::new();
+
+    while let Some((current, depth)) = queue.pop_front() {
+        let Some(task) = tasks.get(&current) else {
+            continue;
+        };
+        for &dependent_id in &task.dependents {
+            if !visited.insert(dependent_id) {
+                continue;
+            }
+            if let Some(TaskReadiness::Blocked { blocking_task_ids }) =
+                tasks.get(&dependent_id).map(|t| &t.readiness)
+            {
+                blocked.push(BlockedTask {
+                    task_id: dependent_id,
+                    depth: depth + 1,
+                    blocking_task_ids: blocking_task_ids.clone(),
+                });
+            }
+            queue.push_back((dependent_id, depth + 1));
+        }
+    }
+
+    blocked
+}
+
+/// Longest chain of unfinished tasks, ordered from the first task to start to the last
+///
+/// Completed and cancelled tasks are left out, so the length is the number of tasks that still
+/// have to run one after another.
+pub fn get_critical_path(plan: &ExecutionPlan) -> Vec<Uuid> {
+    // Levels are topologically ordered, so every dependency is visited before its dependents
+    let mut longest: HashMap<Uuid, (usize, Option<Uuid>)> = HashMap::new();
+    let mut end: Option<(usize, Uuid)> = None;
+
+    for task in plan.levels.iter().flat_map(|level| level.tasks.iter()) {
+        if matches!(
+            task.readiness,
+            TaskReadiness::Completed | TaskReadiness::Cancelled
+        ) {
+            continue;
+        }
+
+        let (length, previous) = task
+            .dependencies
+            .iter()
+            .filter_map(|dep_id| longest.get(dep_id).map(|(length, _)| (*length, *dep_id)))
+            .max_by_key(|(length, _)| *length)
+            .map_or((1, None), |(length, dep_id)| (length + 1, Some(dep_id)));
+        longest.insert(task.task_id, (length, previous));
+
+        if end.is_none_or(|(best, _)| length > best) {
+            end = Some((length, task.task_id));
+        }
+    }
+
+    let mut path = Vec::new();
+    let mut current = end.map(|(_, task_id)| task_id);
+    while let Some(task_id) = current {
+        path.push(task_id);
+        current = longest.get(&task_id).and_then(|(_, previous)| *previous);
+    }
+    path.reverse();
+    path
+}
+
+/// Summarize a plan as a flat metrics snapshot
+pub fn compute_metrics(plan: &ExecutionPlan, state: OrchestratorState) -> OrchestratorMetrics {
+    OrchestratorMetrics {
+        state,
+        total_tasks: plan.total_tasks,
+        ready_tasks: plan.ready_tasks,
+        blocked_tasks: plan.blocked_tasks,
+        in_progress_tasks: plan.in_progress_tasks,
+        in_review_tasks: plan.in_review_tasks,
+        completed_tasks: plan.completed_tasks,
+        level_count: plan.levels.len(),
+        max_level_width: plan
+            .levels
+            .iter()
+            .map(|level| level.tasks.len())
+            .max()
+            .unwrap_or(0),
+        critical_path_length: get_critical_path(plan).len(),
+    }
+}
+
+/// Explain a task's readiness: its own status, each dependency and whether it is satisfied,
+/// and a one-line summary in the requested language
+///
+/// Readiness comes from the execution plan, so it matches what the orchestrator schedules.
+/// Returns `None` when the task is not in `tasks`.
+pub fn explain_readiness(
+    tasks: &[Task],
+    dependencies: &[TaskDependency],
+    task_id: Uuid,
+    language: ExplanationLanguage,
+) -> Option<ReadinessExplanation> {
+    let task = tasks.iter().find(|t| t.id == task_id)?;
+    let plan = build_execution_plan(tasks, dependencies);
+    let readiness = plan
+        .levels
+        .iter()
+        .flat_map(|level| level.tasks.iter())
+        .find(|t| t.task_id == task_id)?
+        .readiness
+        .clone();
+
+    let task_map: HashMap<Uuid, &Task> = tasks.iter().map(|t| (t.id, t)).collect();
+    let dependencies: Vec<DependencyExplanation> = dependencies
+        .iter()
+        .filter(|dep| dep.task_id == task_id)
+        .filter_map(|dep| {
+            let dep_task = task_map.get(&dep.depends_on_task_id)?;
+            Some(DependencyExplanation {
+                task_id: dep_task.id,
+                title: dep_task.title.clone(),
+                status: dep_task.status.clone(),
+                kind: dep.kind,
+                satisfied: dep.kind.is_satisfied_by(&dep_task.status),
+            })
+        })
+        .collect();
+
+    let summary = readiness_summary(&task.status, &readiness, &dependencies, language);
+
+    Some(ReadinessExplanation {
+        task_id,
+        status: task.status.clone(),
+        readiness,
+        dependencies,
+        summary,
+    })
+}
+
+fn readiness_summary(
+    status: &TaskStatus,
+    readiness: &TaskReadiness,
+    dependencies: &[DependencyExplanation],
+    language: ExplanationLanguage,
+) -> String {
+    use ExplanationLanguage::{En, Ja};
+
+    match readiness {
+        TaskReadiness::Ready if dependencies.is_empty() => match language {
+            Ja => "依存タスクがないため、開始できます".to_string(),
+            En => "The task has no dependencies and can start".to_string(),
+        },
+        TaskReadiness::Ready => match language {
+            Ja => "すべての依存関係が満たされているため、開始できます".to_string(),
+            En => "All dependencies are satisfied, so the task can start".to_string(),
+        },
+        TaskReadiness::Blocked { .. } => {
+            let waiting: Vec<&str> = dependencies
+                .iter()
+                .filter(|dep| !dep.satisfied)
+                .map(|dep| dep.title.as_str())
+                .collect();
+            match language {
+                Ja => format!(
+                    "未完了の依存タスクが{}件あります: {}",
+                    waiting.len(),
+                    waiting.join(", ")
+                ),
+                En => format!(
+                    "Waiting on {} unfinished dependencies: {}",
+                    waiting.len(),
+                    waiting.join(", ")
+                ),
+            }
+        }
+        TaskReadiness::InProgress if *status == TaskStatus::InReview => match language {
+            Ja => "レビュー待ちです".to_string(),
+            En => "The task is waiting for review".to_string(),
+        },
+        TaskReadiness::InProgress => match language {
+            Ja => "すでに実行中です".to_string(),
+            En => "The task is already in progress".to_string(),
+        },
+        TaskReadiness::OnHold => match language {
+            Ja => "外部の問題によりブロック中としてマークされています".to_string(),
+            En => "The task is marked as blocked by an external issue".to_string(),
+        },
+        TaskReadiness::Completed => match language {
+            Ja => "すでに完了しています".to_string(),
+            En => "The task is already done".to_string(),
+        },
+        TaskReadiness::Cancelled => match language {
+            Ja => "キャンセルされています".to_string(),
+            En => "The task was cancelled".to_string(),
+        },
+    }
+}
+
+/// Check a project's dependency graph for problems that keep the orchestrator from finishing
+///
+/// `dependencies` are the edges whose dependent task is in `tasks`, as loaded per project.
+/// Self-dependencies are reported on their own rather than as one-task cycles.
+pub fn validate_graph(tasks: &[Task], dependencies: &[TaskDependency]) -> GraphValidationReport {
+    let task_map: HashMap<Uuid, &Task> = tasks.iter().map(|t| (t.id, t)).collect();
+
+    let mut report = GraphValidationReport::default();
+    let mut edges: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    for dep in dependencies {
+        if dep.task_id == dep.depends_on_task_id {
+            report.self_dependencies.push(dep.id);
+        } else if !task_map.contains_key(&dep.depends_on_task_id) {
+            report.cross_project_dependencies.push(dep.id);
+        } else {
+            edges
+                .entry(dep.task_id)
+                .or_default()
+                .push(dep.depends_on_task_id);
+        }
+    }
+    report.cycles = find_cycles(tasks, &edges);
+
+    let plan = build_execution_plan(tasks, dependencies);
+    for task in plan.levels.iter().flat_map(|level| level.tasks.iter()) {
+        let TaskReadiness::Blocked { blocking_task_ids } = &task.readiness else {
+            continue;
+        };
+        let all_cancelled = blocking_task_ids.iter().all(|id| {
+            task_map
+                .get(id)
+                .is_some_and(|blocker| blocker.status == TaskStatus::Cancelled)
+        });
+        if all_cancelled {
+            report.blocked_by_cancelled.push(BlockedByCancelled {
+                task_id: task.task_id,
+                cancelled_task_ids: blocking_task_ids.clone(),
+            });
+        }
+    }
+
+    report
+}
+
+/// Cycles closed by the back edges of a depth-first walk along `edges`, one per back edge
+fn find_cycles(tasks: &[Task], edges: &HashMap<Uuid, Vec<Uuid>>) -> Vec<Vec<Uuid>> {
+    let mut finished: HashSet<Uuid> = HashSet::new();
+    let mut cycles = Vec::new();
+
+    for task in tasks {
+        if finished.contains(&task.id) {
+            continue;
+        }
+
+        // The current walk as (task, index of its next edge to follow)
+        let mut path: Vec<(Uuid, usize)> = vec![(task.id, 0)];
+        while let Some(&(node, next)) = path.last() {
+            let targets = edges.get(&node).map(Vec::as_slice).unwrap_or_default();
+            let Some(&target) = targets.get(next) else {
+                finished.insert(node);
+                path.pop();
+                continue;
+            };
+            if let Some(last) = path.last_mut() {
+                last.1 += 1;
+            }
+
+            if let Some(start) = path.iter().position(|(id, _)| *id == target) {
+                cycles.push(path[start..].iter().map(|(id, _)| *id).collect());
+            } else if !finished.contains(&target) {
+                path.push((target, 0));
+            }
+        }
+    }
+
+    cycles
+}
+
+/// Compare two plans and report tasks whose readiness moved to a different state
+///
+/// Only changes of state count: a task that stays blocked by a different set of tasks is not
+/// reported. Tasks missing from either plan are skipped.
+pub fn diff_readiness(previous: &ExecutionPlan, current: &ExecutionPlan) -> Vec<OrchestratorEvent> {
+    current
+        .diff(previous)
+        .readiness_changes
+        .into_iter()
+        .map(|change| OrchestratorEvent::ReadinessChanged {
+            task_id: change.task_id,
+            from: change.from,
+            to: change.to,
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use db::models::task_dependency::DependencyCreator;
+    use db::models::task::TaskType;
+    use db::models::task_dependency::{DependencyCreator, DependencyKind};
 
     fn create_test_task(id: Uuid, status: TaskStatus) -> Task {
         Task {
@@ -254,6 +1142,7 @@ mod tests {
             title: format!("Task {}", id),
             description: None,
             status,
+            task_type: TaskType::Feature,
             parent_workspace_id: None,
             shared_task_id: None,
             position: None,
@@ -272,6 +1161,19 @@ mod tests {
             genre_id: None,
             created_by: DependencyCreator::User,
             created_at: chrono::Utc::now(),
+            kind: DependencyKind::Strict,
+            label: None,
+        }
+    }
+
+    fn create_test_dependency_of_kind(
+        task_id: Uuid,
+        depends_on: Uuid,
+        kind: DependencyKind,
+    ) -> TaskDependency {
+        TaskDependency {
+            kind,
+            ..create_test_dependency(task_id, depends_on)
         }
     }
 
@@ -320,6 +1222,27 @@ mod tests {
         assert_eq!(plan.completed_tasks, 1);
     }
 
+    #[test]
+    fn test_plan_carries_task_type() {
+        let feature = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let bug = Task {
+            task_type: TaskType::Bug,
+            ..create_test_task(Uuid::new_v4(), TaskStatus::Todo)
+        };
+
+        let plan = build_execution_plan(&[feature.clone(), bug.clone()], &[]);
+
+        let task_type = |id: Uuid| {
+            plan.levels
+                .iter()
+                .flat_map(|level| level.tasks.iter())
+                .find(|task| task.task_id == id)
+                .map(|task| task.task_type.clone())
+        };
+        assert_eq!(task_type(feature.id), Some(TaskType::Feature));
+        assert_eq!(task_type(bug.id), Some(TaskType::Bug));
+    }
+
     #[test]
     fn test_parallel_tasks_same_level() {
         let task1 = create_test_task(Uuid::new_v4(), TaskStatus::Done);
@@ -337,4 +1260,1087 @@ mod tests {
         // task2 and task3 should be in the same level (level 1) and both ready
         assert_eq!(plan.ready_tasks, 2);
     }
+
+    #[test]
+    fn test_level_assignment_matches_plan_levels() {
+        let ids: Vec<Uuid> = (0..6).map(|_| Uuid::new_v4()).collect();
+        let tasks: Vec<Task> = ids
+            .iter()
+            .map(|id| create_test_task(*id, TaskStatus::Todo))
+            .collect();
+
+        // Diamond 0 <- 1, 0 <- 2, {1, 2} <- 3, plus the chain 0 <- 4 <- 5 <- 3 making 3 deeper
+        let deps = vec![
+            create_test_dependency(ids[1], ids[0]),
+            create_test_dependency(ids[2], ids[0]),
+            create_test_dependency(ids[3], ids[1]),
+            create_test_dependency(ids[3], ids[2]),
+            create_test_dependency(ids[4], ids[0]),
+            create_test_dependency(ids[5], ids[4]),
+            create_test_dependency(ids[3], ids[5]),
+        ];
+
+        let sorted = |levels: Vec<Vec<Uuid>>| -> Vec<Vec<Uuid>> {
+            levels
+                .into_iter()
+                .map(|mut level| {
+                    level.sort();
+                    level
+                })
+                .collect()
+        };
+        let plan_levels = sorted(
+            build_execution_plan(&tasks, &deps)
+                .levels
+                .into_iter()
+                .map(|level| level.tasks.into_iter().map(|t| t.task_id).collect())
+                .collect(),
+        );
+        // The DAG layout levels the tasks taking part in an edge from the edges alone
+        let nodes: HashSet<Uuid> = deps
+            .iter()
+            .flat_map(|dep| [dep.task_id, dep.depends_on_task_id])
+            .collect();
+        let layout_levels = sorted(level_assignment(
+            nodes,
+            deps.iter().map(|dep| (dep.task_id, dep.depends_on_task_id)),
+        ));
+
+        assert_eq!(layout_levels, plan_levels);
+        assert_eq!(plan_levels.len(), 4);
+        assert_eq!(plan_levels[3], vec![ids[3]]);
+    }
+
+    #[test]
+    fn test_level_assignment_leaves_out_cycles() {
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+
+        let levels = level_assignment([a, b, c], [(b, c), (c, b)]);
+
+        assert_eq!(levels, vec![vec![a]]);
+    }
+
+    #[test]
+    fn test_progress_half_done() {
+        let task1 = create_test_task(Uuid::new_v4(), TaskStatus::Done);
+        let task2 = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let task3 = create_test_task(Uuid::new_v4(), TaskStatus::Done);
+        let task4 = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let cancelled = create_test_task(Uuid::new_v4(), TaskStatus::Cancelled);
+
+        // task2 waits on task1; task3, task4 and the cancelled task have no dependencies
+        let deps = vec![create_test_dependency(task2.id, task1.id)];
+
+        let plan = build_execution_plan(
+            &[task1.clone(), task2.clone(), task3.clone(), task4.clone(), cancelled],
+            &deps,
+        );
+
+        // Cancelled tasks are left out of the denominator
+        assert_eq!(plan.progress_percent, 50.0);
+        assert_eq!(plan.level_progress.len(), 2);
+        // Level 0 holds task1, task3 (done) and task4 (todo)
+        assert!((plan.level_progress[0] - 200.0 / 3.0).abs() < 1e-4);
+        assert_eq!(plan.level_progress[1], 0.0);
+    }
+
+    #[test]
+    fn test_progress_all_cancelled() {
+        let task1 = create_test_task(Uuid::new_v4(), TaskStatus::Cancelled);
+        let task2 = create_test_task(Uuid::new_v4(), TaskStatus::Cancelled);
+
+        let plan = build_execution_plan(&[task1, task2], &[]);
+
+        assert_eq!(plan.progress_percent, 0.0);
+        assert_eq!(plan.level_progress, vec![0.0]);
+    }
+
+    #[test]
+    fn test_progress_empty_plan() {
+        let plan = build_execution_plan(&[], &[]);
+
+        assert_eq!(plan.progress_percent, 0.0);
+        assert!(plan.level_progress.is_empty());
+    }
+
+    #[test]
+    fn test_strict_dependency_blocks_on_cancelled() {
+        let cancelled = create_test_task(Uuid::new_v4(), TaskStatus::Cancelled);
+        let task = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let deps = vec![create_test_dependency_of_kind(
+            task.id,
+            cancelled.id,
+            DependencyKind::Strict,
+        )];
+
+        let plan = build_execution_plan(&[cancelled.clone(), task.clone()], &deps);
+
+        assert_eq!(plan.ready_tasks, 0);
+        assert_eq!(plan.blocked_tasks, 1);
+    }
+
+    #[test]
+    fn test_skip_if_cancelled_dependency() {
+        let cancelled = create_test_task(Uuid::new_v4(), TaskStatus::Cancelled);
+        let todo = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let after_cancelled = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let after_todo = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let deps = vec![
+            create_test_dependency_of_kind(
+                after_cancelled.id,
+                cancelled.id,
+                DependencyKind::SkipIfCancelled,
+            ),
+            create_test_dependency_of_kind(after_todo.id, todo.id, DependencyKind::SkipIfCancelled),
+        ];
+
+        let plan = build_execution_plan(
+            &[cancelled, todo.clone(), after_cancelled.clone(), after_todo.clone()],
+            &deps,
+        );
+
+        // A cancelled prerequisite is satisfied, an unfinished one still blocks
+        let blocked: Vec<Uuid> = get_tasks_blocked_by(&plan, todo.id)
+            .iter()
+            .map(|t| t.task_id)
+            .collect();
+        assert_eq!(blocked, vec![after_todo.id]);
+        assert!(get_ready_tasks(&plan)
+            .iter()
+            .any(|t| t.task_id == after_cancelled.id));
+    }
+
+    #[test]
+    fn test_optional_dependency_never_blocks() {
+        let prerequisite = create_test_task(Uuid::new_v4(), TaskStatus::InProgress);
+        let task = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let deps = vec![create_test_dependency_of_kind(
+            task.id,
+            prerequisite.id,
+            DependencyKind::Optional,
+        )];
+
+        let plan = build_execution_plan(&[prerequisite.clone(), task.clone()], &deps);
+
+        assert_eq!(plan.ready_tasks, 1);
+        assert_eq!(plan.blocked_tasks, 0);
+        // The edge is still part of the graph
+        assert_eq!(plan.levels.len(), 2);
+        assert_eq!(plan.levels[1].tasks[0].dependencies, vec![prerequisite.id]);
+    }
+
+    #[test]
+    fn test_promoting_optional_dependency_blocks_dependent() {
+        let prerequisite = create_test_task(Uuid::new_v4(), TaskStatus::InProgress);
+        let task = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let tasks = [prerequisite.clone(), task.clone()];
+        let optional =
+            create_test_dependency_of_kind(task.id, prerequisite.id, DependencyKind::Optional);
+        let strict = TaskDependency {
+            kind: DependencyKind::Strict,
+            ..optional.clone()
+        };
+
+        let readiness = |deps: &[TaskDependency]| {
+            let plan = build_execution_plan(&tasks, deps);
+            plan.levels
+                .iter()
+                .flat_map(|level| &level.tasks)
+                .find(|t| t.task_id == task.id)
+                .map(|t| t.readiness.clone())
+                .unwrap()
+        };
+
+        assert!(matches!(readiness(&[optional]), TaskReadiness::Ready));
+        assert!(matches!(
+            readiness(&[strict]),
+            TaskReadiness::Blocked { blocking_task_ids } if blocking_task_ids == vec![prerequisite.id]
+        ));
+    }
+
+    #[test]
+    fn test_diff_readiness_reports_unblocked_dependent() {
+        let blocker_id = Uuid::new_v4();
+        let dependent = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let deps = vec![create_test_dependency(dependent.id, blocker_id)];
+
+        let before = build_execution_plan(
+            &[
+                create_test_task(blocker_id, TaskStatus::InProgress),
+                dependent.clone(),
+            ],
+            &deps,
+        );
+        let after = build_execution_plan(
+            &[
+                create_test_task(blocker_id, TaskStatus::Done),
+                dependent.clone(),
+            ],
+            &deps,
+        );
+
+        let events = diff_readiness(&before, &after);
+
+        assert_eq!(events.len(), 2);
+        assert!(events.iter().any(|event| matches!(
+            event,
+            OrchestratorEvent::ReadinessChanged {
+                task_id,
+                from: TaskReadiness::Blocked { .. },
+                to: TaskReadiness::Ready,
+            } if *task_id == dependent.id
+        )));
+        assert!(events.iter().any(|event| matches!(
+            event,
+            OrchestratorEvent::ReadinessChanged {
+                task_id,
+                from: TaskReadiness::InProgress,
+                to: TaskReadiness::Completed,
+            } if *task_id == blocker_id
+        )));
+    }
+
+    #[test]
+    fn test_diff_readiness_ignores_unchanged_state() {
+        let first = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let second = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let dependent = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let deps = vec![
+            create_test_dependency(dependent.id, first.id),
+            create_test_dependency(dependent.id, second.id),
+        ];
+
+        let before =
+            build_execution_plan(&[first.clone(), second.clone(), dependent.clone()], &deps);
+        let after = build_execution_plan(
+            &[
+                create_test_task(first.id, TaskStatus::Todo),
+                create_test_task(second.id, TaskStatus::Todo),
+                dependent.clone(),
+                create_test_task(Uuid::new_v4(), TaskStatus::Todo),
+            ],
+            &deps,
+        );
+
+        // Same states everywhere, and the newly added task has nothing to diff against
+        assert!(diff_readiness(&before, &after).is_empty());
+    }
+
+    #[test]
+    fn test_get_blocked_downstream_chain() {
+        // root -> a -> b -> c, plus d which also waits on an unrelated task
+        let root = create_test_task(Uuid::new_v4(), TaskStatus::InProgress);
+        let a = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let b = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let c = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let d = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let other = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let done = create_test_task(Uuid::new_v4(), TaskStatus::Done);
+        let deps = vec![
+            create_test_dependency(a.id, root.id),
+            create_test_dependency(b.id, a.id),
+            create_test_dependency(c.id, b.id),
+            create_test_dependency(d.id, root.id),
+            create_test_dependency(d.id, other.id),
+            create_test_dependency(done.id, root.id),
+        ];
+
+        let plan = build_execution_plan(
+            &[
+                root.clone(),
+                a.clone(),
+                b.clone(),
+                c.clone(),
+                d.clone(),
+                other.clone(),
+                done.clone(),
+            ],
+            &deps,
+        );
+        let blocked = get_blocked_downstream(&plan, root.id);
+
+        // The done task is downstream but not blocked; the unrelated blocker is not downstream
+        let ids: Vec<Uuid> = blocked.iter().map(|t| t.task_id).collect();
+        assert_eq!(ids.len(), 4);
+        assert!(!ids.contains(&done.id));
+        assert!(!ids.contains(&other.id));
+
+        let find = |id: Uuid| blocked.iter().find(|t| t.task_id == id).unwrap();
+        assert_eq!(find(a.id).depth, 1);
+        assert_eq!(find(b.id).depth, 2);
+        assert_eq!(find(c.id).depth, 3);
+        assert_eq!(find(a.id).blocking_task_ids, vec![root.id]);
+        assert_eq!(find(d.id).blocking_task_ids.len(), 2);
+
+        // Nearest first
+        let depths: Vec<usize> = blocked.iter().map(|t| t.depth).collect();
+        let mut sorted = depths.clone();
+        sorted.sort();
+        assert_eq!(depths, sorted);
+    }
+
+    #[test]
+    fn test_forecast_unblocked_needs_every_blocker() {
+        let blockers: Vec<Task> = (0..3)
+            .map(|_| create_test_task(Uuid::new_v4(), TaskStatus::InProgress))
+            .collect();
+        let dependent = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let deps: Vec<TaskDependency> = blockers
+            .iter()
+            .map(|blocker| create_test_dependency(dependent.id, blocker.id))
+            .collect();
+        let mut tasks = blockers.clone();
+        tasks.push(dependent.clone());
+
+        let plan = build_execution_plan(&tasks, &deps);
+        let ids: Vec<Uuid> = blockers.iter().map(|blocker| blocker.id).collect();
+
+        assert!(forecast_unblocked(&plan, &ids[..2]).is_empty());
+        assert_eq!(forecast_unblocked(&plan, &ids), vec![dependent.id]);
+    }
+
+    #[test]
+    fn test_forecast_unblocked_skips_completing_tasks() {
+        // a -> b -> c: completing a and b unblocks b's dependent c, but b is not reported
+        let a = create_test_task(Uuid::new_v4(), TaskStatus::InProgress);
+        let b = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let c = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let deps = vec![
+            create_test_dependency(b.id, a.id),
+            create_test_dependency(c.id, b.id),
+        ];
+
+        let plan = build_execution_plan(&[a.clone(), b.clone(), c.clone()], &deps);
+
+        assert_eq!(forecast_unblocked(&plan, &[a.id]), vec![b.id]);
+        assert_eq!(forecast_unblocked(&plan, &[a.id, b.id]), vec![c.id]);
+    }
+
+    #[test]
+    fn test_next_action_starts_highest_priority_ready_task() {
+        let mut first = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        first.position = Some(0);
+        let mut second = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        second.position = Some(1);
+
+        let plan = build_execution_plan(&[second.clone(), first.clone()], &[]);
+
+        assert_eq!(
+            next_action(&plan, OrchestratorState::Idle),
+            NextAction::Start { task_id: first.id }
+        );
+    }
+
+    #[test]
+    fn test_next_action_all_blocked_points_at_unblocking_task() {
+        // a unblocks b alone, c unblocks d and e
+        let a = create_test_task(Uuid::new_v4(), TaskStatus::InProgress);
+        let b = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let c = create_test_task(Uuid::new_v4(), TaskStatus::InReview);
+        let d = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let e = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let deps = vec![
+            create_test_dependency(b.id, a.id),
+            create_test_dependency(d.id, c.id),
+            create_test_dependency(e.id, c.id),
+        ];
+
+        let plan = build_execution_plan(&[a, b, c.clone(), d.clone(), e.clone()], &deps);
+        let NextAction::Wait {
+            reason,
+            unblocking_task,
+        } = next_action(&plan, OrchestratorState::Running)
+        else {
+            panic!("no task should be ready");
+        };
+
+        assert_eq!(reason, WaitReason::AllBlocked);
+        let unblocking_task = unblocking_task.unwrap();
+        assert_eq!(unblocking_task.task_id, c.id);
+        let mut unblocks = unblocking_task.unblocks;
+        unblocks.sort();
+        let mut expected = vec![d.id, e.id];
+        expected.sort();
+        assert_eq!(unblocks, expected);
+
+        let NextAction::Wait { reason, .. } = next_action(&plan, OrchestratorState::Idle) else {
+            panic!("no task should be ready");
+        };
+        assert_eq!(reason, WaitReason::OrchestratorIdle);
+    }
+
+    #[test]
+    fn test_next_action_all_done() {
+        let a = create_test_task(Uuid::new_v4(), TaskStatus::Done);
+        let b = create_test_task(Uuid::new_v4(), TaskStatus::Cancelled);
+        let deps = vec![create_test_dependency(b.id, a.id)];
+
+        let plan = build_execution_plan(&[a, b], &deps);
+
+        assert_eq!(
+            next_action(&plan, OrchestratorState::Running),
+            NextAction::Wait {
+                reason: WaitReason::AllDone,
+                unblocking_task: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_at_risk_transitively_stuck() {
+        // on_hold <- waiting <- stuck, plus running <- blocked_by_running
+        let on_hold = create_test_task(Uuid::new_v4(), TaskStatus::Blocked);
+        let waiting = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let stuck = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let running = create_test_task(Uuid::new_v4(), TaskStatus::InProgress);
+        let blocked_by_running = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let deps = vec![
+            create_test_dependency(waiting.id, on_hold.id),
+            create_test_dependency(stuck.id, waiting.id),
+            create_test_dependency(blocked_by_running.id, running.id),
+        ];
+
+        let plan = build_execution_plan(
+            &[
+                on_hold.clone(),
+                waiting.clone(),
+                stuck.clone(),
+                running,
+                blocked_by_running,
+            ],
+            &deps,
+        );
+        let at_risk = at_risk_tasks(&plan, &HashMap::new(), 60, Utc::now());
+
+        // Both tasks behind the on-hold task are stuck; waiting on a running task is not
+        assert_eq!(
+            at_risk,
+            vec![
+                AtRiskTask {
+                    task_id: waiting.id,
+                    reason: RiskReason::TransitivelyStuck {
+                        stuck_behind: vec![on_hold.id],
+                    },
+                },
+                AtRiskTask {
+                    task_id: stuck.id,
+                    reason: RiskReason::TransitivelyStuck {
+                        stuck_behind: vec![waiting.id],
+                    },
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_at_risk_stale_in_progress() {
+        let stale = create_test_task(Uuid::new_v4(), TaskStatus::InProgress);
+        let fresh = create_test_task(Uuid::new_v4(), TaskStatus::InProgress);
+        let untracked = create_test_task(Uuid::new_v4(), TaskStatus::InProgress);
+        let in_review = create_test_task(Uuid::new_v4(), TaskStatus::InReview);
+        let now = Utc::now();
+        let started_at = HashMap::from([
+            (stale.id, now - chrono::Duration::seconds(120)),
+            (fresh.id, now - chrono::Duration::seconds(30)),
+            (in_review.id, now - chrono::Duration::seconds(120)),
+        ]);
+
+        let plan = build_execution_plan(&[stale.clone(), fresh, untracked, in_review], &[]);
+
+        assert_eq!(
+            at_risk_tasks(&plan, &started_at, 60, now),
+            vec![AtRiskTask {
+                task_id: stale.id,
+                reason: RiskReason::StaleInProgress { elapsed_secs: 120 },
+            }]
+        );
+    }
+
+    #[test]
+    fn test_get_tasks_by_readiness() {
+        // done -> blocked, plus a lone running task and a lone ready task
+        let done = create_test_task(Uuid::new_v4(), TaskStatus::Done);
+        let running = create_test_task(Uuid::new_v4(), TaskStatus::InProgress);
+        let ready = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let blocked = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let deps = vec![create_test_dependency(blocked.id, running.id)];
+
+        let plan = build_execution_plan(
+            &[done.clone(), running.clone(), ready.clone(), blocked.clone()],
+            &deps,
+        );
+        let ids = |filter| -> Vec<Uuid> {
+            get_tasks_by_readiness(&plan, filter)
+                .iter()
+                .map(|task| task.task_id)
+                .collect()
+        };
+
+        assert_eq!(ids(ReadinessFilter::Ready), vec![ready.id]);
+        assert_eq!(ids(ReadinessFilter::Blocked), vec![blocked.id]);
+        assert_eq!(ids(ReadinessFilter::InProgress), vec![running.id]);
+        assert_eq!(ids(ReadinessFilter::Completed), vec![done.id]);
+    }
+
+    #[test]
+    fn test_build_execution_plan_filtered_by_genre() {
+        let backend = Uuid::new_v4();
+        let frontend = Uuid::new_v4();
+        let api = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let worker = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let ui = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let tagged = |task_id, depends_on, genre_id| TaskDependency {
+            genre_id,
+            ..create_test_dependency(task_id, depends_on)
+        };
+        // api -> worker (backend), worker -> ui (frontend)
+        let deps = vec![
+            tagged(worker.id, api.id, Some(backend)),
+            tagged(ui.id, worker.id, Some(frontend)),
+        ];
+        let tasks = vec![api.clone(), worker.clone(), ui.clone()];
+
+        let full = build_execution_plan(&tasks, &deps);
+        assert_eq!(full.levels.len(), 3);
+        assert_eq!(full.ready_tasks, 1);
+
+        let filtered = build_execution_plan_filtered(&tasks, &deps, backend);
+        assert_eq!(filtered.levels.len(), 2);
+        let level_0: Vec<Uuid> = filtered.levels[0].tasks.iter().map(|t| t.task_id).collect();
+        assert!(level_0.contains(&api.id));
+        assert!(level_0.contains(&ui.id));
+        assert_eq!(filtered.levels[1].tasks[0].task_id, worker.id);
+        // The frontend edge no longer blocks the UI task
+        assert_eq!(filtered.ready_tasks, 2);
+        assert_eq!(filtered.blocked_tasks, 1);
+
+        let unrelated = build_execution_plan_filtered(&tasks, &deps, Uuid::new_v4());
+        assert_eq!(unrelated.levels.len(), 1);
+        assert_eq!(unrelated.ready_tasks, 3);
+    }
+
+    #[test]
+    fn test_compute_metrics_matches_plan() {
+        // done -> a -> b -> c, done -> d, plus a standalone e
+        let done = create_test_task(Uuid::new_v4(), TaskStatus::Done);
+        let a = create_test_task(Uuid::new_v4(), TaskStatus::InProgress);
+        let b = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let c = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let d = create_test_task(Uuid::new_v4(), TaskStatus::InReview);
+        let e = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let deps = vec![
+            create_test_dependency(a.id, done.id),
+            create_test_dependency(b.id, a.id),
+            create_test_dependency(c.id, b.id),
+            create_test_dependency(d.id, done.id),
+        ];
+
+        let plan = build_execution_plan(
+            &[done.clone(), a.clone(), b.clone(), c.clone(), d, e],
+            &deps,
+        );
+        let metrics = compute_metrics(&plan, OrchestratorState::Running);
+
+        assert_eq!(
+            metrics,
+            OrchestratorMetrics {
+                state: OrchestratorState::Running,
+                total_tasks: 6,
+                ready_tasks: 1,
+                blocked_tasks: 2,
+                // In-review tasks still occupy a slot
+                in_progress_tasks: 2,
+                in_review_tasks: 1,
+                completed_tasks: 1,
+                level_count: 4,
+                max_level_width: 2,
+                critical_path_length: 3,
+            }
+        );
+        // The finished task is not part of the remaining chain
+        assert_eq!(get_critical_path(&plan), vec![a.id, b.id, c.id]);
+    }
+
+    #[test]
+    fn test_explain_readiness_lists_dependencies() {
+        let done = create_test_task(Uuid::new_v4(), TaskStatus::Done);
+        let cancelled = create_test_task(Uuid::new_v4(), TaskStatus::Cancelled);
+        let todo = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let task = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let deps = vec![
+            create_test_dependency(task.id, done.id),
+            create_test_dependency_of_kind(task.id, cancelled.id, DependencyKind::SkipIfCancelled),
+            create_test_dependency(task.id, todo.id),
+        ];
+        let tasks = vec![done.clone(), cancelled.clone(), todo.clone(), task.clone()];
+
+        let explanation =
+            explain_readiness(&tasks, &deps, task.id, ExplanationLanguage::En).unwrap();
+
+        assert_eq!(explanation.status, TaskStatus::Todo);
+        assert!(matches!(
+            explanation.readiness,
+            TaskReadiness::Blocked { ref blocking_task_ids } if blocking_task_ids == &vec![todo.id]
+        ));
+        let satisfied: Vec<(Uuid, bool)> = explanation
+            .dependencies
+            .iter()
+            .map(|dep| (dep.task_id, dep.satisfied))
+            .collect();
+        assert_eq!(
+            satisfied,
+            vec![(done.id, true), (cancelled.id, true), (todo.id, false)]
+        );
+        assert_eq!(
+            explanation.summary,
+            format!("Waiting on 1 unfinished dependencies: {}", todo.title)
+        );
+    }
+
+    #[test]
+    fn test_explain_readiness_ready_and_unknown_task() {
+        let done = create_test_task(Uuid::new_v4(), TaskStatus::Done);
+        let task = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let deps = vec![create_test_dependency(task.id, done.id)];
+        let tasks = vec![done.clone(), task.clone()];
+
+        let explanation =
+            explain_readiness(&tasks, &deps, task.id, ExplanationLanguage::Ja).unwrap();
+        assert!(matches!(explanation.readiness, TaskReadiness::Ready));
+        assert_eq!(explanation.dependencies.len(), 1);
+        assert!(explanation.dependencies[0].satisfied);
+        assert_eq!(
+            explanation.summary,
+            "すべての依存関係が満たされているため、開始できます"
+        );
+
+        assert!(
+            explain_readiness(&tasks, &deps, Uuid::new_v4(), ExplanationLanguage::Ja).is_none()
+        );
+    }
+
+    /// Assert that every edge between ordered tasks points backwards in `order`
+    fn assert_respects_dependencies(order: &[Uuid], deps: &[TaskDependency]) {
+        let index: HashMap<Uuid, usize> =
+            order.iter().enumerate().map(|(i, id)| (*id, i)).collect();
+        for dep in deps {
+            if let (Some(task), Some(prerequisite)) =
+                (index.get(&dep.task_id), index.get(&dep.depends_on_task_id))
+            {
+                assert!(
+                    prerequisite < task,
+                    "{:?} must come before {:?}",
+                    dep.depends_on_task_id,
+                    dep.task_id
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_topological_order_respects_dependencies() {
+        // a -> b -> d, a -> c -> d, e independent
+        let ids: Vec<Uuid> = (0..5).map(|_| Uuid::new_v4()).collect();
+        let tasks: Vec<Task> = ids
+            .iter()
+            .map(|id| create_test_task(*id, TaskStatus::Todo))
+            .collect();
+        let deps = vec![
+            create_test_dependency(ids[1], ids[0]),
+            create_test_dependency(ids[2], ids[0]),
+            create_test_dependency(ids[3], ids[1]),
+            create_test_dependency(ids[3], ids[2]),
+        ];
+
+        let result = topological_order(&tasks, &deps);
+
+        assert_eq!(result.order.len(), 5);
+        assert!(result.cyclic.is_empty());
+        assert_respects_dependencies(&result.order, &deps);
+        assert_eq!(result.order.last(), Some(&ids[3]));
+    }
+
+    #[test]
+    fn test_topological_order_sorts_levels_by_priority() {
+        let mut first = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let mut second = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        first.position = Some(1);
+        second.position = Some(2);
+
+        // Input order is reversed; the level is sorted by position
+        let result = topological_order(&[second.clone(), first.clone()], &[]);
+
+        assert_eq!(result.order, vec![first.id, second.id]);
+    }
+
+    #[test]
+    fn test_topological_order_separates_cycles() {
+        // a <-> b is a cycle, c depends on a, d is independent
+        let ids: Vec<Uuid> = (0..4).map(|_| Uuid::new_v4()).collect();
+        let tasks: Vec<Task> = ids
+            .iter()
+            .map(|id| create_test_task(*id, TaskStatus::Todo))
+            .collect();
+        let deps = vec![
+            create_test_dependency(ids[0], ids[1]),
+            create_test_dependency(ids[1], ids[0]),
+            create_test_dependency(ids[2], ids[0]),
+        ];
+
+        let result = topological_order(&tasks, &deps);
+
+        assert_eq!(result.order, vec![ids[3]]);
+        let cyclic: HashSet<Uuid> = result.cyclic.iter().copied().collect();
+        assert_eq!(cyclic, HashSet::from([ids[0], ids[1], ids[2]]));
+    }
+
+    #[test]
+    fn test_filter_plan_by_assignee() {
+        // a -> b -> c, with a and c assigned to alice and b to bob
+        let a = create_test_task(Uuid::new_v4(), TaskStatus::Done);
+        let b = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let c = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let d = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let deps = vec![
+            create_test_dependency(b.id, a.id),
+            create_test_dependency(c.id, b.id),
+        ];
+        let mut plan = build_execution_plan(&[a.clone(), b.clone(), c.clone(), d.clone()], &deps);
+        let owners = HashMap::from([
+            (a.id, "alice".to_string()),
+            (b.id, "bob".to_string()),
+            (c.id, "alice".to_string()),
+        ]);
+        assign_owners(&mut plan, &owners);
+
+        let alice = filter_plan_by_assignee(&plan, "alice");
+        let ids: HashSet<Uuid> = alice
+            .levels
+            .iter()
+            .flat_map(|level| level.tasks.iter().map(|t| t.task_id))
+            .collect();
+        assert_eq!(ids, HashSet::from([a.id, c.id]));
+        assert_eq!(alice.total_tasks, 2);
+        assert_eq!(alice.completed_tasks, 1);
+        assert_eq!(alice.blocked_tasks, 1);
+        assert_eq!(alice.ready_tasks, 0);
+        // Levels keep their numbers from the full plan
+        let levels: Vec<usize> = alice.levels.iter().map(|level| level.level).collect();
+        assert_eq!(levels, vec![0, 2]);
+
+        let bob = filter_plan_by_assignee(&plan, "bob");
+        assert_eq!(bob.total_tasks, 1);
+        assert_eq!(bob.ready_tasks, 1);
+        assert_eq!(bob.levels[0].tasks[0].task_id, b.id);
+
+        // Unassigned tasks never match
+        let nobody = filter_plan_by_assignee(&plan, "carol");
+        assert!(nobody.levels.is_empty());
+        assert_eq!(nobody.total_tasks, 0);
+    }
+
+    #[test]
+    fn test_compact_levels_keeps_chain_apart() {
+        // a -> b -> c -> d: every level holds the previous level's dependent
+        let tasks: Vec<Task> = (0..4)
+            .map(|_| create_test_task(Uuid::new_v4(), TaskStatus::Todo))
+            .collect();
+        let deps: Vec<TaskDependency> = tasks
+            .windows(2)
+            .map(|pair| create_test_dependency(pair[1].id, pair[0].id))
+            .collect();
+        let plan = build_execution_plan(&tasks, &deps);
+
+        let compacted = compact_levels(&plan, &plan, 4);
+
+        assert_eq!(compacted.levels.len(), 4);
+        for (level, task) in compacted.levels.iter().zip(&tasks) {
+            assert_eq!(level.tasks.len(), 1);
+            assert_eq!(level.tasks[0].task_id, task.id);
+        }
+        assert_eq!(compacted.total_tasks, 4);
+    }
+
+    #[test]
+    fn test_compact_levels_merges_independent_singletons() {
+        // bob owns the chain p -> q; alice owns a, b (after p) and c (after q), none of which
+        // depend on each other, so her view is three singleton levels
+        let p = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let q = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let a = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let b = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let c = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let deps = vec![
+            create_test_dependency(q.id, p.id),
+            create_test_dependency(b.id, p.id),
+            create_test_dependency(c.id, q.id),
+        ];
+        let tasks = [p.clone(), q.clone(), a.clone(), b.clone(), c.clone()];
+        let mut plan = build_execution_plan(&tasks, &deps);
+        let owners = HashMap::from([
+            (p.id, "bob".to_string()),
+            (q.id, "bob".to_string()),
+            (a.id, "alice".to_string()),
+            (b.id, "alice".to_string()),
+            (c.id, "alice".to_string()),
+        ]);
+        assign_owners(&mut plan, &owners);
+        let alice = filter_plan_by_assignee(&plan, "alice");
+        assert_eq!(alice.levels.len(), 3);
+
+        let compacted = compact_levels(&alice, &plan, 4);
+        assert_eq!(compacted.levels.len(), 1);
+        assert_eq!(compacted.levels[0].level, 0);
+        let ids: HashSet<Uuid> = compacted.levels[0]
+            .tasks
+            .iter()
+            .map(|t| t.task_id)
+            .collect();
+        assert_eq!(ids, HashSet::from([a.id, b.id, c.id]));
+        assert_eq!(compacted.total_tasks, 3);
+        assert_eq!(compacted.ready_tasks, 1);
+
+        // The width limit still splits the merged levels
+        let capped = compact_levels(&alice, &plan, 2);
+        let levels: Vec<usize> = capped.levels.iter().map(|level| level.level).collect();
+        assert_eq!(levels, vec![0, 2]);
+        assert_eq!(capped.levels[0].tasks.len(), 2);
+    }
+
+    #[test]
+    fn test_compact_levels_keeps_prerequisite_behind_hidden_task_apart() {
+        // alice owns a and c, bob owns q, and c -> q -> a: her view skips level 1
+        let a = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let q = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let c = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let deps = vec![
+            create_test_dependency(q.id, a.id),
+            create_test_dependency(c.id, q.id),
+        ];
+        let mut plan = build_execution_plan(&[a.clone(), q.clone(), c.clone()], &deps);
+        let owners = HashMap::from([
+            (a.id, "alice".to_string()),
+            (q.id, "bob".to_string()),
+            (c.id, "alice".to_string()),
+        ]);
+        assign_owners(&mut plan, &owners);
+        let alice = filter_plan_by_assignee(&plan, "alice");
+        assert_eq!(alice.levels.len(), 2);
+
+        // c still waits on a through q, so the two levels stay apart
+        let compacted = compact_levels(&alice, &plan, 4);
+        let levels: Vec<usize> = compacted.levels.iter().map(|level| level.level).collect();
+        assert_eq!(levels, vec![0, 2]);
+    }
+
+    #[test]
+    fn test_compact_levels_leaves_full_plan_unchanged() {
+        // A diamond next to an independent pair; every level after the first waits on the one
+        // before it, so nothing can merge however wide the limit
+        let tasks: Vec<Task> = (0..6)
+            .map(|_| create_test_task(Uuid::new_v4(), TaskStatus::Todo))
+            .collect();
+        let deps = vec![
+            create_test_dependency(tasks[1].id, tasks[0].id),
+            create_test_dependency(tasks[2].id, tasks[0].id),
+            create_test_dependency(tasks[3].id, tasks[1].id),
+            create_test_dependency(tasks[3].id, tasks[2].id),
+            create_test_dependency(tasks[5].id, tasks[4].id),
+        ];
+        let plan = build_execution_plan(&tasks, &deps);
+
+        let compacted = compact_levels(&plan, &plan, 10);
+        let shape = |plan: &ExecutionPlan| -> Vec<(usize, Vec<Uuid>)> {
+            plan.levels
+                .iter()
+                .map(|level| {
+                    let ids = level.tasks.iter().map(|task| task.task_id).collect();
+                    (level.level, ids)
+                })
+                .collect()
+        };
+        assert_eq!(shape(&compacted), shape(&plan));
+        assert_eq!(compacted.levels.len(), 3);
+    }
+
+    #[test]
+    fn test_gantt_starts_dependents_at_prerequisite_end() {
+        // b depends on a, which has an estimate; b falls back to the default
+        let a = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let b = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let deps = vec![create_test_dependency(b.id, a.id)];
+        let plan = build_execution_plan(&[a.clone(), b.clone()], &deps);
+        let estimates = HashMap::from([(a.id, 30)]);
+
+        let chart = build_gantt(&plan, &estimates, 2);
+        let entry = |id: Uuid| chart.entries.iter().find(|e| e.task_id == id).unwrap();
+
+        assert_eq!(
+            (entry(a.id).start_minutes, entry(a.id).end_minutes),
+            (0, 30)
+        );
+        assert!(entry(a.id).estimated);
+        assert_eq!(entry(b.id).start_minutes, entry(a.id).end_minutes);
+        assert_eq!(entry(b.id).end_minutes, 30 + DEFAULT_TASK_MINUTES);
+        assert!(!entry(b.id).estimated);
+        assert_eq!(entry(b.id).dependencies, vec![a.id]);
+        assert_eq!(chart.total_minutes, 30 + DEFAULT_TASK_MINUTES);
+    }
+
+    #[test]
+    fn test_gantt_respects_max_parallel() {
+        let done = create_test_task(Uuid::new_v4(), TaskStatus::Done);
+        let tasks: Vec<Task> = (0..3)
+            .map(|_| create_test_task(Uuid::new_v4(), TaskStatus::Todo))
+            .chain(std::iter::once(done.clone()))
+            .collect();
+        let plan = build_execution_plan(&tasks, &[]);
+        let estimates: HashMap<Uuid, u32> = tasks.iter().map(|t| (t.id, 10)).collect();
+
+        let chart = build_gantt(&plan, &estimates, 2);
+
+        // Two tasks run at once, the third waits for a slot; the done task takes no time
+        let mut starts: Vec<u32> = chart
+            .entries
+            .iter()
+            .filter(|e| e.task_id != done.id)
+            .map(|e| e.start_minutes)
+            .collect();
+        starts.sort();
+        assert_eq!(starts, vec![0, 0, 10]);
+        let done_entry = chart.entries.iter().find(|e| e.task_id == done.id).unwrap();
+        assert_eq!((done_entry.start_minutes, done_entry.end_minutes), (0, 0));
+        assert_eq!(chart.total_minutes, 20);
+    }
+
+    #[test]
+    fn test_max_depth_of_chain() {
+        let tasks: Vec<Task> = (0..10)
+            .map(|_| create_test_task(Uuid::new_v4(), TaskStatus::Todo))
+            .collect();
+        let deps: Vec<TaskDependency> = tasks
+            .windows(2)
+            .map(|pair| create_test_dependency(pair[1].id, pair[0].id))
+            .collect();
+
+        let plan = build_execution_plan(&tasks, &deps);
+        assert_eq!(plan.levels.len(), 10);
+        assert_eq!(plan.max_depth, 9);
+
+        assert_eq!(build_execution_plan(&tasks, &[]).max_depth, 0);
+        assert_eq!(build_execution_plan(&[], &[]).max_depth, 0);
+    }
+
+    #[test]
+    fn test_schedule_with_cap_splits_wide_level() {
+        let tasks: Vec<Task> = (0..5)
+            .map(|_| create_test_task(Uuid::new_v4(), TaskStatus::Todo))
+            .collect();
+
+        let slots = schedule_with_cap(&tasks, &[], 2);
+        let sizes: Vec<usize> = slots.iter().map(Vec::len).collect();
+        assert_eq!(sizes, vec![2, 2, 1]);
+
+        let plan = build_execution_plan_capped(&tasks, &[], 2);
+        assert_eq!(plan.levels.len(), 3);
+        assert_eq!(plan.total_tasks, 5);
+        assert_eq!(plan.ready_tasks, 5);
+        assert_eq!(plan.max_depth, 0);
+    }
+
+    #[test]
+    fn test_schedule_with_cap_respects_dependencies() {
+        // head -> middle -> tail, next to four independent tasks
+        let head = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let middle = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let tail = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let mut tasks = vec![head.clone(), middle.clone(), tail.clone()];
+        tasks.extend((0..4).map(|_| create_test_task(Uuid::new_v4(), TaskStatus::Todo)));
+        let deps = vec![
+            create_test_dependency(middle.id, head.id),
+            create_test_dependency(tail.id, middle.id),
+        ];
+
+        let slots = schedule_with_cap(&tasks, &deps, 2);
+        assert!(slots.iter().all(|slot| slot.len() <= 2));
+        assert_eq!(slots.iter().map(Vec::len).sum::<usize>(), 7);
+
+        let slot_of = |id: Uuid| slots.iter().position(|slot| slot.contains(&id)).unwrap();
+        // The chain head goes first so the chain doesn't stretch the schedule
+        assert_eq!(slot_of(head.id), 0);
+        assert!(slot_of(head.id) < slot_of(middle.id));
+        assert!(slot_of(middle.id) < slot_of(tail.id));
+        assert_eq!(slots.len(), 4);
+    }
+
+    #[test]
+    fn test_validate_graph_reports_cycles() {
+        // a -> b -> c -> a, with d depending on a outside the cycle
+        let ids: Vec<Uuid> = (0..4).map(|_| Uuid::new_v4()).collect();
+        let tasks: Vec<Task> = ids
+            .iter()
+            .map(|id| create_test_task(*id, TaskStatus::Todo))
+            .collect();
+        let deps = vec![
+            create_test_dependency(ids[0], ids[1]),
+            create_test_dependency(ids[1], ids[2]),
+            create_test_dependency(ids[2], ids[0]),
+            create_test_dependency(ids[3], ids[0]),
+        ];
+
+        let report = validate_graph(&tasks, &deps);
+
+        assert_eq!(report.cycles, vec![vec![ids[0], ids[1], ids[2]]]);
+        assert!(report.self_dependencies.is_empty());
+        assert!(report.cross_project_dependencies.is_empty());
+        assert!(!report.is_valid());
+    }
+
+    #[test]
+    fn test_validate_graph_reports_bad_edges() {
+        let task = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let self_dep = create_test_dependency(task.id, task.id);
+        let foreign = create_test_dependency(task.id, Uuid::new_v4());
+
+        let report = validate_graph(&[task], &[self_dep.clone(), foreign.clone()]);
+
+        assert_eq!(report.self_dependencies, vec![self_dep.id]);
+        assert_eq!(report.cross_project_dependencies, vec![foreign.id]);
+        assert!(report.cycles.is_empty());
+    }
+
+    #[test]
+    fn test_validate_graph_reports_tasks_blocked_by_cancelled() {
+        let cancelled = create_test_task(Uuid::new_v4(), TaskStatus::Cancelled);
+        let todo = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let stuck = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let waiting = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let tolerant = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let deps = vec![
+            create_test_dependency(stuck.id, cancelled.id),
+            // Also waiting on a task that can still finish
+            create_test_dependency(waiting.id, cancelled.id),
+            create_test_dependency(waiting.id, todo.id),
+            create_test_dependency_of_kind(
+                tolerant.id,
+                cancelled.id,
+                DependencyKind::SkipIfCancelled,
+            ),
+        ];
+        let tasks = vec![cancelled.clone(), todo, stuck.clone(), waiting, tolerant];
+
+        let report = validate_graph(&tasks, &deps);
+
+        assert_eq!(
+            report.blocked_by_cancelled,
+            vec![BlockedByCancelled {
+                task_id: stuck.id,
+                cancelled_task_ids: vec![cancelled.id],
+            }]
+        );
+        assert!(report.cycles.is_empty());
+
+        let healthy = validate_graph(&tasks[..2], &[]);
+        assert!(healthy.is_valid());
+    }
 }
@@ -1,34 +1,112 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 use uuid::Uuid;
 
 use db::models::task::{Task, TaskStatus};
 use db::models::task_dependency::TaskDependency;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
 
-use crate::models::TransitionValidation;
+use crate::messages::OrchestratorMessage;
+use crate::models::{StartReadiness, TaskDepth, TransitionValidation};
 
-/// Validates a task status transition
+/// Every task status, used to enumerate the default transition matrix
+const ALL_STATUSES: [TaskStatus; 6] = [
+    TaskStatus::Todo,
+    TaskStatus::InProgress,
+    TaskStatus::InReview,
+    TaskStatus::Blocked,
+    TaskStatus::Done,
+    TaskStatus::Cancelled,
+];
+
+/// A single allowed status change
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS, JsonSchema)]
+pub struct StatusTransition {
+    pub from: TaskStatus,
+    pub to: TaskStatus,
+}
+
+/// Set of status transitions a project allows
+#[derive(Debug, Clone, Serialize, Deserialize, TS, JsonSchema)]
+pub struct TransitionRuleSet {
+    pub allowed: Vec<StatusTransition>,
+}
+
+impl TransitionRuleSet {
+    /// Check whether the rule set permits moving from one status to another
+    pub fn allows(&self, from: &TaskStatus, to: &TaskStatus) -> bool {
+        self.allowed.iter().any(|t| &t.from == from && &t.to == to)
+    }
+
+    /// Check that the rule set is usable before a project adopts it
+    ///
+    /// Rejects sets that list a transition twice and sets in which a new task can never be
+    /// completed, i.e. with no chain of allowed transitions from todo to done.
+    pub fn validate(&self) -> Result<(), OrchestratorMessage> {
+        for (index, transition) in self.allowed.iter().enumerate() {
+            if self.allowed[..index].contains(transition) {
+                return Err(OrchestratorMessage::DuplicateTransitionRule {
+                    from: transition.from.clone(),
+                    to: transition.to.clone(),
+                });
+            }
+        }
+
+        let mut reached = vec![TaskStatus::Todo];
+        let mut queue = VecDeque::from([TaskStatus::Todo]);
+        while let Some(status) = queue.pop_front() {
+            if status == TaskStatus::Done {
+                return Ok(());
+            }
+            for transition in self.allowed.iter().filter(|t| t.from == status) {
+                if !reached.contains(&transition.to) {
+                    reached.push(transition.to.clone());
+                    queue.push_back(transition.to.clone());
+                }
+            }
+        }
+        Err(OrchestratorMessage::NoPathToDone)
+    }
+}
+
+impl Default for TransitionRuleSet {
+    /// The built-in transition matrix
+    fn default() -> Self {
+        let allowed = ALL_STATUSES
+            .iter()
+            .flat_map(|from| ALL_STATUSES.iter().map(move |to| (from, to)))
+            .filter(|(from, to)| is_valid_transition(from, to))
+            .map(|(from, to)| StatusTransition {
+                from: from.clone(),
+                to: to.clone(),
+            })
+            .collect();
+        Self { allowed }
+    }
+}
+
+/// Validates a task status transition against the project's transition rules
 pub fn validate_transition(
     task: &Task,
     new_status: &TaskStatus,
     all_tasks: &[Task],
     dependencies: &[TaskDependency],
+    rules: &TransitionRuleSet,
 ) -> TransitionValidation {
     let current = &task.status;
 
     // Same status is always valid (no-op)
     if current == new_status {
-        return TransitionValidation::Valid;
+        return TransitionValidation::Valid { forced: false };
     }
 
-    // Check if transition is allowed based on state machine rules
-    if !is_valid_transition(current, new_status) {
-        return TransitionValidation::Invalid {
-            reason: format!(
-                "Cannot transition from {} to {}",
-                status_to_string(current),
-                status_to_string(new_status)
-            ),
-        };
+    // Check if transition is allowed based on the project's rule set
+    if !rules.allows(current, new_status) {
+        return TransitionValidation::invalid(OrchestratorMessage::InvalidTransition {
+            from: current.clone(),
+            to: new_status.clone(),
+        });
     }
 
     // Check dependency constraints for certain transitions
@@ -37,13 +115,12 @@ pub fn validate_transition(
             // Can only start if all dependencies are done
             let blocking = get_blocking_tasks(task.id, all_tasks, dependencies);
             if !blocking.is_empty() {
-                return TransitionValidation::RequiresConfirmation {
-                    reason: format!(
-                        "Task has {} incomplete dependencies. Starting this task may cause issues.",
-                        blocking.len()
-                    ),
-                    blocking_tasks: blocking,
-                };
+                return TransitionValidation::requires_confirmation(
+                    OrchestratorMessage::IncompleteDependencies {
+                        count: blocking.len(),
+                    },
+                    blocking,
+                );
             }
         }
         TaskStatus::Done => {
@@ -52,10 +129,30 @@ pub fn validate_transition(
         _ => {}
     }
 
-    TransitionValidation::Valid
+    TransitionValidation::Valid { forced: false }
 }
 
-/// Check if a status transition is allowed by the state machine
+/// Validates a task status transition, optionally letting a human force through
+/// transitions the rule set would reject
+///
+/// Dependency confirmations are still reported; only `Invalid` results are overridden.
+pub fn validate_transition_with_override(
+    task: &Task,
+    new_status: &TaskStatus,
+    all_tasks: &[Task],
+    dependencies: &[TaskDependency],
+    rules: &TransitionRuleSet,
+    allow_override: bool,
+) -> TransitionValidation {
+    match validate_transition(task, new_status, all_tasks, dependencies, rules) {
+        TransitionValidation::Invalid { .. } if allow_override => {
+            TransitionValidation::Valid { forced: true }
+        }
+        other => other,
+    }
+}
+
+/// Check if a status transition is allowed by the default state machine
 fn is_valid_transition(from: &TaskStatus, to: &TaskStatus) -> bool {
     use TaskStatus::*;
 
@@ -69,6 +166,11 @@ fn is_valid_transition(from: &TaskStatus, to: &TaskStatus) -> bool {
             | (InProgress, InReview)
             | (InProgress, Done)
             | (InProgress, Cancelled)
+            | (InProgress, Blocked)
+            // From Blocked
+            | (Blocked, InProgress)
+            | (Blocked, Todo)
+            | (Blocked, Cancelled)
             // From InReview
             | (InReview, InProgress)
             | (InReview, Done)
@@ -81,7 +183,7 @@ fn is_valid_transition(from: &TaskStatus, to: &TaskStatus) -> bool {
     )
 }
 
-/// Get task IDs that are blocking the given task (not yet completed dependencies)
+/// Get task IDs that are blocking the given task (dependencies not yet satisfied for their kind)
 fn get_blocking_tasks(
     task_id: Uuid,
     all_tasks: &[Task],
@@ -94,7 +196,7 @@ fn get_blocking_tasks(
         .filter(|dep| dep.task_id == task_id)
         .filter_map(|dep| {
             task_map.get(&dep.depends_on_task_id).and_then(|t| {
-                if t.status != TaskStatus::Done {
+                if !dep.kind.is_satisfied_by(&t.status) {
                     Some(t.id)
                 } else {
                     None
@@ -105,28 +207,135 @@ fn get_blocking_tasks(
 }
 
 /// Convert TaskStatus to a human-readable string
-fn status_to_string(status: &TaskStatus) -> &'static str {
+pub(crate) fn status_to_string(status: &TaskStatus) -> &'static str {
     match status {
         TaskStatus::Todo => "todo",
         TaskStatus::InProgress => "in_progress",
         TaskStatus::InReview => "in_review",
+        TaskStatus::Blocked => "blocked",
         TaskStatus::Done => "done",
         TaskStatus::Cancelled => "cancelled",
     }
 }
 
+/// Determine whether a task can be started, reporting the reason when it can't
+pub fn start_readiness(
+    task: &Task,
+    all_tasks: &[Task],
+    dependencies: &[TaskDependency],
+) -> StartReadiness {
+    if task.status != TaskStatus::Todo {
+        return StartReadiness::WrongStatus {
+            current: task.status.clone(),
+        };
+    }
+
+    let blocking = get_blocking_tasks(task.id, all_tasks, dependencies);
+    if blocking.is_empty() {
+        StartReadiness::Ready
+    } else {
+        StartReadiness::Blocked { blocking }
+    }
+}
+
 /// Check if a task can be started (all dependencies satisfied)
 pub fn can_start_task(
     task: &Task,
     all_tasks: &[Task],
     dependencies: &[TaskDependency],
 ) -> bool {
-    if task.status != TaskStatus::Todo {
-        return false;
+    start_readiness(task, all_tasks, dependencies) == StartReadiness::Ready
+}
+
+/// Check whether `candidate` blocks the given task, directly or through a chain of blocking
+/// dependencies
+///
+/// Only unsatisfied edges are followed, so a finished task on the path breaks the chain.
+/// Visited tracking keeps cyclic graphs from looping.
+pub fn is_transitively_blocked_by(
+    task_id: Uuid,
+    candidate: Uuid,
+    all_tasks: &[Task],
+    dependencies: &[TaskDependency],
+) -> bool {
+    let task_map: HashMap<Uuid, &Task> = all_tasks.iter().map(|t| (t.id, t)).collect();
+    let mut blocking_edges: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    for dep in dependencies {
+        let blocking = task_map
+            .get(&dep.depends_on_task_id)
+            .is_some_and(|t| !dep.kind.is_satisfied_by(&t.status));
+        if blocking {
+            blocking_edges
+                .entry(dep.task_id)
+                .or_default()
+                .push(dep.depends_on_task_id);
+        }
     }
 
-    let blocking = get_blocking_tasks(task.id, all_tasks, dependencies);
-    blocking.is_empty()
+    walk_breadth_first(task_id, &blocking_edges)
+        .iter()
+        .any(|reached| reached.task_id == candidate)
+}
+
+/// Find the dependents that could never be completed if the given task were cancelled
+///
+/// A dependent needs all of its prerequisites, so it is impacted as soon as one edge to the
+/// cancelled task or another impacted task needs that prerequisite done. Dependents that reach
+/// the impacted set only through edges tolerating a cancelled prerequisite survive. Finished
+/// dependents are never impacted. Nearest first.
+pub fn cancellation_impact(
+    task_id: Uuid,
+    all_tasks: &[Task],
+    dependencies: &[TaskDependency],
+) -> Vec<Uuid> {
+    let task_map: HashMap<Uuid, &Task> = all_tasks.iter().map(|t| (t.id, t)).collect();
+    let mut prerequisites: HashMap<Uuid, Vec<&TaskDependency>> = HashMap::new();
+    for dep in dependencies {
+        prerequisites.entry(dep.task_id).or_default().push(dep);
+    }
+
+    let candidates: Vec<Uuid> = get_all_descendants(task_id, dependencies)
+        .into_iter()
+        .map(|reached| reached.task_id)
+        .filter(|id| {
+            task_map.get(id).is_some_and(|t| {
+                !matches!(t.status, TaskStatus::Done | TaskStatus::Cancelled)
+            })
+        })
+        .collect();
+
+    // Grow the impacted set until it settles, since a dependent may be visited before the
+    // prerequisite that cuts it off
+    let mut impacted: HashSet<Uuid> = HashSet::from([task_id]);
+    loop {
+        let mut grew = false;
+        for candidate in &candidates {
+            if impacted.contains(candidate) {
+                continue;
+            }
+            let edges = prerequisites
+                .get(candidate)
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            let cut_off = edges.iter().any(|dep| {
+                impacted.contains(&dep.depends_on_task_id)
+                    && !dep.kind.is_satisfied_by(&TaskStatus::Cancelled)
+            });
+            if cut_off {
+                impacted.insert(*candidate);
+                grew = true;
+            }
+        }
+        if !grew {
+            break;
+        }
+    }
+
+    // Report in the nearest-first order of the walk
+    candidates
+        .into_iter()
+        .filter(|id| impacted.contains(id))
+        .collect()
 }
 
 /// Get all tasks that depend on the given task (direct dependents)
@@ -147,10 +356,52 @@ pub fn get_dependency_tasks(task_id: Uuid, dependencies: &[TaskDependency]) -> V
         .collect()
 }
 
+/// Get every task the given task transitively depends on, nearest first
+pub fn get_all_ancestors(task_id: Uuid, dependencies: &[TaskDependency]) -> Vec<TaskDepth> {
+    let mut edges: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    for dep in dependencies {
+        edges.entry(dep.task_id).or_default().push(dep.depends_on_task_id);
+    }
+    walk_breadth_first(task_id, &edges)
+}
+
+/// Get every task that transitively depends on the given task, nearest first
+pub fn get_all_descendants(task_id: Uuid, dependencies: &[TaskDependency]) -> Vec<TaskDepth> {
+    let mut edges: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    for dep in dependencies {
+        edges.entry(dep.depends_on_task_id).or_default().push(dep.task_id);
+    }
+    walk_breadth_first(task_id, &edges)
+}
+
+/// Breadth-first walk from `root`, recording the shortest depth of each reached task
+/// The root itself is excluded; visited tracking keeps cyclic graphs from looping
+fn walk_breadth_first(root: Uuid, edges: &HashMap<Uuid, Vec<Uuid>>) -> Vec<TaskDepth> {
+    let mut visited: HashSet<Uuid> = HashSet::from([root]);
+    let mut queue: VecDeque<(Uuid, usize)> = VecDeque::from([(root, 0)]);
+    let mut reached = Vec::new();
+
+    while let Some((current, depth)) = queue.pop_front() {
+        for &next in edges.get(&current).into_iter().flatten() {
+            if visited.insert(next) {
+                reached.push(TaskDepth {
+                    task_id: next,
+                    depth: depth + 1,
+                });
+                queue.push_back((next, depth + 1));
+            }
+        }
+    }
+
+    reached
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use db::models::task_dependency::DependencyCreator;
+    use crate::models::ExplanationLanguage;
+    use db::models::task::TaskType;
+    use db::models::task_dependency::{DependencyCreator, DependencyKind};
 
     fn create_test_task(id: Uuid, status: TaskStatus) -> Task {
         Task {
@@ -159,6 +410,7 @@ mod tests {
             title: format!("Task {}", id),
             description: None,
             status,
+            task_type: TaskType::Feature,
             parent_workspace_id: None,
             shared_task_id: None,
             position: None,
@@ -177,6 +429,8 @@ mod tests {
             genre_id: None,
             created_by: DependencyCreator::User,
             created_at: chrono::Utc::now(),
+            kind: DependencyKind::Strict,
+            label: None,
         }
     }
 
@@ -197,6 +451,19 @@ mod tests {
         assert!(!is_valid_transition(&TaskStatus::Todo, &TaskStatus::InReview));
     }
 
+    #[test]
+    fn test_blocked_transitions() {
+        assert!(is_valid_transition(&TaskStatus::InProgress, &TaskStatus::Blocked));
+        assert!(is_valid_transition(&TaskStatus::Blocked, &TaskStatus::InProgress));
+        assert!(is_valid_transition(&TaskStatus::Blocked, &TaskStatus::Todo));
+        assert!(is_valid_transition(&TaskStatus::Blocked, &TaskStatus::Cancelled));
+
+        // A task has to be picked up before it can get stuck, and must resume before finishing
+        assert!(!is_valid_transition(&TaskStatus::Todo, &TaskStatus::Blocked));
+        assert!(!is_valid_transition(&TaskStatus::Blocked, &TaskStatus::Done));
+        assert!(!is_valid_transition(&TaskStatus::Blocked, &TaskStatus::InReview));
+    }
+
     #[test]
     fn test_can_start_task_no_dependencies() {
         let task = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
@@ -221,6 +488,48 @@ mod tests {
         assert!(can_start_task(&task, &[task.clone(), dep_task.clone()], &deps));
     }
 
+    #[test]
+    fn test_start_readiness_ready() {
+        let dep_task = create_test_task(Uuid::new_v4(), TaskStatus::Done);
+        let task = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let deps = vec![create_test_dependency(task.id, dep_task.id)];
+
+        assert_eq!(
+            start_readiness(&task, &[task.clone(), dep_task], &deps),
+            StartReadiness::Ready
+        );
+    }
+
+    #[test]
+    fn test_start_readiness_wrong_status() {
+        let task = create_test_task(Uuid::new_v4(), TaskStatus::InReview);
+
+        assert_eq!(
+            start_readiness(&task, std::slice::from_ref(&task), &[]),
+            StartReadiness::WrongStatus {
+                current: TaskStatus::InReview
+            }
+        );
+    }
+
+    #[test]
+    fn test_start_readiness_blocked() {
+        let done = create_test_task(Uuid::new_v4(), TaskStatus::Done);
+        let pending = create_test_task(Uuid::new_v4(), TaskStatus::InProgress);
+        let task = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let deps = vec![
+            create_test_dependency(task.id, done.id),
+            create_test_dependency(task.id, pending.id),
+        ];
+
+        assert_eq!(
+            start_readiness(&task, &[task.clone(), done, pending.clone()], &deps),
+            StartReadiness::Blocked {
+                blocking: vec![pending.id]
+            }
+        );
+    }
+
     #[test]
     fn test_validate_transition_with_blocking_dependency() {
         let dep_task = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
@@ -228,8 +537,363 @@ mod tests {
         let deps = vec![create_test_dependency(task.id, dep_task.id)];
         let all_tasks = vec![task.clone(), dep_task.clone()];
 
-        let result = validate_transition(&task, &TaskStatus::InProgress, &all_tasks, &deps);
+        let result = validate_transition(
+            &task,
+            &TaskStatus::InProgress,
+            &all_tasks,
+            &deps,
+            &TransitionRuleSet::default(),
+        );
 
         assert!(matches!(result, TransitionValidation::RequiresConfirmation { .. }));
     }
+
+    #[test]
+    fn test_override_forces_invalid_transition() {
+        let task = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let all_tasks = vec![task.clone()];
+        let rules = TransitionRuleSet::default();
+
+        let result = validate_transition_with_override(
+            &task,
+            &TaskStatus::Done,
+            &all_tasks,
+            &[],
+            &rules,
+            true,
+        );
+
+        assert!(matches!(result, TransitionValidation::Valid { forced: true }));
+    }
+
+    #[test]
+    fn test_invalid_transition_without_override() {
+        let task = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let all_tasks = vec![task.clone()];
+        let rules = TransitionRuleSet::default();
+
+        let result = validate_transition_with_override(
+            &task,
+            &TaskStatus::Done,
+            &all_tasks,
+            &[],
+            &rules,
+            false,
+        );
+
+        assert!(matches!(result, TransitionValidation::Invalid { .. }));
+    }
+
+    #[test]
+    fn test_invalid_transition_localized() {
+        let task = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let all_tasks = vec![task.clone()];
+        let rules = TransitionRuleSet::default();
+
+        let result = validate_transition(&task, &TaskStatus::Done, &all_tasks, &[], &rules);
+        let reason = |validation: TransitionValidation| match validation {
+            TransitionValidation::Invalid { code, reason } => {
+                assert_eq!(
+                    code,
+                    OrchestratorMessage::InvalidTransition {
+                        from: TaskStatus::Todo,
+                        to: TaskStatus::Done,
+                    }
+                );
+                reason
+            }
+            other => panic!("expected invalid, got {:?}", other),
+        };
+
+        assert_eq!(
+            reason(result.clone().localized(ExplanationLanguage::En)),
+            "Cannot transition from todo to done"
+        );
+        assert_eq!(
+            reason(result.localized(ExplanationLanguage::Ja)),
+            "todo から done へは変更できません"
+        );
+    }
+
+    #[test]
+    fn test_override_does_not_mark_valid_transition_as_forced() {
+        let task = create_test_task(Uuid::new_v4(), TaskStatus::InProgress);
+        let all_tasks = vec![task.clone()];
+        let rules = TransitionRuleSet::default();
+
+        let result = validate_transition_with_override(
+            &task,
+            &TaskStatus::Done,
+            &all_tasks,
+            &[],
+            &rules,
+            true,
+        );
+
+        assert!(matches!(result, TransitionValidation::Valid { forced: false }));
+    }
+
+    #[test]
+    fn test_subtree_linear_chain() {
+        // c -> b -> a (c depends on b, b depends on a)
+        let a = Uuid::new_v4();
+        let b = Uuid::new_v4();
+        let c = Uuid::new_v4();
+        let deps = vec![create_test_dependency(b, a), create_test_dependency(c, b)];
+
+        assert_eq!(
+            get_all_ancestors(c, &deps),
+            vec![
+                TaskDepth { task_id: b, depth: 1 },
+                TaskDepth { task_id: a, depth: 2 },
+            ]
+        );
+        assert_eq!(
+            get_all_descendants(a, &deps),
+            vec![
+                TaskDepth { task_id: b, depth: 1 },
+                TaskDepth { task_id: c, depth: 2 },
+            ]
+        );
+        assert!(get_all_ancestors(a, &deps).is_empty());
+        assert!(get_all_descendants(c, &deps).is_empty());
+    }
+
+    #[test]
+    fn test_transitively_blocked_by_deep_chain() {
+        // c -> b -> a, all incomplete
+        let a = Uuid::new_v4();
+        let b = Uuid::new_v4();
+        let c = Uuid::new_v4();
+        let tasks = vec![
+            create_test_task(a, TaskStatus::InProgress),
+            create_test_task(b, TaskStatus::Todo),
+            create_test_task(c, TaskStatus::Todo),
+        ];
+        let deps = vec![create_test_dependency(b, a), create_test_dependency(c, b)];
+
+        assert!(is_transitively_blocked_by(c, a, &tasks, &deps));
+        assert!(is_transitively_blocked_by(c, b, &tasks, &deps));
+        assert!(!is_transitively_blocked_by(a, c, &tasks, &deps));
+    }
+
+    #[test]
+    fn test_transitively_blocked_by_ignores_other_branches() {
+        // c depends on b and on done task x; x depends on incomplete a
+        let a = Uuid::new_v4();
+        let b = Uuid::new_v4();
+        let c = Uuid::new_v4();
+        let x = Uuid::new_v4();
+        let tasks = vec![
+            create_test_task(a, TaskStatus::Todo),
+            create_test_task(b, TaskStatus::Todo),
+            create_test_task(c, TaskStatus::Todo),
+            create_test_task(x, TaskStatus::Done),
+        ];
+        let deps = vec![
+            create_test_dependency(c, b),
+            create_test_dependency(c, x),
+            create_test_dependency(x, a),
+        ];
+
+        assert!(is_transitively_blocked_by(c, b, &tasks, &deps));
+        // a only reaches c through the finished task x
+        assert!(!is_transitively_blocked_by(c, a, &tasks, &deps));
+        assert!(!is_transitively_blocked_by(c, x, &tasks, &deps));
+    }
+
+    #[test]
+    fn test_transitively_blocked_by_terminates_on_cycle() {
+        let a = Uuid::new_v4();
+        let b = Uuid::new_v4();
+        let outside = Uuid::new_v4();
+        let tasks = vec![
+            create_test_task(a, TaskStatus::Todo),
+            create_test_task(b, TaskStatus::Todo),
+            create_test_task(outside, TaskStatus::Todo),
+        ];
+        let deps = vec![create_test_dependency(a, b), create_test_dependency(b, a)];
+
+        assert!(is_transitively_blocked_by(a, b, &tasks, &deps));
+        assert!(!is_transitively_blocked_by(a, outside, &tasks, &deps));
+    }
+
+    #[test]
+    fn test_subtree_terminates_on_cycle() {
+        let a = Uuid::new_v4();
+        let b = Uuid::new_v4();
+        let c = Uuid::new_v4();
+        let deps = vec![
+            create_test_dependency(a, b),
+            create_test_dependency(b, c),
+            create_test_dependency(c, a),
+        ];
+
+        let ancestors = get_all_ancestors(a, &deps);
+        assert_eq!(
+            ancestors,
+            vec![
+                TaskDepth { task_id: b, depth: 1 },
+                TaskDepth { task_id: c, depth: 2 },
+            ]
+        );
+
+        let descendants = get_all_descendants(a, &deps);
+        assert_eq!(descendants.len(), 2);
+    }
+
+    #[test]
+    fn test_cancellation_impact_diamond() {
+        // d depends on b and c, which both depend on a; b gets cancelled
+        let a = Uuid::new_v4();
+        let b = Uuid::new_v4();
+        let c = Uuid::new_v4();
+        let d = Uuid::new_v4();
+        let tasks = vec![
+            create_test_task(a, TaskStatus::Done),
+            create_test_task(b, TaskStatus::Todo),
+            create_test_task(c, TaskStatus::Todo),
+            create_test_task(d, TaskStatus::Todo),
+        ];
+        let deps = vec![
+            create_test_dependency(b, a),
+            create_test_dependency(c, a),
+            create_test_dependency(d, b),
+            create_test_dependency(d, c),
+        ];
+
+        // d needs both b and c, so cancelling b strands it
+        assert_eq!(cancellation_impact(b, &tasks, &deps), vec![d]);
+
+        // Unless its edge to b tolerates a cancelled prerequisite
+        let tolerant = vec![
+            create_test_dependency(b, a),
+            create_test_dependency(c, a),
+            TaskDependency {
+                kind: DependencyKind::SkipIfCancelled,
+                ..create_test_dependency(d, b)
+            },
+            create_test_dependency(d, c),
+        ];
+        assert!(cancellation_impact(b, &tasks, &tolerant).is_empty());
+    }
+
+    #[test]
+    fn test_cancellation_impact_chain() {
+        // c -> b -> a, plus x depending on a only through a cancel-tolerant edge
+        let a = Uuid::new_v4();
+        let b = Uuid::new_v4();
+        let c = Uuid::new_v4();
+        let x = Uuid::new_v4();
+        let tasks = vec![
+            create_test_task(a, TaskStatus::Todo),
+            create_test_task(b, TaskStatus::Todo),
+            create_test_task(c, TaskStatus::Todo),
+            create_test_task(x, TaskStatus::Todo),
+        ];
+        let deps = vec![
+            create_test_dependency(b, a),
+            create_test_dependency(c, b),
+            TaskDependency {
+                kind: DependencyKind::SkipIfCancelled,
+                ..create_test_dependency(x, a)
+            },
+        ];
+
+        assert_eq!(cancellation_impact(a, &tasks, &deps), vec![b, c]);
+    }
+
+    #[test]
+    fn test_default_rule_set_matches_state_machine() {
+        let rules = TransitionRuleSet::default();
+
+        for from in &ALL_STATUSES {
+            for to in &ALL_STATUSES {
+                assert_eq!(rules.allows(from, to), is_valid_transition(from, to));
+            }
+        }
+    }
+
+    #[test]
+    fn test_custom_rule_set_rejects_reopen() {
+        let task = create_test_task(Uuid::new_v4(), TaskStatus::Done);
+        let all_tasks = vec![task.clone()];
+
+        let default_result = validate_transition(
+            &task,
+            &TaskStatus::Todo,
+            &all_tasks,
+            &[],
+            &TransitionRuleSet::default(),
+        );
+        assert!(matches!(
+            default_result,
+            TransitionValidation::Valid { forced: false }
+        ));
+
+        // Forbid reopening finished work
+        let mut strict = TransitionRuleSet::default();
+        strict.allowed.retain(|t| t.from != TaskStatus::Done);
+
+        let strict_result =
+            validate_transition(&task, &TaskStatus::Todo, &all_tasks, &[], &strict);
+        assert!(matches!(strict_result, TransitionValidation::Invalid { .. }));
+    }
+
+    #[test]
+    fn test_custom_rule_set_keeps_dependency_check() {
+        let dep_task = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let task = create_test_task(Uuid::new_v4(), TaskStatus::Todo);
+        let deps = vec![create_test_dependency(task.id, dep_task.id)];
+        let all_tasks = vec![task.clone(), dep_task.clone()];
+
+        let rules = TransitionRuleSet {
+            allowed: vec![StatusTransition {
+                from: TaskStatus::Todo,
+                to: TaskStatus::InProgress,
+            }],
+        };
+
+        let result =
+            validate_transition(&task, &TaskStatus::InProgress, &all_tasks, &deps, &rules);
+        assert!(matches!(result, TransitionValidation::RequiresConfirmation { .. }));
+    }
+
+    #[test]
+    fn test_rule_set_validation() {
+        assert_eq!(TransitionRuleSet::default().validate(), Ok(()));
+
+        let step = |from: TaskStatus, to: TaskStatus| StatusTransition { from, to };
+        let chain = TransitionRuleSet {
+            allowed: vec![
+                step(TaskStatus::Todo, TaskStatus::InProgress),
+                step(TaskStatus::InProgress, TaskStatus::Done),
+            ],
+        };
+        assert_eq!(chain.validate(), Ok(()));
+
+        let empty = TransitionRuleSet { allowed: vec![] };
+        assert_eq!(empty.validate(), Err(OrchestratorMessage::NoPathToDone));
+
+        // Done is reachable only from a status todo can't get to
+        let stranded = TransitionRuleSet {
+            allowed: vec![
+                step(TaskStatus::Todo, TaskStatus::Cancelled),
+                step(TaskStatus::InReview, TaskStatus::Done),
+            ],
+        };
+        assert_eq!(stranded.validate(), Err(OrchestratorMessage::NoPathToDone));
+
+        let mut duplicated = chain.clone();
+        duplicated
+            .allowed
+            .push(step(TaskStatus::Todo, TaskStatus::InProgress));
+        assert_eq!(
+            duplicated.validate(),
+            Err(OrchestratorMessage::DuplicateTransitionRule {
+                from: TaskStatus::Todo,
+                to: TaskStatus::InProgress,
+            })
+        );
+    }
 }
@@ -31,19 +31,28 @@ fn generate_types_content() -> String {
         db::models::tag::CreateTag::decl(),
         db::models::tag::UpdateTag::decl(),
         db::models::task::TaskStatus::decl(),
+        db::models::task::TaskType::decl(),
         db::models::task::Task::decl(),
         db::models::task::TaskWithAttemptStatus::decl(),
         db::models::task::TaskRelationships::decl(),
         db::models::task::CreateTask::decl(),
         db::models::task::UpdateTask::decl(),
+        db::models::task::DagPositionUpdate::decl(),
         db::models::task_dependency::DependencyCreator::decl(),
+        db::models::task_dependency::DependencyKind::decl(),
         db::models::task_dependency::TaskDependency::decl(),
         db::models::task_dependency::CreateTaskDependency::decl(),
         db::models::task_dependency::UpdateTaskDependency::decl(),
         db::models::dependency_genre::DependencyGenre::decl(),
+        db::models::dependency_genre::DependencyGenreWithUsage::decl(),
         db::models::dependency_genre::CreateDependencyGenre::decl(),
         db::models::dependency_genre::UpdateDependencyGenre::decl(),
         db::models::dependency_genre::ReorderGenresRequest::decl(),
+        db::models::dependency_graph::DependencyGraphExport::decl(),
+        db::models::dependency_graph::ExportedGenre::decl(),
+        db::models::dependency_graph::ExportedDependency::decl(),
+        db::models::dependency_graph::DependencyImportReport::decl(),
+        db::models::dependency_graph::GraphCloneReport::decl(),
         db::models::scratch::DraftFollowUpData::decl(),
         db::models::scratch::DraftWorkspaceData::decl(),
         db::models::scratch::DraftWorkspaceRepo::decl(),
@@ -74,6 +83,7 @@ fn generate_types_content() -> String {
         db::models::github_issue_mapping::SyncDirection::decl(),
         db::models::task_property::TaskProperty::decl(),
         db::models::task_property::CreateTaskProperty::decl(),
+        db::models::task_property::TaskPropertyChange::decl(),
         db::models::task_property::PropertySource::decl(),
         utils::approvals::ApprovalStatus::decl(),
         utils::approvals::CreateApprovalRequest::decl(),
@@ -139,20 +149,73 @@ fn generate_types_content() -> String {
         server::routes::tasks::ShareTaskResponse::decl(),
         server::routes::tasks::CreateAndStartTaskRequest::decl(),
         server::routes::task_dependencies::CreateDependencyRequest::decl(),
+        server::routes::task_dependencies::CloneGraphRequest::decl(),
         server::routes::task_dependencies::UpdateDependencyRequest::decl(),
+        server::routes::task_dependencies::UpdateDependencyKindRequest::decl(),
         server::routes::task_dependencies::UpdatePositionRequest::decl(),
+        server::routes::task_dependencies::SubtreeDirection::decl(),
+        server::routes::task_dependencies::SubtreeQuery::decl(),
+        server::routes::task_dependencies::ListDependenciesQuery::decl(),
+        server::routes::task_dependencies::BlockedByQuery::decl(),
+        server::routes::task_dependencies::ReadinessExplanationQuery::decl(),
+        server::routes::task_dependencies::LanguageQuery::decl(),
+        server::routes::task_dependencies::DeleteDependenciesQuery::decl(),
+        server::routes::task_dependencies::TaskSubtreeResponse::decl(),
         server::routes::dependency_genres::CreateGenreRequest::decl(),
         server::routes::dependency_genres::UpdateGenreRequest::decl(),
         server::routes::dependency_genres::ReorderGenresApiRequest::decl(),
+        server::routes::dependency_genres::MergeGenresRequest::decl(),
+        server::routes::dependency_genres::ListGenresQuery::decl(),
+        server::routes::dependency_genres::OnReferenced::decl(),
+        server::routes::dependency_genres::DeleteGenreQuery::decl(),
         server::routes::orchestration::OrchestratorStateResponse::decl(),
+        server::routes::orchestration::OrchestratorStateQuery::decl(),
+        server::routes::orchestration::ReadyTasksQuery::decl(),
+        server::routes::orchestration::TasksByReadinessQuery::decl(),
+        server::routes::orchestration::AtRiskTasksQuery::decl(),
+        server::routes::orchestration::RecentEventsQuery::decl(),
+        server::routes::orchestration::PlanDiffQuery::decl(),
+        server::routes::orchestration::PlanDiffResponse::decl(),
+        server::routes::orchestration::SetAutoStartRequest::decl(),
+        server::routes::orchestration::SetTaskTimeoutRequest::decl(),
+        server::routes::orchestration::TransitionLanguageQuery::decl(),
         server::routes::orchestration::ValidateTransitionRequest::decl(),
         server::routes::orchestration::TaskFailedRequest::decl(),
+        server::routes::orchestration::BulkTransitionRequest::decl(),
+        server::routes::orchestration::ForecastUnblockedRequest::decl(),
         orchestrator::ExecutionPlan::decl(),
+        orchestrator::TopologicalOrder::decl(),
+        orchestrator::GanttEntry::decl(),
+        orchestrator::GanttChart::decl(),
+        orchestrator::PlanDiff::decl(),
+        orchestrator::ReadinessTransition::decl(),
+        orchestrator::LevelChange::decl(),
+        orchestrator::VersionedPlan::decl(),
         orchestrator::ExecutionLevel::decl(),
         orchestrator::ExecutableTask::decl(),
         orchestrator::TaskReadiness::decl(),
+        orchestrator::TaskDepth::decl(),
+        orchestrator::BlockedTask::decl(),
+        orchestrator::OrchestratorMessage::decl(),
         orchestrator::TransitionValidation::decl(),
+        orchestrator::BulkTransitionResult::decl(),
+        orchestrator::StartReadiness::decl(),
+        orchestrator::GraphValidationReport::decl(),
+        orchestrator::BlockedByCancelled::decl(),
+        orchestrator::ReadinessFilter::decl(),
+        orchestrator::PlanMode::decl(),
+        orchestrator::ExplanationLanguage::decl(),
+        orchestrator::DependencyExplanation::decl(),
+        orchestrator::ReadinessExplanation::decl(),
+        orchestrator::StatusTransition::decl(),
+        orchestrator::TransitionRuleSet::decl(),
         orchestrator::OrchestratorState::decl(),
+        orchestrator::OrchestratorMetrics::decl(),
+        orchestrator::WaitReason::decl(),
+        orchestrator::UnblockingTask::decl(),
+        orchestrator::NextAction::decl(),
+        orchestrator::RiskReason::decl(),
+        orchestrator::AtRiskTask::decl(),
         orchestrator::OrchestratorEvent::decl(),
         server::routes::task_attempts::pr::CreatePrApiRequest::decl(),
         server::routes::images::ImageResponse::decl(),
@@ -201,6 +264,7 @@ fn generate_types_content() -> String {
         services::services::queued_message::QueueStatus::decl(),
         services::services::git::ConflictOp::decl(),
         services::services::github::projects::GitHubProject::decl(),
+        services::services::github::projects::GitHubContentType::decl(),
         services::services::github::projects::GitHubIssue::decl(),
         services::services::github::projects::GitHubLabel::decl(),
         services::services::github::projects::GitHubMilestone::decl(),
@@ -209,9 +273,15 @@ fn generate_types_content() -> String {
         services::services::github::projects::ProjectField::decl(),
         services::services::github::projects::ProjectFieldOption::decl(),
         services::services::github::sync::StatusMapping::decl(),
+        services::services::github::sync::SyncErrorKind::decl(),
+        services::services::github::sync::SyncError::decl(),
         services::services::github::sync::SyncResult::decl(),
         server::routes::github::CreateGitHubLinkRequest::decl(),
         server::routes::github::GitHubLinkResponse::decl(),
+        server::routes::github::SyncGitHubLinkQuery::decl(),
+        server::routes::github::UnlinkGitHubMappingQuery::decl(),
+        server::routes::github::UpdateSyncDirectionRequest::decl(),
+        server::routes::github::GitHubStatusQuery::decl(),
         server::routes::github::GitHubStatusResponse::decl(),
         executors::actions::ExecutorAction::decl(),
         executors::mcp_config::McpConfig::decl(),
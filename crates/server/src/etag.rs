@@ -0,0 +1,90 @@
+//! Conditional JSON responses for endpoints that clients poll.
+//!
+//! The ETag is a hash of the serialized body, so it changes exactly when the payload does and
+//! a client sending it back in `If-None-Match` gets `304 Not Modified` instead of the body.
+
+use axum::{
+    http::{HeaderMap, HeaderValue, StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::error::ApiError;
+
+/// Strong ETag for a serialized body
+pub fn etag_for(body: &[u8]) -> String {
+    format!("\"{:x}\"", Sha256::digest(body))
+}
+
+/// Whether any tag in the request's `If-None-Match` headers matches `etag`
+///
+/// Weak tags compare equal to their strong form, as the header's weak comparison requires.
+fn if_none_match(headers: &HeaderMap, etag: &str) -> bool {
+    headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(str::trim)
+        .any(|tag| tag == "*" || tag.trim_start_matches("W/") == etag)
+}
+
+/// Respond with `body` as JSON and its ETag, or `304 Not Modified` when the client has it
+pub fn conditional_json<T: Serialize>(headers: &HeaderMap, body: &T) -> Result<Response, ApiError> {
+    let bytes = serde_json::to_vec(body).map_err(|e| ApiError::InternalServer(e.to_string()))?;
+    let etag = etag_for(&bytes);
+
+    let mut response = if if_none_match(headers, &etag) {
+        StatusCode::NOT_MODIFIED.into_response()
+    } else {
+        ([(header::CONTENT_TYPE, "application/json")], bytes).into_response()
+    };
+    let value =
+        HeaderValue::from_str(&etag).map_err(|e| ApiError::InternalServer(e.to_string()))?;
+    response.headers_mut().insert(header::ETAG, value);
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn etag_of(response: &Response) -> String {
+        response.headers()[header::ETAG]
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    fn with_if_none_match(etag: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::IF_NONE_MATCH, HeaderValue::from_str(etag).unwrap());
+        headers
+    }
+
+    #[test]
+    fn test_repeated_request_with_etag_is_not_modified() {
+        let body = vec!["a", "b"];
+        let first = conditional_json(&HeaderMap::new(), &body).unwrap();
+        assert_eq!(first.status(), StatusCode::OK);
+        let etag = etag_of(&first);
+
+        let repeated = conditional_json(&with_if_none_match(&etag), &body).unwrap();
+        assert_eq!(repeated.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(etag_of(&repeated), etag);
+
+        let weak = conditional_json(&with_if_none_match(&format!("W/{}", etag)), &body).unwrap();
+        assert_eq!(weak.status(), StatusCode::NOT_MODIFIED);
+    }
+
+    #[test]
+    fn test_changed_body_gets_new_etag() {
+        let first = conditional_json(&HeaderMap::new(), &vec!["a", "b"]).unwrap();
+        let etag = etag_of(&first);
+
+        let changed = conditional_json(&with_if_none_match(&etag), &vec!["a", "b", "c"]).unwrap();
+        assert_eq!(changed.status(), StatusCode::OK);
+        assert_ne!(etag_of(&changed), etag);
+    }
+}
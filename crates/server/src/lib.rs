@@ -1,6 +1,8 @@
 pub mod error;
+pub mod etag;
 pub mod mcp;
 pub mod middleware;
+pub mod openapi;
 pub mod routes;
 
 // #[cfg(feature = "cloud")]
@@ -97,7 +97,7 @@ pub struct ListTasksRequest {
     #[schemars(description = "The ID of the project to list tasks from")]
     pub project_id: Uuid,
     #[schemars(
-        description = "Optional status filter: 'todo', 'inprogress', 'inreview', 'done', 'cancelled'"
+        description = "Optional status filter: 'todo', 'inprogress', 'inreview', 'blocked', 'done', 'cancelled'"
     )]
     pub status: Option<String>,
     #[schemars(description = "Maximum number of tasks to return (default: 50)")]
@@ -193,7 +193,7 @@ pub struct UpdateTaskRequest {
     pub title: Option<String>,
     #[schemars(description = "New description for the task")]
     pub description: Option<String>,
-    #[schemars(description = "New status: 'todo', 'inprogress', 'inreview', 'done', 'cancelled'")]
+    #[schemars(description = "New status: 'todo', 'inprogress', 'inreview', 'blocked', 'done', 'cancelled'")]
     pub status: Option<String>,
     #[schemars(description = "Set to true to clear DAG position and move task back to pool")]
     #[serde(default)]
@@ -698,7 +698,7 @@ impl TaskServer {
                 Ok(s) => Some(s),
                 Err(_) => {
                     return Self::err(
-                        "Invalid status filter. Valid values: 'todo', 'inprogress', 'inreview', 'done', 'cancelled'".to_string(),
+                        "Invalid status filter. Valid values: 'todo', 'inprogress', 'inreview', 'blocked', 'done', 'cancelled'".to_string(),
                         Some(status_str.to_string()),
                     );
                 }
@@ -838,7 +838,7 @@ impl TaskServer {
                 Ok(s) => Some(s),
                 Err(_) => {
                     return Self::err(
-                        "Invalid status filter. Valid values: 'todo', 'inprogress', 'inreview', 'done', 'cancelled'".to_string(),
+                        "Invalid status filter. Valid values: 'todo', 'inprogress', 'inreview', 'blocked', 'done', 'cancelled'".to_string(),
                         Some(status_str.to_string()),
                     );
                 }
@@ -957,6 +957,8 @@ impl TaskServer {
             depends_on_task_id,
             created_by: Some(DependencyCreator::Ai),
             genre_id: None,
+            kind: None,
+            label: None,
         };
 
         let dependency: TaskDependency = match self
@@ -0,0 +1,433 @@
+//! OpenAPI description of the orchestration routes.
+//!
+//! Schemas are derived with `schemars` from the same serde types the handlers use, so they
+//! follow the wire format. The list of operations is written out below, and a test checks it
+//! against the routes mounted in `routes::orchestration::router`. Only the `/orchestrator`
+//! routes are described; the event WebSocket is left out since OpenAPI can't express it.
+
+use orchestrator::{
+    AtRiskTask, BulkTransitionResult, ExecutableTask, ExecutionPlan, GanttChart,
+    GraphValidationReport, NextAction, OrchestratorEvent, OrchestratorMetrics, TopologicalOrder,
+    TransitionRuleSet, TransitionValidation, VersionedPlan,
+};
+use schemars::{JsonSchema, SchemaGenerator, generate::SchemaSettings};
+use serde_json::{Map, Value, json};
+use uuid::Uuid;
+
+use crate::routes::orchestration::{
+    AtRiskTasksQuery, BulkTransitionRequest, ForecastUnblockedRequest, OrchestratorStateQuery,
+    OrchestratorStateResponse, PlanDiffQuery, PlanDiffResponse, ReadyTasksQuery, RecentEventsQuery,
+    SetAutoStartRequest, SetTaskTimeoutRequest, TaskFailedRequest, TasksByReadinessQuery,
+    TransitionLanguageQuery, ValidateTransitionRequest,
+};
+
+/// Prefix every orchestration route is nested under
+const PROJECT_PREFIX: &str = "/api/projects/{id}";
+
+/// OpenAPI 3 document for the `/orchestrator` routes
+pub fn orchestration_document() -> Value {
+    let mut doc = DocumentBuilder::new();
+
+    doc.route::<OrchestratorStateResponse>(
+        "get",
+        "/orchestrator",
+        "Get orchestrator state and execution plan for a project",
+    )
+    .query::<OrchestratorStateQuery>();
+    doc.route::<OrchestratorStateResponse>(
+        "post",
+        "/orchestrator/start",
+        "Start the orchestrator for a project",
+    );
+    doc.route::<OrchestratorStateResponse>(
+        "post",
+        "/orchestrator/pause",
+        "Pause the orchestrator for a project",
+    );
+    doc.route::<OrchestratorStateResponse>(
+        "post",
+        "/orchestrator/resume",
+        "Resume the orchestrator for a project",
+    );
+    doc.route::<OrchestratorStateResponse>(
+        "post",
+        "/orchestrator/stop",
+        "Stop the orchestrator for a project",
+    );
+    doc.route::<OrchestratorStateResponse>(
+        "post",
+        "/orchestrator/reset",
+        "Reset the orchestrator for a project, discarding all in-memory state",
+    );
+    doc.route::<OrchestratorStateResponse>(
+        "put",
+        "/orchestrator/auto-start",
+        "Enable or disable auto-start of ready tasks for a project",
+    )
+    .body::<SetAutoStartRequest>();
+    doc.route::<Option<u32>>(
+        "put",
+        "/orchestrator/task-timeout",
+        "Set how long a task may run before it is reported timed out",
+    )
+    .body::<SetTaskTimeoutRequest>();
+    doc.route::<Vec<Uuid>>(
+        "get",
+        "/orchestrator/timeouts",
+        "Get the running tasks that exceeded the task timeout, longest running first",
+    );
+    doc.route::<Vec<Uuid>>(
+        "get",
+        "/orchestrator/ready-tasks",
+        "Get ready-to-execute tasks for a project",
+    )
+    .query::<ReadyTasksQuery>();
+    doc.route::<NextAction>(
+        "get",
+        "/orchestrator/next-action",
+        "Get the single highest-priority task to start next, or why no task can start",
+    );
+    doc.route::<Vec<AtRiskTask>>(
+        "get",
+        "/orchestrator/at-risk",
+        "Get the tasks that are stuck behind other stuck tasks or have been in progress too long",
+    )
+    .query::<AtRiskTasksQuery>();
+    doc.route::<Vec<ExecutableTask>>(
+        "get",
+        "/orchestrator/tasks",
+        "Get the plan's tasks in one readiness column",
+    )
+    .query::<TasksByReadinessQuery>();
+    doc.route::<OrchestratorMetrics>(
+        "get",
+        "/orchestrator/metrics",
+        "Get a metrics snapshot of the project's orchestration",
+    );
+    doc.route::<Vec<OrchestratorEvent>>(
+        "get",
+        "/orchestrator/recent-events",
+        "Get the orchestrator's most recent events, oldest first",
+    )
+    .query::<RecentEventsQuery>();
+    doc.route::<TopologicalOrder>(
+        "get",
+        "/orchestrator/order",
+        "Get the project's tasks as one flat dependency order",
+    );
+    doc.route::<GanttChart>(
+        "get",
+        "/orchestrator/gantt",
+        "Get a projected schedule of the plan for a Gantt chart",
+    );
+    doc.route::<GraphValidationReport>(
+        "get",
+        "/orchestrator/validate",
+        "Check the project's dependency graph for cycles, bad edges and tasks that can never start",
+    );
+    doc.route::<VersionedPlan>(
+        "get",
+        "/orchestrator/plan",
+        "Get the current execution plan with its version",
+    );
+    doc.route::<PlanDiffResponse>(
+        "get",
+        "/orchestrator/plan/diff",
+        "Get what changed in the execution plan since a version the client already has",
+    )
+    .query::<PlanDiffQuery>();
+    doc.route::<TransitionValidation>(
+        "post",
+        "/orchestrator/validate-transition",
+        "Validate a task status transition",
+    )
+    .query::<TransitionLanguageQuery>()
+    .body::<ValidateTransitionRequest>();
+    doc.route::<Vec<(Uuid, TransitionValidation)>>(
+        "post",
+        "/orchestrator/validate-transitions",
+        "Validate several task status transitions in one call",
+    )
+    .query::<TransitionLanguageQuery>()
+    .body::<Vec<ValidateTransitionRequest>>();
+    doc.route::<Vec<BulkTransitionResult>>(
+        "post",
+        "/orchestrator/bulk-transition",
+        "Move several tasks to the same status, reporting the validation of each",
+    )
+    .query::<TransitionLanguageQuery>()
+    .body::<BulkTransitionRequest>();
+    doc.route::<Vec<Uuid>>(
+        "post",
+        "/orchestrator/retry-failed",
+        "Move the tasks reported failed back to Todo; returns the restarted task IDs",
+    );
+    doc.route::<Vec<Uuid>>(
+        "post",
+        "/orchestrator/forecast-unblocked",
+        "Forecast which tasks would become ready if the given tasks were done",
+    )
+    .body::<ForecastUnblockedRequest>();
+    doc.route::<TransitionRuleSet>(
+        "get",
+        "/orchestrator/transition-rules",
+        "Get the status transition rules for a project",
+    );
+    doc.route::<TransitionRuleSet>(
+        "put",
+        "/orchestrator/transition-rules",
+        "Replace the status transition rules for a project; invalid rule sets get a 400",
+    )
+    .query::<TransitionLanguageQuery>()
+    .body::<TransitionRuleSet>();
+    doc.route::<()>(
+        "post",
+        "/orchestrator/tasks/{task_id}/started",
+        "Notify orchestrator that a task has started",
+    );
+    doc.route::<Vec<Uuid>>(
+        "post",
+        "/orchestrator/tasks/{task_id}/completed",
+        "Notify orchestrator that a task has completed; returns the newly ready tasks",
+    );
+    doc.route::<()>(
+        "post",
+        "/orchestrator/tasks/{task_id}/failed",
+        "Report that a task failed with an error message",
+    )
+    .body::<TaskFailedRequest>();
+    doc.route::<()>(
+        "post",
+        "/orchestrator/tasks/{task_id}/review",
+        "Notify orchestrator that a task is awaiting review",
+    );
+    doc.route::<ExecutionPlan>(
+        "post",
+        "/orchestrator/tasks/{task_id}/skip",
+        "Exclude a task from auto-scheduling without changing its status",
+    )
+    .query::<TransitionLanguageQuery>();
+    doc.route::<ExecutionPlan>(
+        "delete",
+        "/orchestrator/tasks/{task_id}/skip",
+        "Let a skipped task be scheduled again",
+    )
+    .query::<TransitionLanguageQuery>();
+
+    doc.finish()
+}
+
+/// Collects operations and the component schemas they reference
+struct DocumentBuilder {
+    generator: SchemaGenerator,
+    paths: Map<String, Value>,
+}
+
+impl DocumentBuilder {
+    fn new() -> Self {
+        Self {
+            generator: SchemaSettings::openapi3().into_generator(),
+            paths: Map::new(),
+        }
+    }
+
+    /// Describe a route whose successful response carries `R` as the `ApiResponse` data
+    fn route<R: JsonSchema>(&mut self, method: &str, path: &str, summary: &str) -> Operation<'_> {
+        let path = format!("{PROJECT_PREFIX}{path}");
+        let data = self.generator.subschema_for::<R>().to_value();
+        let operation = json!({
+            "summary": summary,
+            "parameters": path_parameters(&path),
+            "responses": {
+                "200": {
+                    "description": "Success",
+                    "content": { "application/json": { "schema": api_response(data) } },
+                },
+            },
+        });
+
+        let item = self.paths.entry(path).or_insert_with(|| json!({}));
+        item[method] = operation;
+        Operation {
+            generator: &mut self.generator,
+            operation: &mut item[method],
+        }
+    }
+
+    fn finish(mut self) -> Value {
+        json!({
+            "openapi": "3.0.3",
+            "info": {
+                "title": "Orchestration API",
+                "version": env!("CARGO_PKG_VERSION"),
+            },
+            "paths": self.paths,
+            "components": { "schemas": self.generator.take_definitions(true) },
+        })
+    }
+}
+
+/// One operation being described, for adding its query parameters and request body
+struct Operation<'a> {
+    generator: &'a mut SchemaGenerator,
+    operation: &'a mut Value,
+}
+
+impl Operation<'_> {
+    /// Describe each field of `Q` as a query parameter
+    fn query<Q: JsonSchema>(self) -> Self {
+        let schema = Q::json_schema(self.generator).to_value();
+        let required: Vec<&str> = schema["required"]
+            .as_array()
+            .map(|names| names.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+
+        if let (Some(properties), Some(parameters)) = (
+            schema["properties"].as_object(),
+            self.operation["parameters"].as_array_mut(),
+        ) {
+            for (name, property) in properties {
+                let mut parameter = json!({
+                    "name": name,
+                    "in": "query",
+                    "required": required.contains(&name.as_str()),
+                    "schema": property,
+                });
+                if let Some(description) = property.get("description") {
+                    parameter["description"] = description.clone();
+                }
+                parameters.push(parameter);
+            }
+        }
+        self
+    }
+
+    /// Describe `B` as the JSON request body
+    fn body<B: JsonSchema>(self) -> Self {
+        let schema = self.generator.subschema_for::<B>().to_value();
+        self.operation["requestBody"] = json!({
+            "required": true,
+            "content": { "application/json": { "schema": schema } },
+        });
+        self
+    }
+}
+
+/// The `{name}` segments of a path, all of which are UUIDs on these routes
+fn path_parameters(path: &str) -> Vec<Value> {
+    path.split('/')
+        .filter_map(|segment| segment.strip_prefix('{')?.strip_suffix('}'))
+        .map(|name| {
+            json!({
+                "name": name,
+                "in": "path",
+                "required": true,
+                "schema": { "type": "string", "format": "uuid" },
+            })
+        })
+        .collect()
+}
+
+/// The `ApiResponse` envelope every route wraps its data in
+fn api_response(data: Value) -> Value {
+    json!({
+        "type": "object",
+        "required": ["success"],
+        "properties": {
+            "success": { "type": "boolean" },
+            "data": data,
+            "message": { "type": "string", "nullable": true },
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_orchestration_document() {
+        let doc = orchestration_document();
+
+        let start = &doc["paths"]["/api/projects/{id}/orchestrator/start"]["post"];
+        assert_eq!(start["parameters"][0]["name"], "id");
+        let response = &start["responses"]["200"]["content"]["application/json"]["schema"];
+        assert_eq!(
+            response["properties"]["data"]["$ref"],
+            "#/components/schemas/OrchestratorStateResponse"
+        );
+
+        let schemas = &doc["components"]["schemas"];
+        assert!(schemas["OrchestratorStateResponse"]["properties"]["plan"].is_object());
+        // Referenced types are collected too
+        assert!(schemas["ExecutionPlan"].is_object());
+
+        let tasks = &doc["paths"]["/api/projects/{id}/orchestrator/tasks"]["get"];
+        assert_eq!(tasks["parameters"][1]["name"], "readiness");
+        assert_eq!(tasks["parameters"][1]["in"], "query");
+        assert_eq!(tasks["parameters"][1]["required"], true);
+    }
+
+    /// Method and path of every `.route(..)` call mounted on the orchestrator router
+    ///
+    /// Axum can't list a router's routes, so they are read from the router's source.
+    fn mounted_routes() -> Vec<(String, String)> {
+        let source = include_str!("routes/orchestration.rs");
+        let start = source.find("let orchestrator_router").unwrap();
+        let end = start + source[start..].find(".layer(").unwrap();
+
+        let mut routes = Vec::new();
+        for (offset, _) in source[start..end].match_indices(".route(") {
+            let call = &source[start + offset + ".route(".len()..end];
+            let mut depth = 1;
+            let close = call
+                .find(|c| {
+                    match c {
+                        '(' => depth += 1,
+                        ')' => depth -= 1,
+                        _ => {}
+                    }
+                    depth == 0
+                })
+                .unwrap();
+            let call = &call[..close];
+            let path = call.split('"').nth(1).unwrap();
+            for method in ["get", "post", "put", "patch", "delete"] {
+                let called = call.match_indices(&format!("{method}(")).any(|(at, _)| {
+                    !call[..at].ends_with(|c: char| c.is_ascii_alphanumeric() || c == '_')
+                });
+                if called {
+                    routes.push((method.to_string(), path.to_string()));
+                }
+            }
+        }
+        routes
+    }
+
+    #[test]
+    fn test_document_matches_mounted_routes() {
+        let doc = orchestration_document();
+
+        let mut mounted: Vec<(String, String)> = mounted_routes()
+            .into_iter()
+            .filter(|(_, path)| !path.ends_with("/ws"))
+            .map(|(method, path)| (method, format!("{PROJECT_PREFIX}{path}")))
+            .collect();
+        mounted.sort();
+
+        let mut documented: Vec<(String, String)> = doc["paths"]
+            .as_object()
+            .unwrap()
+            .iter()
+            .flat_map(|(path, item)| {
+                item.as_object()
+                    .unwrap()
+                    .keys()
+                    .map(move |method| (method.clone(), path.clone()))
+            })
+            .collect();
+        documented.sort();
+
+        assert_eq!(documented, mounted);
+    }
+}
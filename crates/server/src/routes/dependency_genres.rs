@@ -1,16 +1,18 @@
 use axum::{
     Extension, Json, Router,
     extract::{
-        Path, State,
+        Path, Query, State,
         ws::{WebSocket, WebSocketUpgrade},
     },
     middleware::from_fn_with_state,
     response::{IntoResponse, Json as ResponseJson},
-    routing::{get, put},
+    routing::{get, post, put},
 };
 use futures_util::{SinkExt, StreamExt, TryStreamExt};
 use db::models::{
-    dependency_genre::{CreateDependencyGenre, DependencyGenre, UpdateDependencyGenre},
+    dependency_genre::{
+        CreateDependencyGenre, DependencyGenre, DependencyGenreWithUsage, UpdateDependencyGenre,
+    },
     project::Project,
 };
 use deployment::Deployment;
@@ -19,7 +21,11 @@ use ts_rs::TS;
 use utils::response::ApiResponse;
 use uuid::Uuid;
 
-use crate::{DeploymentImpl, error::ApiError, middleware::load_project_middleware};
+use crate::{
+    DeploymentImpl,
+    error::ApiError,
+    middleware::{load_project_middleware, load_project_middleware_with_nested_param},
+};
 
 /// Request body for creating a genre
 #[derive(Debug, Deserialize, TS)]
@@ -43,12 +49,59 @@ pub struct ReorderGenresApiRequest {
     pub genre_ids: Vec<Uuid>,
 }
 
-/// Get all genres for a project
+/// Query parameters for listing a project's genres
+#[derive(Debug, Default, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+pub struct ListGenresQuery {
+    #[serde(default)]
+    pub include_archived: bool,
+}
+
+/// What to do when deleting a genre that dependencies still use
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, TS)]
+#[serde(rename_all = "lowercase")]
+pub enum OnReferenced {
+    /// Refuse to delete the genre
+    #[default]
+    Block,
+    /// Untag the dependencies, then delete the genre
+    Clear,
+}
+
+/// Query parameters for deleting a genre
+#[derive(Debug, Default, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteGenreQuery {
+    #[serde(default)]
+    pub on_referenced: OnReferenced,
+}
+
+/// Request body for merging a genre into another one
+#[derive(Debug, Deserialize, TS)]
+pub struct MergeGenresRequest {
+    pub into: Uuid,
+}
+
+/// Get the genres of a project, with the number of dependencies using each.
+/// Archived genres are left out unless `includeArchived=true`.
 pub async fn get_project_genres(
     Extension(project): Extension<Project>,
     State(deployment): State<DeploymentImpl>,
-) -> Result<ResponseJson<ApiResponse<Vec<DependencyGenre>>>, ApiError> {
-    let genres = DependencyGenre::find_by_project_id(&deployment.db().pool, project.id).await?;
+    Query(query): Query<ListGenresQuery>,
+) -> Result<ResponseJson<ApiResponse<Vec<DependencyGenreWithUsage>>>, ApiError> {
+    let pool = &deployment.db().pool;
+    let genres =
+        DependencyGenre::find_by_project_id(pool, project.id, query.include_archived).await?;
+    let usage_counts = DependencyGenre::usage_counts(pool, project.id).await?;
+
+    let genres = genres
+        .into_iter()
+        .map(|genre| DependencyGenreWithUsage {
+            usage_count: usage_counts.get(&genre.id).copied().unwrap_or(0),
+            genre,
+        })
+        .collect();
+
     Ok(ResponseJson(ApiResponse::success(genres)))
 }
 
@@ -171,19 +224,81 @@ pub async fn update_genre(
     Ok(ResponseJson(ApiResponse::success(genre)))
 }
 
-/// Delete a genre
+/// Archive a genre: it stays on the dependencies it categorizes but is hidden from pickers
+pub async fn archive_genre(
+    State(deployment): State<DeploymentImpl>,
+    Path(genre_id): Path<Uuid>,
+) -> Result<ResponseJson<ApiResponse<DependencyGenre>>, ApiError> {
+    set_genre_archived(&deployment, genre_id, true).await
+}
+
+/// Restore an archived genre
+pub async fn unarchive_genre(
+    State(deployment): State<DeploymentImpl>,
+    Path(genre_id): Path<Uuid>,
+) -> Result<ResponseJson<ApiResponse<DependencyGenre>>, ApiError> {
+    set_genre_archived(&deployment, genre_id, false).await
+}
+
+async fn set_genre_archived(
+    deployment: &DeploymentImpl,
+    genre_id: Uuid,
+    archived: bool,
+) -> Result<ResponseJson<ApiResponse<DependencyGenre>>, ApiError> {
+    let pool = &deployment.db().pool;
+
+    DependencyGenre::find_by_id(pool, genre_id)
+        .await?
+        .ok_or_else(|| ApiError::NotFound(format!("ジャンルが見つかりません: {}", genre_id)))?;
+
+    let genre = DependencyGenre::set_archived(pool, genre_id, archived).await?;
+
+    tracing::info!(
+        "Set archived={} on dependency genre: {}",
+        archived,
+        genre_id
+    );
+
+    Ok(ResponseJson(ApiResponse::success(genre)))
+}
+
+/// Reject deleting a genre that is still in use when the caller asked to block
+fn check_genre_deletable(on_referenced: OnReferenced, usage_count: usize) -> Result<(), ApiError> {
+    if on_referenced == OnReferenced::Block && usage_count > 0 {
+        return Err(ApiError::Conflict(format!(
+            "ジャンルは {} 件の依存関係で使用されています",
+            usage_count
+        )));
+    }
+    Ok(())
+}
+
+/// Delete a genre.
+/// With `onReferenced=block` (default) a genre still used by dependencies is not deleted;
+/// with `onReferenced=clear` those dependencies are untagged first.
 pub async fn delete_genre(
     State(deployment): State<DeploymentImpl>,
     Path(genre_id): Path<Uuid>,
+    Query(query): Query<DeleteGenreQuery>,
 ) -> Result<ResponseJson<ApiResponse<()>>, ApiError> {
     let pool = &deployment.db().pool;
 
     // Check if genre exists
-    DependencyGenre::find_by_id(pool, genre_id)
+    let genre = DependencyGenre::find_by_id(pool, genre_id)
         .await?
         .ok_or_else(|| ApiError::NotFound(format!("ジャンルが見つかりません: {}", genre_id)))?;
 
-    let rows_affected = DependencyGenre::delete(pool, genre_id).await?;
+    let usage_count = DependencyGenre::usage_counts(pool, genre.project_id)
+        .await?
+        .get(&genre_id)
+        .copied()
+        .unwrap_or(0);
+    check_genre_deletable(query.on_referenced, usage_count)?;
+
+    let rows_affected = match query.on_referenced {
+        OnReferenced::Block => DependencyGenre::delete(pool, genre_id).await?,
+        OnReferenced::Clear => DependencyGenre::delete_clearing_references(pool, genre_id).await?,
+    };
 
     if rows_affected == 0 {
         return Err(ApiError::NotFound(
@@ -228,6 +343,58 @@ pub async fn reorder_genres(
     Ok(ResponseJson(ApiResponse::success(genres)))
 }
 
+/// Merge a genre into another genre of the same project.
+/// Dependencies using the source genre are moved to the target, then the source is deleted.
+pub async fn merge_genres(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Path((_project_id, genre_id)): Path<(Uuid, Uuid)>,
+    Json(payload): Json<MergeGenresRequest>,
+) -> Result<ResponseJson<ApiResponse<DependencyGenre>>, ApiError> {
+    let pool = &deployment.db().pool;
+
+    if genre_id == payload.into {
+        return Err(ApiError::BadRequest(
+            "同じジャンル同士はマージできません".to_string(),
+        ));
+    }
+
+    for id in [genre_id, payload.into] {
+        let genre = DependencyGenre::find_by_id(pool, id)
+            .await?
+            .ok_or_else(|| ApiError::NotFound(format!("ジャンルが見つかりません: {}", id)))?;
+
+        if genre.project_id != project.id {
+            return Err(ApiError::BadRequest(
+                "ジャンルはこのプロジェクトに属していません".to_string(),
+            ));
+        }
+    }
+
+    let genre = DependencyGenre::merge(pool, genre_id, payload.into).await?;
+
+    tracing::info!(
+        "Merged dependency genre {} into {} in project {}",
+        genre_id,
+        payload.into,
+        project.id
+    );
+
+    Ok(ResponseJson(ApiResponse::success(genre)))
+}
+
+/// Seed the default genres into a project that has none yet
+pub async fn seed_default_genres(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<DependencyGenre>>>, ApiError> {
+    let genres = DependencyGenre::seed_defaults(&deployment.db().pool, project.id).await?;
+
+    tracing::info!("Seeded default genres in project {}", project.id);
+
+    Ok(ResponseJson(ApiResponse::success(genres)))
+}
+
 pub fn router(deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
     // Project-scoped genre operations (project_id required)
     let project_genres_router = Router::new()
@@ -236,18 +403,30 @@ pub fn router(deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
             get(get_project_genres).post(create_genre),
         )
         .route("/dependency-genres/reorder", put(reorder_genres))
+        .route("/dependency-genres/seed-defaults", post(seed_default_genres))
         .route("/dependency-genres/stream/ws", get(stream_genres_ws))
         .layer(from_fn_with_state(
             deployment.clone(),
             load_project_middleware,
         ));
 
+    // Project-scoped operations on a single genre (project_id and genre_id required)
+    let project_genre_router = Router::new()
+        .route("/dependency-genres/{genre_id}/merge", post(merge_genres))
+        .layer(from_fn_with_state(
+            deployment.clone(),
+            load_project_middleware_with_nested_param,
+        ));
+
     // Direct genre operations (genre_id only)
     let genres_router = Router::new()
-        .route("/{genre_id}", put(update_genre).delete(delete_genre));
+        .route("/{genre_id}", put(update_genre).delete(delete_genre))
+        .route("/{genre_id}/archive", post(archive_genre))
+        .route("/{genre_id}/unarchive", post(unarchive_genre));
 
     Router::new()
         .nest("/projects/{id}", project_genres_router)
+        .nest("/projects/{id}", project_genre_router)
         .nest("/dependency-genres", genres_router)
 }
 
@@ -279,4 +458,34 @@ mod tests {
         let request: ReorderGenresApiRequest = serde_json::from_str(json).unwrap();
         assert_eq!(request.genre_ids.len(), 2);
     }
+
+    #[test]
+    fn test_list_genres_query_excludes_archived_by_default() {
+        let query: ListGenresQuery = serde_json::from_str("{}").unwrap();
+        assert!(!query.include_archived);
+
+        let query: ListGenresQuery = serde_json::from_str(r#"{"includeArchived": true}"#).unwrap();
+        assert!(query.include_archived);
+    }
+
+    #[test]
+    fn test_delete_genre_query_defaults_to_block() {
+        let query: DeleteGenreQuery = serde_json::from_str("{}").unwrap();
+        assert_eq!(query.on_referenced, OnReferenced::Block);
+
+        let query: DeleteGenreQuery = serde_json::from_str(r#"{"onReferenced": "clear"}"#).unwrap();
+        assert_eq!(query.on_referenced, OnReferenced::Clear);
+    }
+
+    #[test]
+    fn test_check_genre_deletable_blocks_referenced_genre() {
+        let result = check_genre_deletable(OnReferenced::Block, 2);
+        assert!(matches!(result, Err(ApiError::Conflict(msg)) if msg.contains('2')));
+        assert!(check_genre_deletable(OnReferenced::Block, 0).is_ok());
+    }
+
+    #[test]
+    fn test_check_genre_deletable_clear_allows_referenced_genre() {
+        assert!(check_genre_deletable(OnReferenced::Clear, 2).is_ok());
+    }
 }
@@ -4,23 +4,24 @@
 
 use axum::{
     Extension, Json, Router,
-    extract::{Path, State},
+    extract::{Path, Query, State},
     middleware::from_fn_with_state,
     response::Json as ResponseJson,
-    routing::{delete, get, post},
+    routing::{delete, get, post, put},
 };
 use db::models::{
-    github_issue_mapping::GitHubIssueMapping,
+    github_issue_mapping::{GitHubIssueMapping, SyncDirection},
     github_project_link::{CreateGitHubProjectLink, GitHubProjectLink},
     project::Project,
 };
 use deployment::Deployment;
 use serde::{Deserialize, Serialize};
 use services::services::github::{
-    GitHubProjectsService, GitHubSyncService,
-    projects::GitHubProject,
+    GitHubProjectsError, GitHubProjectsService, GitHubSyncService,
+    projects::{DEFAULT_PAGE_SIZE, GitHubProject},
     sync::SyncResult,
 };
+use sqlx::SqlitePool;
 use ts_rs::TS;
 use utils::response::ApiResponse;
 use uuid::Uuid;
@@ -38,6 +39,10 @@ pub struct CreateGitHubLinkRequest {
     pub github_owner: String,
     pub github_repo: Option<String>,
     pub github_project_number: Option<i64>,
+    /// GitHub Enterprise hostname; omit for github.com
+    pub github_host: Option<String>,
+    /// Only import issues carrying this label; omit to import every issue
+    pub label_filter: Option<String>,
 }
 
 /// Response for GitHub project link with mapping count
@@ -49,6 +54,18 @@ pub struct GitHubLinkResponse {
     pub issue_count: usize,
 }
 
+/// Report missing access and unknown owners as 403/404 rather than a server error
+fn projects_api_error(context: &str, err: GitHubProjectsError) -> ApiError {
+    let message = format!("{}: {}", context, err);
+    match err {
+        GitHubProjectsError::AccessDenied(_) => ApiError::Forbidden(message),
+        GitHubProjectsError::NotFound(_) | GitHubProjectsError::ProjectNotFound(_) => {
+            ApiError::NotFound(message)
+        }
+        _ => ApiError::InternalServer(message),
+    }
+}
+
 /// List available GitHub Projects for the authenticated user
 pub async fn list_available_projects(
     State(_deployment): State<DeploymentImpl>,
@@ -66,9 +83,9 @@ pub async fn list_available_projects(
     })?;
 
     // Get projects for the current user
-    let projects = projects_service.list_user_projects(&viewer_login).map_err(|e| {
-        ApiError::InternalServer(format!("Failed to list GitHub projects: {}", e))
-    })?;
+    let projects = projects_service
+        .list_user_projects(&viewer_login, DEFAULT_PAGE_SIZE)
+        .map_err(|e| projects_api_error("Failed to list GitHub projects", e))?;
 
     Ok(ResponseJson(ApiResponse::success(projects)))
 }
@@ -84,28 +101,73 @@ pub async fn list_org_projects(
         ApiError::ServiceUnavailable(format!("GitHub CLI not available: {}", e))
     })?;
 
-    let projects = projects_service.list_org_projects(&org).map_err(|e| {
-        ApiError::InternalServer(format!("Failed to list organization projects: {}", e))
-    })?;
+    let projects = projects_service
+        .list_org_projects(&org, DEFAULT_PAGE_SIZE)
+        .map_err(|e| projects_api_error("Failed to list organization projects", e))?;
 
     Ok(ResponseJson(ApiResponse::success(projects)))
 }
 
+/// Trimmed owner and repository name from the path, rejecting blank segments
+fn repo_path_params(owner: &str, repo: &str) -> Result<(String, String), ApiError> {
+    let (owner, repo) = (owner.trim(), repo.trim());
+    if owner.is_empty() || repo.is_empty() {
+        return Err(ApiError::BadRequest(
+            "Repository owner and name must not be empty".to_string(),
+        ));
+    }
+    Ok((owner.to_string(), repo.to_string()))
+}
+
+/// List GitHub Projects attached to a repository
+pub async fn list_repo_projects(
+    State(_deployment): State<DeploymentImpl>,
+    Path((owner, repo)): Path<(String, String)>,
+) -> Result<ResponseJson<ApiResponse<Vec<GitHubProject>>>, ApiError> {
+    let projects_service = GitHubProjectsService::new();
+
+    let projects = repo_projects_with(
+        &owner,
+        &repo,
+        || projects_service.check_available(),
+        |owner, repo| projects_service.list_repo_projects(owner, repo, DEFAULT_PAGE_SIZE),
+    )?;
+
+    Ok(ResponseJson(ApiResponse::success(projects)))
+}
+
+/// Body of `list_repo_projects` with the `gh` calls supplied by the caller
+fn repo_projects_with(
+    owner: &str,
+    repo: &str,
+    check_available: impl FnOnce() -> Result<(), GitHubProjectsError>,
+    list: impl FnOnce(&str, &str) -> Result<Vec<GitHubProject>, GitHubProjectsError>,
+) -> Result<Vec<GitHubProject>, ApiError> {
+    let (owner, repo) = repo_path_params(owner, repo)?;
+
+    check_available().map_err(|e| {
+        ApiError::ServiceUnavailable(format!("GitHub CLI not available: {}", e))
+    })?;
+
+    list(&owner, &repo).map_err(|e| projects_api_error("Failed to list repository projects", e))
+}
+
 /// Get GitHub project links for a Vibe project
 pub async fn get_github_links(
     Extension(project): Extension<Project>,
     State(deployment): State<DeploymentImpl>,
 ) -> Result<ResponseJson<ApiResponse<Vec<GitHubLinkResponse>>>, ApiError> {
-    let links = GitHubProjectLink::find_by_project_id(&deployment.db().pool, project.id).await?;
-
-    let mut responses = Vec::new();
-    for link in links {
-        let mappings = GitHubIssueMapping::find_by_link_id(&deployment.db().pool, link.id).await?;
-        responses.push(GitHubLinkResponse {
+    let pool = &deployment.db().pool;
+    let links = GitHubProjectLink::find_by_project_id(pool, project.id).await?;
+    let issue_counts = GitHubIssueMapping::count_by_project(pool, project.id).await?;
+
+    let responses = links
+        .into_iter()
+        .map(|link| GitHubLinkResponse {
+            issue_count: issue_counts.get(&link.id).copied().unwrap_or(0),
             link,
-            issue_count: mappings.len(),
-        });
-    }
+        })
+        .collect();
 
     Ok(ResponseJson(ApiResponse::success(responses)))
 }
@@ -122,6 +184,11 @@ pub async fn create_github_link(
         github_owner: payload.github_owner,
         github_repo: payload.github_repo,
         github_project_number: payload.github_project_number,
+        github_host: payload.github_host.filter(|host| !host.trim().is_empty()),
+        label_filter: payload
+            .label_filter
+            .map(|label| label.trim().to_string())
+            .filter(|label| !label.is_empty()),
     };
 
     let link = GitHubProjectLink::create(&deployment.db().pool, &data).await?;
@@ -205,11 +272,20 @@ pub async fn toggle_github_link_sync(
     Ok(ResponseJson(ApiResponse::success(updated_link)))
 }
 
+/// Query parameters for a manual sync
+#[derive(Debug, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncGitHubLinkQuery {
+    /// Create tasks for closed issues that aren't mapped yet; defaults to true
+    pub import_closed: Option<bool>,
+}
+
 /// Trigger manual sync for a GitHub link
 pub async fn sync_github_link(
     Extension(project): Extension<Project>,
     State(deployment): State<DeploymentImpl>,
     Path((_project_id, link_id)): Path<(Uuid, Uuid)>,
+    Query(query): Query<SyncGitHubLinkQuery>,
 ) -> Result<ResponseJson<ApiResponse<SyncResult>>, ApiError> {
     // Verify the link belongs to this project
     let link = GitHubProjectLink::find_by_id(&deployment.db().pool, link_id)
@@ -222,7 +298,9 @@ pub async fn sync_github_link(
         ));
     }
 
-    let sync_service = GitHubSyncService::new();
+    let sync_service = GitHubSyncService::new()
+        .with_host(link.github_host.clone())
+        .with_import_closed(query.import_closed.unwrap_or(true));
 
     sync_service.check_available().map_err(|e| {
         ApiError::ServiceUnavailable(format!("GitHub CLI not available: {}", e))
@@ -271,11 +349,104 @@ pub async fn get_github_link_mappings(
     Ok(ResponseJson(ApiResponse::success(mappings)))
 }
 
+/// Query parameters for unlinking a single task mapping
+#[derive(Debug, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+pub struct UnlinkGitHubMappingQuery {
+    /// Also remove the GitHub-sourced properties that sync wrote on the task
+    #[serde(default)]
+    pub clear_properties: bool,
+}
+
+/// Stop syncing a single task with its GitHub issue
+///
+/// The task itself is kept; only its mapping (and optionally its GitHub properties) is removed.
+/// Registered outside the project-loading middleware because it has three path parameters.
+pub async fn unlink_github_mapping(
+    State(deployment): State<DeploymentImpl>,
+    Path((project_id, link_id, task_id)): Path<(Uuid, Uuid, Uuid)>,
+    Query(query): Query<UnlinkGitHubMappingQuery>,
+) -> Result<ResponseJson<ApiResponse<()>>, ApiError> {
+    let pool = &deployment.db().pool;
+    let mapping = find_link_mapping(pool, project_id, link_id, task_id).await?;
+
+    let cleared = mapping.unlink(pool, query.clear_properties).await?;
+
+    tracing::info!(
+        "Unlinked task {} from GitHub issue #{} (link {}, {} properties cleared)",
+        task_id,
+        mapping.github_issue_number,
+        link_id,
+        cleared
+    );
+
+    Ok(ResponseJson(ApiResponse::success(())))
+}
+
+/// Request to change which way a single task mapping syncs
+#[derive(Debug, Deserialize, TS)]
+pub struct UpdateSyncDirectionRequest {
+    pub direction: SyncDirection,
+}
+
+/// Change which way a single task syncs with its GitHub issue
+/// `vibe_to_github` keeps the task locally authoritative: syncs from GitHub leave it untouched.
+/// Registered outside the project-loading middleware because it has three path parameters.
+pub async fn update_github_mapping_sync_direction(
+    State(deployment): State<DeploymentImpl>,
+    Path((project_id, link_id, task_id)): Path<(Uuid, Uuid, Uuid)>,
+    Json(payload): Json<UpdateSyncDirectionRequest>,
+) -> Result<ResponseJson<ApiResponse<GitHubIssueMapping>>, ApiError> {
+    let pool = &deployment.db().pool;
+    let mapping = find_link_mapping(pool, project_id, link_id, task_id).await?;
+
+    let updated =
+        GitHubIssueMapping::update_sync_direction(pool, mapping.id, payload.direction).await?;
+
+    tracing::info!(
+        "Set sync direction of task {} (GitHub issue #{}) to {}",
+        task_id,
+        updated.github_issue_number,
+        updated.sync_direction
+    );
+
+    Ok(ResponseJson(ApiResponse::success(updated)))
+}
+
+/// Find the mapping of `task_id` through `link_id`, checking the link belongs to the project
+async fn find_link_mapping(
+    pool: &SqlitePool,
+    project_id: Uuid,
+    link_id: Uuid,
+    task_id: Uuid,
+) -> Result<GitHubIssueMapping, ApiError> {
+    // Verify the link belongs to this project
+    let link = GitHubProjectLink::find_by_id(pool, link_id)
+        .await?
+        .ok_or_else(|| ApiError::NotFound("GitHub link not found".to_string()))?;
+
+    if link.project_id != project_id {
+        return Err(ApiError::Forbidden(
+            "Link does not belong to this project".to_string(),
+        ));
+    }
+
+    // Verify the task is mapped through this link
+    GitHubIssueMapping::find_by_task_id(pool, task_id)
+        .await?
+        .filter(|mapping| mapping.github_project_link_id == link_id)
+        .ok_or_else(|| ApiError::NotFound("GitHub mapping not found".to_string()))
+}
+
 /// Check GitHub CLI availability and authentication status
 pub async fn check_github_status(
     State(_deployment): State<DeploymentImpl>,
+    Query(query): Query<GitHubStatusQuery>,
 ) -> Result<ResponseJson<ApiResponse<GitHubStatusResponse>>, ApiError> {
     let projects_service = GitHubProjectsService::new();
+    if query.force {
+        projects_service.clear_status_cache();
+    }
 
     match projects_service.check_available() {
         Ok(()) => {
@@ -303,6 +474,14 @@ pub async fn check_github_status(
     }
 }
 
+/// Query parameters for the GitHub status endpoint
+#[derive(Debug, Deserialize, TS)]
+pub struct GitHubStatusQuery {
+    /// Run the `gh` checks again instead of using the cached result
+    #[serde(default)]
+    pub force: bool,
+}
+
 #[derive(Debug, Clone, Serialize, TS)]
 #[serde(rename_all = "camelCase")]
 pub struct GitHubStatusResponse {
@@ -348,6 +527,128 @@ pub fn router(deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
         .route("/github/status", get(check_github_status))
         .route("/github/projects", get(list_available_projects))
         .route("/github/organizations/{org}/projects", get(list_org_projects))
+        .route("/github/repositories/{owner}/{repo}/projects", get(list_repo_projects))
         .nest("/projects/{id}", project_github_base_router)
         .nest("/projects/{id}", project_github_nested_router)
+        .route(
+            "/projects/{id}/github-links/{link_id}/mappings/{task_id}",
+            delete(unlink_github_mapping),
+        )
+        .route(
+            "/projects/{id}/github-links/{link_id}/mappings/{task_id}/sync-direction",
+            put(update_github_mapping_sync_direction),
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use services::services::github::GitHubGraphQLError;
+
+    use super::*;
+
+    #[test]
+    fn test_repo_path_params() {
+        let (owner, repo) = repo_path_params(" octocat ", "hello-world").unwrap();
+        assert_eq!(owner, "octocat");
+        assert_eq!(repo, "hello-world");
+
+        assert!(matches!(
+            repo_path_params("octocat", " "),
+            Err(ApiError::BadRequest(_))
+        ));
+        assert!(matches!(
+            repo_path_params("", "hello-world"),
+            Err(ApiError::BadRequest(_))
+        ));
+    }
+
+    fn test_project(owner_login: &str) -> GitHubProject {
+        GitHubProject {
+            id: "PVT_kwXXX".to_string(),
+            title: "Roadmap".to_string(),
+            number: 3,
+            url: format!("https://github.com/orgs/{}/projects/3", owner_login),
+            closed: false,
+            short_description: None,
+            public: true,
+            owner_login: owner_login.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_repo_projects_lists_projects_for_path_params() {
+        let projects = repo_projects_with(
+            " octocat ",
+            "hello-world",
+            || Ok(()),
+            |owner, repo| {
+                assert_eq!((owner, repo), ("octocat", "hello-world"));
+                Ok(vec![test_project(owner)])
+            },
+        )
+        .unwrap();
+
+        let body = serde_json::to_value(ApiResponse::success(projects)).unwrap();
+        assert_eq!(body["success"], true);
+        assert_eq!(body["data"][0]["id"], "PVT_kwXXX");
+        assert_eq!(body["data"][0]["ownerLogin"], "octocat");
+        assert_eq!(body["data"][0]["shortDescription"], serde_json::Value::Null);
+    }
+
+    #[test]
+    fn test_repo_projects_reports_unknown_repository_as_not_found() {
+        let error = repo_projects_with(
+            "octocat",
+            "missing",
+            || Ok(()),
+            |owner, repo| {
+                Err(GitHubProjectsError::ProjectNotFound(format!(
+                    "Repository not found: {}/{}",
+                    owner, repo
+                )))
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(&error, ApiError::NotFound(message) if message.contains("octocat/missing"))
+        );
+        assert_eq!(
+            axum::response::IntoResponse::into_response(error).status(),
+            axum::http::StatusCode::NOT_FOUND
+        );
+
+        let error = repo_projects_with(
+            "octocat",
+            "hello-world",
+            || {
+                Err(GitHubProjectsError::GraphQL(
+                    GitHubGraphQLError::CliNotAvailable,
+                ))
+            },
+            |_, _| panic!("repositories are not listed when gh is unavailable"),
+        )
+        .unwrap_err();
+        assert!(matches!(error, ApiError::ServiceUnavailable(_)));
+    }
+
+    #[test]
+    fn test_sync_github_link_query() {
+        let query: SyncGitHubLinkQuery = serde_json::from_str(r#"{"importClosed":false}"#).unwrap();
+        assert_eq!(query.import_closed, Some(false));
+
+        let query: SyncGitHubLinkQuery = serde_json::from_str("{}").unwrap();
+        assert!(query.import_closed.is_none());
+    }
+
+    #[test]
+    fn test_update_sync_direction_request() {
+        let request: UpdateSyncDirectionRequest =
+            serde_json::from_str(r#"{"direction":"vibe_to_github"}"#).unwrap();
+        assert_eq!(request.direction, SyncDirection::VibeToGithub);
+
+        assert!(
+            serde_json::from_str::<UpdateSyncDirectionRequest>(r#"{"direction":"sideways"}"#)
+                .is_err()
+        );
+    }
 }
@@ -1,28 +1,48 @@
 use axum::{
     Extension, Json, Router,
     extract::{
-        Path, State,
+        Path, Query, State,
         ws::{Message, WebSocket, WebSocketUpgrade},
     },
+    http::HeaderMap,
     middleware::from_fn_with_state,
-    response::{IntoResponse, Json as ResponseJson},
-    routing::{get, post},
+    response::{IntoResponse, Json as ResponseJson, Response},
+    routing::{get, post, put},
 };
-use db::models::project::Project;
+use db::models::{project::Project, task::Task, task_dependency::TaskDependency};
 use deployment::Deployment;
-use futures_util::{SinkExt, StreamExt};
+use futures_util::{Sink, SinkExt, StreamExt};
 use orchestrator::{
-    ExecutionPlan, OrchestratorManager, OrchestratorState,
-    TransitionValidation,
+    AtRiskTask, BulkTransitionResult, DEFAULT_EVENT_CHANNEL_CAPACITY, ExecutableTask,
+    ExecutionPlan, ExplanationLanguage, GanttChart, GraphValidationReport, NextAction,
+    OrchestratorError, OrchestratorEvent, OrchestratorManager, OrchestratorMessage,
+    OrchestratorMetrics, OrchestratorState, PlanDiff, PlanMode, ReadinessFilter, TopologicalOrder,
+    TransitionRequest, TransitionRuleSet, TransitionValidation, VersionedPlan, compact_levels,
+    filter_plan_by_assignee, topological_order,
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
-use tokio::sync::OnceCell;
+use sqlx::SqlitePool;
+use std::{sync::Arc, time::Duration};
+use tokio::sync::{OnceCell, broadcast};
 use ts_rs::TS;
 use utils::response::ApiResponse;
 use uuid::Uuid;
 
-use crate::{DeploymentImpl, error::ApiError, middleware::load_project_middleware};
+use crate::{
+    DeploymentImpl,
+    error::ApiError,
+    etag,
+    middleware::load_project_middleware,
+    openapi,
+    routes::task_dependencies::request_language_or,
+};
+
+/// How often the orchestrator WebSocket is pinged so idle connections survive proxies
+const ORCHESTRATOR_WS_HEARTBEAT: Duration = Duration::from_secs(30);
+
+/// Environment variable overriding how many events each orchestrator subscriber may fall behind
+const ORCHESTRATOR_EVENT_CAPACITY_ENV: &str = "ORCHESTRATOR_EVENT_CAPACITY";
 
 /// Global orchestrator manager instance
 static ORCHESTRATOR_MANAGER: OnceCell<Arc<OrchestratorManager>> = OnceCell::const_new();
@@ -30,42 +50,221 @@ static ORCHESTRATOR_MANAGER: OnceCell<Arc<OrchestratorManager>> = OnceCell::cons
 /// Get or initialize the global orchestrator manager
 async fn get_orchestrator_manager() -> &'static Arc<OrchestratorManager> {
     ORCHESTRATOR_MANAGER
-        .get_or_init(|| async { Arc::new(OrchestratorManager::new(3)) })
+        .get_or_init(|| async {
+            let event_capacity =
+                event_capacity_from(std::env::var(ORCHESTRATOR_EVENT_CAPACITY_ENV).ok());
+            Arc::new(OrchestratorManager::new(3, event_capacity))
+        })
         .await
 }
 
+/// Parse the configured event channel capacity, falling back to the default when unset or invalid
+fn event_capacity_from(value: Option<String>) -> usize {
+    match value.as_deref().map(|value| value.trim().parse::<usize>()) {
+        Some(Ok(capacity)) if capacity > 0 => capacity,
+        Some(_) => {
+            tracing::warn!(
+                "Ignoring invalid {}, using {}",
+                ORCHESTRATOR_EVENT_CAPACITY_ENV,
+                DEFAULT_EVENT_CHANNEL_CAPACITY
+            );
+            DEFAULT_EVENT_CHANNEL_CAPACITY
+        }
+        None => DEFAULT_EVENT_CHANNEL_CAPACITY,
+    }
+}
+
+/// Map an orchestrator failure to an API error, reporting unknown tasks as not found
+fn orchestrator_error(error: OrchestratorError) -> ApiError {
+    match error {
+        OrchestratorError::TaskNotFound(_) => ApiError::NotFound(error.to_string()),
+        error => ApiError::InternalServer(error.to_string()),
+    }
+}
+
+/// Like `orchestrator_error`, rendering unknown tasks through the message catalog
+fn localized_orchestrator_error(
+    error: OrchestratorError,
+    language: ExplanationLanguage,
+) -> ApiError {
+    match error {
+        OrchestratorError::TaskNotFound(task_id) => {
+            ApiError::NotFound(OrchestratorMessage::TaskNotFound { task_id }.render(language))
+        }
+        error => orchestrator_error(error),
+    }
+}
+
+/// Drop the cached execution plan of a project after its tasks or dependencies changed
+pub async fn invalidate_plan(project_id: Uuid) {
+    get_orchestrator_manager()
+        .await
+        .invalidate_plan(project_id)
+        .await;
+}
+
 /// Response containing orchestrator state
-#[derive(Serialize, Deserialize, TS)]
+#[derive(Serialize, Deserialize, TS, JsonSchema)]
 pub struct OrchestratorStateResponse {
     pub state: OrchestratorState,
+    pub auto_start: bool,
     pub plan: ExecutionPlan,
 }
 
+/// Query parameters for the orchestrator state endpoint
+#[derive(Debug, Deserialize, TS, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OrchestratorStateQuery {
+    /// Restrict the plan to dependencies of this genre
+    pub genre_id: Option<Uuid>,
+    /// Only include tasks assigned to this GitHub login
+    pub assignee: Option<String>,
+    /// How tasks are grouped into levels; `capped` limits each level to the parallel limit
+    #[serde(default)]
+    pub mode: PlanMode,
+    /// Merge adjacent levels that fit within the parallel limit and don't depend on each other
+    #[serde(default)]
+    pub compact: bool,
+}
+
+/// Query parameters for the ready tasks endpoint
+#[derive(Debug, Deserialize, TS, JsonSchema)]
+pub struct ReadyTasksQuery {
+    /// Return the tasks that would start even when the orchestrator is not running
+    #[serde(default)]
+    pub preview: bool,
+}
+
+/// Query parameters for listing the plan's tasks in one readiness column
+#[derive(Debug, Deserialize, TS, JsonSchema)]
+pub struct TasksByReadinessQuery {
+    pub readiness: ReadinessFilter,
+}
+
+/// Query parameters for the at-risk tasks endpoint
+#[derive(Debug, Deserialize, TS, JsonSchema)]
+pub struct AtRiskTasksQuery {
+    /// Seconds in progress before a task counts as stale; defaults to the task timeout, then a day
+    pub stale_after_secs: Option<u32>,
+}
+
+/// Query parameters for the recent events endpoint
+#[derive(Debug, Deserialize, TS, JsonSchema)]
+pub struct RecentEventsQuery {
+    /// Maximum number of events to return, newest last; all retained events when omitted
+    pub limit: Option<usize>,
+}
+
+/// Query parameters for the plan diff endpoint
+#[derive(Debug, Deserialize, TS, JsonSchema)]
+pub struct PlanDiffQuery {
+    /// Epoch of the plan version the client currently has
+    pub epoch: Uuid,
+    /// Plan version the client currently has
+    pub since: u32,
+}
+
+/// Changes since a plan version, or the full plan when that version is no longer known or
+/// belongs to an earlier epoch
+#[derive(Debug, Serialize, TS, JsonSchema)]
+pub struct PlanDiffResponse {
+    /// Epoch of the current plan, to pass as `epoch` next time
+    pub epoch: Uuid,
+    /// Version of the current plan, to pass as `since` next time
+    pub version: u32,
+    pub diff: Option<PlanDiff>,
+    /// Only set when `diff` is None
+    pub plan: Option<ExecutionPlan>,
+}
+
+/// Request to toggle automatic start of ready tasks
+#[derive(Deserialize, TS, JsonSchema)]
+pub struct SetAutoStartRequest {
+    pub enabled: bool,
+}
+
+/// Request to set how long a task may run before it is reported timed out
+#[derive(Deserialize, TS, JsonSchema)]
+pub struct SetTaskTimeoutRequest {
+    /// `null` disables the timeout check
+    pub timeout_secs: Option<u32>,
+}
+
+/// Query parameters for the transition validation endpoints
+#[derive(Debug, Deserialize, TS, JsonSchema)]
+pub struct TransitionLanguageQuery {
+    /// Reason language; falls back to the Accept-Language header, then English, which these
+    /// endpoints returned before reasons were localized
+    pub lang: Option<ExplanationLanguage>,
+}
+
 /// Request to validate a task transition
-#[derive(Deserialize, TS)]
+#[derive(Deserialize, TS, JsonSchema)]
 pub struct ValidateTransitionRequest {
     pub task_id: Uuid,
     pub new_status: String,
+    /// Force through a transition the state machine would reject (manual intervention)
+    #[serde(default)]
+    pub allow_override: bool,
+}
+
+/// Request to move several tasks to the same status
+#[derive(Deserialize, TS, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkTransitionRequest {
+    pub task_ids: Vec<Uuid>,
+    pub new_status: String,
+    /// Also apply transitions that need confirmation, e.g. starting a task with open dependencies
+    #[serde(default)]
+    pub confirm: bool,
+}
+
+/// Request to forecast which tasks completing a set of tasks would unblock
+#[derive(Deserialize, TS, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ForecastUnblockedRequest {
+    pub task_ids: Vec<Uuid>,
 }
 
 /// Get orchestrator state and execution plan for a project
 pub async fn get_orchestrator_state(
     Extension(project): Extension<Project>,
     State(deployment): State<DeploymentImpl>,
-) -> Result<ResponseJson<ApiResponse<OrchestratorStateResponse>>, ApiError> {
+    Query(query): Query<OrchestratorStateQuery>,
+    headers: HeaderMap,
+) -> Result<Response, ApiError> {
     let manager = get_orchestrator_manager().await;
     let orchestrator = manager.get_or_create(project.id).await;
+    let pool = &deployment.db().pool;
 
     let state = orchestrator.get_state().await;
-    let plan = orchestrator
-        .build_plan(&deployment.db().pool)
-        .await
-        .map_err(|e| ApiError::InternalServer(e.to_string()))?;
+    let auto_start = orchestrator.is_auto_start().await;
+    let plan = match (query.mode, query.genre_id) {
+        (PlanMode::Capped, genre_id) => orchestrator.build_capped_plan(pool, genre_id).await,
+        (PlanMode::Levels, Some(genre_id)) => {
+            orchestrator.build_plan_for_genre(pool, genre_id).await
+        }
+        (PlanMode::Levels, None) => orchestrator.build_plan(pool).await,
+    }
+    .map_err(orchestrator_error)?;
+    let view = match &query.assignee {
+        Some(assignee) => filter_plan_by_assignee(&plan, assignee),
+        None => plan.clone(),
+    };
+    let plan = if query.compact {
+        compact_levels(&view, &plan, orchestrator.max_parallel_tasks())
+    } else {
+        view
+    };
 
-    Ok(ResponseJson(ApiResponse::success(OrchestratorStateResponse {
-        state,
-        plan,
-    })))
+    etag::conditional_json(
+        &headers,
+        &ApiResponse::success(OrchestratorStateResponse {
+            state,
+            auto_start,
+            plan,
+        }),
+    )
 }
 
 /// Start the orchestrator for a project
@@ -82,15 +281,17 @@ pub async fn start_orchestrator(
         .map_err(|e| ApiError::BadRequest(e.to_string()))?;
 
     let state = orchestrator.get_state().await;
+    let auto_start = orchestrator.is_auto_start().await;
     let plan = orchestrator
         .build_plan(&deployment.db().pool)
         .await
-        .map_err(|e| ApiError::InternalServer(e.to_string()))?;
+        .map_err(orchestrator_error)?;
 
     tracing::info!("Orchestrator started for project {}", project.id);
 
     Ok(ResponseJson(ApiResponse::success(OrchestratorStateResponse {
         state,
+        auto_start,
         plan,
     })))
 }
@@ -109,15 +310,17 @@ pub async fn pause_orchestrator(
         .map_err(|e| ApiError::BadRequest(e.to_string()))?;
 
     let state = orchestrator.get_state().await;
+    let auto_start = orchestrator.is_auto_start().await;
     let plan = orchestrator
         .build_plan(&deployment.db().pool)
         .await
-        .map_err(|e| ApiError::InternalServer(e.to_string()))?;
+        .map_err(orchestrator_error)?;
 
     tracing::info!("Orchestrator paused for project {}", project.id);
 
     Ok(ResponseJson(ApiResponse::success(OrchestratorStateResponse {
         state,
+        auto_start,
         plan,
     })))
 }
@@ -136,15 +339,17 @@ pub async fn resume_orchestrator(
         .map_err(|e| ApiError::BadRequest(e.to_string()))?;
 
     let state = orchestrator.get_state().await;
+    let auto_start = orchestrator.is_auto_start().await;
     let plan = orchestrator
         .build_plan(&deployment.db().pool)
         .await
-        .map_err(|e| ApiError::InternalServer(e.to_string()))?;
+        .map_err(orchestrator_error)?;
 
     tracing::info!("Orchestrator resumed for project {}", project.id);
 
     Ok(ResponseJson(ApiResponse::success(OrchestratorStateResponse {
         state,
+        auto_start,
         plan,
     })))
 }
@@ -163,74 +368,565 @@ pub async fn stop_orchestrator(
         .map_err(|e| ApiError::BadRequest(e.to_string()))?;
 
     let state = orchestrator.get_state().await;
+    let auto_start = orchestrator.is_auto_start().await;
     let plan = orchestrator
         .build_plan(&deployment.db().pool)
         .await
-        .map_err(|e| ApiError::InternalServer(e.to_string()))?;
+        .map_err(orchestrator_error)?;
 
     tracing::info!("Orchestrator stopped for project {}", project.id);
 
     Ok(ResponseJson(ApiResponse::success(OrchestratorStateResponse {
         state,
+        auto_start,
+        plan,
+    })))
+}
+
+/// Reset the orchestrator for a project, discarding all in-memory state
+pub async fn reset_orchestrator(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<OrchestratorStateResponse>>, ApiError> {
+    let manager = get_orchestrator_manager().await;
+    let orchestrator = manager.reset(project.id).await;
+
+    let state = orchestrator.get_state().await;
+    let auto_start = orchestrator.is_auto_start().await;
+    let plan = orchestrator
+        .build_plan(&deployment.db().pool)
+        .await
+        .map_err(orchestrator_error)?;
+
+    tracing::info!("Orchestrator reset for project {}", project.id);
+
+    Ok(ResponseJson(ApiResponse::success(OrchestratorStateResponse {
+        state,
+        auto_start,
+        plan,
+    })))
+}
+
+/// Enable or disable auto-start of ready tasks for a project
+pub async fn set_auto_start(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<SetAutoStartRequest>,
+) -> Result<ResponseJson<ApiResponse<OrchestratorStateResponse>>, ApiError> {
+    let manager = get_orchestrator_manager().await;
+    let orchestrator = manager.get_or_create(project.id).await;
+
+    orchestrator.set_auto_start(payload.enabled).await;
+
+    let state = orchestrator.get_state().await;
+    let auto_start = orchestrator.is_auto_start().await;
+    let plan = orchestrator
+        .build_plan(&deployment.db().pool)
+        .await
+        .map_err(orchestrator_error)?;
+
+    tracing::info!(
+        "Orchestrator auto-start {} for project {}",
+        if auto_start { "enabled" } else { "disabled" },
+        project.id
+    );
+
+    Ok(ResponseJson(ApiResponse::success(OrchestratorStateResponse {
+        state,
+        auto_start,
         plan,
     })))
 }
 
+/// Set how long a task may run before it is reported timed out
+pub async fn set_task_timeout(
+    Extension(project): Extension<Project>,
+    Json(payload): Json<SetTaskTimeoutRequest>,
+) -> Result<ResponseJson<ApiResponse<Option<u32>>>, ApiError> {
+    let manager = get_orchestrator_manager().await;
+    let orchestrator = manager.get_or_create(project.id).await;
+
+    orchestrator
+        .set_task_timeout_secs(payload.timeout_secs)
+        .await;
+
+    tracing::info!(
+        "Orchestrator task timeout for project {}: {}",
+        project.id,
+        payload
+            .timeout_secs
+            .map_or("disabled".to_string(), |secs| format!("{}s", secs))
+    );
+
+    Ok(ResponseJson(ApiResponse::success(payload.timeout_secs)))
+}
+
+/// Get the running tasks that exceeded the task timeout, longest running first
+/// Tasks are only reported; their status is not changed.
+pub async fn get_timed_out_tasks(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<Uuid>>>, ApiError> {
+    let manager = get_orchestrator_manager().await;
+    let orchestrator = manager.get_or_create(project.id).await;
+
+    // Rebuilding drops tasks that finished since the last plan
+    orchestrator
+        .build_plan(&deployment.db().pool)
+        .await
+        .map_err(orchestrator_error)?;
+
+    Ok(ResponseJson(ApiResponse::success(
+        orchestrator.check_timeouts().await,
+    )))
+}
+
+/// Get a metrics snapshot of the project's orchestration
+pub async fn get_orchestrator_metrics(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<OrchestratorMetrics>>, ApiError> {
+    let manager = get_orchestrator_manager().await;
+    let orchestrator = manager.get_or_create(project.id).await;
+
+    let metrics = orchestrator
+        .metrics(&deployment.db().pool)
+        .await
+        .map_err(orchestrator_error)?;
+
+    Ok(ResponseJson(ApiResponse::success(metrics)))
+}
+
+/// Get the single highest-priority task to start next, or why no task can start
+pub async fn get_next_action(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<NextAction>>, ApiError> {
+    let manager = get_orchestrator_manager().await;
+    let orchestrator = manager.get_or_create(project.id).await;
+
+    let action = orchestrator
+        .next_action(&deployment.db().pool)
+        .await
+        .map_err(orchestrator_error)?;
+
+    Ok(ResponseJson(ApiResponse::success(action)))
+}
+
+/// Get the tasks that are stuck behind other stuck tasks or have been in progress too long
+pub async fn get_at_risk_tasks(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Query(query): Query<AtRiskTasksQuery>,
+) -> Result<ResponseJson<ApiResponse<Vec<AtRiskTask>>>, ApiError> {
+    let manager = get_orchestrator_manager().await;
+    let orchestrator = manager.get_or_create(project.id).await;
+
+    let tasks = orchestrator
+        .at_risk_tasks(&deployment.db().pool, query.stale_after_secs)
+        .await
+        .map_err(orchestrator_error)?;
+
+    Ok(ResponseJson(ApiResponse::success(tasks)))
+}
+
+/// Get the orchestrator's most recent events, oldest first
+/// Lets clients catch up on what they missed before (re)subscribing to the event stream.
+pub async fn get_recent_events(
+    Extension(project): Extension<Project>,
+    Query(query): Query<RecentEventsQuery>,
+) -> Result<ResponseJson<ApiResponse<Vec<OrchestratorEvent>>>, ApiError> {
+    let manager = get_orchestrator_manager().await;
+    let orchestrator = manager.get_or_create(project.id).await;
+
+    let events = orchestrator
+        .recent_events(query.limit.unwrap_or(usize::MAX))
+        .await;
+
+    Ok(ResponseJson(ApiResponse::success(events)))
+}
+
+/// Get the current execution plan with its version
+pub async fn get_versioned_plan(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<VersionedPlan>>, ApiError> {
+    let manager = get_orchestrator_manager().await;
+    let orchestrator = manager.get_or_create(project.id).await;
+
+    let versioned = orchestrator
+        .versioned_plan(&deployment.db().pool)
+        .await
+        .map_err(orchestrator_error)?;
+
+    Ok(ResponseJson(ApiResponse::success(versioned)))
+}
+
+/// Get what changed in the execution plan since a version the client already has
+pub async fn get_plan_diff(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Query(query): Query<PlanDiffQuery>,
+) -> Result<ResponseJson<ApiResponse<PlanDiffResponse>>, ApiError> {
+    let manager = get_orchestrator_manager().await;
+    let orchestrator = manager.get_or_create(project.id).await;
+
+    let current = orchestrator
+        .versioned_plan(&deployment.db().pool)
+        .await
+        .map_err(orchestrator_error)?;
+    let diff = orchestrator
+        .plan_diff_since(query.epoch, query.since, &current.plan)
+        .await;
+
+    Ok(ResponseJson(ApiResponse::success(PlanDiffResponse {
+        epoch: current.epoch,
+        version: current.version,
+        plan: diff.is_none().then_some(current.plan),
+        diff,
+    })))
+}
+
 /// Get ready-to-execute tasks for a project
+/// Empty unless the orchestrator is running, or `preview` is set.
 pub async fn get_ready_tasks(
     Extension(project): Extension<Project>,
     State(deployment): State<DeploymentImpl>,
+    Query(query): Query<ReadyTasksQuery>,
 ) -> Result<ResponseJson<ApiResponse<Vec<Uuid>>>, ApiError> {
     let manager = get_orchestrator_manager().await;
     let orchestrator = manager.get_or_create(project.id).await;
+    let pool = &deployment.db().pool;
 
-    let ready = orchestrator
-        .get_ready_to_execute(&deployment.db().pool)
-        .await
-        .map_err(|e| ApiError::InternalServer(e.to_string()))?;
+    let ready = if query.preview {
+        orchestrator.preview_ready(pool).await
+    } else {
+        orchestrator.get_ready_to_execute(pool).await
+    }
+    .map_err(orchestrator_error)?;
 
     Ok(ResponseJson(ApiResponse::success(ready)))
 }
 
+/// Get the plan's tasks in one readiness column, e.g. just the blocked ones
+/// An unknown `readiness` value is rejected with 400 Bad Request.
+pub async fn get_tasks_by_readiness(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Query(query): Query<TasksByReadinessQuery>,
+) -> Result<ResponseJson<ApiResponse<Vec<ExecutableTask>>>, ApiError> {
+    let manager = get_orchestrator_manager().await;
+    let orchestrator = manager.get_or_create(project.id).await;
+
+    let plan = orchestrator
+        .build_plan(&deployment.db().pool)
+        .await
+        .map_err(orchestrator_error)?;
+    let tasks = orchestrator::get_tasks_by_readiness(&plan, query.readiness)
+        .into_iter()
+        .cloned()
+        .collect();
+
+    Ok(ResponseJson(ApiResponse::success(tasks)))
+}
+
+/// Forecast which tasks would become ready if the given tasks were done
+/// Nothing is written; the current plan is only simulated.
+pub async fn forecast_unblocked(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<ForecastUnblockedRequest>,
+) -> Result<ResponseJson<ApiResponse<Vec<Uuid>>>, ApiError> {
+    let manager = get_orchestrator_manager().await;
+    let orchestrator = manager.get_or_create(project.id).await;
+
+    let unblocked = orchestrator
+        .forecast_unblocked(&deployment.db().pool, &payload.task_ids)
+        .await
+        .map_err(orchestrator_error)?;
+
+    Ok(ResponseJson(ApiResponse::success(unblocked)))
+}
+
+/// Get the project's tasks as one flat dependency order.
+/// Tasks caught in a dependency cycle are listed separately in `cyclic`.
+pub async fn get_task_order(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<TopologicalOrder>>, ApiError> {
+    let pool = &deployment.db().pool;
+    let tasks = Task::find_by_project_id(pool, project.id).await?;
+    let dependencies = TaskDependency::find_by_project_id(pool, project.id).await?;
+
+    Ok(ResponseJson(ApiResponse::success(topological_order(
+        &tasks,
+        &dependencies,
+    ))))
+}
+
+/// Check the project's dependency graph for cycles, bad edges and tasks that can never start
+pub async fn validate_graph(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<GraphValidationReport>>, ApiError> {
+    let pool = &deployment.db().pool;
+    let tasks = Task::find_by_project_id(pool, project.id).await?;
+    let dependencies = TaskDependency::find_by_project_id(pool, project.id).await?;
+
+    Ok(ResponseJson(ApiResponse::success(
+        orchestrator::validate_graph(&tasks, &dependencies),
+    )))
+}
+
+/// Get a projected schedule of the plan for a Gantt chart
+/// Offsets are minutes from now; tasks without an `estimate_minutes` property use the default.
+pub async fn get_gantt(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<GanttChart>>, ApiError> {
+    let manager = get_orchestrator_manager().await;
+    let orchestrator = manager.get_or_create(project.id).await;
+
+    let chart = orchestrator
+        .gantt(&deployment.db().pool)
+        .await
+        .map_err(orchestrator_error)?;
+
+    Ok(ResponseJson(ApiResponse::success(chart)))
+}
+
+/// Language of transition reasons for a request
+fn transition_language(
+    lang: Option<ExplanationLanguage>,
+    headers: &HeaderMap,
+) -> ExplanationLanguage {
+    request_language_or(lang, headers, ExplanationLanguage::En)
+}
+
+/// Parse a requested task status, reporting unknown ones in the request's language
+fn parse_status(
+    status: &str,
+    language: ExplanationLanguage,
+) -> Result<db::models::task::TaskStatus, ApiError> {
+    status.parse().map_err(|_| {
+        ApiError::BadRequest(
+            OrchestratorMessage::InvalidStatus {
+                status: status.to_string(),
+            }
+            .render(language),
+        )
+    })
+}
+
 /// Validate a task status transition
 pub async fn validate_transition(
     Extension(project): Extension<Project>,
     State(deployment): State<DeploymentImpl>,
+    Query(query): Query<TransitionLanguageQuery>,
+    headers: HeaderMap,
     Json(payload): Json<ValidateTransitionRequest>,
 ) -> Result<ResponseJson<ApiResponse<TransitionValidation>>, ApiError> {
     let manager = get_orchestrator_manager().await;
     let orchestrator = manager.get_or_create(project.id).await;
 
-    let new_status: db::models::task::TaskStatus = payload
-        .new_status
-        .parse()
-        .map_err(|_| ApiError::BadRequest(format!("Invalid status: {}", payload.new_status)))?;
+    let language = transition_language(query.lang, &headers);
+    let new_status = parse_status(&payload.new_status, language)?;
+
+    if payload.allow_override {
+        tracing::info!(
+            "Validating transition of task {} to {} with manual override",
+            payload.task_id,
+            payload.new_status
+        );
+    }
 
     let validation = orchestrator
-        .validate_task_transition(payload.task_id, &new_status, &deployment.db().pool)
+        .validate_task_transition(
+            payload.task_id,
+            &new_status,
+            payload.allow_override,
+            &deployment.db().pool,
+        )
         .await
-        .map_err(|e| ApiError::InternalServer(e.to_string()))?;
+        .map_err(|error| localized_orchestrator_error(error, language))?
+        .localized(language);
 
     Ok(ResponseJson(ApiResponse::success(validation)))
 }
 
+/// Validate several task status transitions in one call
+pub async fn validate_transitions(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Query(query): Query<TransitionLanguageQuery>,
+    headers: HeaderMap,
+    Json(payload): Json<Vec<ValidateTransitionRequest>>,
+) -> Result<ResponseJson<ApiResponse<Vec<(Uuid, TransitionValidation)>>>, ApiError> {
+    let manager = get_orchestrator_manager().await;
+    let orchestrator = manager.get_or_create(project.id).await;
+
+    let language = transition_language(query.lang, &headers);
+    let requests = payload
+        .iter()
+        .map(|item| {
+            Ok(TransitionRequest {
+                task_id: item.task_id,
+                new_status: parse_status(&item.new_status, language)?,
+                allow_override: item.allow_override,
+            })
+        })
+        .collect::<Result<Vec<_>, ApiError>>()?;
+
+    let validations: Vec<(Uuid, TransitionValidation)> = orchestrator
+        .validate_task_transitions(&requests, &deployment.db().pool)
+        .await
+        .map_err(|error| localized_orchestrator_error(error, language))?
+        .into_iter()
+        .map(|(task_id, validation)| (task_id, validation.localized(language)))
+        .collect();
+
+    Ok(ResponseJson(ApiResponse::success(validations)))
+}
+
+/// Move several tasks to the same status, reporting the validation of each
+/// Only transitions that pass validation are applied; the rest are left unchanged.
+pub async fn bulk_transition(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Query(query): Query<TransitionLanguageQuery>,
+    headers: HeaderMap,
+    Json(payload): Json<BulkTransitionRequest>,
+) -> Result<ResponseJson<ApiResponse<Vec<BulkTransitionResult>>>, ApiError> {
+    let manager = get_orchestrator_manager().await;
+    let orchestrator = manager.get_or_create(project.id).await;
+
+    let language = transition_language(query.lang, &headers);
+    let new_status = parse_status(&payload.new_status, language)?;
+
+    let results: Vec<BulkTransitionResult> = orchestrator
+        .bulk_transition(
+            &payload.task_ids,
+            new_status,
+            payload.confirm,
+            &deployment.db().pool,
+        )
+        .await
+        .map_err(|error| localized_orchestrator_error(error, language))?
+        .into_iter()
+        .map(|result| BulkTransitionResult {
+            validation: result.validation.localized(language),
+            ..result
+        })
+        .collect();
+
+    tracing::info!(
+        "Bulk transition to {} in project {}: {} of {} tasks applied",
+        payload.new_status,
+        project.id,
+        results.iter().filter(|result| result.applied).count(),
+        results.len()
+    );
+
+    Ok(ResponseJson(ApiResponse::success(results)))
+}
+
+/// Move the tasks reported failed back to Todo; returns the restarted task IDs
+pub async fn retry_failed_tasks(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<Uuid>>>, ApiError> {
+    let manager = get_orchestrator_manager().await;
+    let orchestrator = manager.get_or_create(project.id).await;
+
+    let restarted = orchestrator
+        .retry_failed(&deployment.db().pool)
+        .await
+        .map_err(orchestrator_error)?;
+
+    tracing::info!(
+        "Restarted {} failed tasks in project {}",
+        restarted.len(),
+        project.id
+    );
+
+    Ok(ResponseJson(ApiResponse::success(restarted)))
+}
+
+/// Get the status transition rules for a project
+pub async fn get_transition_rules(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<TransitionRuleSet>>, ApiError> {
+    let manager = get_orchestrator_manager().await;
+    let orchestrator = manager.get_or_create(project.id).await;
+
+    let rules = orchestrator
+        .get_transition_rules(&deployment.db().pool)
+        .await
+        .map_err(orchestrator_error)?;
+
+    Ok(ResponseJson(ApiResponse::success(rules)))
+}
+
+/// Replace the status transition rules for a project
+/// The rules are saved with the project; sets with duplicate transitions or no way from todo
+/// to done are rejected.
+pub async fn update_transition_rules(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Query(query): Query<TransitionLanguageQuery>,
+    headers: HeaderMap,
+    Json(payload): Json<TransitionRuleSet>,
+) -> Result<ResponseJson<ApiResponse<TransitionRuleSet>>, ApiError> {
+    let manager = get_orchestrator_manager().await;
+    let orchestrator = manager.get_or_create(project.id).await;
+    let pool = &deployment.db().pool;
+
+    orchestrator
+        .set_transition_rules(pool, payload)
+        .await
+        .map_err(|error| match error {
+            OrchestratorError::InvalidTransitionRules(message) => {
+                ApiError::BadRequest(message.render(transition_language(query.lang, &headers)))
+            }
+            error => orchestrator_error(error),
+        })?;
+    let rules = orchestrator
+        .get_transition_rules(pool)
+        .await
+        .map_err(orchestrator_error)?;
+
+    tracing::info!(
+        "Updated transition rules for project {}: {} allowed transitions",
+        project.id,
+        rules.allowed.len()
+    );
+
+    Ok(ResponseJson(ApiResponse::success(rules)))
+}
+
 /// WebSocket endpoint for orchestrator events
 pub async fn stream_orchestrator_events(
     ws: WebSocketUpgrade,
     Extension(project): Extension<Project>,
-    State(_deployment): State<DeploymentImpl>,
+    State(deployment): State<DeploymentImpl>,
 ) -> impl IntoResponse {
     ws.on_upgrade(move |socket| async move {
-        if let Err(e) = handle_orchestrator_ws(socket, project.id).await {
+        let pool = deployment.db().pool.clone();
+        if let Err(e) = handle_orchestrator_ws(socket, project.id, pool).await {
             tracing::warn!("orchestrator WS closed: {}", e);
         }
     })
 }
 
-async fn handle_orchestrator_ws(socket: WebSocket, project_id: Uuid) -> anyhow::Result<()> {
+async fn handle_orchestrator_ws(
+    socket: WebSocket,
+    project_id: Uuid,
+    pool: SqlitePool,
+) -> anyhow::Result<()> {
     let manager = get_orchestrator_manager().await;
     let orchestrator = manager.get_or_create(project_id).await;
-    let mut receiver = orchestrator.subscribe();
+    let receiver = orchestrator.subscribe();
 
     let (mut sender, mut ws_receiver) = socket.split();
 
@@ -239,11 +935,54 @@ async fn handle_orchestrator_ws(socket: WebSocket, project_id: Uuid) -> anyhow::
         while let Some(Ok(_)) = ws_receiver.next().await {}
     });
 
-    // Forward orchestrator events
-    while let Ok(event) = receiver.recv().await {
-        let json = serde_json::to_string(&event)?;
-        if sender.send(Message::Text(json.into())).await.is_err() {
-            break; // client disconnected
+    let resync = || {
+        let orchestrator = Arc::clone(&orchestrator);
+        let pool = pool.clone();
+        async move { Ok(orchestrator.snapshot_events(&pool).await?) }
+    };
+    forward_orchestrator_events(&mut sender, receiver, ORCHESTRATOR_WS_HEARTBEAT, resync).await
+}
+
+/// Forward orchestrator events to the socket, pinging it every `heartbeat`
+///
+/// When the client falls behind and events are dropped, the events from `resync` are sent
+/// instead so it can rebuild its view. Returns once the client disconnects or the event
+/// channel closes.
+async fn forward_orchestrator_events<S, F, Fut>(
+    sender: &mut S,
+    mut receiver: broadcast::Receiver<OrchestratorEvent>,
+    heartbeat: Duration,
+    mut resync: F,
+) -> anyhow::Result<()>
+where
+    S: Sink<Message> + Unpin,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = anyhow::Result<Vec<OrchestratorEvent>>>,
+{
+    let mut heartbeats = tokio::time::interval(heartbeat);
+    // The first tick fires immediately; the socket was just opened, so skip it
+    heartbeats.tick().await;
+
+    loop {
+        let messages = tokio::select! {
+            event = receiver.recv() => match event {
+                Ok(event) => vec![Message::Text(serde_json::to_string(&event)?.into())],
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::debug!("orchestrator WS lagged by {} events, resyncing", skipped);
+                    resync()
+                        .await?
+                        .iter()
+                        .map(|event| Ok(Message::Text(serde_json::to_string(event)?.into())))
+                        .collect::<anyhow::Result<Vec<_>>>()?
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            },
+            _ = heartbeats.tick() => vec![Message::Ping(Vec::new().into())],
+        };
+        for message in messages {
+            if sender.send(message).await.is_err() {
+                return Ok(()); // client disconnected
+            }
         }
     }
 
@@ -262,7 +1001,7 @@ pub async fn notify_task_started(
     orchestrator
         .on_task_started(task_id, &deployment.db().pool)
         .await
-        .map_err(|e| ApiError::InternalServer(e.to_string()))?;
+        .map_err(orchestrator_error)?;
 
     Ok(ResponseJson(ApiResponse::success(())))
 }
@@ -279,17 +1018,18 @@ pub async fn notify_task_completed(
     let newly_ready = orchestrator
         .on_task_completed(task_id, &deployment.db().pool)
         .await
-        .map_err(|e| ApiError::InternalServer(e.to_string()))?;
+        .map_err(orchestrator_error)?;
 
     Ok(ResponseJson(ApiResponse::success(newly_ready)))
 }
 
 /// Notify orchestrator that a task has failed
-#[derive(Deserialize, TS)]
+#[derive(Deserialize, TS, JsonSchema)]
 pub struct TaskFailedRequest {
     pub error: String,
 }
 
+/// Report that a task failed with an error message
 pub async fn notify_task_failed(
     Extension(project): Extension<Project>,
     State(deployment): State<DeploymentImpl>,
@@ -302,7 +1042,7 @@ pub async fn notify_task_failed(
     orchestrator
         .on_task_failed(task_id, payload.error, &deployment.db().pool)
         .await
-        .map_err(|e| ApiError::InternalServer(e.to_string()))?;
+        .map_err(orchestrator_error)?;
 
     Ok(ResponseJson(ApiResponse::success(())))
 }
@@ -319,11 +1059,83 @@ pub async fn notify_task_review(
     orchestrator
         .on_task_review(task_id, &deployment.db().pool)
         .await
-        .map_err(|e| ApiError::InternalServer(e.to_string()))?;
+        .map_err(orchestrator_error)?;
 
     Ok(ResponseJson(ApiResponse::success(())))
 }
 
+/// Exclude a task from auto-scheduling without changing its status
+pub async fn skip_task(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Path(task_id): Path<Uuid>,
+    Query(query): Query<TransitionLanguageQuery>,
+    headers: HeaderMap,
+) -> Result<ResponseJson<ApiResponse<ExecutionPlan>>, ApiError> {
+    let language = transition_language(query.lang, &headers);
+    set_task_skipped(project, &deployment, task_id, true, language).await
+}
+
+/// Let a skipped task be scheduled again
+pub async fn unskip_task(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Path(task_id): Path<Uuid>,
+    Query(query): Query<TransitionLanguageQuery>,
+    headers: HeaderMap,
+) -> Result<ResponseJson<ApiResponse<ExecutionPlan>>, ApiError> {
+    let language = transition_language(query.lang, &headers);
+    set_task_skipped(project, &deployment, task_id, false, language).await
+}
+
+async fn set_task_skipped(
+    project: Project,
+    deployment: &DeploymentImpl,
+    task_id: Uuid,
+    skipped: bool,
+    language: ExplanationLanguage,
+) -> Result<ResponseJson<ApiResponse<ExecutionPlan>>, ApiError> {
+    let pool = &deployment.db().pool;
+    Task::find_by_id(pool, task_id)
+        .await?
+        .filter(|task| task.project_id == project.id)
+        .ok_or_else(|| {
+            ApiError::NotFound(OrchestratorMessage::TaskNotFound { task_id }.render(language))
+        })?;
+
+    let manager = get_orchestrator_manager().await;
+    let orchestrator = manager.get_or_create(project.id).await;
+
+    if skipped {
+        orchestrator.skip_task(task_id).await;
+    } else {
+        orchestrator.unskip_task(task_id).await;
+    }
+
+    let plan = orchestrator
+        .build_plan(pool)
+        .await
+        .map_err(orchestrator_error)?;
+
+    tracing::info!(
+        "Task {} {} scheduling in project {}",
+        task_id,
+        if skipped {
+            "excluded from"
+        } else {
+            "returned to"
+        },
+        project.id
+    );
+
+    Ok(ResponseJson(ApiResponse::success(plan)))
+}
+
+/// OpenAPI 3 document describing the orchestration routes, for third-party tooling
+pub async fn get_openapi_document() -> ResponseJson<serde_json::Value> {
+    ResponseJson(openapi::orchestration_document())
+}
+
 pub fn router(deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
     let orchestrator_router = Router::new()
         .route("/orchestrator", get(get_orchestrator_state))
@@ -331,8 +1143,30 @@ pub fn router(deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
         .route("/orchestrator/pause", post(pause_orchestrator))
         .route("/orchestrator/resume", post(resume_orchestrator))
         .route("/orchestrator/stop", post(stop_orchestrator))
+        .route("/orchestrator/reset", post(reset_orchestrator))
+        .route("/orchestrator/auto-start", put(set_auto_start))
+        .route("/orchestrator/task-timeout", put(set_task_timeout))
+        .route("/orchestrator/timeouts", get(get_timed_out_tasks))
         .route("/orchestrator/ready-tasks", get(get_ready_tasks))
+        .route("/orchestrator/next-action", get(get_next_action))
+        .route("/orchestrator/at-risk", get(get_at_risk_tasks))
+        .route("/orchestrator/tasks", get(get_tasks_by_readiness))
+        .route("/orchestrator/metrics", get(get_orchestrator_metrics))
+        .route("/orchestrator/recent-events", get(get_recent_events))
+        .route("/orchestrator/order", get(get_task_order))
+        .route("/orchestrator/gantt", get(get_gantt))
+        .route("/orchestrator/validate", get(validate_graph))
+        .route("/orchestrator/plan", get(get_versioned_plan))
+        .route("/orchestrator/plan/diff", get(get_plan_diff))
         .route("/orchestrator/validate-transition", post(validate_transition))
+        .route("/orchestrator/validate-transitions", post(validate_transitions))
+        .route("/orchestrator/bulk-transition", post(bulk_transition))
+        .route("/orchestrator/retry-failed", post(retry_failed_tasks))
+        .route("/orchestrator/forecast-unblocked", post(forecast_unblocked))
+        .route(
+            "/orchestrator/transition-rules",
+            get(get_transition_rules).put(update_transition_rules),
+        )
         .route("/orchestrator/stream/ws", get(stream_orchestrator_events))
         .route(
             "/orchestrator/tasks/{task_id}/started",
@@ -350,10 +1184,189 @@ pub fn router(deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
             "/orchestrator/tasks/{task_id}/review",
             post(notify_task_review),
         )
+        .route(
+            "/orchestrator/tasks/{task_id}/skip",
+            post(skip_task).delete(unskip_task),
+        )
         .layer(from_fn_with_state(
             deployment.clone(),
             load_project_middleware,
         ));
 
-    Router::new().nest("/projects/{id}", orchestrator_router)
+    Router::new()
+        .route("/openapi/orchestration.json", get(get_openapi_document))
+        .nest("/projects/{id}", orchestrator_router)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn no_resync() -> impl Future<Output = anyhow::Result<Vec<OrchestratorEvent>>> {
+        async { Ok(Vec::new()) }
+    }
+
+    #[tokio::test]
+    async fn test_heartbeat_on_silent_channel() {
+        let (_events, receiver) = broadcast::channel::<OrchestratorEvent>(4);
+        let mut sent: Vec<Message> = Vec::new();
+
+        // No events arrive, so only heartbeats are sent until the timeout drops the loop
+        let forward =
+            forward_orchestrator_events(&mut sent, receiver, Duration::from_millis(10), no_resync);
+        let _ = tokio::time::timeout(Duration::from_millis(35), forward).await;
+
+        assert!(!sent.is_empty());
+        assert!(
+            sent.iter()
+                .all(|message| matches!(message, Message::Ping(_)))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_forward_stops_when_channel_closes() {
+        let (events, receiver) = broadcast::channel(4);
+        events
+            .send(OrchestratorEvent::StateChanged {
+                state: OrchestratorState::Running,
+            })
+            .unwrap();
+        drop(events);
+        let mut sent: Vec<Message> = Vec::new();
+
+        forward_orchestrator_events(&mut sent, receiver, Duration::from_secs(60), no_resync)
+            .await
+            .unwrap();
+
+        assert_eq!(sent.len(), 1);
+        assert!(matches!(&sent[0], Message::Text(text) if text.as_str().contains("state_changed")));
+    }
+
+    #[tokio::test]
+    async fn test_forward_resyncs_after_lag() {
+        let (events, receiver) = broadcast::channel(1);
+        for _ in 0..3 {
+            events
+                .send(OrchestratorEvent::TaskStarted {
+                    task_id: Uuid::new_v4(),
+                })
+                .unwrap();
+        }
+        drop(events);
+        let mut sent: Vec<Message> = Vec::new();
+
+        // The first two events are dropped; a snapshot replaces them and forwarding continues
+        let resync = || async {
+            Ok(vec![OrchestratorEvent::StateChanged {
+                state: OrchestratorState::Running,
+            }])
+        };
+        forward_orchestrator_events(&mut sent, receiver, Duration::from_secs(60), resync)
+            .await
+            .unwrap();
+
+        assert_eq!(sent.len(), 2);
+        assert!(matches!(&sent[0], Message::Text(text) if text.as_str().contains("state_changed")));
+        assert!(matches!(&sent[1], Message::Text(text) if text.as_str().contains("task_started")));
+    }
+
+    #[test]
+    fn test_event_capacity_from() {
+        assert_eq!(event_capacity_from(None), DEFAULT_EVENT_CHANNEL_CAPACITY);
+        assert_eq!(event_capacity_from(Some(" 512 ".to_string())), 512);
+        assert_eq!(
+            event_capacity_from(Some("0".to_string())),
+            DEFAULT_EVENT_CHANNEL_CAPACITY
+        );
+        assert_eq!(
+            event_capacity_from(Some("lots".to_string())),
+            DEFAULT_EVENT_CHANNEL_CAPACITY
+        );
+    }
+
+    #[test]
+    fn test_tasks_by_readiness_query() {
+        let parse = |uri: &str| Query::<TasksByReadinessQuery>::try_from_uri(&uri.parse().unwrap());
+
+        for (value, expected) in [
+            ("ready", ReadinessFilter::Ready),
+            ("blocked", ReadinessFilter::Blocked),
+            ("in_progress", ReadinessFilter::InProgress),
+            ("completed", ReadinessFilter::Completed),
+        ] {
+            let Query(query) = parse(&format!("/orchestrator/tasks?readiness={}", value)).unwrap();
+            assert_eq!(query.readiness, expected);
+        }
+
+        let rejection = parse("/orchestrator/tasks?readiness=stuck").unwrap_err();
+        assert_eq!(rejection.status(), axum::http::StatusCode::BAD_REQUEST);
+        assert!(parse("/orchestrator/tasks").is_err());
+    }
+
+    #[test]
+    fn test_orchestrator_state_query_compact() {
+        let parse =
+            |uri: &str| Query::<OrchestratorStateQuery>::try_from_uri(&uri.parse().unwrap());
+
+        let Query(query) = parse("/orchestrator?compact=true&mode=capped").unwrap();
+        assert!(query.compact);
+        assert_eq!(query.mode, PlanMode::Capped);
+
+        let Query(query) = parse("/orchestrator").unwrap();
+        assert!(!query.compact);
+        assert_eq!(query.mode, PlanMode::Levels);
+    }
+
+    #[test]
+    fn test_transition_language_defaults_to_english() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(transition_language(None, &headers), ExplanationLanguage::En);
+        assert_eq!(
+            transition_language(Some(ExplanationLanguage::Ja), &headers),
+            ExplanationLanguage::Ja
+        );
+
+        headers.insert(
+            axum::http::header::ACCEPT_LANGUAGE,
+            "ja-JP".parse().unwrap(),
+        );
+        assert_eq!(transition_language(None, &headers), ExplanationLanguage::Ja);
+    }
+
+    #[test]
+    fn test_parse_status_reports_unknown_status_in_request_language() {
+        assert_eq!(
+            parse_status("inreview", ExplanationLanguage::En).unwrap(),
+            db::models::task::TaskStatus::InReview
+        );
+        let error = parse_status("finished", ExplanationLanguage::Ja).unwrap_err();
+        assert!(
+            matches!(&error, ApiError::BadRequest(message) if message == "不正なステータスです: finished")
+        );
+
+        let missing = Uuid::new_v4();
+        let error = localized_orchestrator_error(
+            OrchestratorError::TaskNotFound(missing),
+            ExplanationLanguage::Ja,
+        );
+        assert!(
+            matches!(&error, ApiError::NotFound(message) if message == &format!("タスクが見つかりません: {missing}"))
+        );
+    }
+
+    #[test]
+    fn test_orchestrator_error_reports_unknown_task_as_not_found() {
+        let missing = Uuid::new_v4();
+        let error = orchestrator_error(OrchestratorError::TaskNotFound(missing));
+        assert!(
+            matches!(&error, ApiError::NotFound(message) if message.contains(&missing.to_string()))
+        );
+        assert_eq!(
+            axum::response::IntoResponse::into_response(error).status(),
+            axum::http::StatusCode::NOT_FOUND
+        );
+
+        let error = orchestrator_error(OrchestratorError::Database(sqlx::Error::RowNotFound));
+        assert!(matches!(error, ApiError::InternalServer(_)));
+    }
 }
@@ -1,40 +1,101 @@
+use std::{collections::HashSet, time::Duration};
+
 use axum::{
     Extension, Json, Router,
     extract::{
-        Path, State,
+        Path, Query, State,
         ws::{WebSocket, WebSocketUpgrade},
     },
+    http::{HeaderMap, header},
     middleware::from_fn_with_state,
-    response::{IntoResponse, Json as ResponseJson},
-    routing::{get, put},
+    response::{IntoResponse, Json as ResponseJson, Response},
+    routing::{get, post, put},
 };
-use futures_util::{SinkExt, StreamExt, TryStreamExt};
+use chrono::{DateTime, Utc};
+use futures_util::{SinkExt, Stream, StreamExt};
 use db::models::{
+    dependency_graph::{self, DependencyGraphExport, DependencyImportReport, GraphCloneReport},
     project::Project,
-    task::Task,
-    task_dependency::{CreateTaskDependency, TaskDependency, UpdateTaskDependency},
+    task::{DagPositionUpdate, Task},
+    task_dependency::{
+        CreateTaskDependency, DependencyCreator, DependencyFilter, DependencyKind, TaskDependency,
+        UpdateTaskDependency,
+    },
 };
 use deployment::Deployment;
-use serde::Deserialize;
+use orchestrator::{
+    BlockedTask, ExplanationLanguage, OrchestratorMessage, ReadinessExplanation, StartReadiness,
+    TaskDepth, build_execution_plan, cancellation_impact, explain_readiness, get_all_ancestors,
+    get_all_descendants, get_blocked_downstream, is_transitively_blocked_by, level_assignment,
+    start_readiness,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 use ts_rs::TS;
-use utils::response::ApiResponse;
+use utils::{log_msg::LogMsg, response::ApiResponse};
 use uuid::Uuid;
 
-use crate::{DeploymentImpl, error::ApiError, middleware::load_project_middleware};
+use crate::{
+    DeploymentImpl,
+    error::ApiError,
+    etag,
+    middleware::{load_project_middleware, load_project_middleware_with_nested_param},
+    routes::orchestration,
+};
 
 /// Request body for creating a dependency
 #[derive(Debug, Deserialize, TS)]
 pub struct CreateDependencyRequest {
     pub task_id: Uuid,
     pub depends_on_task_id: Uuid,
-    pub created_by: Option<db::models::task_dependency::DependencyCreator>,
+    pub created_by: Option<DependencyCreator>,
     pub genre_id: Option<Uuid>,
+    pub kind: Option<DependencyKind>,
+    /// Short annotation drawn on the edge; blank means none
+    pub label: Option<String>,
+}
+
+/// Request body for cloning a project's task graph into another project
+#[derive(Debug, Deserialize, TS)]
+pub struct CloneGraphRequest {
+    pub target_project_id: Uuid,
 }
 
 /// Request body for updating a dependency
 #[derive(Debug, Deserialize, TS)]
 pub struct UpdateDependencyRequest {
     pub genre_id: Option<Option<Uuid>>, // Option<Option<>> to allow unsetting: None = no change, Some(None) = clear, Some(Some(id)) = set
+    /// New edge label; a blank string clears it
+    pub label: Option<Option<String>>,
+}
+
+/// Request body for changing how strictly a dependency blocks
+#[derive(Debug, Deserialize, TS)]
+pub struct UpdateDependencyKindRequest {
+    pub kind: DependencyKind,
+}
+
+/// Query parameters for listing a project's dependencies
+#[derive(Debug, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+pub struct ListDependenciesQuery {
+    /// Only return dependencies created by a user or inferred by AI
+    pub created_by: Option<DependencyCreator>,
+    /// Only return dependencies of this genre
+    pub genre_id: Option<Uuid>,
+    /// Only return dependencies created at or after this time
+    pub since: Option<DateTime<Utc>>,
+}
+
+/// Query parameters for deleting dependencies, either one task pair or all edges of a creator
+#[derive(Debug, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteDependenciesQuery {
+    pub task_id: Option<Uuid>,
+    pub depends_on_task_id: Option<Uuid>,
+    pub created_by: Option<DependencyCreator>,
+    /// Error message language; falls back to the Accept-Language header, then Japanese
+    pub lang: Option<ExplanationLanguage>,
 }
 
 /// Request body for updating task position
@@ -43,14 +104,54 @@ pub struct UpdatePositionRequest {
     pub position: i32,
 }
 
-/// Get all dependencies for tasks in a project
+/// Which side of the dependency graph to walk from a task
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize, TS)]
+#[serde(rename_all = "lowercase")]
+pub enum SubtreeDirection {
+    Ancestors,
+    Descendants,
+    #[default]
+    Both,
+}
+
+/// Query parameters for the dependency subtree endpoint
+#[derive(Debug, Deserialize, TS)]
+pub struct SubtreeQuery {
+    #[serde(default)]
+    pub direction: SubtreeDirection,
+    /// Error message language; falls back to the Accept-Language header, then Japanese
+    pub lang: Option<ExplanationLanguage>,
+}
+
+/// Transitive dependencies of a task (empty for a direction that was not requested)
+#[derive(Debug, Serialize, TS)]
+pub struct TaskSubtreeResponse {
+    pub task_id: Uuid,
+    /// Tasks this task depends on, nearest first
+    pub ancestors: Vec<TaskDepth>,
+    /// Tasks depending on this task, nearest first
+    pub descendants: Vec<TaskDepth>,
+}
+
+/// Get all dependencies for tasks in a project, optionally filtered by creator, genre or age
 pub async fn get_project_dependencies(
     Extension(project): Extension<Project>,
     State(deployment): State<DeploymentImpl>,
-) -> Result<ResponseJson<ApiResponse<Vec<TaskDependency>>>, ApiError> {
-    let dependencies =
-        TaskDependency::find_by_project_id(&deployment.db().pool, project.id).await?;
-    Ok(ResponseJson(ApiResponse::success(dependencies)))
+    Query(query): Query<ListDependenciesQuery>,
+    headers: HeaderMap,
+) -> Result<Response, ApiError> {
+    let pool = &deployment.db().pool;
+    let filter = DependencyFilter {
+        created_by: query.created_by,
+        genre_id: query.genre_id,
+        since: query.since,
+    };
+    let dependencies = if filter.is_empty() {
+        TaskDependency::find_by_project_id(pool, project.id).await?
+    } else {
+        TaskDependency::find_by_project_filtered(pool, project.id, &filter).await?
+    };
+    etag::conditional_json(&headers, &ApiResponse::success(dependencies))
 }
 
 /// WebSocket endpoint for streaming dependency updates
@@ -66,17 +167,19 @@ pub async fn stream_dependencies_ws(
     })
 }
 
+/// Window after a dependency change in which further changes are folded into one frame
+const DEPENDENCY_COALESCE_WINDOW: Duration = Duration::from_millis(100);
+
 async fn handle_dependencies_ws(
     socket: WebSocket,
     deployment: DeploymentImpl,
     project_id: uuid::Uuid,
 ) -> anyhow::Result<()> {
-    // Get the raw stream and convert LogMsg to WebSocket messages
-    let mut stream = deployment
+    let stream = deployment
         .events()
         .stream_dependencies_raw(project_id)
-        .await?
-        .map_ok(|msg| msg.to_ws_message_unchecked());
+        .await?;
+    let mut bursts = coalesce_bursts(stream, DEPENDENCY_COALESCE_WINDOW);
 
     // Split socket into sender and receiver
     let (mut sender, mut receiver) = socket.split();
@@ -84,35 +187,377 @@ async fn handle_dependencies_ws(
     // Drain (and ignore) any client->server messages so pings/pongs work
     tokio::spawn(async move { while let Some(Ok(_)) = receiver.next().await {} });
 
-    // Forward server messages
-    while let Some(item) = stream.next().await {
-        match item {
-            Ok(msg) => {
-                if sender.send(msg).await.is_err() {
-                    break; // client disconnected
+    // Forward server messages, replacing bursts of patches (e.g. a bulk import) with one snapshot
+    'forward: while let Some(mut burst) = bursts.next().await {
+        let patches = burst
+            .iter()
+            .filter(|item| matches!(item, Ok(LogMsg::JsonPatch(_))))
+            .count();
+        if patches > 1 {
+            let snapshot = deployment
+                .events()
+                .dependencies_snapshot(project_id)
+                .await?;
+            burst.retain(|item| !matches!(item, Ok(LogMsg::JsonPatch(_))));
+            burst.insert(0, Ok(snapshot));
+        }
+
+        for item in burst {
+            match item {
+                Ok(msg) => {
+                    if sender.send(msg.to_ws_message_unchecked()).await.is_err() {
+                        break 'forward; // client disconnected
+                    }
+                }
+                Err(e) => {
+                    tracing::error!("dependencies stream error: {}", e);
+                    break 'forward;
                 }
-            }
-            Err(e) => {
-                tracing::error!("dependencies stream error: {}", e);
-                break;
             }
         }
     }
     Ok(())
 }
 
+/// Group a stream into bursts. The first item after an idle period is yielded on its own right
+/// away; items arriving within `window` after a yield are collected and yielded together when
+/// the window closes.
+fn coalesce_bursts<S>(stream: S, window: Duration) -> impl Stream<Item = Vec<S::Item>>
+where
+    S: Stream + Unpin,
+{
+    let initial: Option<tokio::time::Instant> = None;
+    futures_util::stream::unfold(
+        (stream.fuse(), initial),
+        move |(mut stream, deadline)| async move {
+            let mut burst = Vec::new();
+            if let Some(deadline) = deadline {
+                while let Ok(Some(item)) = tokio::time::timeout_at(deadline, stream.next()).await {
+                    burst.push(item);
+                }
+            }
+            if burst.is_empty() {
+                // Idle: send the next item as soon as it arrives
+                burst.push(stream.next().await?);
+            }
+            Some((burst, (stream, Some(tokio::time::Instant::now() + window))))
+        },
+    )
+}
+
+/// Get the transitive dependency subtree of a task
+pub async fn get_task_subtree(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Path((_project_id, task_id)): Path<(Uuid, Uuid)>,
+    Query(query): Query<SubtreeQuery>,
+    headers: HeaderMap,
+) -> Result<ResponseJson<ApiResponse<TaskSubtreeResponse>>, ApiError> {
+    let pool = &deployment.db().pool;
+    let language = request_language(query.lang, &headers);
+
+    let task = Task::find_by_id(pool, task_id).await?.ok_or_else(|| {
+        ApiError::NotFound(OrchestratorMessage::TaskNotFound { task_id }.render(language))
+    })?;
+
+    if task.project_id != project.id {
+        return Err(ApiError::BadRequest(
+            OrchestratorMessage::TaskNotInProject.render(language),
+        ));
+    }
+
+    let dependencies = TaskDependency::find_by_project_id(pool, project.id).await?;
+
+    let ancestors = match query.direction {
+        SubtreeDirection::Ancestors | SubtreeDirection::Both => {
+            get_all_ancestors(task_id, &dependencies)
+        }
+        SubtreeDirection::Descendants => Vec::new(),
+    };
+    let descendants = match query.direction {
+        SubtreeDirection::Descendants | SubtreeDirection::Both => {
+            get_all_descendants(task_id, &dependencies)
+        }
+        SubtreeDirection::Ancestors => Vec::new(),
+    };
+
+    Ok(ResponseJson(ApiResponse::success(TaskSubtreeResponse {
+        task_id,
+        ancestors,
+        descendants,
+    })))
+}
+
+/// Get every dependency a task takes part in, both the ones it waits on and the ones waiting on it
+pub async fn get_task_dependencies(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Path((_project_id, task_id)): Path<(Uuid, Uuid)>,
+    Query(query): Query<LanguageQuery>,
+    headers: HeaderMap,
+) -> Result<ResponseJson<ApiResponse<Vec<TaskDependency>>>, ApiError> {
+    let pool = &deployment.db().pool;
+    let language = request_language(query.lang, &headers);
+
+    let task = Task::find_by_id(pool, task_id).await?.ok_or_else(|| {
+        ApiError::NotFound(OrchestratorMessage::TaskNotFound { task_id }.render(language))
+    })?;
+
+    if task.project_id != project.id {
+        return Err(ApiError::BadRequest(
+            OrchestratorMessage::TaskNotInProject.render(language),
+        ));
+    }
+
+    let dependencies = TaskDependency::find_touching(pool, task_id).await?;
+    Ok(ResponseJson(ApiResponse::success(dependencies)))
+}
+
+/// Report whether a task could be started now, and what holds it back otherwise
+pub async fn get_task_start_readiness(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Path((_project_id, task_id)): Path<(Uuid, Uuid)>,
+    Query(query): Query<LanguageQuery>,
+    headers: HeaderMap,
+) -> Result<ResponseJson<ApiResponse<StartReadiness>>, ApiError> {
+    let pool = &deployment.db().pool;
+    let language = request_language(query.lang, &headers);
+
+    let tasks = Task::find_by_project_id(pool, project.id).await?;
+    let task = tasks.iter().find(|t| t.id == task_id).ok_or_else(|| {
+        ApiError::NotFound(OrchestratorMessage::TaskNotFound { task_id }.render(language))
+    })?;
+    let dependencies = TaskDependency::find_by_project_id(pool, project.id).await?;
+
+    Ok(ResponseJson(ApiResponse::success(start_readiness(
+        task,
+        &tasks,
+        &dependencies,
+    ))))
+}
+
+/// Query parameters for the readiness explanation endpoint
+#[derive(Debug, Deserialize, TS)]
+pub struct ReadinessExplanationQuery {
+    /// Summary language; falls back to the Accept-Language header, then Japanese
+    pub lang: Option<ExplanationLanguage>,
+}
+
+/// Query parameters for endpoints whose error messages are localized
+#[derive(Debug, Deserialize, TS, JsonSchema)]
+pub struct LanguageQuery {
+    /// Message language; falls back to the Accept-Language header, then Japanese
+    pub lang: Option<ExplanationLanguage>,
+}
+
+/// Resolve the response language from an explicit `lang`, then the Accept-Language header
+pub(crate) fn request_language(
+    lang: Option<ExplanationLanguage>,
+    headers: &HeaderMap,
+) -> ExplanationLanguage {
+    request_language_or(lang, headers, ExplanationLanguage::default())
+}
+
+/// Like `request_language`, for endpoints that fall back to `fallback` instead of Japanese
+pub(crate) fn request_language_or(
+    lang: Option<ExplanationLanguage>,
+    headers: &HeaderMap,
+    fallback: ExplanationLanguage,
+) -> ExplanationLanguage {
+    lang.or_else(|| language_from_headers(headers))
+        .unwrap_or(fallback)
+}
+
+/// Pick the summary language from the most preferred Accept-Language tag we support
+///
+/// Tags are ranked by their `q` weight (1 when absent), keeping header order among equal
+/// weights; tags weighted 0 or with an unreadable weight are ignored.
+fn language_from_headers(headers: &HeaderMap) -> Option<ExplanationLanguage> {
+    let accept_language = headers.get(header::ACCEPT_LANGUAGE)?.to_str().ok()?;
+    let mut candidates: Vec<(ExplanationLanguage, f32)> = accept_language
+        .split(',')
+        .filter_map(|entry| {
+            let mut parts = entry.split(';');
+            let tag = parts.next()?.trim().to_ascii_lowercase();
+            let language = if tag.starts_with("ja") {
+                ExplanationLanguage::Ja
+            } else if tag.starts_with("en") {
+                ExplanationLanguage::En
+            } else {
+                return None;
+            };
+            let weight = match parts.find_map(|param| param.trim().strip_prefix("q=")) {
+                Some(q) => q.trim().parse::<f32>().ok()?,
+                None => 1.0,
+            };
+            (weight > 0.0).then_some((language, weight))
+        })
+        .collect();
+    // Stable, so equally weighted tags keep their header order
+    candidates.sort_by(|a, b| b.1.total_cmp(&a.1));
+    candidates.first().map(|(language, _)| *language)
+}
+
+/// Explain why a task is ready or blocked, listing each dependency and whether it is satisfied
+pub async fn get_task_readiness_explanation(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Path((_project_id, task_id)): Path<(Uuid, Uuid)>,
+    Query(query): Query<ReadinessExplanationQuery>,
+    headers: HeaderMap,
+) -> Result<ResponseJson<ApiResponse<ReadinessExplanation>>, ApiError> {
+    let pool = &deployment.db().pool;
+
+    let language = request_language(query.lang, &headers);
+    let tasks = Task::find_by_project_id(pool, project.id).await?;
+    let dependencies = TaskDependency::find_by_project_id(pool, project.id).await?;
+
+    let explanation =
+        explain_readiness(&tasks, &dependencies, task_id, language).ok_or_else(|| {
+            ApiError::NotFound(OrchestratorMessage::TaskNotFound { task_id }.render(language))
+        })?;
+    Ok(ResponseJson(ApiResponse::success(explanation)))
+}
+
+/// Query parameters for checking whether a task is blocked by another
+#[derive(Debug, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockedByQuery {
+    pub candidate_id: Uuid,
+    /// Error message language; falls back to the Accept-Language header, then Japanese
+    pub lang: Option<ExplanationLanguage>,
+}
+
+/// Report whether the candidate task blocks a task, directly or through other blocking tasks
+pub async fn get_task_blocked_by(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Path((_project_id, task_id)): Path<(Uuid, Uuid)>,
+    Query(query): Query<BlockedByQuery>,
+    headers: HeaderMap,
+) -> Result<ResponseJson<ApiResponse<bool>>, ApiError> {
+    let pool = &deployment.db().pool;
+    let language = request_language(query.lang, &headers);
+
+    let tasks = Task::find_by_project_id(pool, project.id).await?;
+    for id in [task_id, query.candidate_id] {
+        if !tasks.iter().any(|t| t.id == id) {
+            return Err(ApiError::NotFound(
+                OrchestratorMessage::TaskNotFound { task_id: id }.render(language),
+            ));
+        }
+    }
+    let dependencies = TaskDependency::find_by_project_id(pool, project.id).await?;
+
+    Ok(ResponseJson(ApiResponse::success(
+        is_transitively_blocked_by(task_id, query.candidate_id, &tasks, &dependencies),
+    )))
+}
+
+/// List the dependents that could never be completed if the task were cancelled, nearest first
+/// Lets the UI warn before a cancellation strands downstream work.
+pub async fn get_task_cancellation_impact(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Path((_project_id, task_id)): Path<(Uuid, Uuid)>,
+    Query(query): Query<LanguageQuery>,
+    headers: HeaderMap,
+) -> Result<ResponseJson<ApiResponse<Vec<Uuid>>>, ApiError> {
+    let pool = &deployment.db().pool;
+    let language = request_language(query.lang, &headers);
+
+    let tasks = Task::find_by_project_id(pool, project.id).await?;
+    if !tasks.iter().any(|t| t.id == task_id) {
+        return Err(ApiError::NotFound(
+            OrchestratorMessage::TaskNotFound { task_id }.render(language),
+        ));
+    }
+    let dependencies = TaskDependency::find_by_project_id(pool, project.id).await?;
+
+    Ok(ResponseJson(ApiResponse::success(cancellation_impact(
+        task_id,
+        &tasks,
+        &dependencies,
+    ))))
+}
+
+/// List the tasks downstream of a task that are currently blocked, nearest first
+pub async fn get_blocked_downstream_tasks(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Path((_project_id, task_id)): Path<(Uuid, Uuid)>,
+    Query(query): Query<LanguageQuery>,
+    headers: HeaderMap,
+) -> Result<ResponseJson<ApiResponse<Vec<BlockedTask>>>, ApiError> {
+    let pool = &deployment.db().pool;
+    let language = request_language(query.lang, &headers);
+
+    let tasks = Task::find_by_project_id(pool, project.id).await?;
+    if !tasks.iter().any(|t| t.id == task_id) {
+        return Err(ApiError::NotFound(
+            OrchestratorMessage::TaskNotFound { task_id }.render(language),
+        ));
+    }
+    let dependencies = TaskDependency::find_by_project_id(pool, project.id).await?;
+
+    let plan = build_execution_plan(&tasks, &dependencies);
+    Ok(ResponseJson(ApiResponse::success(get_blocked_downstream(
+        &plan, task_id,
+    ))))
+}
+
+/// Export the project's genres and dependencies as a portable JSON document
+pub async fn export_dependencies(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<DependencyGraphExport>>, ApiError> {
+    let export = DependencyGraphExport::from_project(&deployment.db().pool, project.id).await?;
+    Ok(ResponseJson(ApiResponse::success(export)))
+}
+
+/// Import an exported dependency graph, matching tasks by title
+pub async fn import_dependencies(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<DependencyGraphExport>,
+) -> Result<ResponseJson<ApiResponse<DependencyImportReport>>, ApiError> {
+    let pool = &deployment.db().pool;
+
+    let report = payload.import(pool, project.id).await?;
+
+    if report.created_dependencies > 0 {
+        orchestration::invalidate_plan(project.id).await;
+        recalculate_dag_layout(pool, project.id).await?;
+    }
+
+    tracing::info!(
+        "Imported dependencies into project {}: {} created, {} duplicates, {} cycles, {} unmatched titles",
+        project.id,
+        report.created_dependencies,
+        report.skipped_duplicates,
+        report.skipped_cycles.len(),
+        report.unmatched_titles.len()
+    );
+
+    Ok(ResponseJson(ApiResponse::success(report)))
+}
+
 /// Create a new dependency between tasks
 pub async fn create_dependency(
     Extension(project): Extension<Project>,
     State(deployment): State<DeploymentImpl>,
+    Query(query): Query<LanguageQuery>,
+    headers: HeaderMap,
     Json(payload): Json<CreateDependencyRequest>,
 ) -> Result<ResponseJson<ApiResponse<TaskDependency>>, ApiError> {
     let pool = &deployment.db().pool;
+    let language = request_language(query.lang, &headers);
 
     // 自己参照チェック
     if payload.task_id == payload.depends_on_task_id {
         return Err(ApiError::BadRequest(
-            "タスクは自分自身に依存することはできません".to_string(),
+            OrchestratorMessage::SelfDependency.render(language),
         ));
     }
 
@@ -120,16 +565,18 @@ pub async fn create_dependency(
     let task = Task::find_by_id(pool, payload.task_id)
         .await?
         .ok_or_else(|| {
-            ApiError::NotFound(format!(
-                "タスクが見つかりません: {}",
-                payload.task_id
-            ))
+            ApiError::NotFound(
+                OrchestratorMessage::TaskNotFound {
+                    task_id: payload.task_id,
+                }
+                .render(language),
+            )
         })?;
 
     // タスクがプロジェクトに属しているかチェック
     if task.project_id != project.id {
         return Err(ApiError::BadRequest(
-            "タスクはこのプロジェクトに属していません".to_string(),
+            OrchestratorMessage::TaskNotInProject.render(language),
         ));
     }
 
@@ -137,31 +584,41 @@ pub async fn create_dependency(
     let depends_on_task = Task::find_by_id(pool, payload.depends_on_task_id)
         .await?
         .ok_or_else(|| {
-            ApiError::NotFound(format!(
-                "依存先タスクが見つかりません: {}",
-                payload.depends_on_task_id
-            ))
+            ApiError::NotFound(
+                OrchestratorMessage::DependsOnTaskNotFound {
+                    task_id: payload.depends_on_task_id,
+                }
+                .render(language),
+            )
         })?;
 
     // 依存先タスクもプロジェクトに属しているかチェック
     if depends_on_task.project_id != project.id {
         return Err(ApiError::BadRequest(
-            "依存先タスクはこのプロジェクトに属していません".to_string(),
+            OrchestratorMessage::DependsOnTaskNotInProject.render(language),
         ));
     }
 
     // 重複チェック
     if TaskDependency::exists(pool, payload.task_id, payload.depends_on_task_id).await? {
         return Err(ApiError::Conflict(
-            "この依存関係は既に存在します".to_string(),
+            OrchestratorMessage::DependencyExists.render(language),
         ));
     }
 
-    // 循環依存チェック
-    if TaskDependency::would_create_cycle(pool, payload.task_id, payload.depends_on_task_id).await?
+    // 循環依存チェック（循環するタスクの経路をエラーに含める）
+    if let Some(path) =
+        TaskDependency::find_cycle_path(pool, payload.task_id, payload.depends_on_task_id).await?
     {
+        let mut titles = Vec::with_capacity(path.len() + 1);
+        for task_id in path.iter().chain(path.first()) {
+            let title = Task::find_by_id(pool, *task_id)
+                .await?
+                .map_or_else(|| task_id.to_string(), |task| task.title);
+            titles.push(title);
+        }
         return Err(ApiError::Conflict(
-            "この依存関係を追加すると循環依存が発生します".to_string(),
+            OrchestratorMessage::CycleDetected { path: titles }.render(language),
         ));
     }
 
@@ -171,9 +628,12 @@ pub async fn create_dependency(
         depends_on_task_id: payload.depends_on_task_id,
         created_by: payload.created_by,
         genre_id: payload.genre_id,
+        kind: payload.kind,
+        label: normalize_label(payload.label),
     };
 
     let dependency = TaskDependency::create(pool, &create_data).await?;
+    orchestration::invalidate_plan(project.id).await;
 
     // 依存関係作成後、プロジェクト全体のDAGレイアウトを再計算
     recalculate_dag_layout(pool, project.id).await?;
@@ -187,35 +647,47 @@ pub async fn create_dependency(
     Ok(ResponseJson(ApiResponse::success(dependency)))
 }
 
-/// Update a dependency (e.g., change its genre)
+/// Trim an edge label, treating a blank one as no label
+fn normalize_label(label: Option<String>) -> Option<String> {
+    label
+        .map(|label| label.trim().to_string())
+        .filter(|label| !label.is_empty())
+}
+
+/// Update a dependency (e.g., change its genre or label)
 pub async fn update_dependency(
     State(deployment): State<DeploymentImpl>,
     Path(dependency_id): Path<Uuid>,
+    Query(query): Query<LanguageQuery>,
+    headers: HeaderMap,
     Json(payload): Json<UpdateDependencyRequest>,
 ) -> Result<ResponseJson<ApiResponse<TaskDependency>>, ApiError> {
     let pool = &deployment.db().pool;
+    let language = request_language(query.lang, &headers);
 
     // 依存関係が存在するかチェック
-    TaskDependency::find_by_id(pool, dependency_id)
+    let dependency = TaskDependency::find_by_id(pool, dependency_id)
         .await?
         .ok_or_else(|| {
-            ApiError::NotFound(format!(
-                "依存関係が見つかりません: {}",
-                dependency_id
-            ))
+            ApiError::NotFound(
+                OrchestratorMessage::DependencyNotFound { dependency_id }.render(language),
+            )
         })?;
 
     // 更新実行
     let update_data = UpdateTaskDependency {
         genre_id: payload.genre_id,
+        label: payload.label.map(normalize_label),
     };
 
     let updated = TaskDependency::update(pool, dependency_id, &update_data).await?;
+    invalidate_plan_for_task(pool, dependency.task_id).await?;
 
     tracing::info!(
-        "Updated dependency {}: genre_id = {:?}",
+        "Updated dependency {}: genre_id = {:?}, label = {:?}",
         dependency_id,
-        updated.genre_id
+        updated.genre_id,
+        updated.label
     );
 
     Ok(ResponseJson(ApiResponse::success(updated)))
@@ -225,17 +697,19 @@ pub async fn update_dependency(
 pub async fn delete_dependency(
     State(deployment): State<DeploymentImpl>,
     Path(dependency_id): Path<Uuid>,
+    Query(query): Query<LanguageQuery>,
+    headers: HeaderMap,
 ) -> Result<ResponseJson<ApiResponse<()>>, ApiError> {
     let pool = &deployment.db().pool;
+    let language = request_language(query.lang, &headers);
 
     // 依存関係が存在するかチェック
     let dependency = TaskDependency::find_by_id(pool, dependency_id)
         .await?
         .ok_or_else(|| {
-            ApiError::NotFound(format!(
-                "依存関係が見つかりません: {}",
-                dependency_id
-            ))
+            ApiError::NotFound(
+                OrchestratorMessage::DependencyNotFound { dependency_id }.render(language),
+            )
         })?;
 
     // 削除実行
@@ -243,9 +717,10 @@ pub async fn delete_dependency(
 
     if rows_affected == 0 {
         return Err(ApiError::NotFound(
-            "依存関係の削除に失敗しました".to_string(),
+            OrchestratorMessage::DependencyDeleteFailed.render(language),
         ));
     }
+    invalidate_plan_for_task(pool, dependency.task_id).await?;
 
     tracing::info!(
         "Deleted dependency {}: task {} no longer depends on task {}",
@@ -257,157 +732,292 @@ pub async fn delete_dependency(
     Ok(ResponseJson(ApiResponse::success(())))
 }
 
-/// Update task position
-pub async fn update_task_position(
+/// Change a dependency's kind in place, keeping its creator and creation time
+pub async fn update_dependency_kind(
+    Extension(project): Extension<Project>,
     State(deployment): State<DeploymentImpl>,
-    Path(task_id): Path<Uuid>,
-    Json(payload): Json<UpdatePositionRequest>,
-) -> Result<ResponseJson<ApiResponse<Task>>, ApiError> {
+    Path((_project_id, dependency_id)): Path<(Uuid, Uuid)>,
+    Query(query): Query<LanguageQuery>,
+    headers: HeaderMap,
+    Json(payload): Json<UpdateDependencyKindRequest>,
+) -> Result<ResponseJson<ApiResponse<TaskDependency>>, ApiError> {
     let pool = &deployment.db().pool;
+    let language = request_language(query.lang, &headers);
+    let not_found = || {
+        ApiError::NotFound(
+            OrchestratorMessage::DependencyNotFound { dependency_id }.render(language),
+        )
+    };
 
-    // タスク存在チェック
-    Task::find_by_id(pool, task_id)
+    let dependency = TaskDependency::find_by_id(pool, dependency_id)
         .await?
-        .ok_or_else(|| {
-            ApiError::NotFound(format!("タスクが見つかりません: {}", task_id))
+        .ok_or_else(not_found)?;
+    let task = Task::find_by_id(pool, dependency.task_id)
+        .await?
+        .ok_or_else(not_found)?;
+    if task.project_id != project.id {
+        return Err(not_found());
+    }
+
+    let updated = TaskDependency::update_kind(pool, dependency_id, payload.kind).await?;
+
+    // 前提タスクの現在の状態でブロック判定が変わる場合のみ計画とレイアウトを更新
+    let affects_blocking = match Task::find_by_id(pool, dependency.depends_on_task_id).await? {
+        Some(prerequisite) => {
+            dependency.kind.is_satisfied_by(&prerequisite.status)
+                != updated.kind.is_satisfied_by(&prerequisite.status)
+        }
+        None => true,
+    };
+    if affects_blocking {
+        orchestration::invalidate_plan(project.id).await;
+        recalculate_dag_layout(pool, project.id).await?;
+    }
+
+    tracing::info!(
+        "Changed dependency {} kind from {} to {}",
+        dependency_id,
+        dependency.kind,
+        updated.kind
+    );
+
+    Ok(ResponseJson(ApiResponse::success(updated)))
+}
+
+/// Delete dependencies within a project, either the edge between a task pair or every edge
+/// with a given creator. Returns the number of deleted dependencies.
+pub async fn delete_project_dependencies(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Query(query): Query<DeleteDependenciesQuery>,
+    headers: HeaderMap,
+) -> Result<ResponseJson<ApiResponse<u64>>, ApiError> {
+    let pool = &deployment.db().pool;
+    let language = request_language(query.lang, &headers);
+
+    match (query.task_id, query.depends_on_task_id, query.created_by) {
+        (Some(task_id), Some(depends_on_task_id), None) => {
+            delete_dependency_pair(pool, project.id, task_id, depends_on_task_id, language).await
+        }
+        (None, None, Some(created_by)) => {
+            let rows_affected =
+                TaskDependency::delete_by_project_and_creator(pool, project.id, created_by.clone())
+                    .await?;
+
+            if rows_affected > 0 {
+                orchestration::invalidate_plan(project.id).await;
+                recalculate_dag_layout(pool, project.id).await?;
+            }
+
+            tracing::info!(
+                "Deleted {} dependencies created by {} in project {}",
+                rows_affected,
+                created_by,
+                project.id
+            );
+
+            Ok(ResponseJson(ApiResponse::success(rows_affected)))
+        }
+        _ => Err(ApiError::BadRequest(
+            OrchestratorMessage::MissingDeleteFilter.render(language),
+        )),
+    }
+}
+
+async fn delete_dependency_pair(
+    pool: &sqlx::SqlitePool,
+    project_id: Uuid,
+    task_id: Uuid,
+    depends_on_task_id: Uuid,
+    language: ExplanationLanguage,
+) -> Result<ResponseJson<ApiResponse<u64>>, ApiError> {
+    // 両タスクがプロジェクトに属しているかチェック
+    for id in [task_id, depends_on_task_id] {
+        let task = Task::find_by_id(pool, id).await?.ok_or_else(|| {
+            ApiError::NotFound(OrchestratorMessage::TaskNotFound { task_id: id }.render(language))
         })?;
+        if task.project_id != project_id {
+            return Err(ApiError::BadRequest(
+                OrchestratorMessage::TaskNotInProject.render(language),
+            ));
+        }
+    }
 
-    // 位置を更新
-    let updated_task = Task::update_position(pool, task_id, payload.position).await?;
+    let rows_affected =
+        TaskDependency::delete_dependency(pool, task_id, depends_on_task_id).await?;
+    if rows_affected == 0 {
+        return Err(ApiError::NotFound(
+            OrchestratorMessage::DependencyPairNotFound.render(language),
+        ));
+    }
+
+    orchestration::invalidate_plan(project_id).await;
+    recalculate_dag_layout(pool, project_id).await?;
 
     tracing::info!(
-        "Updated task {} position to {}",
+        "Deleted dependency: task {} no longer depends on task {}",
         task_id,
-        payload.position
+        depends_on_task_id
     );
 
-    Ok(ResponseJson(ApiResponse::success(updated_task)))
+    Ok(ResponseJson(ApiResponse::success(rows_affected)))
+}
+
+/// 依存関係の変更後、タスクが属するプロジェクトの実行計画キャッシュを破棄
+async fn invalidate_plan_for_task(pool: &sqlx::SqlitePool, task_id: Uuid) -> Result<(), ApiError> {
+    if let Some(task) = Task::find_by_id(pool, task_id).await? {
+        orchestration::invalidate_plan(task.project_id).await;
+    }
+    Ok(())
 }
 
-/// Recalculate DAG layout for all tasks with dependencies in a project
-/// Uses topological sort to arrange tasks in a clean hierarchical layout
+/// Re-run the DAG layout of the tasks that take part in a dependency
+/// Levels come from the orchestrator's leveling, so the layout's columns match the plan's levels.
 async fn recalculate_dag_layout(
     pool: &sqlx::SqlitePool,
     project_id: Uuid,
-) -> Result<(), sqlx::Error> {
-    use std::collections::{HashMap, HashSet, VecDeque};
+) -> Result<u64, ApiError> {
+    let dependencies = TaskDependency::find_by_project_id(pool, project_id).await?;
+    let nodes: HashSet<Uuid> = dependencies
+        .iter()
+        .flat_map(|dep| [dep.task_id, dep.depends_on_task_id])
+        .collect();
+    let levels = level_assignment(
+        nodes,
+        dependencies
+            .iter()
+            .map(|dep| (dep.task_id, dep.depends_on_task_id)),
+    );
 
-    // レイアウト定数
-    const NODE_WIDTH: f64 = 220.0;
-    const NODE_HEIGHT: f64 = 80.0;
-    const HORIZONTAL_SPACING: f64 = 120.0;
-    const VERTICAL_SPACING: f64 = 40.0;
+    Ok(dependency_graph::apply_layout(pool, project_id, &levels).await?)
+}
 
-    // プロジェクト内の全タスクと依存関係を取得
-    let tasks = Task::find_by_project_id(pool, project_id).await?;
-    let dependencies = TaskDependency::find_by_project_id(pool, project_id).await?;
+/// Update task position
+pub async fn update_task_position(
+    State(deployment): State<DeploymentImpl>,
+    Path(task_id): Path<Uuid>,
+    Query(query): Query<LanguageQuery>,
+    headers: HeaderMap,
+    Json(payload): Json<UpdatePositionRequest>,
+) -> Result<ResponseJson<ApiResponse<Task>>, ApiError> {
+    let pool = &deployment.db().pool;
+    let language = request_language(query.lang, &headers);
 
-    if dependencies.is_empty() {
-        return Ok(());
-    }
+    // タスク存在チェック
+    Task::find_by_id(pool, task_id).await?.ok_or_else(|| {
+        ApiError::NotFound(OrchestratorMessage::TaskNotFound { task_id }.render(language))
+    })?;
 
-    // 依存関係に関わるタスクIDを収集
-    let mut dag_task_ids: HashSet<Uuid> = HashSet::new();
-    for dep in &dependencies {
-        dag_task_ids.insert(dep.task_id);
-        dag_task_ids.insert(dep.depends_on_task_id);
-    }
+    // 位置を更新
+    let updated_task = Task::update_position(pool, task_id, payload.position).await?;
 
-    // タスクIDからタスクへのマップを作成
-    let task_map: HashMap<Uuid, &Task> = tasks.iter().map(|t| (t.id, t)).collect();
+    tracing::info!(
+        "Updated task {} position to {}",
+        task_id,
+        payload.position
+    );
 
-    // 依存関係グラフを構築
-    // in_degree: 各タスクへの入力エッジ数
-    // dependencies_map: タスクIDから依存先タスクIDへのマップ
-    // dependents_map: タスクIDからそのタスクに依存するタスクIDへのマップ
-    let mut in_degree: HashMap<Uuid, usize> = HashMap::new();
-    let mut dependents_map: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    Ok(ResponseJson(ApiResponse::success(updated_task)))
+}
 
-    for task_id in &dag_task_ids {
-        in_degree.insert(*task_id, 0);
-        dependents_map.insert(*task_id, Vec::new());
-    }
+/// Update the DAG positions of several tasks at once, e.g. after dragging a group of nodes
+pub async fn update_task_positions(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Query(query): Query<LanguageQuery>,
+    headers: HeaderMap,
+    Json(payload): Json<Vec<DagPositionUpdate>>,
+) -> Result<ResponseJson<ApiResponse<u64>>, ApiError> {
+    let pool = &deployment.db().pool;
+    let language = request_language(query.lang, &headers);
 
-    for dep in &dependencies {
-        *in_degree.get_mut(&dep.task_id).unwrap() += 1;
-        dependents_map
-            .get_mut(&dep.depends_on_task_id)
-            .unwrap()
-            .push(dep.task_id);
+    // 全タスクがプロジェクトに属しているかチェック
+    let project_task_ids: HashSet<Uuid> = Task::find_by_project_id(pool, project.id)
+        .await?
+        .iter()
+        .map(|t| t.id)
+        .collect();
+    if let Some(update) = payload
+        .iter()
+        .find(|u| !project_task_ids.contains(&u.task_id))
+    {
+        return Err(ApiError::BadRequest(
+            OrchestratorMessage::TaskIdNotInProject {
+                task_id: update.task_id,
+            }
+            .render(language),
+        ));
     }
 
-    // トポロジカルソート（Kahn's algorithm）でレベルを計算
-    let mut queue: VecDeque<Uuid> = VecDeque::new();
-    let mut levels: HashMap<Uuid, usize> = HashMap::new();
+    let updated = Task::update_dag_positions_bulk(pool, project.id, &payload).await?;
 
-    // 入力エッジがないタスク（ルートノード）をキューに追加
-    for (task_id, &degree) in &in_degree {
-        if degree == 0 {
-            queue.push_back(*task_id);
-            levels.insert(*task_id, 0);
-        }
-    }
+    tracing::info!(
+        "Updated DAG positions of {} tasks in project {}",
+        updated,
+        project.id
+    );
 
-    // BFSでレベルを計算
-    while let Some(task_id) = queue.pop_front() {
-        let current_level = *levels.get(&task_id).unwrap();
+    Ok(ResponseJson(ApiResponse::success(updated)))
+}
 
-        if let Some(dependents) = dependents_map.get(&task_id) {
-            for &dependent_id in dependents {
-                // 依存するタスクのレベルは、依存先の最大レベル + 1
-                let new_level = current_level + 1;
-                let existing_level = levels.entry(dependent_id).or_insert(0);
-                if new_level > *existing_level {
-                    *existing_level = new_level;
-                }
+/// Re-run the DAG layout for the whole project, e.g. after positions got corrupted
+/// Returns the number of tasks that were moved; calling it again on an unchanged graph moves none.
+pub async fn recalculate_layout(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<u64>>, ApiError> {
+    let repositioned = recalculate_dag_layout(&deployment.db().pool, project.id).await?;
+    Ok(ResponseJson(ApiResponse::success(repositioned)))
+}
 
-                // 入力エッジを減らし、0になったらキューに追加
-                let degree = in_degree.get_mut(&dependent_id).unwrap();
-                *degree -= 1;
-                if *degree == 0 {
-                    queue.push_back(dependent_id);
-                }
-            }
-        }
-    }
+/// Tasks with no dependencies and no dependents, which the DAG view leaves unpositioned
+pub async fn get_orphan_tasks(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<Task>>>, ApiError> {
+    let tasks = Task::find_orphans_by_project_id(&deployment.db().pool, project.id).await?;
+    Ok(ResponseJson(ApiResponse::success(tasks)))
+}
 
-    // レベルごとにタスクをグループ化
-    let mut level_groups: HashMap<usize, Vec<Uuid>> = HashMap::new();
-    for (task_id, level) in &levels {
-        level_groups.entry(*level).or_default().push(*task_id);
-    }
+/// Recreate the project's tasks, genres and dependencies in another project as Todo tasks
+pub async fn clone_graph(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Query(query): Query<LanguageQuery>,
+    headers: HeaderMap,
+    Json(payload): Json<CloneGraphRequest>,
+) -> Result<ResponseJson<ApiResponse<GraphCloneReport>>, ApiError> {
+    let pool = &deployment.db().pool;
+    let language = request_language(query.lang, &headers);
 
-    // 各タスクの位置を計算して更新
-    for (level, task_ids) in &level_groups {
-        let x = (*level as f64) * (NODE_WIDTH + HORIZONTAL_SPACING);
+    if payload.target_project_id == project.id {
+        return Err(ApiError::BadRequest(
+            OrchestratorMessage::CloneIntoSelf.render(language),
+        ));
+    }
+    let target = Project::find_by_id(pool, payload.target_project_id)
+        .await?
+        .ok_or_else(|| {
+            ApiError::NotFound(OrchestratorMessage::TargetProjectNotFound.render(language))
+        })?;
 
-        for (index, task_id) in task_ids.iter().enumerate() {
-            let y = (index as f64) * (NODE_HEIGHT + VERTICAL_SPACING);
+    let report = dependency_graph::clone_graph(pool, project.id, target.id).await?;
 
-            // 位置が変わった場合のみ更新
-            if let Some(task) = task_map.get(task_id) {
-                let needs_update = task.dag_position_x != Some(x) || task.dag_position_y != Some(y);
-                if needs_update {
-                    Task::update_dag_position(pool, *task_id, Some(x), Some(y)).await?;
-                    tracing::debug!(
-                        "Updated task {} position to ({}, {})",
-                        task_id,
-                        x,
-                        y
-                    );
-                }
-            }
-        }
+    orchestration::invalidate_plan(target.id).await;
+    if report.created_dependencies > 0 {
+        recalculate_dag_layout(pool, target.id).await?;
     }
 
     tracing::info!(
-        "Recalculated DAG layout for project {}: {} tasks in {} levels",
-        project_id,
-        dag_task_ids.len(),
-        level_groups.len()
+        "Cloned graph of project {} into {}: {} tasks, {} dependencies, {} cycles skipped",
+        project.id,
+        target.id,
+        report.task_ids.len(),
+        report.created_dependencies,
+        report.skipped_cycles
     );
 
-    Ok(())
+    Ok(ResponseJson(ApiResponse::success(report)))
 }
 
 pub fn router(deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
@@ -415,14 +1025,49 @@ pub fn router(deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
     let project_dependencies_router = Router::new()
         .route(
             "/dependencies",
-            get(get_project_dependencies).post(create_dependency),
+            get(get_project_dependencies)
+                .post(create_dependency)
+                .delete(delete_project_dependencies),
         )
         .route("/dependencies/stream/ws", get(stream_dependencies_ws))
+        .route("/dependencies/export", get(export_dependencies))
+        .route("/dependencies/import", post(import_dependencies))
+        .route("/dependencies/recalculate-layout", post(recalculate_layout))
+        .route("/clone-graph", post(clone_graph))
+        .route("/tasks/positions", put(update_task_positions))
+        .route("/tasks/orphans", get(get_orphan_tasks))
         .layer(from_fn_with_state(
             deployment.clone(),
             load_project_middleware,
         ));
 
+    // タスク・依存関係単位の操作（project_id と task_id / dependency_id が必要）
+    let project_task_dependencies_router = Router::new()
+        .route("/tasks/{task_id}/dependencies", get(get_task_dependencies))
+        .route("/tasks/{task_id}/subtree", get(get_task_subtree))
+        .route("/tasks/{task_id}/start-readiness", get(get_task_start_readiness))
+        .route(
+            "/tasks/{task_id}/blocked-downstream",
+            get(get_blocked_downstream_tasks),
+        )
+        .route("/tasks/{task_id}/blocked-by", get(get_task_blocked_by))
+        .route(
+            "/tasks/{task_id}/cancellation-impact",
+            get(get_task_cancellation_impact),
+        )
+        .route(
+            "/tasks/{task_id}/readiness-explanation",
+            get(get_task_readiness_explanation),
+        )
+        .route(
+            "/dependencies/{dependency_id}/kind",
+            put(update_dependency_kind),
+        )
+        .layer(from_fn_with_state(
+            deployment.clone(),
+            load_project_middleware_with_nested_param,
+        ));
+
     // 依存関係の直接操作（dependency_idのみ）
     let dependencies_router = Router::new()
         .route("/{dependency_id}", put(update_dependency).delete(delete_dependency));
@@ -432,6 +1077,7 @@ pub fn router(deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
 
     Router::new()
         .nest("/projects/{id}", project_dependencies_router)
+        .nest("/projects/{id}", project_task_dependencies_router)
         .nest("/dependencies", dependencies_router)
         .nest("/tasks", task_position_router)
 }
@@ -440,6 +1086,116 @@ pub fn router(deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
 mod tests {
     use super::*;
 
+    #[tokio::test]
+    async fn test_coalesce_bursts_folds_rapid_messages() {
+        let messages = futures_util::stream::iter(1..=5);
+
+        let bursts: Vec<Vec<i32>> = coalesce_bursts(messages, Duration::from_millis(50))
+            .collect()
+            .await;
+
+        // The first message goes out immediately and the rest of the burst as one frame
+        assert_eq!(bursts, vec![vec![1], vec![2, 3, 4, 5]]);
+    }
+
+    #[tokio::test]
+    async fn test_coalesce_bursts_forwards_after_idle() {
+        let window = Duration::from_millis(20);
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        let messages = Box::pin(futures_util::stream::unfold(rx, |mut rx| async move {
+            rx.recv().await.map(|item| (item, rx))
+        }));
+        let mut bursts = coalesce_bursts(messages, window);
+
+        tx.send(1).unwrap();
+        assert_eq!(bursts.next().await, Some(vec![1]));
+
+        tokio::time::sleep(window * 3).await;
+        tx.send(2).unwrap();
+        assert_eq!(bursts.next().await, Some(vec![2]));
+
+        drop(tx);
+        assert_eq!(bursts.next().await, None);
+    }
+
+    #[test]
+    fn test_language_from_headers() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(language_from_headers(&headers), None);
+
+        headers.insert(
+            header::ACCEPT_LANGUAGE,
+            "fr-FR, en-US;q=0.8, ja;q=0.5".parse().unwrap(),
+        );
+        assert_eq!(
+            language_from_headers(&headers),
+            Some(ExplanationLanguage::En)
+        );
+
+        headers.insert(header::ACCEPT_LANGUAGE, "ja-JP".parse().unwrap());
+        assert_eq!(
+            language_from_headers(&headers),
+            Some(ExplanationLanguage::Ja)
+        );
+    }
+
+    #[test]
+    fn test_language_from_headers_ranks_by_weight() {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::ACCEPT_LANGUAGE, "en;q=0.1, ja".parse().unwrap());
+        assert_eq!(
+            language_from_headers(&headers),
+            Some(ExplanationLanguage::Ja)
+        );
+
+        // Equal weights keep header order, and q=0 rules a language out
+        headers.insert(
+            header::ACCEPT_LANGUAGE,
+            "en;q=0.5, ja;q=0.5".parse().unwrap(),
+        );
+        assert_eq!(
+            language_from_headers(&headers),
+            Some(ExplanationLanguage::En)
+        );
+        headers.insert(header::ACCEPT_LANGUAGE, "ja;q=0, en;q=0.2".parse().unwrap());
+        assert_eq!(
+            language_from_headers(&headers),
+            Some(ExplanationLanguage::En)
+        );
+        headers.insert(header::ACCEPT_LANGUAGE, "ja;q=0".parse().unwrap());
+        assert_eq!(language_from_headers(&headers), None);
+    }
+
+    #[test]
+    fn test_request_language_renders_messages() {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::ACCEPT_LANGUAGE, "en-US".parse().unwrap());
+
+        let message = OrchestratorMessage::SelfDependency;
+        assert_eq!(
+            message.render(request_language(None, &headers)),
+            "A task cannot depend on itself"
+        );
+        assert_eq!(
+            message.render(request_language(Some(ExplanationLanguage::Ja), &headers)),
+            "タスクは自分自身に依存することはできません"
+        );
+        assert_eq!(
+            message.render(request_language(None, &HeaderMap::new())),
+            "タスクは自分自身に依存することはできません"
+        );
+    }
+
+    #[test]
+    fn test_blocked_by_query_deserialize() {
+        let json = r#"{"candidateId": "00000000-0000-0000-0000-000000000003"}"#;
+        let query: BlockedByQuery = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            query.candidate_id,
+            Uuid::parse_str("00000000-0000-0000-0000-000000000003").unwrap()
+        );
+    }
+
     #[test]
     fn test_create_dependency_request_deserialize() {
         let json = r#"{"task_id": "00000000-0000-0000-0000-000000000001", "depends_on_task_id": "00000000-0000-0000-0000-000000000002"}"#;
@@ -453,6 +1209,61 @@ mod tests {
             Uuid::parse_str("00000000-0000-0000-0000-000000000002").unwrap()
         );
         assert!(request.created_by.is_none());
+        assert!(request.label.is_none());
+    }
+
+    #[test]
+    fn test_normalize_label() {
+        assert_eq!(
+            normalize_label(Some("  schema ".to_string())),
+            Some("schema".to_string())
+        );
+        assert_eq!(normalize_label(Some("   ".to_string())), None);
+        assert_eq!(normalize_label(None), None);
+    }
+
+    #[test]
+    fn test_subtree_query_direction() {
+        let query: SubtreeQuery = serde_json::from_str(r#"{"direction": "ancestors"}"#).unwrap();
+        assert_eq!(query.direction, SubtreeDirection::Ancestors);
+
+        let query: SubtreeQuery = serde_json::from_str("{}").unwrap();
+        assert_eq!(query.direction, SubtreeDirection::Both);
+        assert!(query.lang.is_none());
+
+        let query: SubtreeQuery = serde_json::from_str(r#"{"lang": "en"}"#).unwrap();
+        assert_eq!(query.lang, Some(ExplanationLanguage::En));
+    }
+
+    #[test]
+    fn test_delete_dependencies_query_deserialize() {
+        let query: DeleteDependenciesQuery = serde_json::from_str(
+            r#"{"taskId": "00000000-0000-0000-0000-000000000001", "dependsOnTaskId": "00000000-0000-0000-0000-000000000002"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            query.task_id,
+            Some(Uuid::parse_str("00000000-0000-0000-0000-000000000001").unwrap())
+        );
+        assert_eq!(
+            query.depends_on_task_id,
+            Some(Uuid::parse_str("00000000-0000-0000-0000-000000000002").unwrap())
+        );
+        assert!(query.created_by.is_none());
+
+        let query: DeleteDependenciesQuery =
+            serde_json::from_str(r#"{"createdBy": "ai"}"#).unwrap();
+        assert_eq!(query.created_by, Some(DependencyCreator::Ai));
+        assert!(query.task_id.is_none());
+    }
+
+    #[test]
+    fn test_dag_position_updates_deserialize() {
+        let json = r#"[{"taskId": "00000000-0000-0000-0000-000000000001", "x": 10.5, "y": 20}]"#;
+        let updates: Vec<DagPositionUpdate> = serde_json::from_str(json).unwrap();
+        assert_eq!(updates.len(), 1);
+        assert_eq!(updates[0].x, 10.5);
+        assert_eq!(updates[0].y, 20.0);
     }
 
     #[test]
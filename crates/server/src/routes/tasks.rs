@@ -16,7 +16,7 @@ use db::models::{
     image::TaskImage,
     repo::{Repo, RepoError},
     task::{CreateTask, Task, TaskWithAttemptStatus, UpdateTask},
-    task_property::TaskProperty,
+    task_property::{TaskProperty, TaskPropertyChange},
     workspace::{CreateWorkspace, Workspace},
     workspace_repo::{CreateWorkspaceRepo, WorkspaceRepo},
 };
@@ -34,7 +34,7 @@ use uuid::Uuid;
 
 use crate::{
     DeploymentImpl, error::ApiError, middleware::load_task_middleware,
-    routes::task_attempts::WorkspaceRepoInput,
+    routes::{orchestration, task_attempts::WorkspaceRepoInput},
 };
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -120,6 +120,7 @@ pub async fn create_task(
     );
 
     let task = Task::create(&deployment.db().pool, &payload, id).await?;
+    orchestration::invalidate_plan(task.project_id).await;
 
     if let Some(image_ids) = &payload.image_ids {
         TaskImage::associate_many_dedup(&deployment.db().pool, task.id, image_ids).await?;
@@ -161,6 +162,7 @@ pub async fn create_task_and_start(
 
     let task_id = Uuid::new_v4();
     let task = Task::create(pool, &payload.task, task_id).await?;
+    orchestration::invalidate_plan(task.project_id).await;
 
     if let Some(image_ids) = &payload.task.image_ids {
         TaskImage::associate_many_dedup(pool, task.id, image_ids).await?;
@@ -278,6 +280,7 @@ pub async fn update_task(
         parent_workspace_id,
     )
     .await?;
+    orchestration::invalidate_plan(task.project_id).await;
 
     // Update DAG position if provided, or clear if requested
     if payload.clear_dag_position {
@@ -377,6 +380,7 @@ pub async fn delete_task(
 
     // Commit the transaction - if this fails, all changes are rolled back
     tx.commit().await?;
+    orchestration::invalidate_plan(task.project_id).await;
 
     if total_children_affected > 0 {
         tracing::info!(
@@ -476,6 +480,21 @@ pub async fn get_task_properties(
     Ok(ResponseJson(ApiResponse::success(properties)))
 }
 
+#[derive(Debug, Deserialize)]
+pub struct PropertyHistoryQuery {
+    pub name: String,
+}
+
+/// Value changes of one task property, oldest first
+pub async fn get_task_property_history(
+    Extension(task): Extension<Task>,
+    State(deployment): State<DeploymentImpl>,
+    Query(query): Query<PropertyHistoryQuery>,
+) -> Result<ResponseJson<ApiResponse<Vec<TaskPropertyChange>>>, ApiError> {
+    let history = TaskProperty::history(&deployment.db().pool, task.id, &query.name).await?;
+    Ok(ResponseJson(ApiResponse::success(history)))
+}
+
 /// Bulk fetch task properties for multiple tasks
 #[derive(Debug, Deserialize)]
 pub struct BulkTaskPropertiesQuery {
@@ -510,7 +529,8 @@ pub fn router(deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
         .route("/", put(update_task))
         .route("/", delete(delete_task))
         .route("/share", post(share_task))
-        .route("/properties", get(get_task_properties));
+        .route("/properties", get(get_task_properties))
+        .route("/properties/history", get(get_task_property_history));
 
     let task_id_router = Router::new()
         .route("/", get(get_task))
@@ -542,24 +542,7 @@ impl EventService {
         project_id: Uuid,
     ) -> Result<futures::stream::BoxStream<'static, Result<LogMsg, std::io::Error>>, EventError>
     {
-        // Get initial snapshot of dependencies
-        let dependencies =
-            TaskDependency::find_by_project_id(&self.db.pool, project_id).await?;
-
-        // Convert dependency array to object keyed by dependency ID
-        let dependencies_map: serde_json::Map<String, serde_json::Value> = dependencies
-            .into_iter()
-            .map(|dep| (dep.id.to_string(), serde_json::to_value(dep).unwrap()))
-            .collect();
-
-        let initial_patch = json!([
-            {
-                "op": "replace",
-                "path": "/dependencies",
-                "value": dependencies_map
-            }
-        ]);
-        let initial_msg = LogMsg::JsonPatch(serde_json::from_value(initial_patch).unwrap());
+        let initial_msg = self.dependencies_snapshot(project_id).await?;
 
         // Clone necessary data for the async filter
         let db_pool = self.db.pool.clone();
@@ -624,6 +607,26 @@ impl EventService {
         Ok(combined_stream)
     }
 
+    /// Patch replacing `/dependencies` with the project's current dependencies
+    pub async fn dependencies_snapshot(&self, project_id: Uuid) -> Result<LogMsg, EventError> {
+        let dependencies = TaskDependency::find_by_project_id(&self.db.pool, project_id).await?;
+
+        // Convert dependency array to object keyed by dependency ID
+        let dependencies_map: serde_json::Map<String, serde_json::Value> = dependencies
+            .into_iter()
+            .map(|dep| (dep.id.to_string(), serde_json::to_value(dep).unwrap()))
+            .collect();
+
+        let patch = json!([
+            {
+                "op": "replace",
+                "path": "/dependencies",
+                "value": dependencies_map
+            }
+        ]);
+        Ok(LogMsg::JsonPatch(serde_json::from_value(patch).unwrap()))
+    }
+
     /// Stream raw dependency genre messages for a specific project with initial snapshot
     pub async fn stream_dependency_genres_raw(
         &self,
@@ -631,7 +634,7 @@ impl EventService {
     ) -> Result<futures::stream::BoxStream<'static, Result<LogMsg, std::io::Error>>, EventError>
     {
         // Get initial snapshot of dependency genres
-        let genres = DependencyGenre::find_by_project_id(&self.db.pool, project_id).await?;
+        let genres = DependencyGenre::find_by_project_id(&self.db.pool, project_id, true).await?;
 
         // Convert genres array to object keyed by genre ID
         let genres_map: serde_json::Map<String, serde_json::Value> = genres
@@ -9,7 +9,7 @@ use serde::{de::DeserializeOwned, Deserialize};
 use thiserror::Error;
 use utils::shell::resolve_executable_path_blocking;
 
-#[derive(Debug, Error)]
+#[derive(Debug, Clone, Error)]
 pub enum GitHubGraphQLError {
     #[error("GitHub CLI (`gh`) executable not found")]
     CliNotAvailable,
@@ -39,11 +39,47 @@ struct GraphQLResponse<T> {
 }
 
 #[derive(Debug, Clone, Default)]
-pub struct GitHubGraphQL;
+pub struct GitHubGraphQL {
+    /// GitHub Enterprise hostname passed to `gh --hostname`; `None` targets github.com
+    host: Option<String>,
+}
 
 impl GitHubGraphQL {
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// Target a specific GitHub host (e.g. a GitHub Enterprise Server instance)
+    pub fn with_host(host: Option<String>) -> Self {
+        Self {
+            host: host.filter(|h| !h.trim().is_empty()),
+        }
+    }
+
+    /// The configured GitHub Enterprise hostname, if any
+    pub fn host(&self) -> Option<&str> {
+        self.host.as_deref()
+    }
+
+    /// Arguments for `gh auth status`, scoped to the configured host
+    fn auth_status_args(&self) -> Vec<String> {
+        let mut args = vec!["auth".to_string(), "status".to_string()];
+        self.push_host_args(&mut args);
+        args
+    }
+
+    /// Arguments for `gh api graphql`, scoped to the configured host
+    fn api_args(&self) -> Vec<String> {
+        let mut args = vec!["api".to_string(), "graphql".to_string()];
+        self.push_host_args(&mut args);
+        args
+    }
+
+    fn push_host_args(&self, args: &mut Vec<String>) {
+        if let Some(host) = &self.host {
+            args.push("--hostname".to_string());
+            args.push(host.clone());
+        }
     }
 
     /// Check if the GitHub CLI is available and authenticated.
@@ -51,7 +87,7 @@ impl GitHubGraphQL {
         let gh = resolve_executable_path_blocking("gh").ok_or(GitHubGraphQLError::CliNotAvailable)?;
 
         let output = Command::new(&gh)
-            .args(["auth", "status"])
+            .args(self.auth_status_args())
             .output()
             .map_err(|e| GitHubGraphQLError::QueryFailed(e.to_string()))?;
 
@@ -72,7 +108,7 @@ impl GitHubGraphQL {
         let gh = resolve_executable_path_blocking("gh").ok_or(GitHubGraphQLError::CliNotAvailable)?;
 
         let mut cmd = Command::new(&gh);
-        cmd.args(["api", "graphql"]);
+        cmd.args(self.api_args());
 
         cmd.args(["-f", &format!("query={}", query)]);
 
@@ -192,6 +228,41 @@ pub mod queries {
         }
     "#;
 
+    /// Fragment for pull request fields, matching the shape of `IssueFields`
+    pub const PULL_REQUEST_FRAGMENT: &str = r#"
+        fragment PullRequestFields on PullRequest {
+            id
+            number
+            title
+            body
+            state
+            merged
+            url
+            createdAt
+            updatedAt
+            closedAt
+            author {
+                login
+            }
+            assignees(first: 10) {
+                nodes {
+                    login
+                }
+            }
+            labels(first: 20) {
+                nodes {
+                    name
+                    color
+                }
+            }
+            milestone {
+                id
+                title
+                number
+            }
+        }
+    "#;
+
     /// Query to list projects for a user
     pub const LIST_USER_PROJECTS: &str = r#"
         query ListUserProjects($login: String!, $first: Int!, $after: String) {
@@ -256,9 +327,13 @@ pub mod queries {
                         nodes {
                             id
                             content {
+                                __typename
                                 ... on Issue {
                                     ...IssueFields
                                 }
+                                ... on PullRequest {
+                                    ...PullRequestFields
+                                }
                             }
                             fieldValues(first: 20) {
                                 nodes {
@@ -401,6 +476,74 @@ pub mod queries {
         }
     "#;
 
+    /// Query to get a repository label ID by name
+    pub const GET_LABEL_ID: &str = r#"
+        query GetLabelId($owner: String!, $repo: String!, $name: String!) {
+            repository(owner: $owner, name: $repo) {
+                label(name: $name) {
+                    id
+                }
+            }
+        }
+    "#;
+
+    /// Query to get a user ID by login
+    pub const GET_USER_ID: &str = r#"
+        query GetUserId($login: String!) {
+            user(login: $login) {
+                id
+            }
+        }
+    "#;
+
+    /// Mutation to add a label to an issue
+    pub const ADD_LABEL: &str = r#"
+        mutation AddLabel($labelableId: ID!, $labelId: ID!) {
+            addLabelsToLabelable(input: {
+                labelableId: $labelableId
+                labelIds: [$labelId]
+            }) {
+                clientMutationId
+            }
+        }
+    "#;
+
+    /// Mutation to remove a label from an issue
+    pub const REMOVE_LABEL: &str = r#"
+        mutation RemoveLabel($labelableId: ID!, $labelId: ID!) {
+            removeLabelsFromLabelable(input: {
+                labelableId: $labelableId
+                labelIds: [$labelId]
+            }) {
+                clientMutationId
+            }
+        }
+    "#;
+
+    /// Mutation to add an assignee to an issue
+    pub const ADD_ASSIGNEE: &str = r#"
+        mutation AddAssignee($assignableId: ID!, $assigneeId: ID!) {
+            addAssigneesToAssignable(input: {
+                assignableId: $assignableId
+                assigneeIds: [$assigneeId]
+            }) {
+                clientMutationId
+            }
+        }
+    "#;
+
+    /// Mutation to remove an assignee from an issue
+    pub const REMOVE_ASSIGNEE: &str = r#"
+        mutation RemoveAssignee($assignableId: ID!, $assigneeId: ID!) {
+            removeAssigneesFromAssignable(input: {
+                assignableId: $assignableId
+                assigneeIds: [$assigneeId]
+            }) {
+                clientMutationId
+            }
+        }
+    "#;
+
     /// Query to get viewer (authenticated user) info
     pub const GET_VIEWER: &str = r#"
         query GetViewer {
@@ -421,4 +564,24 @@ mod tests {
         let error = GitHubGraphQLError::QueryFailed("test error".to_string());
         assert!(error.to_string().contains("test error"));
     }
+
+    #[test]
+    fn test_host_args() {
+        let default = GitHubGraphQL::new();
+        assert_eq!(default.api_args(), vec!["api", "graphql"]);
+        assert_eq!(default.auth_status_args(), vec!["auth", "status"]);
+
+        let enterprise = GitHubGraphQL::with_host(Some("github.example.com".to_string()));
+        assert_eq!(
+            enterprise.api_args(),
+            vec!["api", "graphql", "--hostname", "github.example.com"]
+        );
+        assert_eq!(
+            enterprise.auth_status_args(),
+            vec!["auth", "status", "--hostname", "github.example.com"]
+        );
+
+        let blank = GitHubGraphQL::with_host(Some("  ".to_string()));
+        assert_eq!(blank.api_args(), vec!["api", "graphql"]);
+    }
 }
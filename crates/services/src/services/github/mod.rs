@@ -6,9 +6,10 @@
 pub mod graphql;
 pub mod monitor;
 pub mod projects;
+pub mod properties;
 pub mod sync;
 
 pub use graphql::{GitHubGraphQL, GitHubGraphQLError};
-pub use monitor::GitHubSyncMonitor;
+pub use monitor::{GitHubSyncMonitor, GitHubSyncMonitorConfig};
 pub use projects::{GitHubProjectsService, GitHubProjectsError};
 pub use sync::{GitHubSyncService, GitHubSyncError};
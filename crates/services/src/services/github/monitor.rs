@@ -5,6 +5,7 @@
 
 use std::time::Duration;
 
+use chrono::{DateTime, Utc};
 use db::{DBService, models::github_project_link::GitHubProjectLink};
 use thiserror::Error;
 use tokio::time::interval;
@@ -20,31 +21,62 @@ pub enum GitHubMonitorError {
     Database(#[from] sqlx::Error),
 }
 
+/// Scheduling settings for the GitHub sync monitor
+#[derive(Debug, Clone)]
+pub struct GitHubSyncMonitorConfig {
+    /// How often the monitor wakes up to look for links to sync
+    pub poll_interval: Duration,
+    /// Maximum number of links synced per wake-up
+    pub max_links_per_tick: usize,
+    /// A link is not synced again until this much time has passed since its last sync
+    pub min_sync_interval: Duration,
+}
+
+impl Default for GitHubSyncMonitorConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval: Duration::from_secs(300), // Check every 5 minutes
+            max_links_per_tick: 5,
+            min_sync_interval: Duration::from_secs(600),
+        }
+    }
+}
+
 /// Service to periodically sync GitHub Issues to Vibe tasks
 pub struct GitHubSyncMonitor {
     db: DBService,
-    poll_interval: Duration,
+    config: GitHubSyncMonitorConfig,
     sync_service: GitHubSyncService,
 }
 
 impl GitHubSyncMonitor {
-    /// Spawn the monitor service as a background task.
+    /// Spawn the monitor service as a background task with the default configuration.
     ///
     /// Returns a JoinHandle that can be used to await the task.
     pub async fn spawn(db: DBService) -> tokio::task::JoinHandle<()> {
+        Self::spawn_with_config(db, GitHubSyncMonitorConfig::default()).await
+    }
+
+    /// Spawn the monitor service as a background task.
+    ///
+    /// Returns a JoinHandle that can be used to await the task.
+    pub async fn spawn_with_config(
+        db: DBService,
+        config: GitHubSyncMonitorConfig,
+    ) -> tokio::task::JoinHandle<()> {
         let service = Self {
             db,
-            poll_interval: Duration::from_secs(300), // Check every 5 minutes
+            config,
             sync_service: GitHubSyncService::new(),
         };
 
         tokio::spawn(async move {
-            service.start().await;
+            service.run().await;
         })
     }
 
-    /// Start the monitoring loop.
-    async fn start(&self) {
+    /// Run the monitoring loop.
+    pub async fn run(&self) {
         // Check if GitHub CLI is available before starting
         if let Err(e) = self.sync_service.check_available() {
             warn!(
@@ -56,21 +88,21 @@ impl GitHubSyncMonitor {
 
         info!(
             "Starting GitHub sync monitor service with interval {:?}",
-            self.poll_interval
+            self.config.poll_interval
         );
 
-        let mut interval = interval(self.poll_interval);
+        let mut interval = interval(self.config.poll_interval);
 
         loop {
             interval.tick().await;
-            if let Err(e) = self.sync_all_enabled_links().await {
+            if let Err(e) = self.sync_due_links().await {
                 error!("Error syncing GitHub projects: {}", e);
             }
         }
     }
 
-    /// Sync all enabled GitHub project links.
-    async fn sync_all_enabled_links(&self) -> Result<(), GitHubMonitorError> {
+    /// Sync the enabled GitHub project links that are due, oldest first.
+    async fn sync_due_links(&self) -> Result<(), GitHubMonitorError> {
         let enabled_links = GitHubProjectLink::find_all_enabled(&self.db.pool).await?;
 
         if enabled_links.is_empty() {
@@ -78,9 +110,21 @@ impl GitHubSyncMonitor {
             return Ok(());
         }
 
-        info!("Syncing {} enabled GitHub project links", enabled_links.len());
+        let due_links = select_links_to_sync(
+            enabled_links,
+            Utc::now(),
+            self.config.min_sync_interval,
+            self.config.max_links_per_tick,
+        );
 
-        for link in enabled_links {
+        if due_links.is_empty() {
+            debug!("No GitHub links are due for sync");
+            return Ok(());
+        }
+
+        info!("Syncing {} GitHub project links", due_links.len());
+
+        for link in due_links {
             if let Err(e) = self.sync_link(&link).await {
                 error!(
                     "Error syncing GitHub link {} (project {}): {}",
@@ -99,20 +143,29 @@ impl GitHubSyncMonitor {
             link.id, link.github_project_id
         );
 
-        let result = self
-            .sync_service
+        // Links on a GitHub Enterprise host need a service scoped to that host
+        let host_service;
+        let sync_service = match &link.github_host {
+            Some(host) => {
+                host_service = GitHubSyncService::new().with_host(Some(host.clone()));
+                &host_service
+            }
+            None => &self.sync_service,
+        };
+
+        let result = sync_service
             .sync_from_github(&self.db.pool, link, link.project_id)
             .await?;
 
-        if result.items_synced > 0 {
-            info!(
-                "Synced {} items from GitHub project {} ({} created, {} updated)",
-                result.items_synced,
-                link.github_project_id,
-                result.items_created,
-                result.items_updated
-            );
-        }
+        info!(
+            "Synced {} items from GitHub project {} into project {} ({} created, {} updated, {} skipped)",
+            result.items_synced,
+            link.github_project_id,
+            link.project_id,
+            result.items_created,
+            result.items_updated,
+            result.items_skipped
+        );
 
         if !result.errors.is_empty() {
             warn!(
@@ -125,3 +178,88 @@ impl GitHubSyncMonitor {
         Ok(())
     }
 }
+
+/// Pick the links to sync on this tick: links never synced come first, then the least
+/// recently synced ones. Links synced less than `min_sync_interval` ago are skipped.
+fn select_links_to_sync(
+    mut links: Vec<GitHubProjectLink>,
+    now: DateTime<Utc>,
+    min_sync_interval: Duration,
+    max_links: usize,
+) -> Vec<GitHubProjectLink> {
+    links.retain(|link| match link.last_sync_at {
+        Some(last_sync_at) => now
+            .signed_duration_since(last_sync_at)
+            .to_std()
+            .is_ok_and(|elapsed| elapsed >= min_sync_interval),
+        None => true,
+    });
+    // `None` sorts before `Some`, so never-synced links come first
+    links.sort_by_key(|link| link.last_sync_at);
+    links.truncate(max_links);
+    links
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn create_test_link(last_sync_at: Option<DateTime<Utc>>) -> GitHubProjectLink {
+        let now = Utc::now();
+        GitHubProjectLink {
+            id: Uuid::new_v4(),
+            project_id: Uuid::new_v4(),
+            github_project_id: "PVT_test".to_string(),
+            github_owner: "owner".to_string(),
+            github_repo: None,
+            github_project_number: Some(1),
+            github_host: None,
+            label_filter: None,
+            sync_enabled: true,
+            last_sync_at,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[test]
+    fn test_select_links_oldest_first() {
+        let now = Utc::now();
+        let recent = create_test_link(Some(now - chrono::Duration::minutes(20)));
+        let never = create_test_link(None);
+        let oldest = create_test_link(Some(now - chrono::Duration::hours(3)));
+        let links = vec![recent.clone(), never.clone(), oldest.clone()];
+
+        let selected = select_links_to_sync(links, now, Duration::from_secs(600), 10);
+        let ids: Vec<Uuid> = selected.iter().map(|link| link.id).collect();
+        assert_eq!(ids, vec![never.id, oldest.id, recent.id]);
+    }
+
+    #[test]
+    fn test_select_links_skips_recently_synced() {
+        let now = Utc::now();
+        let fresh = create_test_link(Some(now - chrono::Duration::minutes(2)));
+        let stale = create_test_link(Some(now - chrono::Duration::minutes(30)));
+
+        let selected =
+            select_links_to_sync(vec![fresh, stale.clone()], now, Duration::from_secs(600), 10);
+        assert_eq!(selected.len(), 1);
+        assert_eq!(selected[0].id, stale.id);
+    }
+
+    #[test]
+    fn test_select_links_respects_limit() {
+        let now = Utc::now();
+        let links: Vec<GitHubProjectLink> = (1..=4)
+            .map(|hours| create_test_link(Some(now - chrono::Duration::hours(hours))))
+            .collect();
+        let oldest_id = links[3].id;
+        let next_oldest_id = links[2].id;
+
+        let selected = select_links_to_sync(links, now, Duration::from_secs(600), 2);
+        let ids: Vec<Uuid> = selected.iter().map(|link| link.id).collect();
+        assert_eq!(ids, vec![oldest_id, next_oldest_id]);
+    }
+}
@@ -3,23 +3,167 @@
 //! This module provides high-level operations for interacting with GitHub Projects v2,
 //! including listing projects, fetching project items (issues), and updating item fields.
 
+use std::{
+    collections::HashMap,
+    sync::{Arc, LazyLock, Mutex},
+    time::{Duration, Instant},
+};
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use ts_rs::TS;
 
-use super::graphql::{queries, GitHubGraphQL, GitHubGraphQLError};
+use super::graphql::{queries, GitHubGraphQL, GitHubGraphQLError, GraphQLError};
+
+/// Default number of nodes requested per page from paginated connections
+pub const DEFAULT_PAGE_SIZE: u32 = 50;
+
+/// GitHub rejects connection requests for more than 100 nodes
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// Clamp a requested page size to the range GitHub accepts (1..=100)
+pub fn clamp_page_size(page_size: u32) -> u32 {
+    page_size.clamp(1, MAX_PAGE_SIZE)
+}
+
+/// How long `gh` availability and viewer-login results are reused before checking again
+pub const STATUS_CACHE_TTL: Duration = Duration::from_secs(30);
+
+/// Status caches shared by every service targeting the same host (keyed by hostname, "" for github.com)
+static STATUS_CACHES: LazyLock<Mutex<HashMap<String, Arc<StatusCache>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Remembers the last result of a slow check for a fixed time
+#[derive(Debug)]
+pub struct TtlCache<T> {
+    ttl: Duration,
+    entry: Mutex<Option<(Instant, T)>>,
+}
+
+impl<T: Clone> TtlCache<T> {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entry: Mutex::new(None),
+        }
+    }
+
+    /// Return the cached value while it is fresh, otherwise run `refresh` and cache its result.
+    /// The lock is held during `refresh`, so concurrent callers share a single check.
+    pub fn get_or_refresh(&self, refresh: impl FnOnce() -> T) -> T {
+        let mut entry = self.entry.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some((checked_at, value)) = entry.as_ref()
+            && checked_at.elapsed() < self.ttl
+        {
+            return value.clone();
+        }
+        let value = refresh();
+        *entry = Some((Instant::now(), value.clone()));
+        value
+    }
+
+    /// Drop the cached value so the next call runs the check again
+    pub fn clear(&self) {
+        *self.entry.lock().unwrap_or_else(|e| e.into_inner()) = None;
+    }
+}
+
+#[derive(Debug)]
+struct StatusCache {
+    available: TtlCache<Result<(), GitHubGraphQLError>>,
+    viewer_login: TtlCache<Result<String, GitHubGraphQLError>>,
+}
+
+fn shared_status_cache(host: Option<&str>) -> Arc<StatusCache> {
+    let mut caches = STATUS_CACHES.lock().unwrap_or_else(|e| e.into_inner());
+    caches
+        .entry(host.unwrap_or_default().to_string())
+        .or_insert_with(|| {
+            Arc::new(StatusCache {
+                available: TtlCache::new(STATUS_CACHE_TTL),
+                viewer_login: TtlCache::new(STATUS_CACHE_TTL),
+            })
+        })
+        .clone()
+}
+
+/// Add the `first`/`after` pagination variables to a query's variables
+fn paged_variables(
+    mut variables: serde_json::Value,
+    page_size: u32,
+    cursor: Option<&str>,
+) -> serde_json::Value {
+    variables["first"] = serde_json::json!(clamp_page_size(page_size));
+    variables["after"] = serde_json::json!(cursor);
+    variables
+}
 
 #[derive(Debug, Error)]
 pub enum GitHubProjectsError {
     #[error(transparent)]
-    GraphQL(#[from] GitHubGraphQLError),
+    GraphQL(GitHubGraphQLError),
     #[error("Project not found: {0}")]
     ProjectNotFound(String),
     #[error("Issue not found: {0}")]
     IssueNotFound(String),
     #[error("Field not found: {0}")]
     FieldNotFound(String),
+    #[error("Access denied: {0}")]
+    AccessDenied(String),
+    #[error("Not found: {0}")]
+    NotFound(String),
+}
+
+impl From<GitHubGraphQLError> for GitHubProjectsError {
+    fn from(err: GitHubGraphQLError) -> Self {
+        let classified = match &err {
+            GitHubGraphQLError::ApiErrors(errors) => classify_graphql_errors(errors),
+            _ => None,
+        };
+        classified.unwrap_or(Self::GraphQL(err))
+    }
+}
+
+/// Map GraphQL errors that mean "no access" or "doesn't exist" to their own variants
+///
+/// GitHub reports these with a `type` of `FORBIDDEN`/`INSUFFICIENT_SCOPES` or `NOT_FOUND`,
+/// but some responses only carry the message. Access errors win when both kinds appear,
+/// since GitHub also hides resources the viewer can't see behind "could not resolve".
+fn classify_graphql_errors(errors: &[GraphQLError]) -> Option<GitHubProjectsError> {
+    let message = || {
+        errors
+            .iter()
+            .map(|e| e.message.as_str())
+            .collect::<Vec<_>>()
+            .join("; ")
+    };
+
+    if errors.iter().any(is_access_error) {
+        Some(GitHubProjectsError::AccessDenied(message()))
+    } else if errors.iter().any(is_not_found_error) {
+        Some(GitHubProjectsError::NotFound(message()))
+    } else {
+        None
+    }
+}
+
+fn is_access_error(error: &GraphQLError) -> bool {
+    match error.r#type.as_deref() {
+        Some("FORBIDDEN" | "INSUFFICIENT_SCOPES") => true,
+        Some(_) => false,
+        None => {
+            let message = error.message.to_lowercase();
+            message.contains("not accessible") || message.contains("required scopes")
+        }
+    }
+}
+
+fn is_not_found_error(error: &GraphQLError) -> bool {
+    match error.r#type.as_deref() {
+        Some(kind) => kind == "NOT_FOUND",
+        None => error.message.starts_with("Could not resolve to"),
+    }
 }
 
 /// Represents a GitHub Projects v2 project
@@ -36,7 +180,16 @@ pub struct GitHubProject {
     pub owner_login: String,
 }
 
-/// Represents a GitHub Issue
+/// Kind of content behind a project item
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+pub enum GitHubContentType {
+    #[default]
+    Issue,
+    PullRequest,
+}
+
+/// Represents a GitHub Issue, or a pull request with the same fields
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[serde(rename_all = "camelCase")]
 pub struct GitHubIssue {
@@ -44,7 +197,13 @@ pub struct GitHubIssue {
     pub number: i64,
     pub title: String,
     pub body: Option<String>,
+    /// "OPEN" or "CLOSED" for issues; pull requests can also be "MERGED"
     pub state: String,
+    #[serde(default)]
+    pub content_type: GitHubContentType,
+    /// Whether the pull request was merged; always false for issues
+    #[serde(default)]
+    pub merged: bool,
     pub url: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -199,7 +358,8 @@ struct ItemNode {
     field_values: FieldValuesConnection,
 }
 
-/// Custom deserializer that handles empty objects `{}` as None
+/// Custom deserializer that handles empty objects `{}` and content other than issues and pull
+/// requests (e.g. draft issues, which only carry `__typename`) as None
 fn deserialize_content<'de, D>(deserializer: D) -> Result<Option<IssueContent>, D::Error>
 where
     D: serde::Deserializer<'de>,
@@ -212,6 +372,14 @@ where
     match value {
         serde_json::Value::Null => Ok(None),
         serde_json::Value::Object(obj) if obj.is_empty() => Ok(None),
+        serde_json::Value::Object(obj)
+            if obj
+                .get("__typename")
+                .and_then(|t| t.as_str())
+                .is_some_and(|t| t != "Issue" && t != "PullRequest") =>
+        {
+            Ok(None)
+        }
         serde_json::Value::Object(_) => {
             // Try to deserialize as IssueContent
             serde_json::from_value(value).map(Some).map_err(D::Error::custom)
@@ -222,11 +390,15 @@ where
 
 #[derive(Debug, Deserialize)]
 struct IssueContent {
+    #[serde(rename = "__typename", default)]
+    typename: Option<String>,
     id: String,
     number: i64,
     title: String,
     body: Option<String>,
     state: String,
+    #[serde(default)]
+    merged: bool,
     url: String,
     #[serde(rename = "createdAt")]
     created_at: DateTime<Utc>,
@@ -240,6 +412,15 @@ struct IssueContent {
     milestone: Option<MilestoneNode>,
 }
 
+impl IssueContent {
+    fn content_type(&self) -> GitHubContentType {
+        match self.typename.as_deref() {
+            Some("PullRequest") => GitHubContentType::PullRequest,
+            _ => GitHubContentType::Issue,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 struct AuthorNode {
     login: String,
@@ -361,41 +542,87 @@ struct RepositoryIdNode {
     id: String,
 }
 
+#[derive(Debug, Deserialize)]
+struct LabelIdResponse {
+    repository: Option<LabelIdRepository>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LabelIdRepository {
+    label: Option<IdNode>,
+}
+
+#[derive(Debug, Deserialize)]
+struct UserIdResponse {
+    user: Option<IdNode>,
+}
+
+#[derive(Debug, Deserialize)]
+struct IdNode {
+    id: String,
+}
+
 pub struct GitHubProjectsService {
     pub graphql: GitHubGraphQL,
+    status_cache: Arc<StatusCache>,
 }
 
 impl GitHubProjectsService {
     pub fn new() -> Self {
+        Self::with_host(None)
+    }
+
+    /// Target a specific GitHub host; `None` targets github.com
+    pub fn with_host(host: Option<String>) -> Self {
+        let graphql = GitHubGraphQL::with_host(host);
+        let status_cache = shared_status_cache(graphql.host());
         Self {
-            graphql: GitHubGraphQL::new(),
+            graphql,
+            status_cache,
         }
     }
 
-    /// Check if GitHub CLI is available and authenticated
+    /// Check if GitHub CLI is available and authenticated.
+    /// The result is cached for [`STATUS_CACHE_TTL`] per host.
     pub fn check_available(&self) -> Result<(), GitHubProjectsError> {
-        self.graphql.check_available()?;
+        self.status_cache
+            .available
+            .get_or_refresh(|| self.graphql.check_available())?;
         Ok(())
     }
 
-    /// Get the authenticated user's login
+    /// Get the authenticated user's login.
+    /// The result is cached for [`STATUS_CACHE_TTL`] per host.
     pub fn get_viewer_login(&self) -> Result<String, GitHubProjectsError> {
-        let response: ViewerResponse = self.graphql.query(queries::GET_VIEWER, None)?;
-        Ok(response.viewer.login)
+        let login = self.status_cache.viewer_login.get_or_refresh(|| {
+            let response: ViewerResponse = self.graphql.query(queries::GET_VIEWER, None)?;
+            Ok(response.viewer.login)
+        })?;
+        Ok(login)
+    }
+
+    /// Forget cached availability and viewer-login results for this host
+    pub fn clear_status_cache(&self) {
+        self.status_cache.available.clear();
+        self.status_cache.viewer_login.clear();
     }
 
     /// List projects for a user
-    pub fn list_user_projects(&self, login: &str) -> Result<Vec<GitHubProject>, GitHubProjectsError> {
+    pub fn list_user_projects(
+        &self,
+        login: &str,
+        page_size: u32,
+    ) -> Result<Vec<GitHubProject>, GitHubProjectsError> {
         let full_query = format!("{}\n{}", queries::PROJECT_FRAGMENT, queries::LIST_USER_PROJECTS);
         let mut projects = Vec::new();
         let mut cursor: Option<String> = None;
 
         loop {
-            let variables = serde_json::json!({
-                "login": login,
-                "first": 50,
-                "after": cursor
-            });
+            let variables = paged_variables(
+                serde_json::json!({ "login": login }),
+                page_size,
+                cursor.as_deref(),
+            );
 
             let response: UserProjectsResponse = self.graphql.query(&full_query, Some(variables))?;
 
@@ -427,17 +654,21 @@ impl GitHubProjectsService {
     }
 
     /// List projects for an organization
-    pub fn list_org_projects(&self, login: &str) -> Result<Vec<GitHubProject>, GitHubProjectsError> {
+    pub fn list_org_projects(
+        &self,
+        login: &str,
+        page_size: u32,
+    ) -> Result<Vec<GitHubProject>, GitHubProjectsError> {
         let full_query = format!("{}\n{}", queries::PROJECT_FRAGMENT, queries::LIST_ORG_PROJECTS);
         let mut projects = Vec::new();
         let mut cursor: Option<String> = None;
 
         loop {
-            let variables = serde_json::json!({
-                "login": login,
-                "first": 50,
-                "after": cursor
-            });
+            let variables = paged_variables(
+                serde_json::json!({ "login": login }),
+                page_size,
+                cursor.as_deref(),
+            );
 
             let response: OrgProjectsResponse = self.graphql.query(&full_query, Some(variables))?;
 
@@ -473,18 +704,18 @@ impl GitHubProjectsService {
         &self,
         owner: &str,
         repo: &str,
+        page_size: u32,
     ) -> Result<Vec<GitHubProject>, GitHubProjectsError> {
         let full_query = format!("{}\n{}", queries::PROJECT_FRAGMENT, queries::LIST_REPO_PROJECTS);
         let mut projects = Vec::new();
         let mut cursor: Option<String> = None;
 
         loop {
-            let variables = serde_json::json!({
-                "owner": owner,
-                "repo": repo,
-                "first": 50,
-                "after": cursor
-            });
+            let variables = paged_variables(
+                serde_json::json!({ "owner": owner, "repo": repo }),
+                page_size,
+                cursor.as_deref(),
+            );
 
             let response: RepoProjectsResponse = self.graphql.query(&full_query, Some(variables))?;
 
@@ -519,17 +750,23 @@ impl GitHubProjectsService {
     pub fn get_project_items(
         &self,
         project_id: &str,
+        page_size: u32,
     ) -> Result<Vec<GitHubProjectItem>, GitHubProjectsError> {
-        let full_query = format!("{}\n{}", queries::ISSUE_FRAGMENT, queries::GET_PROJECT_ITEMS);
+        let full_query = format!(
+            "{}\n{}\n{}",
+            queries::ISSUE_FRAGMENT,
+            queries::PULL_REQUEST_FRAGMENT,
+            queries::GET_PROJECT_ITEMS
+        );
         let mut items = Vec::new();
         let mut cursor: Option<String> = None;
 
         loop {
-            let variables = serde_json::json!({
-                "projectId": project_id,
-                "first": 50,
-                "after": cursor
-            });
+            let variables = paged_variables(
+                serde_json::json!({ "projectId": project_id }),
+                page_size,
+                cursor.as_deref(),
+            );
 
             let response: ProjectItemsResponse = self.graphql.query(&full_query, Some(variables))?;
 
@@ -539,11 +776,13 @@ impl GitHubProjectsService {
 
             for item in node.items.nodes {
                 let issue = item.content.map(|c| GitHubIssue {
+                    content_type: c.content_type(),
                     id: c.id,
                     number: c.number,
                     title: c.title,
                     body: c.body,
                     state: c.state,
+                    merged: c.merged,
                     url: c.url,
                     created_at: c.created_at,
                     updated_at: c.updated_at,
@@ -689,6 +928,40 @@ impl GitHubProjectsService {
 
         Ok(repository.id)
     }
+
+    /// Get a repository label ID by name, or None if the label does not exist
+    pub fn get_label_id(
+        &self,
+        owner: &str,
+        repo: &str,
+        name: &str,
+    ) -> Result<Option<String>, GitHubProjectsError> {
+        let variables = serde_json::json!({
+            "owner": owner,
+            "repo": repo,
+            "name": name
+        });
+
+        let response: LabelIdResponse =
+            self.graphql.query(queries::GET_LABEL_ID, Some(variables))?;
+
+        let repository = response.repository.ok_or_else(|| {
+            GitHubProjectsError::ProjectNotFound(format!("Repository not found: {}/{}", owner, repo))
+        })?;
+
+        Ok(repository.label.map(|label| label.id))
+    }
+
+    /// Get a user ID by login, or None if the user does not exist
+    pub fn get_user_id(&self, login: &str) -> Result<Option<String>, GitHubProjectsError> {
+        let variables = serde_json::json!({
+            "login": login
+        });
+
+        let response: UserIdResponse = self.graphql.query(queries::GET_USER_ID, Some(variables))?;
+
+        Ok(response.user.map(|user| user.id))
+    }
 }
 
 impl Default for GitHubProjectsService {
@@ -699,8 +972,106 @@ impl Default for GitHubProjectsService {
 
 #[cfg(test)]
 mod tests {
+    use std::cell::Cell;
+
     use super::*;
 
+    #[test]
+    fn test_ttl_cache_reuses_result_within_ttl() {
+        let cache = TtlCache::new(Duration::from_secs(30));
+        let calls = Cell::new(0);
+        let check = || {
+            calls.set(calls.get() + 1);
+            Ok::<_, String>("octocat".to_string())
+        };
+
+        assert_eq!(cache.get_or_refresh(check), Ok("octocat".to_string()));
+        assert_eq!(cache.get_or_refresh(check), Ok("octocat".to_string()));
+        assert_eq!(calls.get(), 1);
+
+        cache.clear();
+        cache.get_or_refresh(check).unwrap();
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn test_ttl_cache_refreshes_when_stale() {
+        let cache = TtlCache::new(Duration::ZERO);
+        let calls = Cell::new(0);
+        let check = || calls.set(calls.get() + 1);
+
+        cache.get_or_refresh(check);
+        cache.get_or_refresh(check);
+        assert_eq!(calls.get(), 2);
+    }
+
+    fn graphql_error(message: &str, kind: Option<&str>) -> GraphQLError {
+        GraphQLError {
+            message: message.to_string(),
+            r#type: kind.map(str::to_string),
+            path: None,
+        }
+    }
+
+    #[test]
+    fn test_graphql_errors_are_classified() {
+        let not_found: GitHubProjectsError = GitHubGraphQLError::ApiErrors(vec![graphql_error(
+            "Could not resolve to an Organization with the login of 'missing-org'.",
+            Some("NOT_FOUND"),
+        )])
+        .into();
+        assert!(matches!(not_found, GitHubProjectsError::NotFound(_)));
+
+        let forbidden: GitHubProjectsError = GitHubGraphQLError::ApiErrors(vec![graphql_error(
+            "Resource not accessible by integration",
+            Some("FORBIDDEN"),
+        )])
+        .into();
+        assert!(matches!(forbidden, GitHubProjectsError::AccessDenied(_)));
+
+        let scopes: GitHubProjectsError = GitHubGraphQLError::ApiErrors(vec![graphql_error(
+            "Your token has not been granted the required scopes to execute this query.",
+            Some("INSUFFICIENT_SCOPES"),
+        )])
+        .into();
+        assert!(matches!(scopes, GitHubProjectsError::AccessDenied(_)));
+
+        // Untyped errors fall back to the message
+        let untyped: GitHubProjectsError = GitHubGraphQLError::ApiErrors(vec![graphql_error(
+            "Resource not accessible by personal access token",
+            None,
+        )])
+        .into();
+        assert!(matches!(untyped, GitHubProjectsError::AccessDenied(_)));
+
+        // Access errors win over not-found errors in the same response
+        let mixed: GitHubProjectsError = GitHubGraphQLError::ApiErrors(vec![
+            graphql_error(
+                "Could not resolve to a ProjectV2 with the number 3.",
+                Some("NOT_FOUND"),
+            ),
+            graphql_error("Resource not accessible by integration", Some("FORBIDDEN")),
+        ])
+        .into();
+        assert!(matches!(mixed, GitHubProjectsError::AccessDenied(_)));
+
+        let other: GitHubProjectsError = GitHubGraphQLError::ApiErrors(vec![graphql_error(
+            "Something went wrong while executing your query.",
+            None,
+        )])
+        .into();
+        assert!(matches!(
+            other,
+            GitHubProjectsError::GraphQL(GitHubGraphQLError::ApiErrors(_))
+        ));
+
+        let auth: GitHubProjectsError = GitHubGraphQLError::AuthFailed("401".to_string()).into();
+        assert!(matches!(
+            auth,
+            GitHubProjectsError::GraphQL(GitHubGraphQLError::AuthFailed(_))
+        ));
+    }
+
     #[test]
     fn test_github_project_serialization() {
         let project = GitHubProject {
@@ -717,4 +1088,61 @@ mod tests {
         let json = serde_json::to_string(&project).unwrap();
         assert!(json.contains("Test Project"));
     }
+
+    #[test]
+    fn test_item_content_detects_pull_requests() {
+        let node: ItemNode = serde_json::from_value(serde_json::json!({
+            "id": "PVTI_1",
+            "content": {
+                "__typename": "PullRequest",
+                "id": "PR_1",
+                "number": 7,
+                "title": "Add sync",
+                "body": null,
+                "state": "MERGED",
+                "merged": true,
+                "url": "https://github.com/test/repo/pull/7",
+                "createdAt": "2026-01-01T00:00:00Z",
+                "updatedAt": "2026-01-02T00:00:00Z",
+                "closedAt": "2026-01-02T00:00:00Z",
+                "author": null,
+                "assignees": { "nodes": [] },
+                "labels": { "nodes": [] },
+                "milestone": null
+            },
+            "fieldValues": { "nodes": [] }
+        }))
+        .unwrap();
+
+        let content = node.content.unwrap();
+        assert_eq!(content.content_type(), GitHubContentType::PullRequest);
+        assert!(content.merged);
+
+        let draft: ItemNode = serde_json::from_value(serde_json::json!({
+            "id": "PVTI_2",
+            "content": { "__typename": "DraftIssue" },
+            "fieldValues": { "nodes": [] }
+        }))
+        .unwrap();
+        assert!(draft.content.is_none());
+    }
+
+    #[test]
+    fn test_clamp_page_size() {
+        assert_eq!(clamp_page_size(DEFAULT_PAGE_SIZE), 50);
+        assert_eq!(clamp_page_size(250), MAX_PAGE_SIZE);
+        assert_eq!(clamp_page_size(0), 1);
+    }
+
+    #[test]
+    fn test_paged_variables_carry_clamped_first() {
+        let variables = paged_variables(serde_json::json!({ "login": "test" }), 500, Some("abc"));
+        assert_eq!(variables["login"], "test");
+        assert_eq!(variables["first"], 100);
+        assert_eq!(variables["after"], "abc");
+
+        let variables = paged_variables(serde_json::json!({ "login": "test" }), 20, None);
+        assert_eq!(variables["first"], 20);
+        assert!(variables["after"].is_null());
+    }
 }
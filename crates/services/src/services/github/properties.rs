@@ -0,0 +1,222 @@
+//! Typed access to the task properties written by GitHub sync.
+//!
+//! Sync stores labels and milestones as JSON strings and project field values as raw text.
+//! These accessors decode them so callers don't parse property strings by hand; missing or
+//! malformed values fall back to an empty default.
+
+pub use db::models::task_property::ASSIGNEES_PROPERTY;
+use db::models::task_property::TaskProperty;
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+use super::projects::{GitHubLabel, GitHubMilestone};
+
+/// Task property holding the issue's labels as a JSON array of `GitHubLabel`
+pub const LABELS_PROPERTY: &str = "labels";
+
+/// Task property holding the issue's milestone as a JSON `GitHubMilestone`
+pub const MILESTONE_PROPERTY: &str = "milestone";
+
+/// Task property holding the raw value of the GitHub Project "Status" field
+pub const STATUS_PROPERTY: &str = "github_status";
+
+/// Labels synced from GitHub, or an empty list when none are stored
+pub async fn labels(pool: &SqlitePool, task_id: Uuid) -> Result<Vec<GitHubLabel>, sqlx::Error> {
+    Ok(TaskProperty::find_json(pool, task_id, LABELS_PROPERTY)
+        .await?
+        .unwrap_or_default())
+}
+
+/// Assignee logins synced from GitHub, or an empty list when none are stored
+pub async fn assignees(pool: &SqlitePool, task_id: Uuid) -> Result<Vec<String>, sqlx::Error> {
+    Ok(TaskProperty::find_json(pool, task_id, ASSIGNEES_PROPERTY)
+        .await?
+        .unwrap_or_default())
+}
+
+/// Milestone synced from GitHub, if any
+pub async fn milestone(
+    pool: &SqlitePool,
+    task_id: Uuid,
+) -> Result<Option<GitHubMilestone>, sqlx::Error> {
+    TaskProperty::find_json(pool, task_id, MILESTONE_PROPERTY).await
+}
+
+/// GitHub Project status synced from GitHub, if any
+pub async fn github_status(
+    pool: &SqlitePool,
+    task_id: Uuid,
+) -> Result<Option<String>, sqlx::Error> {
+    let property = TaskProperty::find_by_task_and_name(pool, task_id, STATUS_PROPERTY).await?;
+    Ok(property.and_then(|property| status_value(&property)))
+}
+
+/// Field values are stored unquoted; a blank value means the field is unset
+fn status_value(property: &TaskProperty) -> Option<String> {
+    let value = property.property_value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use db::models::{
+        project::{CreateProject, Project},
+        task::{CreateTask, Task},
+        task_property::{CreateTaskProperty, PropertySource},
+    };
+
+    use super::*;
+
+    fn property(name: &str, value: &str) -> TaskProperty {
+        TaskProperty {
+            id: Uuid::new_v4(),
+            task_id: Uuid::new_v4(),
+            property_name: name.to_string(),
+            property_value: value.to_string(),
+            source: PropertySource::Github,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_labels_parse_synced_value() {
+        let stored = property(
+            LABELS_PROPERTY,
+            r#"[{"name":"bug","color":"d73a4a"},{"name":"ui","color":"0e8a16"}]"#,
+        );
+        let labels: Vec<GitHubLabel> = stored.parse_value().unwrap();
+        let names: Vec<&str> = labels.iter().map(|l| l.name.as_str()).collect();
+        assert_eq!(names, vec!["bug", "ui"]);
+        assert_eq!(labels[0].color, "d73a4a");
+
+        let malformed = property(LABELS_PROPERTY, r#"["bug", "ui"]"#);
+        assert!(malformed.parse_value::<Vec<GitHubLabel>>().is_none());
+    }
+
+    #[test]
+    fn test_milestone_parse_synced_value() {
+        let stored = property(
+            MILESTONE_PROPERTY,
+            r#"{"id":"MI_1","title":"v1.0","number":3}"#,
+        );
+        let milestone: GitHubMilestone = stored.parse_value().unwrap();
+        assert_eq!(milestone.title, "v1.0");
+        assert_eq!(milestone.number, 3);
+
+        let malformed = property(MILESTONE_PROPERTY, "v1.0");
+        assert!(malformed.parse_value::<GitHubMilestone>().is_none());
+    }
+
+    #[test]
+    fn test_status_value() {
+        assert_eq!(
+            status_value(&property(STATUS_PROPERTY, "In Progress")),
+            Some("In Progress".to_string())
+        );
+        assert_eq!(status_value(&property(STATUS_PROPERTY, "  ")), None);
+    }
+
+    /// Three tasks of a fresh project: one per stored case (valid, malformed, missing)
+    async fn create_tasks(pool: &SqlitePool) -> [Uuid; 3] {
+        let project_id = Uuid::new_v4();
+        let project = CreateProject {
+            name: "Property Accessors".to_string(),
+            repositories: vec![],
+        };
+        Project::create(pool, &project, project_id).await.unwrap();
+        let mut ids = [Uuid::nil(); 3];
+        for (index, id) in ids.iter_mut().enumerate() {
+            *id = Uuid::new_v4();
+            let data =
+                CreateTask::from_title_description(project_id, format!("Task {index}"), None);
+            Task::create(pool, &data, *id).await.unwrap();
+        }
+        ids
+    }
+
+    async fn store(pool: &SqlitePool, task_id: Uuid, name: &str, value: &str) {
+        TaskProperty::upsert(
+            pool,
+            &CreateTaskProperty {
+                task_id,
+                property_name: name.to_string(),
+                property_value: value.to_string(),
+                source: Some(PropertySource::Github),
+            },
+        )
+        .await
+        .unwrap();
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    async fn test_labels_accessor(pool: SqlitePool) {
+        let [valid, malformed, missing] = create_tasks(&pool).await;
+        store(
+            &pool,
+            valid,
+            LABELS_PROPERTY,
+            r#"[{"name":"bug","color":"d73a4a"}]"#,
+        )
+        .await;
+        store(&pool, malformed, LABELS_PROPERTY, "bug").await;
+
+        let names: Vec<String> = labels(&pool, valid)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|label| label.name)
+            .collect();
+        assert_eq!(names, vec!["bug".to_string()]);
+        assert!(labels(&pool, malformed).await.unwrap().is_empty());
+        assert!(labels(&pool, missing).await.unwrap().is_empty());
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    async fn test_assignees_accessor(pool: SqlitePool) {
+        let [valid, malformed, missing] = create_tasks(&pool).await;
+        store(&pool, valid, ASSIGNEES_PROPERTY, r#"["alice","bob"]"#).await;
+        store(&pool, malformed, ASSIGNEES_PROPERTY, r#"{"login":"alice"}"#).await;
+
+        assert_eq!(
+            assignees(&pool, valid).await.unwrap(),
+            vec!["alice".to_string(), "bob".to_string()]
+        );
+        assert!(assignees(&pool, malformed).await.unwrap().is_empty());
+        assert!(assignees(&pool, missing).await.unwrap().is_empty());
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    async fn test_milestone_accessor(pool: SqlitePool) {
+        let [valid, malformed, missing] = create_tasks(&pool).await;
+        store(
+            &pool,
+            valid,
+            MILESTONE_PROPERTY,
+            r#"{"id":"MI_1","title":"v1.0","number":3}"#,
+        )
+        .await;
+        store(&pool, malformed, MILESTONE_PROPERTY, "v1.0").await;
+
+        let stored = milestone(&pool, valid).await.unwrap().unwrap();
+        assert_eq!(stored.title, "v1.0");
+        assert_eq!(stored.number, 3);
+        assert!(milestone(&pool, malformed).await.unwrap().is_none());
+        assert!(milestone(&pool, missing).await.unwrap().is_none());
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    async fn test_github_status_accessor(pool: SqlitePool) {
+        let [valid, blank, missing] = create_tasks(&pool).await;
+        store(&pool, valid, STATUS_PROPERTY, "In Progress").await;
+        store(&pool, blank, STATUS_PROPERTY, "  ").await;
+
+        assert_eq!(
+            github_status(&pool, valid).await.unwrap().as_deref(),
+            Some("In Progress")
+        );
+        assert_eq!(github_status(&pool, blank).await.unwrap(), None);
+        assert_eq!(github_status(&pool, missing).await.unwrap(), None);
+    }
+}
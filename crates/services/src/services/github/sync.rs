@@ -3,6 +3,8 @@
 //! This module handles synchronization between Vibe Kanban tasks and GitHub Issues,
 //! including status mapping and conflict resolution.
 
+use std::collections::HashSet;
+
 use chrono::Utc;
 use db::models::{
     github_issue_mapping::{CreateGitHubIssueMapping, GitHubIssueMapping, SyncDirection},
@@ -17,8 +19,12 @@ use tracing::{debug, info, warn};
 use ts_rs::TS;
 use uuid::Uuid;
 
-use super::graphql::GitHubGraphQLError;
-use super::projects::{GitHubIssue, GitHubProjectItem, GitHubProjectsError, GitHubProjectsService};
+use super::graphql::{GitHubGraphQLError, queries};
+use super::properties::{ASSIGNEES_PROPERTY, LABELS_PROPERTY, MILESTONE_PROPERTY, STATUS_PROPERTY};
+use super::projects::{
+    DEFAULT_PAGE_SIZE, GitHubContentType, GitHubIssue, GitHubProjectItem, GitHubProjectsError,
+    GitHubProjectsService, clamp_page_size,
+};
 
 #[derive(Debug, Error)]
 pub enum GitHubSyncError {
@@ -34,6 +40,25 @@ pub enum GitHubSyncError {
     InvalidMapping(String),
 }
 
+impl GitHubSyncError {
+    /// Coarse category of the error, for clients that react differently to each
+    pub fn kind(&self) -> SyncErrorKind {
+        let graphql = match self {
+            Self::Projects(GitHubProjectsError::GraphQL(e)) | Self::GraphQL(e) => e,
+            Self::Projects(GitHubProjectsError::AccessDenied(_)) => return SyncErrorKind::Auth,
+            Self::Projects(_) => return SyncErrorKind::NotFound,
+            Self::Database(_) => return SyncErrorKind::Database,
+            Self::Conflict(_) => return SyncErrorKind::Conflict,
+            Self::InvalidMapping(_) => return SyncErrorKind::InvalidMapping,
+        };
+        match graphql {
+            GitHubGraphQLError::CliNotAvailable => SyncErrorKind::CliUnavailable,
+            GitHubGraphQLError::AuthFailed(_) => SyncErrorKind::Auth,
+            _ => SyncErrorKind::Api,
+        }
+    }
+}
+
 /// Status mapping between Vibe Kanban and GitHub
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 pub struct StatusMapping {
@@ -61,6 +86,11 @@ impl StatusMapping {
                 github_project_status: "In Review".to_string(),
                 github_issue_state: "OPEN".to_string(),
             },
+            Self {
+                vibe_status: TaskStatus::Blocked,
+                github_project_status: "Blocked".to_string(),
+                github_issue_state: "OPEN".to_string(),
+            },
             Self {
                 vibe_status: TaskStatus::Done,
                 github_project_status: "Done".to_string(),
@@ -79,6 +109,9 @@ impl StatusMapping {
         // First try to match project status (more specific)
         if let Some(status) = project_status {
             let lower = status.to_lowercase();
+            if lower.contains("block") {
+                return TaskStatus::Blocked;
+            }
             if lower.contains("progress") {
                 return TaskStatus::InProgress;
             }
@@ -100,6 +133,29 @@ impl StatusMapping {
         }
     }
 
+    /// Map GitHub pull request state to Vibe status
+    ///
+    /// A merged pull request is done, while one closed without merging is cancelled. Open pull
+    /// requests follow the project status like issues do.
+    pub fn pr_to_vibe(pr_state: &str, merged: bool, project_status: Option<&str>) -> TaskStatus {
+        match pr_state.to_uppercase().as_str() {
+            "MERGED" => TaskStatus::Done,
+            "CLOSED" if merged => TaskStatus::Done,
+            "CLOSED" => TaskStatus::Cancelled,
+            _ => Self::github_to_vibe("OPEN", project_status),
+        }
+    }
+
+    /// Map a project item's content to Vibe status, using the pull request mapping for PRs
+    pub fn content_to_vibe(content: &GitHubIssue, project_status: Option<&str>) -> TaskStatus {
+        match content.content_type {
+            GitHubContentType::PullRequest => {
+                Self::pr_to_vibe(&content.state, content.merged, project_status)
+            }
+            GitHubContentType::Issue => Self::github_to_vibe(&content.state, project_status),
+        }
+    }
+
     /// Map Vibe status to GitHub issue state
     pub fn vibe_to_github_state(status: &TaskStatus) -> &'static str {
         match status {
@@ -109,6 +165,45 @@ impl StatusMapping {
     }
 }
 
+/// Category of a sync failure
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncErrorKind {
+    /// The `gh` CLI is not installed
+    CliUnavailable,
+    /// GitHub rejected the credentials
+    Auth,
+    /// Any other failed GitHub API request or unparseable response
+    Api,
+    /// A project, issue or field no longer exists on GitHub
+    NotFound,
+    Database,
+    Conflict,
+    InvalidMapping,
+}
+
+/// A failure recorded during a sync, tied to the project item when there is one
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncError {
+    pub item_id: Option<String>,
+    pub issue_number: Option<i64>,
+    pub kind: SyncErrorKind,
+    pub message: String,
+}
+
+impl SyncError {
+    /// Record the failure to sync one project item
+    pub fn for_item(item: &GitHubProjectItem, error: &GitHubSyncError) -> Self {
+        Self {
+            item_id: Some(item.id.clone()),
+            issue_number: item.issue.as_ref().map(|issue| issue.number),
+            kind: error.kind(),
+            message: error.to_string(),
+        }
+    }
+}
+
 /// Result of a sync operation
 #[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
 #[serde(rename_all = "camelCase")]
@@ -117,20 +212,158 @@ pub struct SyncResult {
     pub items_created: u32,
     pub items_updated: u32,
     pub items_skipped: u32,
-    pub errors: Vec<String>,
+    pub errors: Vec<SyncError>,
+    /// Mappings whose issue is no longer in the GitHub Project; their tasks are kept
+    pub removed: Vec<GitHubIssueMapping>,
+}
+
+/// What syncing a single project item did
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SyncItemOutcome {
+    Created,
+    Updated,
+    /// Drafts, issues filtered out by label, closed issues left out of an import and issues
+    /// pinned to `vibe_to_github`
+    Skipped,
+}
+
+/// Whether the issue carries `label`, compared case-insensitively by name
+fn has_label(issue: &GitHubIssue, label: &str) -> bool {
+    let label = label.to_lowercase();
+    issue
+        .labels
+        .iter()
+        .any(|candidate| candidate.name.to_lowercase() == label)
+}
+
+/// Whether an issue or pull request state means the item is finished; merged pull requests
+/// report MERGED rather than CLOSED
+fn is_closed_state(state: &str) -> bool {
+    state.eq_ignore_ascii_case("CLOSED") || state.eq_ignore_ascii_case("MERGED")
+}
+
+/// Value of the item's GitHub Project "Status" field, if set
+fn project_status(item: &GitHubProjectItem) -> Option<&str> {
+    item.field_values
+        .iter()
+        .find(|field| field.field_name.eq_ignore_ascii_case("Status"))
+        .map(|field| field.value.as_str())
+}
+
+/// Task property holding the label names last seen on GitHub
+const SYNCED_LABELS_PROPERTY: &str = "github_labels_synced";
+
+/// Task property holding the assignee logins last seen on GitHub
+const SYNCED_ASSIGNEES_PROPERTY: &str = "github_assignees_synced";
+
+/// Task property holding whether a pull request item was merged
+const PR_MERGED_PROPERTY: &str = "github_pr_merged";
+
+/// Names to add to and remove from a GitHub issue so it matches the local values
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NameDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+}
+
+impl NameDiff {
+    /// Compute the changes needed to go from `previous` to `desired`
+    pub fn between(previous: &[String], desired: &[String]) -> Self {
+        let mut added: Vec<String> = Vec::new();
+        for name in desired {
+            if !previous.contains(name) && !added.contains(name) {
+                added.push(name.clone());
+            }
+        }
+
+        let mut removed: Vec<String> = Vec::new();
+        for name in previous {
+            if !desired.contains(name) && !removed.contains(name) {
+                removed.push(name.clone());
+            }
+        }
+
+        Self { added, removed }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Parse a property value holding a JSON array of names, or of objects with a `name` field
+/// (the shape `labels` is stored in when pulled from GitHub)
+fn parse_property_names(value: &str) -> Vec<String> {
+    let values: Vec<serde_json::Value> = serde_json::from_str(value).unwrap_or_default();
+    values
+        .into_iter()
+        .filter_map(|v| match v {
+            serde_json::Value::String(name) => Some(name),
+            serde_json::Value::Object(map) => map
+                .get("name")
+                .and_then(|name| name.as_str())
+                .map(str::to_string),
+            _ => None,
+        })
+        .collect()
 }
 
+/// GraphQL mutations and variable names used to push one kind of name list to an issue
+struct NameMutations {
+    add: &'static str,
+    remove: &'static str,
+    target_var: &'static str,
+    id_var: &'static str,
+}
+
+const LABEL_MUTATIONS: NameMutations = NameMutations {
+    add: queries::ADD_LABEL,
+    remove: queries::REMOVE_LABEL,
+    target_var: "labelableId",
+    id_var: "labelId",
+};
+
+const ASSIGNEE_MUTATIONS: NameMutations = NameMutations {
+    add: queries::ADD_ASSIGNEE,
+    remove: queries::REMOVE_ASSIGNEE,
+    target_var: "assignableId",
+    id_var: "assigneeId",
+};
+
 pub struct GitHubSyncService {
     projects_service: GitHubProjectsService,
+    page_size: u32,
+    import_closed: bool,
 }
 
 impl GitHubSyncService {
     pub fn new() -> Self {
         Self {
             projects_service: GitHubProjectsService::new(),
+            page_size: DEFAULT_PAGE_SIZE,
+            import_closed: true,
         }
     }
 
+    /// Set the number of project items fetched per GitHub request (clamped to 1..=100)
+    pub fn with_page_size(mut self, page_size: u32) -> Self {
+        self.page_size = clamp_page_size(page_size);
+        self
+    }
+
+    /// Target a specific GitHub host, usually a link's `github_host`; `None` targets github.com
+    pub fn with_host(mut self, host: Option<String>) -> Self {
+        self.projects_service = GitHubProjectsService::with_host(host);
+        self
+    }
+
+    /// Whether closed issues and merged pull requests without a task yet become tasks (the default)
+    /// When `false` they are skipped; closed issues that are already mapped keep updating.
+    pub fn with_import_closed(mut self, import_closed: bool) -> Self {
+        self.import_closed = import_closed;
+        self
+    }
+
     /// Check if GitHub CLI is available and authenticated
     pub fn check_available(&self) -> Result<(), GitHubSyncError> {
         self.projects_service.check_available()?;
@@ -144,44 +377,66 @@ impl GitHubSyncService {
         link: &GitHubProjectLink,
         project_id: Uuid,
     ) -> Result<SyncResult, GitHubSyncError> {
-        let mut result = SyncResult::default();
-
         info!(
             "Starting sync from GitHub project {} to Vibe project {}",
             link.github_project_id, project_id
         );
 
         // Get all items from the GitHub project
-        let items = self.projects_service.get_project_items(&link.github_project_id)?;
+        let items = self
+            .projects_service
+            .get_project_items(&link.github_project_id, self.page_size)?;
+        self.sync_items(pool, link, project_id, items).await
+    }
+
+    /// Sync items already fetched from the link's GitHub project
+    async fn sync_items(
+        &self,
+        pool: &SqlitePool,
+        link: &GitHubProjectLink,
+        project_id: Uuid,
+        items: Vec<GitHubProjectItem>,
+    ) -> Result<SyncResult, GitHubSyncError> {
+        let mut result = SyncResult::default();
+        let seen_issues: HashSet<i64> = items
+            .iter()
+            .filter_map(|item| item.issue.as_ref().map(|issue| issue.number))
+            .collect();
 
         for item in items {
             match self.sync_item_from_github(pool, link, project_id, &item).await {
-                Ok(created) => {
-                    if created {
-                        result.items_created += 1;
-                    } else {
-                        result.items_updated += 1;
-                    }
+                Ok(SyncItemOutcome::Created) => {
+                    result.items_created += 1;
+                    result.items_synced += 1;
+                }
+                Ok(SyncItemOutcome::Updated) => {
+                    result.items_updated += 1;
                     result.items_synced += 1;
                 }
+                Ok(SyncItemOutcome::Skipped) => result.items_skipped += 1,
                 Err(e) => {
-                    let error_msg = format!(
-                        "Failed to sync item {}: {}",
-                        item.id,
-                        e
-                    );
-                    warn!("{}", error_msg);
-                    result.errors.push(error_msg);
+                    warn!("Failed to sync item {}: {}", item.id, e);
+                    result.errors.push(SyncError::for_item(&item, &e));
                 }
             }
         }
 
+        // Flag mappings of issues removed from the project instead of deleting their tasks, and
+        // unflag those whose issue came back
+        result.removed =
+            GitHubIssueMapping::update_stale_flags(pool, link.id, &seen_issues).await?;
+
         // Update last sync timestamp
         GitHubProjectLink::update_last_sync_at(pool, link.id).await?;
 
         info!(
-            "Sync completed: {} synced, {} created, {} updated, {} errors",
-            result.items_synced, result.items_created, result.items_updated, result.errors.len()
+            "Sync completed: {} synced, {} created, {} updated, {} skipped, {} removed, {} errors",
+            result.items_synced,
+            result.items_created,
+            result.items_updated,
+            result.items_skipped,
+            result.removed.len(),
+            result.errors.len()
         );
 
         Ok(result)
@@ -194,16 +449,27 @@ impl GitHubSyncService {
         link: &GitHubProjectLink,
         project_id: Uuid,
         item: &GitHubProjectItem,
-    ) -> Result<bool, GitHubSyncError> {
+    ) -> Result<SyncItemOutcome, GitHubSyncError> {
         // Skip items that don't have an issue (draft items, etc.)
         let issue = match &item.issue {
             Some(i) => i,
             None => {
                 debug!("Skipping project item {} without issue content", item.id);
-                return Ok(false);
+                return Ok(SyncItemOutcome::Skipped);
             }
         };
 
+        // Skip issues without the link's label, if it has one
+        if let Some(label) = &link.label_filter
+            && !has_label(issue, label)
+        {
+            debug!(
+                "Skipping issue #{} - missing label filter '{}'",
+                issue.number, label
+            );
+            return Ok(SyncItemOutcome::Skipped);
+        }
+
         // Check if we already have a mapping for this issue
         let existing_mapping =
             GitHubIssueMapping::find_by_github_issue(pool, link.id, issue.number).await?;
@@ -215,7 +481,7 @@ impl GitHubSyncService {
                     "Skipping issue #{} - sync direction is vibe_to_github only",
                     issue.number
                 );
-                return Ok(false);
+                return Ok(SyncItemOutcome::Skipped);
             }
 
             // Update existing task
@@ -231,7 +497,13 @@ impl GitHubSyncService {
             )
             .await?;
 
-            Ok(false)
+            Ok(SyncItemOutcome::Updated)
+        } else if !self.import_closed && is_closed_state(&issue.state) {
+            debug!(
+                "Skipping issue #{} - closed issues are not imported",
+                issue.number
+            );
+            Ok(SyncItemOutcome::Skipped)
         } else {
             // Create new task and mapping
             let task_id = self.create_task_from_issue(pool, project_id, issue, item).await?;
@@ -245,9 +517,19 @@ impl GitHubSyncService {
                 github_issue_url: issue.url.clone(),
                 sync_direction: Some(SyncDirection::Bidirectional),
             };
-            GitHubIssueMapping::create(pool, &mapping_data).await?;
+            let mapping = GitHubIssueMapping::upsert(pool, &mapping_data).await?;
 
-            Ok(true)
+            // A concurrent sync mapped this issue first; drop the duplicate task
+            if mapping.task_id != task_id {
+                debug!(
+                    "Issue #{} was already mapped to task {}; removing duplicate task {}",
+                    issue.number, mapping.task_id, task_id
+                );
+                Task::delete(pool, task_id).await?;
+                return Ok(SyncItemOutcome::Updated);
+            }
+
+            Ok(SyncItemOutcome::Created)
         }
     }
 
@@ -259,9 +541,14 @@ impl GitHubSyncService {
         issue: &GitHubIssue,
         item: &GitHubProjectItem,
     ) -> Result<Uuid, GitHubSyncError> {
-        // All imported tasks start as Todo (agent not started)
-        // GitHub status is stored in task_properties for reference
-        let status = TaskStatus::Todo;
+        // Imported issues start as Todo (agent not started); GitHub status is stored in
+        // task_properties for reference. Pull requests take their status from their state.
+        let status = match issue.content_type {
+            GitHubContentType::Issue => TaskStatus::Todo,
+            GitHubContentType::PullRequest => {
+                StatusMapping::content_to_vibe(issue, project_status(item))
+            }
+        };
 
         // Create task
         let task_id = Uuid::new_v4();
@@ -305,14 +592,21 @@ impl GitHubSyncService {
             .ok_or_else(|| GitHubSyncError::InvalidMapping(format!("Task {} not found", task_id)))?;
 
         // Update task: keep existing status (agent workflow), only update title/description
-        // GitHub status is stored in task_properties
+        // GitHub status is stored in task_properties. A merged or closed pull request is
+        // finished for good, so its state wins.
+        let status = match issue.content_type {
+            GitHubContentType::PullRequest if !issue.state.eq_ignore_ascii_case("OPEN") => {
+                StatusMapping::pr_to_vibe(&issue.state, issue.merged, project_status(item))
+            }
+            _ => existing_task.status, // Preserve agent workflow status
+        };
         Task::update(
             pool,
             task_id,
             existing_task.project_id,
             issue.title.clone(),
             issue.body.clone(),
-            existing_task.status, // Preserve agent workflow status
+            status,
             existing_task.parent_workspace_id,
         )
         .await?;
@@ -360,22 +654,33 @@ impl GitHubSyncService {
         )
         .await?;
 
-        // Sync labels
-        if !issue.labels.is_empty() {
-            let labels_json = serde_json::to_string(&issue.labels)
-                .unwrap_or_else(|_| "[]".to_string());
+        // Sync the merged flag for pull requests
+        if issue.content_type == GitHubContentType::PullRequest {
             TaskProperty::upsert(
                 pool,
                 &CreateTaskProperty {
                     task_id,
-                    property_name: "labels".to_string(),
-                    property_value: labels_json,
+                    property_name: PR_MERGED_PROPERTY.to_string(),
+                    property_value: issue.merged.to_string(),
                     source: Some(PropertySource::Github),
                 },
             )
             .await?;
         }
 
+        // Sync labels, empty lists included so a removal on GitHub isn't pushed back
+        let labels_json = serde_json::to_string(&issue.labels).unwrap_or_else(|_| "[]".to_string());
+        TaskProperty::upsert(
+            pool,
+            &CreateTaskProperty {
+                task_id,
+                property_name: LABELS_PROPERTY.to_string(),
+                property_value: labels_json,
+                source: Some(PropertySource::Github),
+            },
+        )
+        .await?;
+
         // Sync milestone
         if let Some(milestone) = &issue.milestone {
             let milestone_json = serde_json::to_string(milestone)
@@ -384,7 +689,7 @@ impl GitHubSyncService {
                 pool,
                 &CreateTaskProperty {
                     task_id,
-                    property_name: "milestone".to_string(),
+                    property_name: MILESTONE_PROPERTY.to_string(),
                     property_value: milestone_json,
                     source: Some(PropertySource::Github),
                 },
@@ -392,24 +697,64 @@ impl GitHubSyncService {
             .await?;
         }
 
-        // Sync assignees
-        if !issue.assignees.is_empty() {
-            let assignees_json = serde_json::to_string(&issue.assignees)
-                .unwrap_or_else(|_| "[]".to_string());
+        // Sync assignees, empty lists included so a removal on GitHub isn't pushed back
+        let assignees_json =
+            serde_json::to_string(&issue.assignees).unwrap_or_else(|_| "[]".to_string());
+        TaskProperty::upsert(
+            pool,
+            &CreateTaskProperty {
+                task_id,
+                property_name: ASSIGNEES_PROPERTY.to_string(),
+                property_value: assignees_json,
+                source: Some(PropertySource::Github),
+            },
+        )
+        .await?;
+
+        // Remember what GitHub currently has, so local label/assignee edits can be diffed
+        let label_names: Vec<&str> = issue.labels.iter().map(|l| l.name.as_str()).collect();
+        TaskProperty::upsert(
+            pool,
+            &CreateTaskProperty {
+                task_id,
+                property_name: SYNCED_LABELS_PROPERTY.to_string(),
+                property_value: serde_json::to_string(&label_names)
+                    .unwrap_or_else(|_| "[]".to_string()),
+                source: Some(PropertySource::Github),
+            },
+        )
+        .await?;
+        TaskProperty::upsert(
+            pool,
+            &CreateTaskProperty {
+                task_id,
+                property_name: SYNCED_ASSIGNEES_PROPERTY.to_string(),
+                property_value: serde_json::to_string(&issue.assignees)
+                    .unwrap_or_else(|_| "[]".to_string()),
+                source: Some(PropertySource::Github),
+            },
+        )
+        .await?;
+
+        // Sync the GitHub Project status
+        if let Some(status) = project_status(item) {
             TaskProperty::upsert(
                 pool,
                 &CreateTaskProperty {
                     task_id,
-                    property_name: "github_assignees".to_string(),
-                    property_value: assignees_json,
+                    property_name: STATUS_PROPERTY.to_string(),
+                    property_value: status.to_string(),
                     source: Some(PropertySource::Github),
                 },
             )
             .await?;
         }
 
-        // Sync GitHub Project field values (Status, Priority, ジャンル, etc.)
+        // Sync the other GitHub Project field values (Priority, ジャンル, etc.)
         for field_value in &item.field_values {
+            if field_value.field_name.eq_ignore_ascii_case("Status") {
+                continue;
+            }
             let property_name = format!("github_{}", field_value.field_name.to_lowercase().replace(' ', "_"));
             TaskProperty::upsert(
                 pool,
@@ -478,6 +823,8 @@ impl GitHubSyncService {
             task.id, mapping.github_issue_number, issue_state
         );
 
+        self.sync_task_labels_to_github(pool, task.id).await?;
+
         // Update vibe_updated_at timestamp
         GitHubIssueMapping::update_sync_timestamps(pool, mapping.id, None, Some(Utc::now()))
             .await?;
@@ -485,6 +832,149 @@ impl GitHubSyncService {
         Ok(())
     }
 
+    /// Push local edits of a task's `labels` and `github_assignees` properties to its GitHub issue.
+    /// Only the difference against the values last seen on GitHub is sent.
+    pub async fn sync_task_labels_to_github(
+        &self,
+        pool: &SqlitePool,
+        task_id: Uuid,
+    ) -> Result<(), GitHubSyncError> {
+        let Some(mapping) = GitHubIssueMapping::find_by_task_id(pool, task_id).await? else {
+            debug!("No GitHub mapping found for task {}", task_id);
+            return Ok(());
+        };
+
+        if matches!(mapping.sync_direction, SyncDirection::GithubToVibe) {
+            debug!(
+                "Skipping labels of task {} - sync direction is github_to_vibe only",
+                task_id
+            );
+            return Ok(());
+        }
+
+        let link = GitHubProjectLink::find_by_id(pool, mapping.github_project_link_id)
+            .await?
+            .ok_or_else(|| {
+                GitHubSyncError::InvalidMapping(format!(
+                    "GitHub link {} not found",
+                    mapping.github_project_link_id
+                ))
+            })?;
+
+        // Labels
+        if let Some(diff) = Self::property_diff(pool, task_id, LABELS_PROPERTY, SYNCED_LABELS_PROPERTY).await? {
+            match &link.github_repo {
+                Some(repo) => {
+                    self.apply_name_diff(&mapping.github_issue_id, &diff, &LABEL_MUTATIONS, |name| {
+                        self.projects_service
+                            .get_label_id(&link.github_owner, repo, name)
+                    })?;
+                    Self::store_synced_names(pool, task_id, LABELS_PROPERTY, SYNCED_LABELS_PROPERTY)
+                        .await?;
+                }
+                None => warn!(
+                    "Skipping labels of task {} - GitHub link {} has no repository",
+                    task_id, link.id
+                ),
+            }
+        }
+
+        // Assignees
+        if let Some(diff) =
+            Self::property_diff(pool, task_id, ASSIGNEES_PROPERTY, SYNCED_ASSIGNEES_PROPERTY)
+                .await?
+        {
+            self.apply_name_diff(&mapping.github_issue_id, &diff, &ASSIGNEE_MUTATIONS, |login| {
+                self.projects_service.get_user_id(login)
+            })?;
+            Self::store_synced_names(pool, task_id, ASSIGNEES_PROPERTY, SYNCED_ASSIGNEES_PROPERTY)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Diff a task's local name list property against the last values seen on GitHub.
+    /// Returns None when the task has no local value or nothing changed.
+    async fn property_diff(
+        pool: &SqlitePool,
+        task_id: Uuid,
+        property_name: &str,
+        synced_property_name: &str,
+    ) -> Result<Option<NameDiff>, GitHubSyncError> {
+        let Some(desired) = TaskProperty::find_by_task_and_name(pool, task_id, property_name).await?
+        else {
+            return Ok(None);
+        };
+        let previous = TaskProperty::find_by_task_and_name(pool, task_id, synced_property_name)
+            .await?
+            .map(|p| parse_property_names(&p.property_value))
+            .unwrap_or_default();
+
+        let diff = NameDiff::between(&previous, &parse_property_names(&desired.property_value));
+        Ok((!diff.is_empty()).then_some(diff))
+    }
+
+    /// Record the local name list as what GitHub now has
+    async fn store_synced_names(
+        pool: &SqlitePool,
+        task_id: Uuid,
+        property_name: &str,
+        synced_property_name: &str,
+    ) -> Result<(), GitHubSyncError> {
+        let names = TaskProperty::find_by_task_and_name(pool, task_id, property_name)
+            .await?
+            .map(|p| parse_property_names(&p.property_value))
+            .unwrap_or_default();
+
+        TaskProperty::upsert(
+            pool,
+            &CreateTaskProperty {
+                task_id,
+                property_name: synced_property_name.to_string(),
+                property_value: serde_json::to_string(&names).unwrap_or_else(|_| "[]".to_string()),
+                source: Some(PropertySource::Github),
+            },
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Apply a name diff to an issue, one mutation per name.
+    /// Names that cannot be resolved to a GitHub node ID are skipped with a warning.
+    fn apply_name_diff(
+        &self,
+        issue_id: &str,
+        diff: &NameDiff,
+        mutations: &NameMutations,
+        resolve_id: impl Fn(&str) -> Result<Option<String>, GitHubProjectsError>,
+    ) -> Result<(), GitHubSyncError> {
+        let changes = diff
+            .added
+            .iter()
+            .map(|name| (name, mutations.add))
+            .chain(diff.removed.iter().map(|name| (name, mutations.remove)));
+
+        for (name, mutation) in changes {
+            let Some(node_id) = resolve_id(name)? else {
+                warn!("Skipping '{}' - not found on GitHub", name);
+                continue;
+            };
+
+            let mut variables = serde_json::json!({});
+            variables[mutations.target_var] = serde_json::Value::String(issue_id.to_string());
+            variables[mutations.id_var] = serde_json::Value::String(node_id);
+
+            let _result: serde_json::Value = self
+                .projects_service
+                .graphql
+                .mutate(mutation, Some(variables))?;
+        }
+
+        Ok(())
+    }
+
     /// Update a GitHub issue via GraphQL mutation
     fn update_github_issue(
         &self,
@@ -493,8 +983,6 @@ impl GitHubSyncService {
         body: Option<&str>,
         state: Option<&str>,
     ) -> Result<(), GitHubSyncError> {
-        use super::graphql::queries;
-
         let full_query = format!("{}\n{}", queries::ISSUE_FRAGMENT, queries::UPDATE_ISSUE);
 
         let mut variables = serde_json::json!({
@@ -528,6 +1016,12 @@ impl Default for GitHubSyncService {
 
 #[cfg(test)]
 mod tests {
+    use db::models::{
+        github_project_link::CreateGitHubProjectLink,
+        project::{CreateProject, Project},
+        task::CreateTask,
+    };
+
     use super::*;
 
     #[test]
@@ -548,6 +1042,66 @@ mod tests {
             StatusMapping::github_to_vibe("OPEN", Some("In Review")),
             TaskStatus::InReview
         );
+        assert_eq!(
+            StatusMapping::github_to_vibe("OPEN", Some("Blocked")),
+            TaskStatus::Blocked
+        );
+    }
+
+    #[test]
+    fn test_status_mapping_pr_to_vibe() {
+        assert_eq!(
+            StatusMapping::pr_to_vibe("MERGED", true, None),
+            TaskStatus::Done
+        );
+        assert_eq!(
+            StatusMapping::pr_to_vibe("CLOSED", false, None),
+            TaskStatus::Cancelled
+        );
+        assert_eq!(
+            StatusMapping::pr_to_vibe("OPEN", false, None),
+            TaskStatus::Todo
+        );
+        assert_eq!(
+            StatusMapping::pr_to_vibe("OPEN", false, Some("In Review")),
+            TaskStatus::InReview
+        );
+        // A merged or closed state wins over a stale project status
+        assert_eq!(
+            StatusMapping::pr_to_vibe("MERGED", true, Some("In Progress")),
+            TaskStatus::Done
+        );
+    }
+
+    #[test]
+    fn test_status_mapping_content_to_vibe() {
+        let json = serde_json::json!({
+            "id": "PR_1",
+            "number": 7,
+            "title": "Add sync",
+            "body": null,
+            "state": "CLOSED",
+            "url": "https://github.com/test/repo/pull/7",
+            "createdAt": "2026-01-01T00:00:00Z",
+            "updatedAt": "2026-01-02T00:00:00Z",
+            "closedAt": "2026-01-02T00:00:00Z",
+            "authorLogin": null,
+            "assignees": [],
+            "labels": [],
+            "milestone": null
+        });
+        let mut content: GitHubIssue = serde_json::from_value(json).unwrap();
+        assert_eq!(content.content_type, GitHubContentType::Issue);
+        assert_eq!(
+            StatusMapping::content_to_vibe(&content, None),
+            TaskStatus::Done
+        );
+
+        content.content_type = GitHubContentType::PullRequest;
+        assert_eq!(
+            StatusMapping::content_to_vibe(&content, None),
+            TaskStatus::Cancelled
+        );
     }
 
     #[test]
@@ -557,10 +1111,565 @@ mod tests {
             StatusMapping::vibe_to_github_state(&TaskStatus::InProgress),
             "OPEN"
         );
+        assert_eq!(
+            StatusMapping::vibe_to_github_state(&TaskStatus::Blocked),
+            "OPEN"
+        );
         assert_eq!(StatusMapping::vibe_to_github_state(&TaskStatus::Done), "CLOSED");
         assert_eq!(
             StatusMapping::vibe_to_github_state(&TaskStatus::Cancelled),
             "CLOSED"
         );
     }
+
+    #[test]
+    fn test_sync_error_for_item_records_issue_number() {
+        let item: GitHubProjectItem = serde_json::from_value(serde_json::json!({
+            "id": "PVTI_1",
+            "issue": {
+                "id": "I_1",
+                "number": 42,
+                "title": "Broken",
+                "body": null,
+                "state": "OPEN",
+                "url": "https://github.com/test/repo/issues/42",
+                "createdAt": "2026-01-01T00:00:00Z",
+                "updatedAt": "2026-01-02T00:00:00Z",
+                "closedAt": null,
+                "authorLogin": null,
+                "assignees": [],
+                "labels": [],
+                "milestone": null
+            },
+            "fieldValues": []
+        }))
+        .unwrap();
+        let error = GitHubSyncError::InvalidMapping("Task not found".to_string());
+
+        let sync_error = SyncError::for_item(&item, &error);
+
+        assert_eq!(sync_error.item_id.as_deref(), Some("PVTI_1"));
+        assert_eq!(sync_error.issue_number, Some(42));
+        assert_eq!(sync_error.kind, SyncErrorKind::InvalidMapping);
+        assert_eq!(sync_error.message, "Invalid mapping: Task not found");
+    }
+
+    #[test]
+    fn test_sync_error_kind() {
+        let auth = GitHubSyncError::GraphQL(GitHubGraphQLError::AuthFailed("401".to_string()));
+        assert_eq!(auth.kind(), SyncErrorKind::Auth);
+
+        let missing_cli = GitHubSyncError::Projects(GitHubProjectsError::GraphQL(
+            GitHubGraphQLError::CliNotAvailable,
+        ));
+        assert_eq!(missing_cli.kind(), SyncErrorKind::CliUnavailable);
+
+        let not_found =
+            GitHubSyncError::Projects(GitHubProjectsError::ProjectNotFound("PVT_1".to_string()));
+        assert_eq!(not_found.kind(), SyncErrorKind::NotFound);
+
+        let parse = GitHubSyncError::GraphQL(GitHubGraphQLError::ParseError("bad".to_string()));
+        assert_eq!(parse.kind(), SyncErrorKind::Api);
+    }
+
+    fn names(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn test_name_diff_added_and_removed() {
+        let previous = names(&["bug", "backend"]);
+        let desired = names(&["bug", "frontend", "urgent"]);
+
+        let diff = NameDiff::between(&previous, &desired);
+        assert_eq!(diff.added, names(&["frontend", "urgent"]));
+        assert_eq!(diff.removed, names(&["backend"]));
+    }
+
+    #[test]
+    fn test_name_diff_unchanged_is_empty() {
+        let labels = names(&["bug", "backend"]);
+        let reordered = names(&["backend", "bug"]);
+        assert!(NameDiff::between(&labels, &reordered).is_empty());
+    }
+
+    #[test]
+    fn test_name_diff_from_nothing_synced() {
+        let diff = NameDiff::between(&[], &names(&["bug"]));
+        assert_eq!(diff.added, names(&["bug"]));
+        assert!(diff.removed.is_empty());
+    }
+
+    #[test]
+    fn test_parse_property_names() {
+        let labels = r#"[{"name": "bug", "color": "d73a4a"}, {"name": "docs", "color": "0075ca"}]"#;
+        assert_eq!(parse_property_names(labels), names(&["bug", "docs"]));
+        assert_eq!(parse_property_names(r#"["alice", "bob"]"#), names(&["alice", "bob"]));
+        assert!(parse_property_names("not json").is_empty());
+    }
+
+    async fn create_sync_test_link(
+        pool: &SqlitePool,
+        label_filter: Option<&str>,
+    ) -> GitHubProjectLink {
+        let project_id = Uuid::new_v4();
+        let project = CreateProject {
+            name: "Sync Test".to_string(),
+            repositories: vec![],
+        };
+        Project::create(pool, &project, project_id).await.unwrap();
+        GitHubProjectLink::create(
+            pool,
+            &CreateGitHubProjectLink {
+                project_id,
+                github_project_id: "PVT_sync".to_string(),
+                github_owner: "test".to_string(),
+                github_repo: Some("repo".to_string()),
+                github_project_number: Some(1),
+                github_host: None,
+                label_filter: label_filter.map(str::to_string),
+            },
+        )
+        .await
+        .unwrap()
+    }
+
+    fn issue_item(number: i64, title: &str, labels: &[&str]) -> GitHubProjectItem {
+        let labels: Vec<serde_json::Value> = labels
+            .iter()
+            .map(|name| serde_json::json!({ "name": name, "color": "ededed" }))
+            .collect();
+        serde_json::from_value(serde_json::json!({
+            "id": format!("PVTI_{number}"),
+            "issue": {
+                "id": format!("I_{number}"),
+                "number": number,
+                "title": title,
+                "body": null,
+                "state": "CLOSED",
+                "url": format!("https://github.com/test/repo/issues/{number}"),
+                "createdAt": "2026-01-01T00:00:00Z",
+                "updatedAt": "2026-01-02T00:00:00Z",
+                "closedAt": "2026-01-02T00:00:00Z",
+                "authorLogin": null,
+                "assignees": [],
+                "labels": labels,
+                "milestone": null
+            },
+            "fieldValues": []
+        }))
+        .unwrap()
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    async fn test_sync_skips_vibe_to_github_mapping(pool: SqlitePool) {
+        let link = create_sync_test_link(&pool, None).await;
+        let task_id = Uuid::new_v4();
+        let task =
+            CreateTask::from_title_description(link.project_id, "Local title".to_string(), None);
+        Task::create(&pool, &task, task_id).await.unwrap();
+        let mapping = GitHubIssueMapping::create(
+            &pool,
+            &CreateGitHubIssueMapping {
+                task_id,
+                github_project_link_id: link.id,
+                github_issue_number: 42,
+                github_issue_id: "I_42".to_string(),
+                github_issue_url: "https://github.com/test/repo/issues/42".to_string(),
+                sync_direction: None,
+            },
+        )
+        .await
+        .unwrap();
+        GitHubIssueMapping::update_sync_direction(&pool, mapping.id, SyncDirection::VibeToGithub)
+            .await
+            .unwrap();
+
+        let item = issue_item(42, "Title from GitHub", &[]);
+        let outcome = GitHubSyncService::new()
+            .sync_item_from_github(&pool, &link, link.project_id, &item)
+            .await
+            .unwrap();
+
+        // The task stays locally authoritative and the mapping is not marked as synced
+        assert_eq!(outcome, SyncItemOutcome::Skipped);
+        let task = Task::find_by_id(&pool, task_id).await.unwrap().unwrap();
+        assert_eq!(task.title, "Local title");
+        assert_eq!(task.status, TaskStatus::Todo);
+        let stored = GitHubIssueMapping::find_by_id(&pool, mapping.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(stored.last_synced_at.is_none());
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    async fn test_sync_imports_only_issues_with_label_filter(pool: SqlitePool) {
+        let link = create_sync_test_link(&pool, Some("agent-ready")).await;
+        let service = GitHubSyncService::new();
+
+        let labelled = issue_item(1, "Labelled", &["bug", "Agent-Ready"]);
+        let outcome = service
+            .sync_item_from_github(&pool, &link, link.project_id, &labelled)
+            .await
+            .unwrap();
+        assert_eq!(outcome, SyncItemOutcome::Created);
+
+        let unlabelled = issue_item(2, "Unlabelled", &["bug"]);
+        let outcome = service
+            .sync_item_from_github(&pool, &link, link.project_id, &unlabelled)
+            .await
+            .unwrap();
+        assert_eq!(outcome, SyncItemOutcome::Skipped);
+
+        let mappings = GitHubIssueMapping::find_by_link_id(&pool, link.id)
+            .await
+            .unwrap();
+        assert_eq!(mappings.len(), 1);
+        assert_eq!(mappings[0].github_issue_number, 1);
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    async fn test_sync_items_with_label_filter_skips_unlabelled_issues(pool: SqlitePool) {
+        let link = create_sync_test_link(&pool, Some("agent-ready")).await;
+        let service = GitHubSyncService::new();
+        let items = vec![
+            issue_item(1, "Labelled", &["agent-ready"]),
+            issue_item(2, "Unlabelled", &["bug"]),
+            issue_item(3, "No labels", &[]),
+        ];
+
+        let result = service
+            .sync_items(&pool, &link, link.project_id, items)
+            .await
+            .unwrap();
+        assert_eq!(result.items_created, 1);
+        assert_eq!(result.items_synced, 1);
+        assert_eq!(result.items_skipped, 2);
+        assert!(result.errors.is_empty());
+
+        let mappings = GitHubIssueMapping::find_by_link_id(&pool, link.id)
+            .await
+            .unwrap();
+        assert_eq!(mappings.len(), 1);
+        assert_eq!(mappings[0].github_issue_number, 1);
+        let tasks = Task::find_by_project_id(&pool, link.project_id)
+            .await
+            .unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].title, "Labelled");
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    async fn test_sync_skips_unmapped_closed_issue_when_not_importing_closed(pool: SqlitePool) {
+        let link = create_sync_test_link(&pool, None).await;
+        let service = GitHubSyncService::new().with_import_closed(false);
+
+        let closed = issue_item(7, "Closed long ago", &[]);
+        let outcome = service
+            .sync_item_from_github(&pool, &link, link.project_id, &closed)
+            .await
+            .unwrap();
+
+        assert_eq!(outcome, SyncItemOutcome::Skipped);
+        assert!(
+            GitHubIssueMapping::find_by_link_id(&pool, link.id)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert!(
+            Task::find_by_project_id(&pool, link.project_id)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    async fn test_sync_updates_mapped_closed_issue_when_not_importing_closed(pool: SqlitePool) {
+        let link = create_sync_test_link(&pool, None).await;
+        let task_id = Uuid::new_v4();
+        let task =
+            CreateTask::from_title_description(link.project_id, "Local title".to_string(), None);
+        Task::create(&pool, &task, task_id).await.unwrap();
+        let mapping = GitHubIssueMapping::create(
+            &pool,
+            &CreateGitHubIssueMapping {
+                task_id,
+                github_project_link_id: link.id,
+                github_issue_number: 8,
+                github_issue_id: "I_8".to_string(),
+                github_issue_url: "https://github.com/test/repo/issues/8".to_string(),
+                sync_direction: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        let closed = issue_item(8, "Title from GitHub", &[]);
+        let outcome = GitHubSyncService::new()
+            .with_import_closed(false)
+            .sync_item_from_github(&pool, &link, link.project_id, &closed)
+            .await
+            .unwrap();
+
+        assert_eq!(outcome, SyncItemOutcome::Updated);
+        let task = Task::find_by_id(&pool, task_id).await.unwrap().unwrap();
+        assert_eq!(task.title, "Title from GitHub");
+        let stored = GitHubIssueMapping::find_by_id(&pool, mapping.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(stored.last_synced_at.is_some());
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    async fn test_sync_skips_unmapped_merged_pull_request_when_not_importing_closed(
+        pool: SqlitePool,
+    ) {
+        let link = create_sync_test_link(&pool, None).await;
+        let service = GitHubSyncService::new().with_import_closed(false);
+
+        let merged = pr_item(9, "MERGED", true, "Done");
+        let outcome = service
+            .sync_item_from_github(&pool, &link, link.project_id, &merged)
+            .await
+            .unwrap();
+        assert_eq!(outcome, SyncItemOutcome::Skipped);
+
+        let open = pr_item(10, "OPEN", false, "Todo");
+        let outcome = service
+            .sync_item_from_github(&pool, &link, link.project_id, &open)
+            .await
+            .unwrap();
+        assert_eq!(outcome, SyncItemOutcome::Created);
+
+        let mappings = GitHubIssueMapping::find_by_link_id(&pool, link.id)
+            .await
+            .unwrap();
+        assert_eq!(mappings.len(), 1);
+        assert_eq!(mappings[0].github_issue_number, 10);
+    }
+
+    fn pr_item(number: i64, state: &str, merged: bool, project_status: &str) -> GitHubProjectItem {
+        serde_json::from_value(serde_json::json!({
+            "id": format!("PVTI_{number}"),
+            "issue": {
+                "id": format!("PR_{number}"),
+                "number": number,
+                "title": format!("Pull request {number}"),
+                "body": null,
+                "state": state,
+                "url": format!("https://github.com/test/repo/pull/{number}"),
+                "createdAt": "2026-01-01T00:00:00Z",
+                "updatedAt": "2026-01-02T00:00:00Z",
+                "closedAt": null,
+                "authorLogin": null,
+                "assignees": [],
+                "labels": [],
+                "milestone": null,
+                "contentType": "pull_request",
+                "merged": merged
+            },
+            "fieldValues": [{ "fieldName": "Status", "value": project_status }]
+        }))
+        .unwrap()
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    async fn test_sync_maps_pull_request_state_to_task_status(pool: SqlitePool) {
+        let link = create_sync_test_link(&pool, None).await;
+        let service = GitHubSyncService::new();
+        let task_status = |number: i64| {
+            let pool = pool.clone();
+            let link_id = link.id;
+            async move {
+                let mapping = GitHubIssueMapping::find_by_github_issue(&pool, link_id, number)
+                    .await
+                    .unwrap()
+                    .unwrap();
+                Task::find_by_id(&pool, mapping.task_id)
+                    .await
+                    .unwrap()
+                    .unwrap()
+                    .status
+            }
+        };
+
+        // New pull requests take their status from GitHub
+        for (number, state, merged, expected) in [
+            (1, "MERGED", true, TaskStatus::Done),
+            (2, "CLOSED", false, TaskStatus::Cancelled),
+            (3, "OPEN", false, TaskStatus::InReview),
+        ] {
+            let item = pr_item(number, state, merged, "In Review");
+            let outcome = service
+                .sync_item_from_github(&pool, &link, link.project_id, &item)
+                .await
+                .unwrap();
+            assert_eq!(outcome, SyncItemOutcome::Created);
+            assert_eq!(task_status(number).await, expected);
+        }
+
+        // An open pull request keeps the agent workflow status on later syncs...
+        let item = pr_item(3, "OPEN", false, "Todo");
+        service
+            .sync_item_from_github(&pool, &link, link.project_id, &item)
+            .await
+            .unwrap();
+        assert_eq!(task_status(3).await, TaskStatus::InReview);
+
+        // ...until it is merged
+        let item = pr_item(3, "MERGED", true, "In Review");
+        let outcome = service
+            .sync_item_from_github(&pool, &link, link.project_id, &item)
+            .await
+            .unwrap();
+        assert_eq!(outcome, SyncItemOutcome::Updated);
+        assert_eq!(task_status(3).await, TaskStatus::Done);
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    async fn test_sync_stores_properties_readable_through_accessors(pool: SqlitePool) {
+        use crate::services::github::properties;
+
+        let link = create_sync_test_link(&pool, None).await;
+        let item: GitHubProjectItem = serde_json::from_value(serde_json::json!({
+            "id": "PVTI_5",
+            "issue": {
+                "id": "I_5",
+                "number": 5,
+                "title": "With properties",
+                "body": null,
+                "state": "OPEN",
+                "url": "https://github.com/test/repo/issues/5",
+                "createdAt": "2026-01-01T00:00:00Z",
+                "updatedAt": "2026-01-02T00:00:00Z",
+                "closedAt": null,
+                "authorLogin": null,
+                "assignees": ["alice", "bob"],
+                "labels": [{ "name": "bug", "color": "d73a4a" }],
+                "milestone": { "id": "MI_1", "title": "v1.0", "number": 3 }
+            },
+            "fieldValues": [
+                { "fieldName": "Status", "value": "In Progress" },
+                { "fieldName": "Priority", "value": "High" }
+            ]
+        }))
+        .unwrap();
+
+        GitHubSyncService::new()
+            .sync_item_from_github(&pool, &link, link.project_id, &item)
+            .await
+            .unwrap();
+        let task_id = GitHubIssueMapping::find_by_github_issue(&pool, link.id, 5)
+            .await
+            .unwrap()
+            .unwrap()
+            .task_id;
+
+        let labels = properties::labels(&pool, task_id).await.unwrap();
+        assert_eq!(labels.len(), 1);
+        assert_eq!(labels[0].name, "bug");
+        assert_eq!(
+            properties::assignees(&pool, task_id).await.unwrap(),
+            vec!["alice".to_string(), "bob".to_string()]
+        );
+        let milestone = properties::milestone(&pool, task_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(milestone.title, "v1.0");
+        assert_eq!(
+            properties::github_status(&pool, task_id)
+                .await
+                .unwrap()
+                .as_deref(),
+            Some("In Progress")
+        );
+        let priority = TaskProperty::find_by_task_and_name(&pool, task_id, "github_priority")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(priority.property_value, "High");
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    async fn test_sync_clears_last_label_and_assignee_without_push_diff(pool: SqlitePool) {
+        let link = create_sync_test_link(&pool, None).await;
+        let service = GitHubSyncService::new();
+        let item = |labels: serde_json::Value, assignees: serde_json::Value| -> GitHubProjectItem {
+            serde_json::from_value(serde_json::json!({
+                "id": "PVTI_6",
+                "issue": {
+                    "id": "I_6",
+                    "number": 6,
+                    "title": "Losing its label",
+                    "body": null,
+                    "state": "OPEN",
+                    "url": "https://github.com/test/repo/issues/6",
+                    "createdAt": "2026-01-01T00:00:00Z",
+                    "updatedAt": "2026-01-02T00:00:00Z",
+                    "closedAt": null,
+                    "authorLogin": null,
+                    "assignees": assignees,
+                    "labels": labels,
+                    "milestone": null
+                },
+                "fieldValues": []
+            }))
+            .unwrap()
+        };
+
+        let labelled = item(
+            serde_json::json!([{ "name": "bug", "color": "d73a4a" }]),
+            serde_json::json!(["alice"]),
+        );
+        service
+            .sync_item_from_github(&pool, &link, link.project_id, &labelled)
+            .await
+            .unwrap();
+        let task_id = GitHubIssueMapping::find_by_github_issue(&pool, link.id, 6)
+            .await
+            .unwrap()
+            .unwrap()
+            .task_id;
+
+        // The last label and assignee are removed on GitHub
+        let cleared = item(serde_json::json!([]), serde_json::json!([]));
+        service
+            .sync_item_from_github(&pool, &link, link.project_id, &cleared)
+            .await
+            .unwrap();
+
+        for (property_name, synced_property_name) in [
+            (LABELS_PROPERTY, SYNCED_LABELS_PROPERTY),
+            (ASSIGNEES_PROPERTY, SYNCED_ASSIGNEES_PROPERTY),
+        ] {
+            let local = TaskProperty::find_by_task_and_name(&pool, task_id, property_name)
+                .await
+                .unwrap()
+                .unwrap();
+            assert!(parse_property_names(&local.property_value).is_empty());
+            let diff = GitHubSyncService::property_diff(
+                &pool,
+                task_id,
+                property_name,
+                synced_property_name,
+            )
+            .await
+            .unwrap();
+            assert!(diff.is_none(), "{property_name} would be pushed back");
+        }
+    }
+
+    #[test]
+    fn test_has_label_ignores_case() {
+        let item = issue_item(1, "Labelled", &["Agent-Ready"]);
+        let issue = item.issue.as_ref().unwrap();
+        assert!(has_label(issue, "agent-ready"));
+        assert!(has_label(issue, "AGENT-READY"));
+        assert!(!has_label(issue, "agent"));
+    }
 }
@@ -4,6 +4,7 @@ use std::{
 };
 
 use db::models::{
+    dependency_genre::DependencyGenre,
     project::{CreateProject, Project, ProjectError, SearchMatchType, SearchResult, UpdateProject},
     project_repo::{CreateProjectRepo, ProjectRepo},
     repo::Repo,
@@ -115,6 +116,8 @@ impl ProjectService {
             ProjectRepo::create(pool, project.id, repo_entity.id).await?;
         }
 
+        DependencyGenre::seed_defaults(pool, project.id).await?;
+
         Ok(project)
     }
 
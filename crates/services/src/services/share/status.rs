@@ -4,7 +4,8 @@ use remote::db::tasks::TaskStatus as RemoteTaskStatus;
 pub(super) fn to_remote(status: &TaskStatus) -> RemoteTaskStatus {
     match status {
         TaskStatus::Todo => RemoteTaskStatus::Todo,
-        TaskStatus::InProgress => RemoteTaskStatus::InProgress,
+        // The shared task model has no blocked state; a blocked task is still in flight
+        TaskStatus::InProgress | TaskStatus::Blocked => RemoteTaskStatus::InProgress,
         TaskStatus::InReview => RemoteTaskStatus::InReview,
         TaskStatus::Done => RemoteTaskStatus::Done,
         TaskStatus::Cancelled => RemoteTaskStatus::Cancelled,
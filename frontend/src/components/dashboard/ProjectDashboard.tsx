@@ -16,6 +16,7 @@ export function ProjectDashboard({ tasks, onViewTaskDetails }: ProjectDashboardP
       todo: 0,
       inprogress: 0,
       inreview: 0,
+      blocked: 0,
       done: 0,
       cancelled: 0,
     };
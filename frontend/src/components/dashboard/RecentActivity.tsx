@@ -1,6 +1,6 @@
 import { useMemo } from 'react';
 import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/card';
-import { Circle, Play, Eye, CheckCircle2, XCircle } from 'lucide-react';
+import { Circle, Play, Eye, Ban, CheckCircle2, XCircle } from 'lucide-react';
 import type { TaskWithAttemptStatus, TaskStatus } from 'shared/types';
 import { formatDistanceToNow } from 'date-fns';
 import { ja } from 'date-fns/locale';
@@ -14,6 +14,7 @@ const STATUS_ICONS: Record<TaskStatus, React.ReactNode> = {
   todo: <Circle className="h-4 w-4 text-slate-500" />,
   inprogress: <Play className="h-4 w-4 text-blue-500" />,
   inreview: <Eye className="h-4 w-4 text-amber-500" />,
+  blocked: <Ban className="h-4 w-4 text-orange-500" />,
   done: <CheckCircle2 className="h-4 w-4 text-emerald-500" />,
   cancelled: <XCircle className="h-4 w-4 text-red-500" />,
 };
@@ -22,6 +23,7 @@ const STATUS_LABELS: Record<TaskStatus, string> = {
   todo: 'Todo',
   inprogress: '進行中',
   inreview: 'レビュー中',
+  blocked: 'ブロック中',
   done: '完了',
   cancelled: 'キャンセル',
 };
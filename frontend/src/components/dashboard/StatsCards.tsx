@@ -1,5 +1,5 @@
 import { Card, CardContent } from '@/components/ui/card';
-import { Circle, Play, Eye, CheckCircle2, XCircle } from 'lucide-react';
+import { Circle, Play, Eye, Ban, CheckCircle2, XCircle } from 'lucide-react';
 import type { TaskStatus } from 'shared/types';
 
 interface StatsCardsProps {
@@ -36,6 +36,13 @@ const statConfigs: StatCardConfig[] = [
     color: 'text-amber-600 dark:text-amber-400',
     bgColor: 'bg-amber-100 dark:bg-amber-900/30',
   },
+  {
+    status: 'blocked',
+    label: 'ブロック中',
+    icon: <Ban className="h-5 w-5" />,
+    color: 'text-orange-600 dark:text-orange-400',
+    bgColor: 'bg-orange-100 dark:bg-orange-900/30',
+  },
   {
     status: 'done',
     label: '完了',
@@ -54,7 +61,7 @@ const statConfigs: StatCardConfig[] = [
 
 export function StatsCards({ taskCounts }: StatsCardsProps) {
   return (
-    <div className="grid grid-cols-2 sm:grid-cols-3 lg:grid-cols-5 gap-4">
+    <div className="grid grid-cols-2 sm:grid-cols-3 lg:grid-cols-6 gap-4">
       {statConfigs.map((config) => (
         <Card key={config.status} className="border shadow-sm">
           <CardContent className="pt-4 pb-4">
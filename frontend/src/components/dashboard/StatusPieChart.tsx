@@ -11,6 +11,7 @@ const STATUS_COLORS: Record<TaskStatus, string> = {
   todo: '#64748b',
   inprogress: '#3b82f6',
   inreview: '#f59e0b',
+  blocked: '#f97316',
   done: '#10b981',
   cancelled: '#ef4444',
 };
@@ -19,6 +20,7 @@ const STATUS_LABELS: Record<TaskStatus, string> = {
   todo: 'Todo',
   inprogress: '進行中',
   inreview: 'レビュー中',
+  blocked: 'ブロック中',
   done: '完了',
   cancelled: 'キャンセル',
 };
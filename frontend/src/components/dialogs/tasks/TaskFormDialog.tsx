@@ -487,6 +487,9 @@ const TaskFormDialogImpl = NiceModal.create<TaskFormDialogProps>((props) => {
                       <SelectItem value="inreview">
                         {t('taskFormDialog.statusOptions.inreview')}
                       </SelectItem>
+                      <SelectItem value="blocked">
+                        {t('taskFormDialog.statusOptions.blocked')}
+                      </SelectItem>
                       <SelectItem value="done">
                         {t('taskFormDialog.statusOptions.done')}
                       </SelectItem>
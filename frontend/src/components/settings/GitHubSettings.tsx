@@ -80,6 +80,8 @@ export function GitHubSettings({ projectId, className }: GitHubSettingsProps) {
         githubOwner: result.project.ownerLogin,
         githubRepo: null,
         githubProjectNumber: result.project.number,
+        githubHost: null,
+        labelFilter: null,
       });
     }
   };
@@ -104,6 +104,8 @@ export function TaskImportSettings({ projectId, className }: TaskImportSettingsP
         githubOwner: result.project.ownerLogin,
         githubRepo: null,
         githubProjectNumber: result.project.number,
+        githubHost: null,
+        labelFilter: null,
       });
     }
   };
@@ -24,6 +24,7 @@ import {
   CheckCircle2,
   XCircle,
   Clock,
+  Ban,
 } from 'lucide-react';
 
 interface DependenciesViewProps {
@@ -58,6 +59,13 @@ const statusConfig: Record<
     icon: Eye,
     label: 'In Review',
   },
+  blocked: {
+    border: 'border-l-orange-500',
+    bg: 'bg-white dark:bg-slate-900',
+    badge: 'bg-orange-50 text-orange-600 dark:bg-orange-950 dark:text-orange-400',
+    icon: Ban,
+    label: 'Blocked',
+  },
   done: {
     border: 'border-l-emerald-500',
     bg: 'bg-white dark:bg-slate-900',
@@ -274,11 +282,12 @@ export function DependenciesView({
           setEdges(layoutedEdges);
         } else {
           // Fallback: arrange by status columns (left to right)
-          const statusOrder: TaskStatus[] = ['todo', 'inprogress', 'inreview', 'done', 'cancelled'];
+          const statusOrder: TaskStatus[] = ['todo', 'inprogress', 'inreview', 'blocked', 'done', 'cancelled'];
           const tasksByStatus: Record<TaskStatus, Node<TaskNodeData>[]> = {
             todo: [],
             inprogress: [],
             inreview: [],
+            blocked: [],
             done: [],
             cancelled: [],
           };
@@ -18,6 +18,7 @@ const ALL_STATUSES: TaskStatus[] = [
   'todo',
   'inprogress',
   'inreview',
+  'blocked',
   'done',
   'cancelled',
 ];
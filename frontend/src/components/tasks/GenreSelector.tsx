@@ -60,8 +60,11 @@ export function GenreSelector({
     }
   }, [onCreateNew, searchValue, handleSelect]);
 
-  const filteredGenres = genres.filter((g) =>
-    g.name.toLowerCase().includes(searchValue.toLowerCase())
+  // Archived genres can't be picked, but the current selection stays visible
+  const filteredGenres = genres.filter(
+    (g) =>
+      (!g.archived || g.id === selectedGenreId) &&
+      g.name.toLowerCase().includes(searchValue.toLowerCase())
   );
 
   const showCreateOption =
@@ -206,22 +209,24 @@ export function QuickGenreSelector({
       >
         <X className="w-3 h-3 text-muted-foreground" />
       </button>
-      {genres.map((genre) => (
-        <button
-          key={genre.id}
-          type="button"
-          onClick={() => onSelect(genre.id)}
-          disabled={disabled}
-          className={cn(
-            'w-6 h-6 rounded-full',
-            'hover:ring-2 hover:ring-ring hover:ring-offset-1 transition-all',
-            selectedGenreId === genre.id && 'ring-2 ring-ring ring-offset-2',
-            disabled && 'opacity-50 cursor-not-allowed'
-          )}
-          style={{ backgroundColor: genre.color }}
-          title={genre.name}
-        />
-      ))}
+      {genres
+        .filter((genre) => !genre.archived || genre.id === selectedGenreId)
+        .map((genre) => (
+          <button
+            key={genre.id}
+            type="button"
+            onClick={() => onSelect(genre.id)}
+            disabled={disabled}
+            className={cn(
+              'w-6 h-6 rounded-full',
+              'hover:ring-2 hover:ring-ring hover:ring-offset-1 transition-all',
+              selectedGenreId === genre.id && 'ring-2 ring-ring ring-offset-2',
+              disabled && 'opacity-50 cursor-not-allowed'
+            )}
+            style={{ backgroundColor: genre.color }}
+            title={genre.name}
+          />
+        ))}
     </div>
   );
 }
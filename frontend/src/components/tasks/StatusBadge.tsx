@@ -7,6 +7,7 @@ const statusPillColors: Record<TaskStatus, string> = {
   todo: 'bg-slate-100 text-slate-600 dark:bg-slate-700/50 dark:text-slate-300',
   inprogress: 'bg-blue-50 text-blue-600 dark:bg-blue-900/30 dark:text-blue-400',
   inreview: 'bg-amber-50 text-amber-600 dark:bg-amber-900/30 dark:text-amber-400',
+  blocked: 'bg-orange-50 text-orange-600 dark:bg-orange-900/30 dark:text-orange-400',
   done: 'bg-emerald-50 text-emerald-600 dark:bg-emerald-900/30 dark:text-emerald-400',
   cancelled: 'bg-red-50 text-red-500 dark:bg-red-900/30 dark:text-red-400',
 };
@@ -14,6 +14,7 @@ interface TaskDAGEdgeProps extends EdgeProps {
     animated?: boolean;
     genreColor?: string;
     genreName?: string;
+    label?: string | null;
   };
 }
 
@@ -81,6 +82,20 @@ export const TaskDAGEdge = memo(function TaskDAGEdge({
         onMouseEnter={() => setIsHovered(true)}
         onMouseLeave={() => setIsHovered(false)}
       />
+      {!isHovered && data?.label && (
+        <EdgeLabelRenderer>
+          <div
+            style={{
+              position: 'absolute',
+              transform: `translate(-50%, -50%) translate(${labelX}px,${labelY}px)`,
+              pointerEvents: 'none',
+            }}
+            className="nodrag nopan px-1.5 py-0.5 rounded border bg-background text-xs text-muted-foreground"
+          >
+            {data.label}
+          </div>
+        </EdgeLabelRenderer>
+      )}
       {isHovered && (
         <EdgeLabelRenderer>
           <div
@@ -103,6 +118,11 @@ export const TaskDAGEdge = memo(function TaskDAGEdge({
                 {data.genreName}
               </div>
             )}
+            {data?.label && (
+              <div className="px-2 py-0.5 rounded border bg-background text-xs shadow-md">
+                {data.label}
+              </div>
+            )}
             {data?.onDelete && (
               <button
                 onClick={handleDelete}
@@ -8,7 +8,8 @@ import {
   Clock,
   Lock,
   Play,
-  Eye
+  Eye,
+  Ban
 } from 'lucide-react';
 import type { TaskWithAttemptStatus, TaskReadiness } from 'shared/types';
 import { cn } from '@/lib/utils';
@@ -25,7 +26,7 @@ interface TaskDAGNodeProps {
 }
 
 // Helper to get readiness type from TaskReadiness union
-function getReadinessType(readiness: TaskReadiness): 'ready' | 'blocked' | 'in_progress' | 'completed' | 'cancelled' {
+function getReadinessType(readiness: TaskReadiness): 'ready' | 'blocked' | 'in_progress' | 'on_hold' | 'completed' | 'cancelled' {
   if (typeof readiness === 'string') {
     return readiness;
   }
@@ -77,6 +78,14 @@ const STATUS_CONFIG = {
     Icon: Eye,
     label: 'In Review',
   },
+  blocked: {
+    borderColor: 'border-orange-400 dark:border-orange-500',
+    bgColor: 'bg-orange-50 dark:bg-orange-950/40',
+    iconBg: 'bg-orange-100 dark:bg-orange-900/60',
+    iconColor: 'text-orange-600 dark:text-orange-400',
+    Icon: Ban,
+    label: 'Blocked',
+  },
   cancelled: {
     borderColor: 'border-red-400 dark:border-red-500',
     bgColor: 'bg-red-50 dark:bg-red-950/40',
@@ -127,6 +127,7 @@ function createEdges(
         onDelete,
         genreColor: genre?.color,
         genreName: genre?.name,
+        label: dep.label,
       },
     };
   });
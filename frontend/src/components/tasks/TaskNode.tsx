@@ -8,6 +8,7 @@ const STATUS_ICONS: Record<TaskStatus, string> = {
   todo: '○',
   inprogress: '⏳',
   inreview: '👀',
+  blocked: '⛔',
   done: '✓',
   cancelled: '✕',
 };
@@ -19,6 +20,8 @@ const STATUS_NODE_COLORS: Record<TaskStatus, string> = {
     'border-blue-400 bg-blue-50 dark:border-blue-500 dark:bg-blue-900/30',
   inreview:
     'border-amber-400 bg-amber-50 dark:border-amber-500 dark:bg-amber-900/30',
+  blocked:
+    'border-orange-400 bg-orange-50 dark:border-orange-500 dark:bg-orange-900/30',
   done: 'border-emerald-400 bg-emerald-50 dark:border-emerald-500 dark:bg-emerald-900/30',
   cancelled:
     'border-red-400 bg-red-50 dark:border-red-500 dark:bg-red-900/30',
@@ -29,6 +32,7 @@ const STATUS_ICON_COLORS: Record<TaskStatus, string> = {
   todo: 'text-slate-500 dark:text-slate-400',
   inprogress: 'text-blue-600 dark:text-blue-400',
   inreview: 'text-amber-600 dark:text-amber-400',
+  blocked: 'text-orange-600 dark:text-orange-400',
   done: 'text-emerald-600 dark:text-emerald-400',
   cancelled: 'text-red-500 dark:text-red-400',
 };
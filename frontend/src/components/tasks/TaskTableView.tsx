@@ -21,6 +21,7 @@ const TASK_STATUSES: TaskStatus[] = [
   'todo',
   'inprogress',
   'inreview',
+  'blocked',
   'done',
   'cancelled',
 ];
@@ -91,6 +92,7 @@ function TaskTableViewComponent({
             title: sharedTask.title,
             description: sharedTask.description,
             status: sharedTask.status,
+            task_type: 'feature',
             parent_workspace_id: null,
             shared_task_id: sharedTask.id,
             project_id: sharedTask.project_id,
@@ -149,13 +151,14 @@ function TaskTableViewComponent({
           comparison = taskA.title.localeCompare(taskB.title, 'ja');
           break;
         case 'status': {
-          // Status order: todo < inprogress < inreview < done < cancelled
+          // Status order: todo < inprogress < inreview < blocked < done < cancelled
           const statusOrder: Record<TaskStatus, number> = {
             todo: 0,
             inprogress: 1,
             inreview: 2,
-            done: 3,
-            cancelled: 4,
+            blocked: 3,
+            done: 4,
+            cancelled: 5,
           };
           comparison = statusOrder[taskA.status] - statusOrder[taskB.status];
           break;
@@ -9,7 +9,7 @@ import type {
 import { orchestrationApi } from '@/lib/api';
 
 // Helper to get readiness type from TaskReadiness union
-function getReadinessType(readiness: TaskReadiness): 'ready' | 'blocked' | 'in_progress' | 'completed' | 'cancelled' {
+function getReadinessType(readiness: TaskReadiness): 'ready' | 'blocked' | 'in_progress' | 'on_hold' | 'completed' | 'cancelled' {
   if (typeof readiness === 'string') {
     return readiness;
   }
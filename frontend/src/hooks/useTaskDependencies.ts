@@ -2,7 +2,7 @@ import { useCallback, useMemo } from 'react';
 import { useMutation } from '@tanstack/react-query';
 import { useJsonPatchWsStream } from './useJsonPatchWsStream';
 import { dependenciesApi, ApiError } from '@/lib/api';
-import type { TaskDependency } from 'shared/types';
+import type { DependencyKind, TaskDependency } from 'shared/types';
 
 export const dependencyKeys = {
   all: ['dependencies'] as const,
@@ -50,10 +50,14 @@ export function useTaskDependencies(projectId: string | undefined) {
       task_id: string;
       depends_on_task_id: string;
       genre_id?: string | null;
+      kind?: DependencyKind | null;
+      label?: string | null;
     }) => dependenciesApi.create(projectId!, {
       task_id: input.task_id,
       depends_on_task_id: input.depends_on_task_id,
       genre_id: input.genre_id ?? null,
+      kind: input.kind ?? null,
+      label: input.label ?? null,
     }),
     // No need for onSuccess - WebSocket will handle the update
     onError: (err: ApiError) => {
@@ -78,7 +82,7 @@ export function useTaskDependencies(projectId: string | undefined) {
       data,
     }: {
       dependencyId: string;
-      data: { genre_id?: string | null };
+      data: { genre_id?: string | null; label?: string | null };
     }) => dependenciesApi.update(dependencyId, data),
     // No need for onSuccess - WebSocket will handle the update
     onError: (err: ApiError) => {
@@ -6,8 +6,9 @@ const STATUS_ORDER: Record<TaskStatus, number> = {
   todo: 0,
   inprogress: 1,
   inreview: 2,
-  done: 3,
-  cancelled: 4,
+  blocked: 3,
+  done: 4,
+  cancelled: 5,
 };
 
 export function useTaskSort<T extends TaskWithAttemptStatus>(
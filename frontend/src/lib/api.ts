@@ -13,7 +13,14 @@ import {
   CreateTag,
   CreateDependencyGenre,
   CreateTaskDependency,
+  DagPositionUpdate,
+  DependencyCreator,
   DependencyGenre,
+  DependencyGenreWithUsage,
+  DependencyGraphExport,
+  DependencyImportReport,
+  DependencyKind,
+  GraphCloneReport,
   DirectoryListResponse,
   DirectoryEntry,
   ExecutionProcess,
@@ -101,7 +108,10 @@ import {
   CreateGitHubLinkRequest,
   SyncResult,
   GitHubIssueMapping,
+  SyncDirection,
   TaskProperty,
+  TaskPropertyChange,
+  OnReferenced,
 } from 'shared/types';
 import type { WorkspaceWithSession } from '@/types/attempt';
 import { createWorkspaceWithSession } from '@/types/attempt';
@@ -406,6 +416,21 @@ export const tasksApi = {
     return handleApiResponse<Task>(response);
   },
 
+  /** Update the DAG positions of several tasks in one request */
+  updateDagPositions: async (
+    projectId: string,
+    updates: DagPositionUpdate[]
+  ): Promise<number> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/tasks/positions`,
+      {
+        method: 'PUT',
+        body: JSON.stringify(updates),
+      }
+    );
+    return handleApiResponse<number>(response);
+  },
+
   delete: async (taskId: string): Promise<void> => {
     const response = await makeRequest(`/api/tasks/${taskId}`, {
       method: 'DELETE',
@@ -459,6 +484,17 @@ export const tasksApi = {
     return handleApiResponse<TaskProperty[]>(response);
   },
 
+  /** Value changes of one property, oldest first */
+  getPropertyHistory: async (
+    taskId: string,
+    propertyName: string
+  ): Promise<TaskPropertyChange[]> => {
+    const response = await makeRequest(
+      `/api/tasks/${taskId}/properties/history?name=${encodeURIComponent(propertyName)}`
+    );
+    return handleApiResponse<TaskPropertyChange[]>(response);
+  },
+
   getBulkProperties: async (
     taskIds: string[]
   ): Promise<Record<string, TaskProperty[]>> => {
@@ -1418,8 +1454,9 @@ export const queueApi = {
 // GitHub Integration API
 export const githubApi = {
   /** Check GitHub CLI availability and authentication status */
-  getStatus: async (): Promise<GitHubStatusResponse> => {
-    const response = await makeRequest('/api/github/status');
+  getStatus: async (force = false): Promise<GitHubStatusResponse> => {
+    const query = force ? '?force=true' : '';
+    const response = await makeRequest(`/api/github/status${query}`);
     return handleApiResponse<GitHubStatusResponse>(response);
   },
 
@@ -1435,6 +1472,17 @@ export const githubApi = {
     return handleApiResponse<GitHubProject[]>(response);
   },
 
+  /** Get GitHub Projects attached to a repository */
+  getRepoProjects: async (
+    owner: string,
+    repo: string
+  ): Promise<GitHubProject[]> => {
+    const response = await makeRequest(
+      `/api/github/repositories/${owner}/${repo}/projects`
+    );
+    return handleApiResponse<GitHubProject[]>(response);
+  },
+
   /** Get GitHub project links for a project */
   getLinks: async (projectId: string): Promise<GitHubLinkResponse[]> => {
     const response = await makeRequest(
@@ -1476,10 +1524,18 @@ export const githubApi = {
     return handleApiResponse<void>(response);
   },
 
-  /** Trigger manual sync for a GitHub project link */
-  syncLink: async (projectId: string, linkId: string): Promise<SyncResult> => {
+  /**
+   * Trigger manual sync for a GitHub project link.
+   * Pass `importClosed: false` to skip closed issues that don't have a task yet.
+   */
+  syncLink: async (
+    projectId: string,
+    linkId: string,
+    options: { importClosed?: boolean } = {}
+  ): Promise<SyncResult> => {
+    const query = options.importClosed === false ? '?importClosed=false' : '';
     const response = await makeRequest(
-      `/api/projects/${projectId}/github-links/${linkId}/sync`,
+      `/api/projects/${projectId}/github-links/${linkId}/sync${query}`,
       {
         method: 'POST',
       }
@@ -1513,18 +1569,86 @@ export const githubApi = {
     );
     return handleApiResponse<GitHubIssueMapping[]>(response);
   },
+
+  /** Stop syncing a single task, optionally clearing its GitHub properties */
+  unlinkMapping: async (
+    projectId: string,
+    linkId: string,
+    taskId: string,
+    clearProperties = false
+  ): Promise<void> => {
+    const query = clearProperties ? '?clearProperties=true' : '';
+    const response = await makeRequest(
+      `/api/projects/${projectId}/github-links/${linkId}/mappings/${taskId}${query}`,
+      {
+        method: 'DELETE',
+      }
+    );
+    return handleApiResponse<void>(response);
+  },
+
+  /** Change which way a single task syncs with its GitHub issue */
+  updateMappingSyncDirection: async (
+    projectId: string,
+    linkId: string,
+    taskId: string,
+    direction: SyncDirection
+  ): Promise<GitHubIssueMapping> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/github-links/${linkId}/mappings/${taskId}/sync-direction`,
+      {
+        method: 'PUT',
+        body: JSON.stringify({ direction }),
+      }
+    );
+    return handleApiResponse<GitHubIssueMapping>(response);
+  },
 };
 
 // Task Dependencies API
 export const dependenciesApi = {
-  /** Get all dependencies for a project */
-  getByProject: async (projectId: string): Promise<TaskDependency[]> => {
+  /** Get all dependencies for a project, optionally filtered by creator, genre or age */
+  getByProject: async (
+    projectId: string,
+    filters: {
+      createdBy?: DependencyCreator;
+      genreId?: string;
+      since?: string;
+    } = {}
+  ): Promise<TaskDependency[]> => {
+    const params = new URLSearchParams();
+    if (filters.createdBy) params.set('createdBy', filters.createdBy);
+    if (filters.genreId) params.set('genreId', filters.genreId);
+    if (filters.since) params.set('since', filters.since);
+    const query = params.toString() ? `?${params.toString()}` : '';
+    const response = await makeRequest(
+      `/api/projects/${projectId}/dependencies${query}`
+    );
+    return handleApiResponse<TaskDependency[]>(response);
+  },
+
+  /** Get the dependencies a task waits on and the ones waiting on it, in one call */
+  getByTask: async (
+    projectId: string,
+    taskId: string
+  ): Promise<TaskDependency[]> => {
     const response = await makeRequest(
-      `/api/projects/${projectId}/dependencies`
+      `/api/projects/${projectId}/tasks/${taskId}/dependencies`
     );
     return handleApiResponse<TaskDependency[]>(response);
   },
 
+  /** Dependents that could never be completed if the task were cancelled */
+  getCancellationImpact: async (
+    projectId: string,
+    taskId: string
+  ): Promise<string[]> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/tasks/${taskId}/cancellation-impact`
+    );
+    return handleApiResponse<string[]>(response);
+  },
+
   /** Create a new dependency */
   create: async (
     projectId: string,
@@ -1543,10 +1667,10 @@ export const dependenciesApi = {
     return handleApiResponse<TaskDependency>(response);
   },
 
-  /** Update a dependency (e.g., change its genre) */
+  /** Update a dependency (e.g., change its genre or label) */
   update: async (
     dependencyId: string,
-    data: { genre_id?: string | null }
+    data: { genre_id?: string | null; label?: string | null }
   ): Promise<TaskDependency> => {
     const response = await makeRequest(`/api/dependencies/${dependencyId}`, {
       method: 'PUT',
@@ -1555,6 +1679,22 @@ export const dependenciesApi = {
     return handleApiResponse<TaskDependency>(response);
   },
 
+  /** Change how strictly a dependency blocks, keeping its creation metadata */
+  updateKind: async (
+    projectId: string,
+    dependencyId: string,
+    kind: DependencyKind
+  ): Promise<TaskDependency> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/dependencies/${dependencyId}/kind`,
+      {
+        method: 'PUT',
+        body: JSON.stringify({ kind }),
+      }
+    );
+    return handleApiResponse<TaskDependency>(response);
+  },
+
   /** Delete a dependency */
   delete: async (dependencyId: string): Promise<void> => {
     const response = await makeRequest(`/api/dependencies/${dependencyId}`, {
@@ -1562,16 +1702,101 @@ export const dependenciesApi = {
     });
     return handleApiResponse<void>(response);
   },
+
+  /** Delete the dependency between a task pair */
+  deleteByPair: async (
+    projectId: string,
+    taskId: string,
+    dependsOnTaskId: string
+  ): Promise<number> => {
+    const params = new URLSearchParams({ taskId, dependsOnTaskId });
+    const response = await makeRequest(
+      `/api/projects/${projectId}/dependencies?${params.toString()}`,
+      { method: 'DELETE' }
+    );
+    return handleApiResponse<number>(response);
+  },
+
+  /** Delete every dependency in a project created by a user or by AI */
+  deleteByCreator: async (
+    projectId: string,
+    createdBy: DependencyCreator
+  ): Promise<number> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/dependencies?createdBy=${createdBy}`,
+      { method: 'DELETE' }
+    );
+    return handleApiResponse<number>(response);
+  },
+
+  /** Export the project's genres and dependencies, keyed by task title */
+  exportGraph: async (projectId: string): Promise<DependencyGraphExport> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/dependencies/export`
+    );
+    return handleApiResponse<DependencyGraphExport>(response);
+  },
+
+  /** Import an exported dependency graph into the project */
+  importGraph: async (
+    projectId: string,
+    data: DependencyGraphExport
+  ): Promise<DependencyImportReport> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/dependencies/import`,
+      {
+        method: 'POST',
+        body: JSON.stringify(data),
+      }
+    );
+    return handleApiResponse<DependencyImportReport>(response);
+  },
+
+  /** Recreate the project's tasks and dependencies in another project */
+  cloneGraph: async (
+    projectId: string,
+    targetProjectId: string
+  ): Promise<GraphCloneReport> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/clone-graph`,
+      {
+        method: 'POST',
+        body: JSON.stringify({ target_project_id: targetProjectId }),
+      }
+    );
+    return handleApiResponse<GraphCloneReport>(response);
+  },
+
+  /** Re-run the DAG layout; returns the number of tasks that were moved */
+  recalculateLayout: async (projectId: string): Promise<number> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/dependencies/recalculate-layout`,
+      { method: 'POST' }
+    );
+    return handleApiResponse<number>(response);
+  },
+
+  /** Tasks with no dependencies and no dependents, left out of the DAG layout */
+  getOrphanTasks: async (projectId: string): Promise<Task[]> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/tasks/orphans`
+    );
+    return handleApiResponse<Task[]>(response);
+  },
 };
 
 // Dependency Genres API
 export const dependencyGenresApi = {
-  /** Get all genres for a project */
-  getByProject: async (projectId: string): Promise<DependencyGenre[]> => {
+  /** Get the genres of a project; archived ones only with `includeArchived` */
+  getByProject: async (
+    projectId: string,
+    includeArchived = false
+  ): Promise<DependencyGenreWithUsage[]> => {
+    const query = includeArchived ? '?includeArchived=true' : '';
     const response = await makeRequest(
-      `/api/projects/${projectId}/dependency-genres`
+      `/api/projects/${projectId}/dependency-genres${query}`
     );
-    return handleApiResponse<DependencyGenre[]>(response);
+    return handleApiResponse<DependencyGenreWithUsage[]>(response);
   },
 
   /** Create a new genre */
@@ -1601,11 +1826,32 @@ export const dependencyGenresApi = {
     return handleApiResponse<DependencyGenre>(response);
   },
 
-  /** Delete a genre */
-  delete: async (genreId: string): Promise<void> => {
-    const response = await makeRequest(`/api/dependency-genres/${genreId}`, {
-      method: 'DELETE',
-    });
+  /** Archive or restore a genre */
+  setArchived: async (
+    genreId: string,
+    archived: boolean
+  ): Promise<DependencyGenre> => {
+    const action = archived ? 'archive' : 'unarchive';
+    const response = await makeRequest(
+      `/api/dependency-genres/${genreId}/${action}`,
+      {
+        method: 'POST',
+      }
+    );
+    return handleApiResponse<DependencyGenre>(response);
+  },
+
+  /** Delete a genre; `clear` untags dependencies that still use it */
+  delete: async (
+    genreId: string,
+    onReferenced: OnReferenced = 'block'
+  ): Promise<void> => {
+    const response = await makeRequest(
+      `/api/dependency-genres/${genreId}?onReferenced=${onReferenced}`,
+      {
+        method: 'DELETE',
+      }
+    );
     return handleApiResponse<void>(response);
   },
 
@@ -1623,19 +1869,54 @@ export const dependencyGenresApi = {
     );
     return handleApiResponse<DependencyGenre[]>(response);
   },
+
+  /** Merge a genre into another one, moving its dependencies */
+  merge: async (
+    projectId: string,
+    genreId: string,
+    intoGenreId: string
+  ): Promise<DependencyGenre> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/dependency-genres/${genreId}/merge`,
+      {
+        method: 'POST',
+        body: JSON.stringify({ into: intoGenreId }),
+      }
+    );
+    return handleApiResponse<DependencyGenre>(response);
+  },
+
+  /** Seed the default genres into a project that has none yet */
+  seedDefaults: async (projectId: string): Promise<DependencyGenre[]> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/dependency-genres/seed-defaults`,
+      { method: 'POST' }
+    );
+    return handleApiResponse<DependencyGenre[]>(response);
+  },
 };
 
 // Orchestration API
 export const orchestrationApi = {
-  /** Get orchestrator state and execution plan for a project */
+  /** Get orchestrator state and execution plan, optionally for one genre or assignee */
   getState: async (
-    projectId: string
+    projectId: string,
+    genreId?: string,
+    assignee?: string,
+    mode?: import('shared/types').PlanMode,
+    compact?: boolean
   ): Promise<{
     state: import('shared/types').OrchestratorState;
     plan: import('shared/types').ExecutionPlan;
   }> => {
+    const params = new URLSearchParams();
+    if (genreId) params.set('genreId', genreId);
+    if (assignee) params.set('assignee', assignee);
+    if (mode) params.set('mode', mode);
+    if (compact) params.set('compact', 'true');
+    const query = params.toString() ? `?${params.toString()}` : '';
     const response = await makeRequest(
-      `/api/projects/${projectId}/orchestrator`
+      `/api/projects/${projectId}/orchestrator${query}`
     );
     return handleApiResponse<{
       state: import('shared/types').OrchestratorState;
@@ -1711,14 +1992,135 @@ export const orchestrationApi = {
     }>(response);
   },
 
-  /** Get tasks that are ready to execute */
-  getReadyTasks: async (projectId: string): Promise<string[]> => {
+  /** Reset the orchestrator, discarding its in-memory state */
+  reset: async (
+    projectId: string
+  ): Promise<import('shared/types').OrchestratorStateResponse> => {
     const response = await makeRequest(
-      `/api/projects/${projectId}/orchestrator/ready-tasks`
+      `/api/projects/${projectId}/orchestrator/reset`,
+      { method: 'POST' }
+    );
+    return handleApiResponse<
+      import('shared/types').OrchestratorStateResponse
+    >(response);
+  },
+
+  /** Get tasks that are ready to execute; with `preview`, also while not running */
+  getReadyTasks: async (
+    projectId: string,
+    preview = false
+  ): Promise<string[]> => {
+    const query = preview ? '?preview=true' : '';
+    const response = await makeRequest(
+      `/api/projects/${projectId}/orchestrator/ready-tasks${query}`
     );
     return handleApiResponse<string[]>(response);
   },
 
+  /** Get the plan's tasks in one readiness column */
+  getTasksByReadiness: async (
+    projectId: string,
+    readiness: import('shared/types').ReadinessFilter
+  ): Promise<import('shared/types').ExecutableTask[]> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/orchestrator/tasks?readiness=${readiness}`
+    );
+    return handleApiResponse<import('shared/types').ExecutableTask[]>(
+      response
+    );
+  },
+
+  /** Get the single task to start next, or why none can start */
+  getNextAction: async (
+    projectId: string
+  ): Promise<import('shared/types').NextAction> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/orchestrator/next-action`
+    );
+    return handleApiResponse<import('shared/types').NextAction>(response);
+  },
+
+  /** Get tasks stuck behind other stuck tasks or in progress for too long */
+  getAtRiskTasks: async (
+    projectId: string,
+    staleAfterSecs?: number
+  ): Promise<import('shared/types').AtRiskTask[]> => {
+    const query =
+      staleAfterSecs != null ? `?stale_after_secs=${staleAfterSecs}` : '';
+    const response = await makeRequest(
+      `/api/projects/${projectId}/orchestrator/at-risk${query}`
+    );
+    return handleApiResponse<import('shared/types').AtRiskTask[]>(response);
+  },
+
+  /** Get the orchestrator's most recent events, oldest first */
+  getRecentEvents: async (
+    projectId: string,
+    limit?: number
+  ): Promise<import('shared/types').OrchestratorEvent[]> => {
+    const query = limit != null ? `?limit=${limit}` : '';
+    const response = await makeRequest(
+      `/api/projects/${projectId}/orchestrator/recent-events${query}`
+    );
+    return handleApiResponse<import('shared/types').OrchestratorEvent[]>(
+      response
+    );
+  },
+
+  /** Get all tasks in one dependency order, with cyclic tasks listed apart */
+  getTaskOrder: async (
+    projectId: string
+  ): Promise<import('shared/types').TopologicalOrder> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/orchestrator/order`
+    );
+    return handleApiResponse<import('shared/types').TopologicalOrder>(response);
+  },
+
+  /** Get the projected start and end of each task, in minutes from now */
+  getGantt: async (
+    projectId: string
+  ): Promise<import('shared/types').GanttChart> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/orchestrator/gantt`
+    );
+    return handleApiResponse<import('shared/types').GanttChart>(response);
+  },
+
+  /** Get the current execution plan with its version */
+  getVersionedPlan: async (
+    projectId: string
+  ): Promise<import('shared/types').VersionedPlan> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/orchestrator/plan`
+    );
+    return handleApiResponse<import('shared/types').VersionedPlan>(response);
+  },
+
+  /** Check the dependency graph for cycles, bad edges and tasks that can never start */
+  validateGraph: async (
+    projectId: string
+  ): Promise<import('shared/types').GraphValidationReport> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/orchestrator/validate`
+    );
+    return handleApiResponse<import('shared/types').GraphValidationReport>(
+      response
+    );
+  },
+
+  /** Get plan changes since a version, or the full plan if that version has expired */
+  getPlanDiff: async (
+    projectId: string,
+    epoch: string,
+    since: number
+  ): Promise<import('shared/types').PlanDiffResponse> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/orchestrator/plan/diff?epoch=${epoch}&since=${since}`
+    );
+    return handleApiResponse<import('shared/types').PlanDiffResponse>(response);
+  },
+
   /** Validate a task transition */
   validateTransition: async (
     projectId: string,
@@ -1737,10 +2139,100 @@ export const orchestrationApi = {
     );
   },
 
+  /** Move several tasks to a new status, applying only the valid transitions */
+  bulkTransition: async (
+    projectId: string,
+    taskIds: string[],
+    newStatus: string,
+    confirm = false
+  ): Promise<import('shared/types').BulkTransitionResult[]> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/orchestrator/bulk-transition`,
+      {
+        method: 'POST',
+        body: JSON.stringify({ taskIds, newStatus, confirm }),
+      }
+    );
+    return handleApiResponse<import('shared/types').BulkTransitionResult[]>(
+      response
+    );
+  },
+
+  /** Set how long a task may run before it is reported timed out; null disables it */
+  setTaskTimeout: async (
+    projectId: string,
+    timeoutSecs: number | null
+  ): Promise<number | null> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/orchestrator/task-timeout`,
+      {
+        method: 'PUT',
+        body: JSON.stringify({ timeout_secs: timeoutSecs }),
+      }
+    );
+    return handleApiResponse<number | null>(response);
+  },
+
+  /** Running tasks that exceeded the task timeout, longest running first */
+  getTimedOutTasks: async (projectId: string): Promise<string[]> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/orchestrator/timeouts`
+    );
+    return handleApiResponse<string[]>(response);
+  },
+
+  /** Move failed tasks back to Todo; returns the restarted task IDs */
+  retryFailed: async (projectId: string): Promise<string[]> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/orchestrator/retry-failed`,
+      { method: 'POST' }
+    );
+    return handleApiResponse<string[]>(response);
+  },
+
+  /** Tasks that would become ready if all of the given tasks were done */
+  forecastUnblocked: async (
+    projectId: string,
+    taskIds: string[]
+  ): Promise<string[]> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/orchestrator/forecast-unblocked`,
+      {
+        method: 'POST',
+        body: JSON.stringify({ taskIds }),
+      }
+    );
+    return handleApiResponse<string[]>(response);
+  },
+
   /** Get WebSocket stream URL for orchestrator events */
   getStreamUrl: (projectId: string): string =>
     `/api/projects/${projectId}/orchestrator/stream/ws`,
 
+  /** Exclude a task from auto-scheduling without changing its status */
+  skipTask: async (
+    projectId: string,
+    taskId: string
+  ): Promise<import('shared/types').ExecutionPlan> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/orchestrator/tasks/${taskId}/skip`,
+      { method: 'POST' }
+    );
+    return handleApiResponse<import('shared/types').ExecutionPlan>(response);
+  },
+
+  /** Let a skipped task be scheduled again */
+  unskipTask: async (
+    projectId: string,
+    taskId: string
+  ): Promise<import('shared/types').ExecutionPlan> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/orchestrator/tasks/${taskId}/skip`,
+      { method: 'DELETE' }
+    );
+    return handleApiResponse<import('shared/types').ExecutionPlan>(response);
+  },
+
   /** Notify orchestrator that a task has started */
   notifyTaskStarted: async (
     projectId: string,
@@ -139,6 +139,7 @@ export function CompletedTasks() {
       todo: [],
       inprogress: [],
       inreview: [],
+      blocked: [],
       done: [],
       cancelled: [],
     };
@@ -89,6 +89,7 @@ const TASK_STATUSES = [
   'todo',
   'inprogress',
   'inreview',
+  'blocked',
   'done',
   'cancelled',
 ] as const;
@@ -423,6 +424,7 @@ export function ProjectTasks() {
       todo: [],
       inprogress: [],
       inreview: [],
+      blocked: [],
       done: [],
       cancelled: [],
     };
@@ -525,6 +527,7 @@ export function ProjectTasks() {
       todo: [],
       inprogress: [],
       inreview: [],
+      blocked: [],
       done: [],
       cancelled: [],
     };
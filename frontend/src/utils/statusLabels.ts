@@ -4,6 +4,7 @@ export const statusLabels: Record<TaskStatus, string> = {
   todo: 'To Do',
   inprogress: 'In Progress',
   inreview: 'In Review',
+  blocked: 'Blocked',
   done: 'Done',
   cancelled: 'Cancelled',
 };
@@ -12,6 +13,7 @@ export const statusBoardColors: Record<TaskStatus, string> = {
   todo: '--neutral-foreground',
   inprogress: '--info',
   inreview: '--warning',
+  blocked: '--warning',
   done: '--success',
   cancelled: '--destructive',
 };
@@ -21,6 +23,7 @@ export const statusBadgeColors: Record<TaskStatus, string> = {
   todo: 'bg-gray-100 text-gray-700 dark:bg-neutral dark:text-neutral-foreground',
   inprogress: 'bg-blue-100 text-blue-700 dark:bg-info/20 dark:text-info-foreground',
   inreview: 'bg-amber-100 text-amber-700 dark:bg-warning/20 dark:text-warning-foreground',
+  blocked: 'bg-orange-100 text-orange-700 dark:bg-warning/20 dark:text-warning-foreground',
   done: 'bg-green-100 text-green-700 dark:bg-success/20 dark:text-success-foreground',
   cancelled: 'bg-red-100 text-red-700 dark:bg-destructive/20 dark:text-destructive',
 };
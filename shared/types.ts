@@ -46,11 +46,13 @@ export type CreateTag = { tag_name: string, content: string, };
 
 export type UpdateTag = { tag_name: string | null, content: string | null, };
 
-export type TaskStatus = "todo" | "inprogress" | "inreview" | "done" | "cancelled";
+export type TaskStatus = "todo" | "inprogress" | "inreview" | "blocked" | "done" | "cancelled";
 
-export type Task = { id: string, project_id: string, title: string, description: string | null, status: TaskStatus, parent_workspace_id: string | null, shared_task_id: string | null, position: number | null, dag_position_x: number | null, dag_position_y: number | null, created_at: string, updated_at: string, };
+export type TaskType = "feature" | "bug" | "chore";
 
-export type TaskWithAttemptStatus = { has_in_progress_attempt: boolean, last_attempt_failed: boolean, executor: string, id: string, project_id: string, title: string, description: string | null, status: TaskStatus, parent_workspace_id: string | null, shared_task_id: string | null, position: number | null, dag_position_x: number | null, dag_position_y: number | null, created_at: string, updated_at: string, };
+export type Task = { id: string, project_id: string, title: string, description: string | null, status: TaskStatus, task_type: TaskType, parent_workspace_id: string | null, shared_task_id: string | null, position: number | null, dag_position_x: number | null, dag_position_y: number | null, created_at: string, updated_at: string, };
+
+export type TaskWithAttemptStatus = { has_in_progress_attempt: boolean, last_attempt_failed: boolean, executor: string, id: string, project_id: string, title: string, description: string | null, status: TaskStatus, task_type: TaskType, parent_workspace_id: string | null, shared_task_id: string | null, position: number | null, dag_position_x: number | null, dag_position_y: number | null, created_at: string, updated_at: string, };
 
 export type TaskRelationships = { parent_task: Task | null, current_workspace: Workspace, children: Array<Task>, };
 
@@ -62,15 +64,33 @@ export type UpdateTask = { title: string | null, description: string | null, sta
  */
 clear_dag_position: boolean, };
 
+export type DagPositionUpdate = { taskId: string, x: number, y: number, };
+
 export type DependencyCreator = "user" | "ai";
 
-export type TaskDependency = { id: string, task_id: string, depends_on_task_id: string, genre_id: string | null, created_at: string, created_by: DependencyCreator, };
+export type DependencyKind = "strict" | "skip_if_cancelled" | "optional";
+
+export type TaskDependency = { id: string, task_id: string, depends_on_task_id: string, genre_id: string | null, created_at: string, created_by: DependencyCreator, kind: DependencyKind, 
+/**
+ * Short free-text annotation drawn on the edge, independent of the genre
+ */
+label: string | null, };
 
-export type CreateTaskDependency = { task_id: string, depends_on_task_id: string, created_by: DependencyCreator | null, genre_id: string | null, };
+export type CreateTaskDependency = { task_id: string, depends_on_task_id: string, created_by: DependencyCreator | null, genre_id: string | null, kind: DependencyKind | null, label: string | null, };
 
-export type UpdateTaskDependency = { genre_id: string | null | null, };
+export type UpdateTaskDependency = { genre_id: string | null | null, label: string | null | null, };
 
-export type DependencyGenre = { id: string, project_id: string, name: string, color: string, position: number, created_at: string, updated_at: string, };
+export type DependencyGenre = { id: string, project_id: string, name: string, color: string, position: number, 
+/**
+ * Hidden from pickers but still used to render the dependencies it categorizes
+ */
+archived: boolean, created_at: string, updated_at: string, };
+
+export type DependencyGenreWithUsage = { usage_count: number, id: string, project_id: string, name: string, color: string, position: number, 
+/**
+ * Hidden from pickers but still used to render the dependencies it categorizes
+ */
+archived: boolean, created_at: string, updated_at: string, };
 
 export type CreateDependencyGenre = { project_id: string, name: string, color: string | null, position: number | null, };
 
@@ -78,6 +98,40 @@ export type UpdateDependencyGenre = { name: string | null, color: string | null,
 
 export type ReorderGenresRequest = { genre_ids: Array<string>, };
 
+export type DependencyGraphExport = { genres: Array<ExportedGenre>, dependencies: Array<ExportedDependency>, };
+
+export type ExportedGenre = { name: string, color: string, position: number, };
+
+export type ExportedDependency = { task: string, depends_on: string, genre: string | null, kind: DependencyKind, label: string | null, };
+
+export type DependencyImportReport = { created_genres: number, created_dependencies: number, 
+/**
+ * Edges that already existed in the project
+ */
+skipped_duplicates: number, 
+/**
+ * Edges left out because they would close a cycle
+ */
+skipped_cycles: Array<ExportedDependency>, 
+/**
+ * Titles with no task in the project; edges referencing them are skipped
+ */
+unmatched_titles: Array<string>, 
+/**
+ * Titles shared by several tasks in the project; edges referencing them are skipped
+ */
+ambiguous_titles: Array<string>, };
+
+export type GraphCloneReport = { 
+/**
+ * Source task id to the id of the task created for it in the target project
+ */
+task_ids: { [key in string]?: string }, created_genres: number, created_dependencies: number, 
+/**
+ * Edges left out because they would close a cycle in the target project
+ */
+skipped_cycles: number, };
+
 export type DraftFollowUpData = { message: string, variant: string | null, };
 
 export type DraftWorkspaceData = { message: string, project_id: string | null, repos: Array<DraftWorkspaceRepo>, selected_profile: ExecutorProfileId | null, };
@@ -130,11 +184,23 @@ export type MergeStatus = "open" | "merged" | "closed" | "unknown";
 
 export type PullRequestInfo = { number: bigint, url: string, status: MergeStatus, merged_at: string | null, merge_commit_sha: string | null, };
 
-export type GitHubProjectLink = { id: string, project_id: string, github_project_id: string, github_owner: string, github_repo: string | null, github_project_number: bigint | null, sync_enabled: boolean, last_sync_at: string | null, created_at: string, updated_at: string, };
+export type GitHubProjectLink = { id: string, project_id: string, github_project_id: string, github_owner: string, github_repo: string | null, github_project_number: bigint | null, 
+/**
+ * GitHub Enterprise hostname; `None` targets github.com
+ */
+github_host: string | null, 
+/**
+ * Only issues carrying this label (case-insensitive) are synced; `None` syncs every issue
+ */
+label_filter: string | null, sync_enabled: boolean, last_sync_at: string | null, created_at: string, updated_at: string, };
 
-export type CreateGitHubProjectLink = { project_id: string, github_project_id: string, github_owner: string, github_repo: string | null, github_project_number: bigint | null, };
+export type CreateGitHubProjectLink = { project_id: string, github_project_id: string, github_owner: string, github_repo: string | null, github_project_number: bigint | null, github_host: string | null, label_filter: string | null, };
 
-export type GitHubIssueMapping = { id: string, task_id: string, github_project_link_id: string, github_issue_number: bigint, github_issue_id: string, github_issue_url: string, sync_direction: SyncDirection, last_synced_at: string | null, github_updated_at: string | null, vibe_updated_at: string | null, created_at: string, updated_at: string, };
+export type GitHubIssueMapping = { id: string, task_id: string, github_project_link_id: string, github_issue_number: bigint, github_issue_id: string, github_issue_url: string, sync_direction: SyncDirection, last_synced_at: string | null, github_updated_at: string | null, vibe_updated_at: string | null, 
+/**
+ * The issue was no longer in the GitHub Project at the last sync
+ */
+stale: boolean, created_at: string, updated_at: string, };
 
 export type CreateGitHubIssueMapping = { task_id: string, github_project_link_id: string, github_issue_number: bigint, github_issue_id: string, github_issue_url: string, sync_direction: SyncDirection | null, };
 
@@ -144,6 +210,8 @@ export type TaskProperty = { id: string, task_id: string, property_name: string,
 
 export type CreateTaskProperty = { task_id: string, property_name: string, property_value: string, source: PropertySource | null, };
 
+export type TaskPropertyChange = { id: string, task_id: string, property_name: string, old_value: string, new_value: string, source: PropertySource, changed_at: string, };
+
 export type PropertySource = "vibe" | "github";
 
 export type ApprovalStatus = { "status": "pending" } | { "status": "approved" } | { "status": "denied", reason?: string, } | { "status": "timed_out" };
@@ -284,24 +352,189 @@ export type ShareTaskResponse = { shared_task_id: string, };
 
 export type CreateAndStartTaskRequest = { task: CreateTask, executor_profile_id: ExecutorProfileId, repos: Array<WorkspaceRepoInput>, };
 
-export type CreateDependencyRequest = { task_id: string, depends_on_task_id: string, created_by: DependencyCreator | null, genre_id: string | null, };
+export type CreateDependencyRequest = { task_id: string, depends_on_task_id: string, created_by: DependencyCreator | null, genre_id: string | null, kind: DependencyKind | null, 
+/**
+ * Short annotation drawn on the edge; blank means none
+ */
+label: string | null, };
+
+export type CloneGraphRequest = { target_project_id: string, };
+
+export type UpdateDependencyRequest = { genre_id: string | null | null, 
+/**
+ * New edge label; a blank string clears it
+ */
+label: string | null | null, };
 
-export type UpdateDependencyRequest = { genre_id: string | null | null, };
+export type UpdateDependencyKindRequest = { kind: DependencyKind, };
 
 export type UpdatePositionRequest = { position: number, };
 
+export type SubtreeDirection = "ancestors" | "descendants" | "both";
+
+export type SubtreeQuery = { direction: SubtreeDirection, 
+/**
+ * Error message language; falls back to the Accept-Language header, then Japanese
+ */
+lang: ExplanationLanguage | null, };
+
+export type ListDependenciesQuery = { 
+/**
+ * Only return dependencies created by a user or inferred by AI
+ */
+createdBy: DependencyCreator | null, 
+/**
+ * Only return dependencies of this genre
+ */
+genreId: string | null, 
+/**
+ * Only return dependencies created at or after this time
+ */
+since: string | null, };
+
+export type BlockedByQuery = { candidateId: string, 
+/**
+ * Error message language; falls back to the Accept-Language header, then Japanese
+ */
+lang: ExplanationLanguage | null, };
+
+export type ReadinessExplanationQuery = { 
+/**
+ * Summary language; falls back to the Accept-Language header, then Japanese
+ */
+lang: ExplanationLanguage | null, };
+
+export type LanguageQuery = { 
+/**
+ * Message language; falls back to the Accept-Language header, then Japanese
+ */
+lang: ExplanationLanguage | null, };
+
+export type DeleteDependenciesQuery = { taskId: string | null, dependsOnTaskId: string | null, createdBy: DependencyCreator | null, 
+/**
+ * Error message language; falls back to the Accept-Language header, then Japanese
+ */
+lang: ExplanationLanguage | null, };
+
+export type TaskSubtreeResponse = { task_id: string, 
+/**
+ * Tasks this task depends on, nearest first
+ */
+ancestors: Array<TaskDepth>, 
+/**
+ * Tasks depending on this task, nearest first
+ */
+descendants: Array<TaskDepth>, };
+
 export type CreateGenreRequest = { name: string, color: string | null, position: number | null, };
 
 export type UpdateGenreRequest = { name: string | null, color: string | null, position: number | null, };
 
 export type ReorderGenresApiRequest = { genre_ids: Array<string>, };
 
-export type OrchestratorStateResponse = { state: OrchestratorState, plan: ExecutionPlan, };
+export type MergeGenresRequest = { into: string, };
+
+export type ListGenresQuery = { includeArchived: boolean, };
+
+export type OnReferenced = "block" | "clear";
+
+export type DeleteGenreQuery = { onReferenced: OnReferenced, };
+
+export type OrchestratorStateResponse = { state: OrchestratorState, auto_start: boolean, plan: ExecutionPlan, };
+
+export type OrchestratorStateQuery = { 
+/**
+ * Restrict the plan to dependencies of this genre
+ */
+genreId: string | null, 
+/**
+ * Only include tasks assigned to this GitHub login
+ */
+assignee: string | null, 
+/**
+ * How tasks are grouped into levels; `capped` limits each level to the parallel limit
+ */
+mode: PlanMode, 
+/**
+ * Merge adjacent levels that fit within the parallel limit and don't depend on each other
+ */
+compact: boolean, };
+
+export type ReadyTasksQuery = { 
+/**
+ * Return the tasks that would start even when the orchestrator is not running
+ */
+preview: boolean, };
+
+export type TasksByReadinessQuery = { readiness: ReadinessFilter, };
 
-export type ValidateTransitionRequest = { task_id: string, new_status: string, };
+export type AtRiskTasksQuery = { 
+/**
+ * Seconds in progress before a task counts as stale; defaults to the task timeout, then a day
+ */
+stale_after_secs: number | null, };
+
+export type RecentEventsQuery = { 
+/**
+ * Maximum number of events to return, newest last; all retained events when omitted
+ */
+limit: number | null, };
+
+export type PlanDiffQuery = { 
+/**
+ * Epoch of the plan version the client currently has
+ */
+epoch: string, 
+/**
+ * Plan version the client currently has
+ */
+since: number, };
+
+export type PlanDiffResponse = { 
+/**
+ * Epoch of the current plan, to pass as `epoch` next time
+ */
+epoch: string, 
+/**
+ * Version of the current plan, to pass as `since` next time
+ */
+version: number, diff: PlanDiff | null, 
+/**
+ * Only set when `diff` is None
+ */
+plan: ExecutionPlan | null, };
+
+export type SetAutoStartRequest = { enabled: boolean, };
+
+export type SetTaskTimeoutRequest = { 
+/**
+ * `null` disables the timeout check
+ */
+timeout_secs: number | null, };
+
+export type TransitionLanguageQuery = { 
+/**
+ * Reason language; falls back to the Accept-Language header, then English, which these
+ * endpoints returned before reasons were localized
+ */
+lang: ExplanationLanguage | null, };
+
+export type ValidateTransitionRequest = { task_id: string, new_status: string, 
+/**
+ * Force through a transition the state machine would reject (manual intervention)
+ */
+allow_override: boolean, };
 
 export type TaskFailedRequest = { error: string, };
 
+export type BulkTransitionRequest = { taskIds: Array<string>, newStatus: string, 
+/**
+ * Also apply transitions that need confirmation, e.g. starting a task with open dependencies
+ */
+confirm: boolean, };
+
+export type ForecastUnblockedRequest = { taskIds: Array<string>, };
+
 export type ExecutionPlan = { 
 /**
  * All tasks grouped by execution level (tasks in same level can run in parallel)
@@ -330,11 +563,81 @@ ready_tasks: number,
 /**
  * Number of tasks blocked by dependencies
  */
-blocked_tasks: number, };
+blocked_tasks: number, 
+/**
+ * Number of tasks put on hold with the `Blocked` status
+ */
+on_hold_tasks: number, 
+/**
+ * Overall completion (0-100) over non-cancelled tasks; 0 when there is nothing to do
+ */
+progress_percent: number, 
+/**
+ * Completion (0-100) of each level, in the same order as `levels`
+ */
+level_progress: Array<number>, 
+/**
+ * Length of the longest dependency chain, i.e. the number of levels minus one
+ */
+max_depth: number, };
+
+export type TopologicalOrder = { 
+/**
+ * Tasks ordered so that every task comes after all of its prerequisites
+ */
+order: Array<string>, 
+/**
+ * Tasks left out of `order` because they are in a dependency cycle or depend on one
+ */
+cyclic: Array<string>, };
+
+export type GanttEntry = { task_id: string, start_minutes: number, end_minutes: number, 
+/**
+ * Whether the duration came from the task's estimate rather than the default
+ */
+estimated: boolean, 
+/**
+ * Prerequisites drawn as arrows into this bar
+ */
+dependencies: Array<string>, };
+
+export type GanttChart = { 
+/**
+ * Bars in dependency order
+ */
+entries: Array<GanttEntry>, 
+/**
+ * When the last task is projected to finish
+ */
+total_minutes: number, };
+
+export type PlanDiff = { 
+/**
+ * Tasks only in the newer plan
+ */
+added_tasks: Array<string>, 
+/**
+ * Tasks only in the older plan
+ */
+removed_tasks: Array<string>, readiness_changes: Array<ReadinessTransition>, level_changes: Array<LevelChange>, };
+
+export type ReadinessTransition = { task_id: string, from: TaskReadiness, to: TaskReadiness, };
+
+export type LevelChange = { task_id: string, from: number, to: number, };
+
+export type VersionedPlan = { 
+/**
+ * Changes whenever the project's orchestrator is reset or recreated
+ */
+epoch: string, version: number, plan: ExecutionPlan, };
 
 export type ExecutionLevel = { level: number, tasks: Array<ExecutableTask>, };
 
-export type ExecutableTask = { task_id: string, status: TaskStatus, readiness: TaskReadiness, 
+export type ExecutableTask = { task_id: string, status: TaskStatus, 
+/**
+ * Kind of work the task represents, e.g. feature or bug
+ */
+task_type: TaskType, readiness: TaskReadiness, 
 /**
  * Tasks that must complete before this task can start
  */
@@ -342,15 +645,111 @@ dependencies: Array<string>,
 /**
  * Tasks that depend on this task
  */
-dependents: Array<string>, };
+dependents: Array<string>, 
+/**
+ * Scheduling rank within the plan (lower starts first): by position, then creation time
+ */
+priority: number, 
+/**
+ * When the orchestrator first saw the task as ready; `None` unless it is ready now
+ */
+ready_since: string | null, 
+/**
+ * GitHub login of the task's first assignee, from its `github_assignees` property
+ */
+assigned_to: string | null, 
+/**
+ * Excluded from scheduling by the user; the task keeps its status and readiness
+ */
+skipped: boolean, };
+
+export type TaskReadiness = "ready" | { "blocked": { blocking_task_ids: Array<string>, } } | "in_progress" | "on_hold" | "completed" | "cancelled";
+
+export type TaskDepth = { task_id: string, depth: number, };
+
+export type BlockedTask = { task_id: string, 
+/**
+ * Distance from the task the walk started at
+ */
+depth: number, blocking_task_ids: Array<string>, };
+
+export type OrchestratorMessage = { "code": "invalid_transition", "args": { from: TaskStatus, to: TaskStatus, } } | { "code": "incomplete_dependencies", "args": { count: number, } } | { "code": "duplicate_task" } | { "code": "task_not_found", "args": { task_id: string, } } | { "code": "depends_on_task_not_found", "args": { task_id: string, } } | { "code": "dependency_not_found", "args": { dependency_id: string, } } | { "code": "dependency_pair_not_found" } | { "code": "dependency_delete_failed" } | { "code": "self_dependency" } | { "code": "task_not_in_project" } | { "code": "task_id_not_in_project", "args": { task_id: string, } } | { "code": "depends_on_task_not_in_project" } | { "code": "dependency_exists" } | { "code": "cycle_detected", "args": { path: Array<string>, } } | { "code": "missing_delete_filter" } | { "code": "clone_into_self" } | { "code": "target_project_not_found" } | { "code": "duplicate_transition_rule", "args": { from: TaskStatus, to: TaskStatus, } } | { "code": "no_path_to_done" } | { "code": "invalid_status", "args": { status: string, } };
 
-export type TaskReadiness = "ready" | { "blocked": { blocking_task_ids: Array<string>, } } | "in_progress" | "completed" | "cancelled";
+export type TransitionValidation = { "type": "valid", forced: boolean, } | { "type": "invalid", code: OrchestratorMessage, reason: string, } | { "type": "requires_confirmation", code: OrchestratorMessage, reason: string, blocking_tasks: Array<string>, };
 
-export type TransitionValidation = { "type": "valid" } | { "type": "invalid", reason: string, } | { "type": "requires_confirmation", reason: string, blocking_tasks: Array<string>, };
+export type BulkTransitionResult = { task_id: string, validation: TransitionValidation, 
+/**
+ * Whether the task's status was changed
+ */
+applied: boolean, };
+
+export type StartReadiness = { "type": "ready" } | { "type": "wrong_status", current: TaskStatus, } | { "type": "blocked", blocking: Array<string>, };
+
+export type GraphValidationReport = { 
+/**
+ * Each cycle as the tasks along it; every task depends on the next, the last on the first
+ */
+cycles: Array<Array<string>>, 
+/**
+ * Dependencies whose task depends on itself
+ */
+self_dependencies: Array<string>, 
+/**
+ * Dependencies on a task outside the project
+ */
+cross_project_dependencies: Array<string>, 
+/**
+ * Tasks held back only by cancelled prerequisites, so they can never become ready
+ */
+blocked_by_cancelled: Array<BlockedByCancelled>, };
+
+export type BlockedByCancelled = { task_id: string, cancelled_task_ids: Array<string>, };
+
+export type ReadinessFilter = "ready" | "blocked" | "in_progress" | "completed";
+
+export type PlanMode = "levels" | "capped";
+
+export type ExplanationLanguage = "ja" | "en";
+
+export type DependencyExplanation = { task_id: string, title: string, status: TaskStatus, kind: DependencyKind, 
+/**
+ * Whether the dependency's kind is satisfied by its task's status
+ */
+satisfied: boolean, };
+
+export type ReadinessExplanation = { task_id: string, status: TaskStatus, readiness: TaskReadiness, dependencies: Array<DependencyExplanation>, summary: string, };
+
+export type StatusTransition = { from: TaskStatus, to: TaskStatus, };
+
+export type TransitionRuleSet = { allowed: Array<StatusTransition>, };
 
 export type OrchestratorState = "idle" | "running" | "paused" | "stopping";
 
-export type OrchestratorEvent = { "type": "task_started", "data": { task_id: string, } } | { "type": "task_completed", "data": { task_id: string, } } | { "type": "task_failed", "data": { task_id: string, error: string, } } | { "type": "task_awaiting_review", "data": { task_id: string, } } | { "type": "state_changed", "data": { state: OrchestratorState, } } | { "type": "plan_updated", "data": { plan: ExecutionPlan, } };
+export type OrchestratorMetrics = { state: OrchestratorState, total_tasks: number, ready_tasks: number, blocked_tasks: number, in_progress_tasks: number, in_review_tasks: number, completed_tasks: number, 
+/**
+ * Number of execution levels
+ */
+level_count: number, 
+/**
+ * Most tasks in a single level, i.e. the peak parallelism the graph allows
+ */
+max_level_width: number, 
+/**
+ * Tasks on the longest chain of unfinished dependencies
+ */
+critical_path_length: number, };
+
+export type WaitReason = "all_blocked" | "all_done" | "orchestrator_idle";
+
+export type UnblockingTask = { task_id: string, unblocks: Array<string>, };
+
+export type NextAction = { "type": "start", task_id: string, } | { "type": "wait", reason: WaitReason, unblocking_task: UnblockingTask | null, };
+
+export type RiskReason = { "type": "transitively_stuck", stuck_behind: Array<string>, } | { "type": "stale_in_progress", elapsed_secs: number, };
+
+export type AtRiskTask = { task_id: string, reason: RiskReason, };
+
+export type OrchestratorEvent = { "type": "task_started", "data": { task_id: string, } } | { "type": "task_completed", "data": { task_id: string, } } | { "type": "task_failed", "data": { task_id: string, error: string, } } | { "type": "task_awaiting_review", "data": { task_id: string, } } | { "type": "state_changed", "data": { state: OrchestratorState, } } | { "type": "plan_updated", "data": { plan: ExecutionPlan, } } | { "type": "auto_start_requested", "data": { task_ids: Array<string>, } } | { "type": "readiness_changed", "data": { task_id: string, from: TaskReadiness, to: TaskReadiness, } } | { "type": "deep_chain_warning", "data": { depth: number, } } | { "type": "task_timed_out", "data": { task_id: string, elapsed_secs: number, } };
 
 export type CreatePrApiRequest = { title: string, body: string | null, target_branch: string | null, draft: boolean | null, repo_id: string, auto_generate_description: boolean, };
 
@@ -522,7 +921,17 @@ export type ConflictOp = "rebase" | "merge" | "cherry_pick" | "revert";
 
 export type GitHubProject = { id: string, title: string, number: bigint, url: string, closed: boolean, shortDescription: string | null, public: boolean, ownerLogin: string, };
 
-export type GitHubIssue = { id: string, number: bigint, title: string, body: string | null, state: string, url: string, createdAt: string, updatedAt: string, closedAt: string | null, authorLogin: string | null, assignees: Array<string>, labels: Array<GitHubLabel>, milestone: GitHubMilestone | null, };
+export type GitHubContentType = "issue" | "pull_request";
+
+export type GitHubIssue = { id: string, number: bigint, title: string, body: string | null, 
+/**
+ * "OPEN" or "CLOSED" for issues; pull requests can also be "MERGED"
+ */
+state: string, contentType: GitHubContentType, 
+/**
+ * Whether the pull request was merged; always false for issues
+ */
+merged: boolean, url: string, createdAt: string, updatedAt: string, closedAt: string | null, authorLogin: string | null, assignees: Array<string>, labels: Array<GitHubLabel>, milestone: GitHubMilestone | null, };
 
 export type GitHubLabel = { name: string, color: string, };
 
@@ -538,11 +947,55 @@ export type ProjectFieldOption = { id: string, name: string, };
 
 export type StatusMapping = { vibe_status: TaskStatus, github_project_status: string, github_issue_state: string, };
 
-export type SyncResult = { itemsSynced: number, itemsCreated: number, itemsUpdated: number, itemsSkipped: number, errors: Array<string>, };
+export type SyncErrorKind = "cli_unavailable" | "auth" | "api" | "not_found" | "database" | "conflict" | "invalid_mapping";
+
+export type SyncError = { itemId: string | null, issueNumber: bigint | null, kind: SyncErrorKind, message: string, };
+
+export type SyncResult = { itemsSynced: number, itemsCreated: number, itemsUpdated: number, itemsSkipped: number, errors: Array<SyncError>, 
+/**
+ * Mappings whose issue is no longer in the GitHub Project; their tasks are kept
+ */
+removed: Array<GitHubIssueMapping>, };
+
+export type CreateGitHubLinkRequest = { githubProjectId: string, githubOwner: string, githubRepo: string | null, githubProjectNumber: bigint | null, 
+/**
+ * GitHub Enterprise hostname; omit for github.com
+ */
+githubHost: string | null, 
+/**
+ * Only import issues carrying this label; omit to import every issue
+ */
+labelFilter: string | null, };
+
+export type GitHubLinkResponse = { issueCount: number, id: string, project_id: string, github_project_id: string, github_owner: string, github_repo: string | null, github_project_number: bigint | null, 
+/**
+ * GitHub Enterprise hostname; `None` targets github.com
+ */
+github_host: string | null, 
+/**
+ * Only issues carrying this label (case-insensitive) are synced; `None` syncs every issue
+ */
+label_filter: string | null, sync_enabled: boolean, last_sync_at: string | null, created_at: string, updated_at: string, };
+
+export type SyncGitHubLinkQuery = { 
+/**
+ * Create tasks for closed issues that aren't mapped yet; defaults to true
+ */
+importClosed: boolean | null, };
 
-export type CreateGitHubLinkRequest = { githubProjectId: string, githubOwner: string, githubRepo: string | null, githubProjectNumber: bigint | null, };
+export type UnlinkGitHubMappingQuery = { 
+/**
+ * Also remove the GitHub-sourced properties that sync wrote on the task
+ */
+clearProperties: boolean, };
 
-export type GitHubLinkResponse = { issueCount: number, id: string, project_id: string, github_project_id: string, github_owner: string, github_repo: string | null, github_project_number: bigint | null, sync_enabled: boolean, last_sync_at: string | null, created_at: string, updated_at: string, };
+export type UpdateSyncDirectionRequest = { direction: SyncDirection, };
+
+export type GitHubStatusQuery = { 
+/**
+ * Run the `gh` checks again instead of using the cached result
+ */
+force: boolean, };
 
 export type GitHubStatusResponse = { available: boolean, authenticated: boolean, userLogin: string | null, error: string | null, };
 